@@ -0,0 +1,2 @@
+pub mod note_archive;
+pub mod note_import;
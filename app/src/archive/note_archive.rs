@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures_core::Stream;
+use std::pin::Pin;
+use uuid::Uuid;
+
+use common::error::AppError;
+use dmn::entities::note::Note;
+
+/// Note as written to, or read from, an archive file.
+#[derive(Debug, Clone)]
+pub struct ArchivedNote {
+    /// Identifier the note had when exported, informational only on import.
+    pub id: Option<Uuid>,
+    pub title: String,
+    pub content: String,
+    /// Tag names.
+    pub tags: Vec<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Note> for ArchivedNote {
+    fn from(note: &Note) -> Self {
+        Self {
+            id: Some(note.id()),
+            title: note.title().to_string(),
+            content: note.content().to_string(),
+            tags: note
+                .tags()
+                .iter()
+                .map(|tag| tag.name().to_string())
+                .collect(),
+            created_at: Some(note.created_at()),
+            updated_at: Some(note.updated_at()),
+        }
+    }
+}
+
+/// Outcome of reading a single file of an archive.
+#[derive(Debug)]
+pub struct ArchiveEntry {
+    /// Path of the file within the archive.
+    pub file_name: String,
+    pub note: Result<ArchivedNote, AppError>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteArchiveFormat {
+    /// ZIP archive holding one Markdown file with front matter per note.
+    Markdown,
+}
+
+pub type ArchivedNoteStream = Pin<Box<dyn Stream<Item = Result<ArchivedNote, AppError>> + Send>>;
+
+/// Archive contents, in chunks.
+pub type NoteArchiveStream = Pin<Box<dyn Stream<Item = Result<Vec<u8>, AppError>> + Send>>;
+
+#[async_trait]
+pub trait NoteArchiveCodec: Send + Sync {
+    /// Packs the notes into an archive, produced as the notes are pulled from the stream.
+    fn write_archive(
+        &self,
+        format: NoteArchiveFormat,
+        notes: ArchivedNoteStream,
+    ) -> NoteArchiveStream;
+
+    /// Reads every note file of the archive, a malformed file failing on its own entry only.
+    async fn read_archive(
+        &self,
+        format: NoteArchiveFormat,
+        data: Vec<u8>,
+    ) -> Result<Vec<ArchiveEntry>, AppError>;
+}
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use super::note_archive::ArchivedNote;
+use common::error::AppError;
+use dmn::entities::note_import_job::NoteImportFormat;
+
+/// Outcome of reading a single note of an imported file.
+#[derive(Debug)]
+pub struct NoteImportEntry {
+    /// Where the note was found (e.g. file name within an archive, or position within the file).
+    pub item: String,
+    pub note: Result<ArchivedNote, AppError>,
+}
+
+#[async_trait]
+pub trait NoteImportReader: Send + Sync {
+    /// Reads every note of the file, a malformed note failing on its own entry only.
+    async fn read_notes(
+        &self,
+        format: NoteImportFormat,
+        data: Vec<u8>,
+    ) -> Result<Vec<NoteImportEntry>, AppError>;
+}
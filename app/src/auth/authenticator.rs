@@ -3,7 +3,7 @@ use uuid::Uuid;
 
 use crate::{
     auth::context::{AuthAccessContext, AuthRefreshContext},
-    types::auth_token::{AuthTokenPair, UniqueAccessTokenIdentifier},
+    types::auth_token::{AuthTokenPair, AuthTokenSession},
 };
 use common::error::AppError;
 
@@ -12,7 +12,7 @@ pub trait Authenticator: Send + Sync {
     async fn generate_auth_tokens(
         &self,
         user_id: Uuid,
-        revoke_token_pair_id: Option<UniqueAccessTokenIdentifier>,
+        session: AuthTokenSession,
     ) -> Result<AuthTokenPair, AppError>;
 
     async fn authenticate_access_token(&self, token: String)
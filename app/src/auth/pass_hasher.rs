@@ -4,4 +4,16 @@ pub trait PasswordHasher: Send + Sync {
     fn hash(&self, password: &str) -> Result<String, AppError>;
 
     fn verify(&self, password: &str, password_hash: &str) -> Result<bool, AppError>;
+
+    /// Whether the hash was produced by this hasher's algorithm, e.g. judging by its prefix.
+    fn supports(&self, password_hash: &str) -> bool {
+        let _ = password_hash;
+        true
+    }
+
+    /// Whether the hash should be replaced by a fresh one, e.g. as it uses outdated parameters.
+    fn needs_rehash(&self, password_hash: &str) -> bool {
+        let _ = password_hash;
+        false
+    }
 }
@@ -5,13 +5,15 @@ use dmn::entities::user::UniqueUserIdentifier;
 
 #[async_trait]
 pub trait PasswordService: Send + Sync {
-    fn hash(&self, password: &str) -> Result<String, AppError>;
+    async fn hash(&self, password: &str) -> Result<String, AppError>;
 
+    /// May transparently upgrade the stored hash once the password is verified.
     async fn verify_password(
         &self,
         user_id: &UniqueUserIdentifier,
         password: &str,
     ) -> Result<(), AppError>;
 
-    fn validate_password_strength(&self, password: &str) -> Result<(), AppError>;
+    /// Lists every violated rule within a `BadRequest` error's details.
+    fn validate_password_strength(&self, password: &str, email: &str) -> Result<(), AppError>;
 }
@@ -1,7 +1,9 @@
-use crate::types::auth_token::JsonWebTokenData;
+use crate::types::auth_token::{JsonWebKey, JsonWebTokenData};
 use common::error::AppError;
 
 pub trait TokenAdapter: Send + Sync {
     fn encode(&self, token: JsonWebTokenData) -> Result<String, AppError>;
     fn decode(&self, token: &str) -> Result<JsonWebTokenData, AppError>;
+    /// Keys third parties may verify tokens with, i.e. none for symmetric signing.
+    fn public_keys(&self) -> Vec<JsonWebKey>;
 }
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use common::error::AppError;
+use dmn::events::DomainEvent;
+
+/// Reacts to committed [`DomainEvent`]s.<br />
+/// Delivery is at least once, so handling the same event again has to be harmless. Events are not
+/// redelivered to handlers once they succeeded though, even if other handlers failed.
+#[async_trait]
+pub trait DomainEventHandler: Send + Sync {
+    /// Identifies the handler among the ones done with an event, so it has to stay the same across
+    /// releases.
+    fn name(&self) -> &'static str;
+
+    /// `event_id` stays the same across redeliveries.<br />
+    /// Failures get the event redelivered later.
+    async fn handle(&self, event_id: Uuid, event: &DomainEvent) -> Result<(), AppError>;
+}
@@ -0,0 +1,3 @@
+pub mod domain_events;
+pub mod note_events;
+pub mod webhook_events;
@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use futures_core::Stream;
+use std::{pin::Pin, sync::Arc};
+use uuid::Uuid;
+
+use crate::events::domain_events::DomainEventHandler;
+use common::error::AppError;
+use dmn::events::DomainEvent;
+
+/// Change notification about a note, delivered to its owner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteEvent {
+    pub kind: NoteEventKind,
+    pub note_id: Uuid,
+    pub user_id: Uuid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteEventKind {
+    Created,
+    Updated,
+    /// The note was moved to the trash.
+    Deleted,
+}
+
+impl NoteEvent {
+    /// Note events are derived from the domain events about notes.
+    pub fn from_domain_event(event: &DomainEvent) -> Option<Self> {
+        let (kind, note_id, user_id) = match *event {
+            DomainEvent::NoteCreated { note_id, user_id } => {
+                (NoteEventKind::Created, note_id, user_id)
+            }
+            DomainEvent::NoteUpdated { note_id, user_id } => {
+                (NoteEventKind::Updated, note_id, user_id)
+            }
+            DomainEvent::NoteDeleted { note_id, user_id } => {
+                (NoteEventKind::Deleted, note_id, user_id)
+            }
+            _ => return None,
+        };
+        Some(Self {
+            kind,
+            note_id,
+            user_id,
+        })
+    }
+}
+
+pub type NoteEventStream = Pin<Box<dyn Stream<Item = NoteEvent> + Send>>;
+
+#[async_trait]
+pub trait NoteEventPublisher: Send + Sync {
+    async fn publish(&self, event: NoteEvent) -> Result<(), AppError>;
+}
+
+#[async_trait]
+pub trait NoteEventSubscriber: Send + Sync {
+    /// Streams events of the user's notes published from now on, by any instance.
+    ///
+    /// The stream ends when events were missed, subscribers have to catch up on the note changes.
+    async fn subscribe(&self, user_id: Uuid) -> Result<NoteEventStream, AppError>;
+}
+
+/// Publishes the note events matching the dispatched domain events.
+pub struct NoteEventRelay {
+    note_event_publisher: Arc<dyn NoteEventPublisher + Send + Sync>,
+}
+
+impl NoteEventRelay {
+    pub fn new(note_event_publisher: Arc<dyn NoteEventPublisher + Send + Sync>) -> Self {
+        Self {
+            note_event_publisher,
+        }
+    }
+}
+
+#[async_trait]
+impl DomainEventHandler for NoteEventRelay {
+    fn name(&self) -> &'static str {
+        "note_events"
+    }
+
+    async fn handle(&self, _event_id: Uuid, event: &DomainEvent) -> Result<(), AppError> {
+        match NoteEvent::from_domain_event(event) {
+            Some(note_event) => self.note_event_publisher.publish(note_event).await,
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::events::domain_events::DomainEventHandler;
+use common::{
+    error::AppError,
+    tx::{TransactionResult, UnitOfWork},
+};
+use dmn::{
+    entities::{webhook::WebhookEventType, webhook_delivery::CreateWebhookDeliveryData},
+    events::DomainEvent,
+    repos::{webhook::WebhookRepository, webhook_delivery::WebhookDeliveryRepository},
+};
+
+/// Schedules a delivery of the dispatched domain events to every subscribed webhook of the user.
+pub struct WebhookEventRelay {
+    unit_of_work: Arc<dyn UnitOfWork>,
+    webhook_repo: Arc<dyn WebhookRepository + Send + Sync>,
+    webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository + Send + Sync>,
+}
+
+impl WebhookEventRelay {
+    pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        webhook_repo: Arc<dyn WebhookRepository + Send + Sync>,
+        webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            unit_of_work,
+            webhook_repo,
+            webhook_delivery_repo,
+        }
+    }
+}
+
+#[async_trait]
+impl DomainEventHandler for WebhookEventRelay {
+    fn name(&self) -> &'static str {
+        "webhooks"
+    }
+
+    async fn handle(&self, event_id: Uuid, event: &DomainEvent) -> Result<(), AppError> {
+        let Some(event_type) = WebhookEventType::from_domain_event(event) else {
+            return Ok(());
+        };
+        let user_id = event.user_id();
+        let note_id = event.note_id();
+
+        let webhook_repo = self.webhook_repo.clone();
+        let webhook_delivery_repo = self.webhook_delivery_repo.clone();
+        self.unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    let webhooks = webhook_repo.get_webhooks(Some(ctx), Some(user_id)).await?;
+                    for webhook in webhooks {
+                        if !webhook.accepts(event_type) {
+                            continue;
+                        }
+                        let delivery_data = CreateWebhookDeliveryData {
+                            webhook_id: webhook.id(),
+                            event_id,
+                            event_type,
+                            note_id,
+                            next_attempt_at: Some(Utc::now()),
+                        };
+                        // Redelivered events find their deliveries already scheduled
+                        webhook_delivery_repo
+                            .create_webhook_delivery(Some(ctx), delivery_data)
+                            .await?;
+                    }
+                    Ok(TransactionResult::new(()))
+                })
+            }))
+            .await?;
+        Ok(())
+    }
+}
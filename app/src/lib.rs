@@ -1,5 +1,9 @@
+pub mod archive;
 pub mod auth;
+pub mod events;
 pub mod repos;
 pub mod state;
+pub mod storage;
 pub mod types;
 pub mod usecases;
+pub mod webhooks;
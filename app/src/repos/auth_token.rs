@@ -1,9 +1,10 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::types::auth_token::{
-    AccessToken, AuthTokenPair, RefreshToken, UniqueAccessTokenIdentifier,
-    UniqueRefreshTokenIdentifier,
+    AccessToken, AuthClientInfo, AuthSession, AuthTokenPair, RefreshToken, RevokedRefreshToken,
+    UniqueAccessTokenIdentifier, UniqueRefreshTokenIdentifier,
 };
 use common::{error::AppError, tx::ctx::TransactionContext};
 
@@ -44,4 +45,67 @@ pub trait AuthTokenRepository: Send + Sync {
         ctx: Option<&mut dyn TransactionContext>,
         token_id: &UniqueAccessTokenIdentifier,
     ) -> Result<(), AppError>;
+
+    /// Only returns sessions whose refresh token hasn't expired yet, most recently used first.
+    async fn get_user_sessions(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        user_id: Uuid,
+    ) -> Result<Vec<AuthSession>, AppError>;
+
+    async fn create_session(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        session: AuthSession,
+    ) -> Result<AuthSession, AppError>;
+
+    /// Records the session as used now, along with the client it was used from if provided.
+    async fn touch_session(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        session_id: Uuid,
+        client_info: Option<AuthClientInfo>,
+    ) -> Result<(), AppError>;
+
+    /// Revokes the session's token pair along with it.<br />
+    /// If `user_id` is provided, only deletes the session if it belongs to that user.
+    async fn delete_session(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        session_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<(), AppError>;
+
+    /// Revokes every session of the user, returning how many were deleted.
+    async fn delete_user_sessions(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        user_id: Uuid,
+    ) -> Result<u64, AppError>;
+
+    async fn get_revoked_refresh_token(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        token_id: Uuid,
+    ) -> Result<RevokedRefreshToken, AppError>;
+
+    async fn create_revoked_refresh_token(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        revoked_token: RevokedRefreshToken,
+    ) -> Result<RevokedRefreshToken, AppError>;
+
+    /// Forgets the revoked refresh tokens which can't be reused anymore, returning how many were deleted.
+    async fn delete_expired_revoked_refresh_tokens(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        expired_before: DateTime<Utc>,
+    ) -> Result<u64, AppError>;
+
+    /// Revokes the sessions holding a refresh token of the family, returning how many were deleted.
+    async fn delete_token_family(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        family_id: Uuid,
+    ) -> Result<u64, AppError>;
 }
@@ -1,80 +1,493 @@
 use std::sync::Arc;
 
 use crate::{
-    auth::{authenticator::Authenticator, pass_service::PasswordService},
-    usecases::{auth, note, user},
+    archive::{note_archive::NoteArchiveCodec, note_import::NoteImportReader},
+    auth::{
+        authenticator::Authenticator, pass_service::PasswordService, token_adapter::TokenAdapter,
+    },
+    events::note_events::NoteEventSubscriber,
+    repos::auth_token::AuthTokenRepository,
+    storage::blob_store::BlobStore,
+    usecases::{
+        attachment, auth, note, note_import_job, note_public_link, note_share, notebook, tag, user,
+        webhook,
+    },
+    webhooks::webhook_sender::WebhookSender,
+};
+use common::tx::UnitOfWork;
+use dmn::repos::{
+    attachment::AttachmentRepository, note::NoteRepository,
+    note_import_job::NoteImportJobRepository, note_public_link::NotePublicLinkRepository,
+    note_revision::NoteRevisionRepository, note_share::NoteShareRepository,
+    notebook::NotebookRepository, outbox::OutboxRepository, tag::TagRepository,
+    user::UserRepository, webhook::WebhookRepository, webhook_delivery::WebhookDeliveryRepository,
 };
-use dmn::repos::{note::NoteRepository, user::UserRepository};
 
 #[derive(Clone)]
 pub struct AppState {
     // Authenticator
     pub authenticator: Arc<dyn Authenticator + Send + Sync>,
+    // Attachment Use Cases
+    pub delete_attachment_use_case: attachment::DeleteAttachmentUseCase,
+    pub download_attachment_use_case: attachment::DownloadAttachmentUseCase,
+    pub get_attachments_use_case: attachment::GetAttachmentsUseCase,
+    pub purge_orphaned_attachment_blobs_use_case: attachment::PurgeOrphanedAttachmentBlobsUseCase,
+    pub upload_attachment_use_case: attachment::UploadAttachmentUseCase,
     // Authentication Use Cases
     pub change_user_pass_use_case: auth::ChangeUserPasswordUseCase,
     pub auth_login_use_case: auth::AuthLoginUseCase,
+    pub auth_logout_use_case: auth::AuthLogoutUseCase,
+    pub auth_logout_all_use_case: auth::AuthLogoutAllUseCase,
     pub auth_refresh_use_case: auth::AuthRefreshUseCase,
+    pub get_auth_sessions_use_case: auth::GetAuthSessionsUseCase,
+    pub get_json_web_keys_use_case: auth::GetJsonWebKeysUseCase,
+    pub purge_revoked_refresh_tokens_use_case: auth::PurgeRevokedRefreshTokensUseCase,
+    pub revoke_auth_session_use_case: auth::RevokeAuthSessionUseCase,
     // Note Use Cases
+    pub bulk_delete_notes_use_case: note::BulkDeleteNotesUseCase,
+    pub bulk_move_notes_use_case: note::BulkMoveNotesUseCase,
+    pub bulk_note_operations_use_case: note::BulkNoteOperationsUseCase,
+    pub bulk_tag_notes_use_case: note::BulkTagNotesUseCase,
     pub create_note_use_case: note::CreateNoteUseCase,
     pub delete_note_use_case: note::DeleteNoteUseCase,
+    pub empty_trash_use_case: note::EmptyTrashUseCase,
+    pub export_notes_use_case: note::ExportNotesUseCase,
     pub get_note_use_case: note::GetNoteUseCase,
+    pub get_note_changes_use_case: note::GetNoteChangesUseCase,
+    pub get_note_revision_use_case: note::GetNoteRevisionUseCase,
+    pub get_note_revisions_use_case: note::GetNoteRevisionsUseCase,
     pub get_notes_use_case: note::GetNotesUseCase,
+    pub get_trashed_notes_use_case: note::GetTrashedNotesUseCase,
+    pub import_notes_use_case: note::ImportNotesUseCase,
+    pub move_note_use_case: note::MoveNoteUseCase,
+    pub patch_note_use_case: note::PatchNoteUseCase,
+    pub purge_expired_notes_use_case: note::PurgeExpiredNotesUseCase,
+    pub purge_note_use_case: note::PurgeNoteUseCase,
+    pub restore_note_use_case: note::RestoreNoteUseCase,
+    pub restore_note_revision_use_case: note::RestoreNoteRevisionUseCase,
+    pub search_notes_use_case: note::SearchNotesUseCase,
+    pub set_note_flag_use_case: note::SetNoteFlagUseCase,
+    pub subscribe_note_events_use_case: note::SubscribeNoteEventsUseCase,
     pub update_note_use_case: note::UpdateNoteUseCase,
+    // Note Import Job Use Cases
+    pub create_note_import_job_use_case: note_import_job::CreateNoteImportJobUseCase,
+    pub get_note_import_job_use_case: note_import_job::GetNoteImportJobUseCase,
+    pub process_note_import_job_use_case: note_import_job::ProcessNoteImportJobUseCase,
+    // Note Public Link Use Cases
+    pub create_note_public_link_use_case: note_public_link::CreateNotePublicLinkUseCase,
+    pub get_note_public_link_use_case: note_public_link::GetNotePublicLinkUseCase,
+    pub get_public_note_use_case: note_public_link::GetPublicNoteUseCase,
+    pub revoke_note_public_link_use_case: note_public_link::RevokeNotePublicLinkUseCase,
+    // Note Share Use Cases
+    pub get_note_shares_use_case: note_share::GetNoteSharesUseCase,
+    pub get_shared_notes_use_case: note_share::GetSharedNotesUseCase,
+    pub revoke_note_share_use_case: note_share::RevokeNoteShareUseCase,
+    pub share_note_use_case: note_share::ShareNoteUseCase,
+    pub update_note_share_use_case: note_share::UpdateNoteShareUseCase,
+    // Notebook Use Cases
+    pub create_notebook_use_case: notebook::CreateNotebookUseCase,
+    pub delete_notebook_use_case: notebook::DeleteNotebookUseCase,
+    pub get_notebook_use_case: notebook::GetNotebookUseCase,
+    pub get_notebooks_use_case: notebook::GetNotebooksUseCase,
+    pub update_notebook_use_case: notebook::UpdateNotebookUseCase,
+    // Tag Use Cases
+    pub attach_note_tag_use_case: tag::AttachNoteTagUseCase,
+    pub create_tag_use_case: tag::CreateTagUseCase,
+    pub delete_tag_use_case: tag::DeleteTagUseCase,
+    pub detach_note_tag_use_case: tag::DetachNoteTagUseCase,
+    pub get_tags_use_case: tag::GetTagsUseCase,
+    pub rename_tag_use_case: tag::RenameTagUseCase,
     // User Use Cases
     pub delete_self_user_use_case: user::DeleteSelfUserUseCase,
     pub get_self_user_use_case: user::GetSelfUserUseCase,
     pub register_user_use_case: user::RegisterUserUseCase,
     pub update_self_user_use_case: user::UpdateSelfUserUseCase,
+    // Webhook Use Cases
+    pub create_webhook_use_case: webhook::CreateWebhookUseCase,
+    pub delete_webhook_use_case: webhook::DeleteWebhookUseCase,
+    pub deliver_webhooks_use_case: webhook::DeliverWebhooksUseCase,
+    pub get_webhook_use_case: webhook::GetWebhookUseCase,
+    pub get_webhook_deliveries_use_case: webhook::GetWebhookDeliveriesUseCase,
+    pub get_webhooks_use_case: webhook::GetWebhooksUseCase,
+    pub send_webhook_test_event_use_case: webhook::SendWebhookTestEventUseCase,
+    pub update_webhook_use_case: webhook::UpdateWebhookUseCase,
 }
 
 impl AppState {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         authenticator: Arc<dyn Authenticator + Send + Sync>,
+        token_adapter: Arc<dyn TokenAdapter + Send + Sync>,
         pass_service: Arc<dyn PasswordService + Send + Sync>,
+        unit_of_work: Arc<dyn UnitOfWork>,
+        blob_store: Arc<dyn BlobStore + Send + Sync>,
+        note_archive_codec: Arc<dyn NoteArchiveCodec + Send + Sync>,
+        note_import_reader: Arc<dyn NoteImportReader + Send + Sync>,
+        note_event_subscriber: Arc<dyn NoteEventSubscriber + Send + Sync>,
+        webhook_sender: Arc<dyn WebhookSender + Send + Sync>,
+        attachment_repo: Arc<dyn AttachmentRepository + Send + Sync>,
+        auth_token_repo: Arc<dyn AuthTokenRepository + Send + Sync>,
         note_repo: Arc<dyn NoteRepository + Send + Sync>,
+        note_import_job_repo: Arc<dyn NoteImportJobRepository + Send + Sync>,
+        note_public_link_repo: Arc<dyn NotePublicLinkRepository + Send + Sync>,
+        note_revision_repo: Arc<dyn NoteRevisionRepository + Send + Sync>,
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+        notebook_repo: Arc<dyn NotebookRepository + Send + Sync>,
+        outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+        tag_repo: Arc<dyn TagRepository + Send + Sync>,
         user_repo: Arc<dyn UserRepository + Send + Sync>,
+        webhook_repo: Arc<dyn WebhookRepository + Send + Sync>,
+        webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository + Send + Sync>,
     ) -> Self {
+        // Attachment Use Cases
+        let delete_attachment_use_case = attachment::DeleteAttachmentUseCase::new(
+            attachment_repo.clone(),
+            note_share_repo.clone(),
+        );
+        let download_attachment_use_case = attachment::DownloadAttachmentUseCase::new(
+            attachment_repo.clone(),
+            note_share_repo.clone(),
+            blob_store.clone(),
+        );
+        let get_attachments_use_case = attachment::GetAttachmentsUseCase::new(
+            attachment_repo.clone(),
+            note_share_repo.clone(),
+        );
+        let purge_orphaned_attachment_blobs_use_case =
+            attachment::PurgeOrphanedAttachmentBlobsUseCase::new(
+                attachment_repo.clone(),
+                blob_store.clone(),
+            );
+        let upload_attachment_use_case = attachment::UploadAttachmentUseCase::new(
+            unit_of_work.clone(),
+            attachment_repo.clone(),
+            note_share_repo.clone(),
+            blob_store.clone(),
+        );
         // Authentication Use Cases
-        let change_user_pass_use_case =
-            auth::ChangeUserPasswordUseCase::new(user_repo.clone(), pass_service.clone());
+        let change_user_pass_use_case = auth::ChangeUserPasswordUseCase::new(
+            unit_of_work.clone(),
+            outbox_repo.clone(),
+            user_repo.clone(),
+            pass_service.clone(),
+        );
         let auth_login_use_case = auth::AuthLoginUseCase::new(
             authenticator.clone(),
             user_repo.clone(),
             pass_service.clone(),
         );
+        let auth_logout_use_case = auth::AuthLogoutUseCase::new(auth_token_repo.clone());
+        let auth_logout_all_use_case = auth::AuthLogoutAllUseCase::new(auth_token_repo.clone());
         let auth_refresh_use_case = auth::AuthRefreshUseCase::new(authenticator.clone());
+        let get_auth_sessions_use_case = auth::GetAuthSessionsUseCase::new(auth_token_repo.clone());
+        let get_json_web_keys_use_case = auth::GetJsonWebKeysUseCase::new(token_adapter.clone());
+        let purge_revoked_refresh_tokens_use_case =
+            auth::PurgeRevokedRefreshTokensUseCase::new(auth_token_repo.clone());
+        let revoke_auth_session_use_case =
+            auth::RevokeAuthSessionUseCase::new(auth_token_repo.clone());
         // Note Use Cases
-        let create_note_use_case = note::CreateNoteUseCase::new(note_repo.clone());
-        let delete_note_use_case = note::DeleteNoteUseCase::new(note_repo.clone());
-        let get_note_use_case = note::GetNoteUseCase::new(note_repo.clone());
+        let bulk_delete_notes_use_case = note::BulkDeleteNotesUseCase::new(
+            unit_of_work.clone(),
+            note_repo.clone(),
+            note_share_repo.clone(),
+            outbox_repo.clone(),
+        );
+        let bulk_move_notes_use_case = note::BulkMoveNotesUseCase::new(
+            unit_of_work.clone(),
+            note_repo.clone(),
+            note_share_repo.clone(),
+            notebook_repo.clone(),
+            outbox_repo.clone(),
+        );
+        let bulk_note_operations_use_case = note::BulkNoteOperationsUseCase::new(
+            unit_of_work.clone(),
+            note_repo.clone(),
+            note_share_repo.clone(),
+            note_revision_repo.clone(),
+            notebook_repo.clone(),
+            outbox_repo.clone(),
+        );
+        let bulk_tag_notes_use_case = note::BulkTagNotesUseCase::new(
+            unit_of_work.clone(),
+            note_repo.clone(),
+            note_share_repo.clone(),
+            tag_repo.clone(),
+        );
+        let create_note_use_case = note::CreateNoteUseCase::new(
+            unit_of_work.clone(),
+            note_repo.clone(),
+            notebook_repo.clone(),
+            outbox_repo.clone(),
+        );
+        let delete_note_use_case = note::DeleteNoteUseCase::new(
+            unit_of_work.clone(),
+            note_repo.clone(),
+            note_share_repo.clone(),
+            outbox_repo.clone(),
+        );
+        let empty_trash_use_case = note::EmptyTrashUseCase::new(note_repo.clone());
+        let export_notes_use_case =
+            note::ExportNotesUseCase::new(note_repo.clone(), note_archive_codec.clone());
+        let get_note_use_case =
+            note::GetNoteUseCase::new(note_repo.clone(), note_share_repo.clone());
+        let get_note_changes_use_case = note::GetNoteChangesUseCase::new(note_repo.clone());
+        let get_note_revision_use_case = note::GetNoteRevisionUseCase::new(
+            note_repo.clone(),
+            note_share_repo.clone(),
+            note_revision_repo.clone(),
+        );
+        let get_note_revisions_use_case =
+            note::GetNoteRevisionsUseCase::new(note_share_repo.clone(), note_revision_repo.clone());
         let get_notes_use_case = note::GetNotesUseCase::new(note_repo.clone());
-        let update_note_use_case = note::UpdateNoteUseCase::new(note_repo.clone());
+        let get_trashed_notes_use_case = note::GetTrashedNotesUseCase::new(note_repo.clone());
+        let import_notes_use_case = note::ImportNotesUseCase::new(
+            unit_of_work.clone(),
+            note_repo.clone(),
+            notebook_repo.clone(),
+            outbox_repo.clone(),
+            tag_repo.clone(),
+            note_archive_codec.clone(),
+        );
+        let move_note_use_case = note::MoveNoteUseCase::new(
+            unit_of_work.clone(),
+            note_repo.clone(),
+            note_share_repo.clone(),
+            notebook_repo.clone(),
+            outbox_repo.clone(),
+        );
+        let patch_note_use_case = note::PatchNoteUseCase::new(
+            unit_of_work.clone(),
+            note_repo.clone(),
+            note_share_repo.clone(),
+            note_revision_repo.clone(),
+            outbox_repo.clone(),
+        );
+        let purge_expired_notes_use_case = note::PurgeExpiredNotesUseCase::new(note_repo.clone());
+        let purge_note_use_case = note::PurgeNoteUseCase::new(note_repo.clone());
+        let restore_note_use_case = note::RestoreNoteUseCase::new(
+            unit_of_work.clone(),
+            note_repo.clone(),
+            outbox_repo.clone(),
+        );
+        let restore_note_revision_use_case = note::RestoreNoteRevisionUseCase::new(
+            unit_of_work.clone(),
+            note_repo.clone(),
+            note_share_repo.clone(),
+            note_revision_repo.clone(),
+            outbox_repo.clone(),
+        );
+        let search_notes_use_case = note::SearchNotesUseCase::new(note_repo.clone());
+        let set_note_flag_use_case = note::SetNoteFlagUseCase::new(
+            unit_of_work.clone(),
+            note_repo.clone(),
+            note_share_repo.clone(),
+            outbox_repo.clone(),
+        );
+        let subscribe_note_events_use_case =
+            note::SubscribeNoteEventsUseCase::new(note_event_subscriber.clone());
+        let update_note_use_case = note::UpdateNoteUseCase::new(
+            unit_of_work.clone(),
+            note_repo.clone(),
+            note_share_repo.clone(),
+            note_revision_repo.clone(),
+            outbox_repo.clone(),
+        );
+        // Note Import Job Use Cases
+        let create_note_import_job_use_case = note_import_job::CreateNoteImportJobUseCase::new(
+            unit_of_work.clone(),
+            note_import_job_repo.clone(),
+            blob_store.clone(),
+        );
+        let get_note_import_job_use_case =
+            note_import_job::GetNoteImportJobUseCase::new(note_import_job_repo.clone());
+        let process_note_import_job_use_case = note_import_job::ProcessNoteImportJobUseCase::new(
+            unit_of_work.clone(),
+            note_repo.clone(),
+            notebook_repo.clone(),
+            note_import_job_repo.clone(),
+            outbox_repo.clone(),
+            tag_repo.clone(),
+            blob_store.clone(),
+            note_import_reader.clone(),
+        );
+        // Note Public Link Use Cases
+        let create_note_public_link_use_case = note_public_link::CreateNotePublicLinkUseCase::new(
+            note_public_link_repo.clone(),
+            note_share_repo.clone(),
+        );
+        let get_note_public_link_use_case = note_public_link::GetNotePublicLinkUseCase::new(
+            note_public_link_repo.clone(),
+            note_share_repo.clone(),
+        );
+        let get_public_note_use_case = note_public_link::GetPublicNoteUseCase::new(
+            note_repo.clone(),
+            note_public_link_repo.clone(),
+        );
+        let revoke_note_public_link_use_case = note_public_link::RevokeNotePublicLinkUseCase::new(
+            note_public_link_repo.clone(),
+            note_share_repo.clone(),
+        );
+        // Note Share Use Cases
+        let get_note_shares_use_case =
+            note_share::GetNoteSharesUseCase::new(note_share_repo.clone());
+        let get_shared_notes_use_case = note_share::GetSharedNotesUseCase::new(note_repo.clone());
+        let revoke_note_share_use_case =
+            note_share::RevokeNoteShareUseCase::new(note_share_repo.clone());
+        let share_note_use_case =
+            note_share::ShareNoteUseCase::new(note_share_repo.clone(), user_repo.clone());
+        let update_note_share_use_case =
+            note_share::UpdateNoteShareUseCase::new(note_share_repo.clone());
+        // Notebook Use Cases
+        let create_notebook_use_case = notebook::CreateNotebookUseCase::new(notebook_repo.clone());
+        let delete_notebook_use_case = notebook::DeleteNotebookUseCase::new(notebook_repo.clone());
+        let get_notebook_use_case = notebook::GetNotebookUseCase::new(notebook_repo.clone());
+        let get_notebooks_use_case = notebook::GetNotebooksUseCase::new(notebook_repo.clone());
+        let update_notebook_use_case =
+            notebook::UpdateNotebookUseCase::new(unit_of_work.clone(), notebook_repo.clone());
+        // Tag Use Cases
+        let attach_note_tag_use_case = tag::AttachNoteTagUseCase::new(
+            note_repo.clone(),
+            note_share_repo.clone(),
+            tag_repo.clone(),
+        );
+        let create_tag_use_case = tag::CreateTagUseCase::new(tag_repo.clone());
+        let delete_tag_use_case = tag::DeleteTagUseCase::new(tag_repo.clone());
+        let detach_note_tag_use_case = tag::DetachNoteTagUseCase::new(
+            note_repo.clone(),
+            note_share_repo.clone(),
+            tag_repo.clone(),
+        );
+        let get_tags_use_case = tag::GetTagsUseCase::new(tag_repo.clone());
+        let rename_tag_use_case = tag::RenameTagUseCase::new(tag_repo.clone());
         // User Use Cases
-        let delete_self_user_use_case =
-            user::DeleteSelfUserUseCase::new(user_repo.clone(), pass_service.clone());
+        let delete_self_user_use_case = user::DeleteSelfUserUseCase::new(
+            unit_of_work.clone(),
+            outbox_repo.clone(),
+            user_repo.clone(),
+            pass_service.clone(),
+        );
         let get_self_user_use_case = user::GetSelfUserUseCase::new();
-        let register_user_use_case =
-            user::RegisterUserUseCase::new(user_repo.clone(), pass_service.clone());
-        let update_self_user_use_case = user::UpdateSelfUserUseCase::new(user_repo.clone());
+        let register_user_use_case = user::RegisterUserUseCase::new(
+            unit_of_work.clone(),
+            outbox_repo.clone(),
+            user_repo.clone(),
+            pass_service.clone(),
+        );
+        let update_self_user_use_case = user::UpdateSelfUserUseCase::new(
+            unit_of_work.clone(),
+            outbox_repo.clone(),
+            user_repo.clone(),
+        );
+        // Webhook Use Cases
+        let create_webhook_use_case =
+            webhook::CreateWebhookUseCase::new(webhook_repo.clone(), webhook_sender.clone());
+        let delete_webhook_use_case = webhook::DeleteWebhookUseCase::new(webhook_repo.clone());
+        let deliver_webhooks_use_case = webhook::DeliverWebhooksUseCase::new(
+            webhook_repo.clone(),
+            webhook_delivery_repo.clone(),
+            webhook_sender.clone(),
+        );
+        let get_webhook_use_case = webhook::GetWebhookUseCase::new(webhook_repo.clone());
+        let get_webhook_deliveries_use_case = webhook::GetWebhookDeliveriesUseCase::new(
+            webhook_repo.clone(),
+            webhook_delivery_repo.clone(),
+        );
+        let get_webhooks_use_case = webhook::GetWebhooksUseCase::new(webhook_repo.clone());
+        let send_webhook_test_event_use_case = webhook::SendWebhookTestEventUseCase::new(
+            webhook_repo.clone(),
+            webhook_delivery_repo.clone(),
+            webhook_sender.clone(),
+        );
+        let update_webhook_use_case =
+            webhook::UpdateWebhookUseCase::new(webhook_repo.clone(), webhook_sender.clone());
 
         AppState {
             // Authenticator
             authenticator,
+            // Attachment Use Cases
+            delete_attachment_use_case,
+            download_attachment_use_case,
+            get_attachments_use_case,
+            purge_orphaned_attachment_blobs_use_case,
+            upload_attachment_use_case,
             // Authentication Use Cases
             change_user_pass_use_case,
             auth_login_use_case,
+            auth_logout_use_case,
+            auth_logout_all_use_case,
             auth_refresh_use_case,
+            get_auth_sessions_use_case,
+            get_json_web_keys_use_case,
+            purge_revoked_refresh_tokens_use_case,
+            revoke_auth_session_use_case,
             // Note Use Cases
+            bulk_delete_notes_use_case,
+            bulk_move_notes_use_case,
+            bulk_note_operations_use_case,
+            bulk_tag_notes_use_case,
             create_note_use_case,
             delete_note_use_case,
+            empty_trash_use_case,
+            export_notes_use_case,
             get_note_use_case,
+            get_note_changes_use_case,
+            get_note_revision_use_case,
+            get_note_revisions_use_case,
             get_notes_use_case,
+            get_trashed_notes_use_case,
+            import_notes_use_case,
+            move_note_use_case,
+            patch_note_use_case,
+            purge_expired_notes_use_case,
+            purge_note_use_case,
+            restore_note_use_case,
+            restore_note_revision_use_case,
+            search_notes_use_case,
+            set_note_flag_use_case,
+            subscribe_note_events_use_case,
             update_note_use_case,
+            // Note Import Job Use Cases
+            create_note_import_job_use_case,
+            get_note_import_job_use_case,
+            process_note_import_job_use_case,
+            // Note Public Link Use Cases
+            create_note_public_link_use_case,
+            get_note_public_link_use_case,
+            get_public_note_use_case,
+            revoke_note_public_link_use_case,
+            // Note Share Use Cases
+            get_note_shares_use_case,
+            get_shared_notes_use_case,
+            revoke_note_share_use_case,
+            share_note_use_case,
+            update_note_share_use_case,
+            // Notebook Use Cases
+            create_notebook_use_case,
+            delete_notebook_use_case,
+            get_notebook_use_case,
+            get_notebooks_use_case,
+            update_notebook_use_case,
+            // Tag Use Cases
+            attach_note_tag_use_case,
+            create_tag_use_case,
+            delete_tag_use_case,
+            detach_note_tag_use_case,
+            get_tags_use_case,
+            rename_tag_use_case,
             // User Use Cases
             delete_self_user_use_case,
             get_self_user_use_case,
             register_user_use_case,
             update_self_user_use_case,
+            // Webhook Use Cases
+            create_webhook_use_case,
+            delete_webhook_use_case,
+            deliver_webhooks_use_case,
+            get_webhook_use_case,
+            get_webhook_deliveries_use_case,
+            get_webhooks_use_case,
+            send_webhook_test_event_use_case,
+            update_webhook_use_case,
         }
     }
 }
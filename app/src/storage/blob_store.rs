@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+
+use common::error::AppError;
+
+/// Storage for binary contents, addressed by opaque keys.
+#[async_trait]
+pub trait BlobStore: Send + Sync {
+    /// Stores the blob, replacing any previous one under the same key.
+    async fn put_blob(&self, key: &str, data: Vec<u8>) -> Result<(), AppError>;
+
+    async fn get_blob(&self, key: &str) -> Result<Vec<u8>, AppError>;
+
+    /// Deleting a missing blob succeeds, so that deletions can safely be retried.
+    async fn delete_blob(&self, key: &str) -> Result<(), AppError>;
+}
@@ -16,6 +16,29 @@ pub enum JsonWebTokenDataVariant {
     RefreshToken,
 }
 
+/// Public key verifying tokens signed by the matching private key (RFC 7517).
+#[derive(Debug, Clone)]
+pub struct JsonWebKey {
+    pub kid: String,
+    pub algorithm: JsonWebKeyAlgorithm,
+    pub params: JsonWebKeyParams,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JsonWebKeyAlgorithm {
+    Rs256,
+    Es256,
+    EdDsa,
+}
+
+/// Key type specific parameters, base64url-encoded.
+#[derive(Debug, Clone)]
+pub enum JsonWebKeyParams {
+    Rsa { modulus: String, exponent: String },
+    EllipticCurve { curve: String, x: String, y: String },
+    OctetKeyPair { curve: String, x: String },
+}
+
 #[derive(Debug, Clone)]
 pub enum UniqueAccessTokenIdentifier {
     Id(Uuid),
@@ -33,6 +56,7 @@ pub enum UniqueRefreshTokenIdentifier {
 pub struct AccessToken {
     pub id: Uuid,
     pub user_id: Uuid,
+    pub session_id: Uuid,
     pub jwt: String,
     pub expires_at: DateTime<Utc>,
 }
@@ -42,16 +66,61 @@ pub struct RefreshToken {
     pub id: Uuid,
     pub user_id: Uuid,
     pub access_token_id: Uuid,
+    /// Shared by every refresh token rotated out of the same login.
+    pub family_id: Uuid,
     pub jwt: String,
     pub expires_at: DateTime<Utc>,
 }
 
+/// Refresh token replaced on rotation, any later use of it indicating it got stolen.
+#[derive(Debug, Clone)]
+pub struct RevokedRefreshToken {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub family_id: Uuid,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone)]
 pub struct AuthTokenPair {
     pub access_token: AccessToken,
     pub refresh_token: RefreshToken,
 }
 
+/// Token pairs issued from a single login, the pair being rotated on refresh.
+#[derive(Debug, Clone)]
+pub struct AuthSession {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    /// Address the session was last logged in or refreshed from.
+    pub ip_address: Option<String>,
+    /// User agent the session was last logged in or refreshed with.
+    pub user_agent: Option<String>,
+    pub created_at: DateTime<Utc>,
+    /// Last time a token pair was issued for the session, accesses in between aren't tracked.
+    pub last_refreshed_at: DateTime<Utc>,
+}
+
+/// Client an authentication request originates from, for informational purposes only.
+#[derive(Debug, Clone, Default)]
+pub struct AuthClientInfo {
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+/// Session a newly generated token pair belongs to.
+#[derive(Debug, Clone)]
+pub enum AuthTokenSession {
+    /// Starts a new session, e.g. on login.
+    Start(AuthClientInfo),
+    /// Replaces the given token pair within its session, e.g. on refresh.
+    Rotate {
+        revoke_token_pair_id: UniqueAccessTokenIdentifier,
+        client_info: AuthClientInfo,
+    },
+}
+
 impl fmt::Display for UniqueAccessTokenIdentifier {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
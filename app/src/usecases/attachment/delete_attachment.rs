@@ -0,0 +1,45 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::repos::{attachment::AttachmentRepository, note_share::NoteShareRepository};
+
+#[derive(Clone)]
+pub struct DeleteAttachmentUseCase {
+    attachment_repo: Arc<dyn AttachmentRepository + Send + Sync>,
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+}
+
+impl DeleteAttachmentUseCase {
+    pub fn new(
+        attachment_repo: Arc<dyn AttachmentRepository + Send + Sync>,
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            attachment_repo,
+            note_share_repo,
+        }
+    }
+
+    /// Deletes the attachment, its blob is removed later on by [`super::PurgeOrphanedAttachmentBlobsUseCase`].
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: DeleteAttachmentInput,
+    ) -> Result<(), AppError> {
+        self.note_share_repo
+            .get_note_access(None, input.note_id, auth_ctx.user.id())
+            .await?
+            .ensure_can_write(input.note_id)?;
+        self.attachment_repo
+            .delete_attachment(None, input.note_id, input.attachment_id)
+            .await
+    }
+}
+
+#[derive(Debug)]
+pub struct DeleteAttachmentInput {
+    pub note_id: Uuid,
+    pub attachment_id: Uuid,
+}
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{auth::context::AuthAccessContext, storage::blob_store::BlobStore};
+use common::error::AppError;
+use dmn::{
+    entities::attachment::Attachment,
+    repos::{attachment::AttachmentRepository, note_share::NoteShareRepository},
+};
+
+#[derive(Clone)]
+pub struct DownloadAttachmentUseCase {
+    attachment_repo: Arc<dyn AttachmentRepository + Send + Sync>,
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+    blob_store: Arc<dyn BlobStore + Send + Sync>,
+}
+
+impl DownloadAttachmentUseCase {
+    pub fn new(
+        attachment_repo: Arc<dyn AttachmentRepository + Send + Sync>,
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+        blob_store: Arc<dyn BlobStore + Send + Sync>,
+    ) -> Self {
+        Self {
+            attachment_repo,
+            note_share_repo,
+            blob_store,
+        }
+    }
+
+    /// Returns the attachment along with its file contents.
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: DownloadAttachmentInput,
+    ) -> Result<(Attachment, Vec<u8>), AppError> {
+        self.note_share_repo
+            .get_note_access(None, input.note_id, auth_ctx.user.id())
+            .await?;
+        let attachment = self
+            .attachment_repo
+            .get_attachment(None, input.note_id, input.attachment_id)
+            .await?;
+        let data = self.blob_store.get_blob(&attachment.blob_key()).await?;
+        Ok((attachment, data))
+    }
+}
+
+#[derive(Debug)]
+pub struct DownloadAttachmentInput {
+    pub note_id: Uuid,
+    pub attachment_id: Uuid,
+}
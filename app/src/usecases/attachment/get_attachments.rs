@@ -0,0 +1,45 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::{
+    entities::attachment::Attachment,
+    repos::{attachment::AttachmentRepository, note_share::NoteShareRepository},
+};
+
+#[derive(Clone)]
+pub struct GetAttachmentsUseCase {
+    attachment_repo: Arc<dyn AttachmentRepository + Send + Sync>,
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+}
+
+impl GetAttachmentsUseCase {
+    pub fn new(
+        attachment_repo: Arc<dyn AttachmentRepository + Send + Sync>,
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            attachment_repo,
+            note_share_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: GetAttachmentsInput,
+    ) -> Result<Vec<Attachment>, AppError> {
+        self.note_share_repo
+            .get_note_access(None, input.note_id, auth_ctx.user.id())
+            .await?;
+        self.attachment_repo
+            .get_note_attachments(None, input.note_id)
+            .await
+    }
+}
+
+#[derive(Debug)]
+pub struct GetAttachmentsInput {
+    pub note_id: Uuid,
+}
@@ -0,0 +1,11 @@
+mod delete_attachment;
+mod download_attachment;
+mod get_attachments;
+mod purge_orphaned_attachment_blobs;
+mod upload_attachment;
+
+pub use delete_attachment::*;
+pub use download_attachment::*;
+pub use get_attachments::*;
+pub use purge_orphaned_attachment_blobs::*;
+pub use upload_attachment::*;
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use crate::storage::blob_store::BlobStore;
+use common::error::AppError;
+use dmn::{entities::attachment::attachment_blob_key, repos::attachment::AttachmentRepository};
+
+/// System task removing the blobs left behind by deleted attachments, including the ones
+/// cascading from purged notes and deleted users.
+#[derive(Clone)]
+pub struct PurgeOrphanedAttachmentBlobsUseCase {
+    attachment_repo: Arc<dyn AttachmentRepository + Send + Sync>,
+    blob_store: Arc<dyn BlobStore + Send + Sync>,
+}
+
+impl PurgeOrphanedAttachmentBlobsUseCase {
+    pub fn new(
+        attachment_repo: Arc<dyn AttachmentRepository + Send + Sync>,
+        blob_store: Arc<dyn BlobStore + Send + Sync>,
+    ) -> Self {
+        Self {
+            attachment_repo,
+            blob_store,
+        }
+    }
+
+    /// Returns how many blobs were removed.
+    pub async fn execute(&self) -> Result<u32, AppError> {
+        const BATCH_SIZE: u32 = 100;
+
+        let mut purged_count = 0;
+        loop {
+            let attachment_ids = self
+                .attachment_repo
+                .get_orphaned_attachment_blobs(None, BATCH_SIZE)
+                .await?;
+            for attachment_id in &attachment_ids {
+                self.blob_store
+                    .delete_blob(&attachment_blob_key(*attachment_id))
+                    .await?;
+                self.attachment_repo
+                    .delete_orphaned_attachment_blob(None, *attachment_id)
+                    .await?;
+                purged_count += 1;
+            }
+            if attachment_ids.len() < BATCH_SIZE as usize {
+                return Ok(purged_count);
+            }
+        }
+    }
+}
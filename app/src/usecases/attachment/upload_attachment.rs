@@ -0,0 +1,89 @@
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{auth::context::AuthAccessContext, storage::blob_store::BlobStore};
+use common::{
+    error::AppError,
+    tx::{TransactionResult, UnitOfWork},
+};
+use dmn::{
+    entities::attachment::{Attachment, CreateAttachmentData},
+    repos::{attachment::AttachmentRepository, note_share::NoteShareRepository},
+};
+
+#[derive(Clone)]
+pub struct UploadAttachmentUseCase {
+    unit_of_work: Arc<dyn UnitOfWork>,
+    attachment_repo: Arc<dyn AttachmentRepository + Send + Sync>,
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+    blob_store: Arc<dyn BlobStore + Send + Sync>,
+}
+
+impl UploadAttachmentUseCase {
+    pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        attachment_repo: Arc<dyn AttachmentRepository + Send + Sync>,
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+        blob_store: Arc<dyn BlobStore + Send + Sync>,
+    ) -> Self {
+        Self {
+            unit_of_work,
+            attachment_repo,
+            note_share_repo,
+            blob_store,
+        }
+    }
+
+    /// Attaches a file to the note.<br />
+    /// The metadata is only committed once the blob has been stored.
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: UploadAttachmentInput,
+    ) -> Result<Attachment, AppError> {
+        let note_id = input.note_id;
+        let user_id = auth_ctx.user.id();
+        let attachment_data = CreateAttachmentData {
+            note_id,
+            file_name: input.file_name,
+            mime_type: input.mime_type,
+            size: input.data.len() as u64,
+            sha256: format!("{:x}", Sha256::digest(&input.data)),
+        }
+        .validate()?;
+        let data = input.data;
+
+        let attachment_repo = self.attachment_repo.clone();
+        let note_share_repo = self.note_share_repo.clone();
+        let blob_store = self.blob_store.clone();
+        let result = self
+            .unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    // Collaborators with write access can attach files too
+                    note_share_repo
+                        .get_note_access(Some(ctx), note_id, user_id)
+                        .await?
+                        .ensure_can_write(note_id)?;
+                    let attachment = attachment_repo
+                        .create_attachment(Some(ctx), attachment_data)
+                        .await?;
+                    blob_store.put_blob(&attachment.blob_key(), data).await?;
+                    Ok(TransactionResult::new(attachment))
+                })
+            }))
+            .await?;
+
+        let attachment = result.extract::<Attachment>()?;
+        Ok(attachment)
+    }
+}
+
+#[derive(Debug)]
+pub struct UploadAttachmentInput {
+    pub note_id: Uuid,
+    pub file_name: String,
+    pub mime_type: String,
+    pub data: Vec<u8>,
+}
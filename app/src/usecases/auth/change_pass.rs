@@ -1,24 +1,33 @@
 use std::sync::Arc;
 
 use crate::auth::{context::AuthAccessContext, pass_service::PasswordService};
-use common::error::AppError;
+use common::{
+    error::AppError,
+    tx::{TransactionResult, UnitOfWork},
+};
 use dmn::{
     entities::user::{UniqueUserIdentifier, UpdateUserData},
-    repos::user::UserRepository,
+    repos::{outbox::OutboxRepository, user::UserRepository},
 };
 
 #[derive(Clone)]
 pub struct ChangeUserPasswordUseCase {
+    unit_of_work: Arc<dyn UnitOfWork>,
+    outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
     user_repo: Arc<dyn UserRepository + Send + Sync>,
     pass_service: Arc<dyn PasswordService + Send + Sync>,
 }
 
 impl ChangeUserPasswordUseCase {
     pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
         user_repo: Arc<dyn UserRepository + Send + Sync>,
         pass_service: Arc<dyn PasswordService + Send + Sync>,
     ) -> Self {
         Self {
+            unit_of_work,
+            outbox_repo,
             user_repo,
             pass_service,
         }
@@ -31,7 +40,7 @@ impl ChangeUserPasswordUseCase {
     ) -> Result<(), AppError> {
         // Validate New Password
         self.pass_service
-            .validate_password_strength(&input.new_password)?;
+            .validate_password_strength(&input.new_password, auth_ctx.user.email())?;
 
         // Target User = Authenticated User
         let user_id = UniqueUserIdentifier::Id(auth_ctx.user.id());
@@ -41,10 +50,22 @@ impl ChangeUserPasswordUseCase {
             .verify_password(&user_id, &input.current_password)
             .await?;
 
-        let new_password_hash = self.pass_service.hash(&input.new_password)?;
+        let new_password_hash = self.pass_service.hash(&input.new_password).await?;
         let user_data = input.into_dmn(new_password_hash);
-        self.user_repo
-            .update_user(None, &user_id, user_data)
+        let user_repo = self.user_repo.clone();
+        let outbox_repo = self.outbox_repo.clone();
+        self.unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    let user = user_repo
+                        .update_user(Some(ctx), &user_id, user_data)
+                        .await?;
+                    outbox_repo
+                        .append_events(Some(ctx), &[user.password_changed_event()])
+                        .await?;
+                    Ok(TransactionResult::new(()))
+                })
+            }))
             .await?;
         Ok(())
     }
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use crate::{auth::token_adapter::TokenAdapter, types::auth_token::JsonWebKey};
+use common::error::AppError;
+
+#[derive(Clone)]
+pub struct GetJsonWebKeysUseCase {
+    token_adapter: Arc<dyn TokenAdapter + Send + Sync>,
+}
+
+impl GetJsonWebKeysUseCase {
+    pub fn new(token_adapter: Arc<dyn TokenAdapter + Send + Sync>) -> Self {
+        Self { token_adapter }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<JsonWebKey>, AppError> {
+        let keys = self.token_adapter.public_keys();
+        Ok(keys)
+    }
+}
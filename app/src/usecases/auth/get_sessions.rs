@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::{
+    auth::context::AuthAccessContext, repos::auth_token::AuthTokenRepository,
+    types::auth_token::AuthSession,
+};
+use common::error::AppError;
+
+#[derive(Clone)]
+pub struct GetAuthSessionsUseCase {
+    token_repo: Arc<dyn AuthTokenRepository + Send + Sync>,
+}
+
+impl GetAuthSessionsUseCase {
+    pub fn new(token_repo: Arc<dyn AuthTokenRepository + Send + Sync>) -> Self {
+        Self { token_repo }
+    }
+
+    pub async fn execute(&self, auth_ctx: AuthAccessContext) -> Result<Vec<AuthSession>, AppError> {
+        let sessions = self
+            .token_repo
+            .get_user_sessions(None, auth_ctx.user.id())
+            .await?;
+        Ok(sessions)
+    }
+}
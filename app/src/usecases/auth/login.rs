@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use crate::{
     auth::{authenticator::Authenticator, pass_service::PasswordService},
-    types::auth_token::AuthTokenPair,
+    types::auth_token::{AuthClientInfo, AuthTokenPair, AuthTokenSession},
 };
 use common::error::AppError;
 use dmn::{entities::user::UniqueUserIdentifier, repos::user::UserRepository};
@@ -53,7 +53,7 @@ impl AuthLoginUseCase {
         // Generate Authentication Tokens
         let token_pair = self
             .authenticator
-            .generate_auth_tokens(user.id(), None)
+            .generate_auth_tokens(user.id(), AuthTokenSession::Start(input.client_info))
             .await?;
 
         Ok(token_pair)
@@ -64,4 +64,5 @@ impl AuthLoginUseCase {
 pub struct AuthLoginInput {
     pub email: String,
     pub password: String,
+    pub client_info: AuthClientInfo,
 }
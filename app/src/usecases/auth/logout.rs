@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use crate::{auth::context::AuthAccessContext, repos::auth_token::AuthTokenRepository};
+use common::error::AppError;
+
+#[derive(Clone)]
+pub struct AuthLogoutUseCase {
+    token_repo: Arc<dyn AuthTokenRepository + Send + Sync>,
+}
+
+impl AuthLogoutUseCase {
+    pub fn new(token_repo: Arc<dyn AuthTokenRepository + Send + Sync>) -> Self {
+        Self { token_repo }
+    }
+
+    pub async fn execute(&self, auth_ctx: AuthAccessContext) -> Result<(), AppError> {
+        // Revoke Current Session (cascades to its token pair)
+        self.token_repo
+            .delete_session(
+                None,
+                auth_ctx.access_token.session_id,
+                Some(auth_ctx.user.id()),
+            )
+            .await?;
+        Ok(())
+    }
+}
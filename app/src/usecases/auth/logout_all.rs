@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::{auth::context::AuthAccessContext, repos::auth_token::AuthTokenRepository};
+use common::error::AppError;
+
+#[derive(Clone)]
+pub struct AuthLogoutAllUseCase {
+    token_repo: Arc<dyn AuthTokenRepository + Send + Sync>,
+}
+
+impl AuthLogoutAllUseCase {
+    pub fn new(token_repo: Arc<dyn AuthTokenRepository + Send + Sync>) -> Self {
+        Self { token_repo }
+    }
+
+    pub async fn execute(&self, auth_ctx: AuthAccessContext) -> Result<(), AppError> {
+        // Revoke Every Session, Current One Included
+        self.token_repo
+            .delete_user_sessions(None, auth_ctx.user.id())
+            .await?;
+        Ok(())
+    }
+}
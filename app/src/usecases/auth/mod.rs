@@ -1,7 +1,19 @@
 mod change_pass;
+mod get_json_web_keys;
+mod get_sessions;
 mod login;
+mod logout;
+mod logout_all;
+mod purge_revoked_refresh_tokens;
 mod refresh;
+mod revoke_session;
 
 pub use change_pass::*;
+pub use get_json_web_keys::*;
+pub use get_sessions::*;
 pub use login::*;
+pub use logout::*;
+pub use logout_all::*;
+pub use purge_revoked_refresh_tokens::*;
 pub use refresh::*;
+pub use revoke_session::*;
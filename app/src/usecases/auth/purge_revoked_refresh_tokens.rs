@@ -0,0 +1,25 @@
+use chrono::Utc;
+use std::sync::Arc;
+
+use crate::repos::auth_token::AuthTokenRepository;
+use common::error::AppError;
+
+/// System task forgetting the revoked refresh tokens which have expired, as their reuse gets
+/// rejected regardless.
+#[derive(Clone)]
+pub struct PurgeRevokedRefreshTokensUseCase {
+    token_repo: Arc<dyn AuthTokenRepository + Send + Sync>,
+}
+
+impl PurgeRevokedRefreshTokensUseCase {
+    pub fn new(token_repo: Arc<dyn AuthTokenRepository + Send + Sync>) -> Self {
+        Self { token_repo }
+    }
+
+    /// Returns how many revoked refresh tokens were deleted.
+    pub async fn execute(&self) -> Result<u64, AppError> {
+        self.token_repo
+            .delete_expired_revoked_refresh_tokens(None, Utc::now())
+            .await
+    }
+}
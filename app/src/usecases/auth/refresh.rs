@@ -2,7 +2,9 @@ use std::sync::Arc;
 
 use crate::{
     auth::{authenticator::Authenticator, context::AuthRefreshContext},
-    types::auth_token::{AuthTokenPair, UniqueAccessTokenIdentifier},
+    types::auth_token::{
+        AuthClientInfo, AuthTokenPair, AuthTokenSession, UniqueAccessTokenIdentifier,
+    },
 };
 use common::error::AppError;
 
@@ -19,14 +21,24 @@ impl AuthRefreshUseCase {
     pub async fn execute(
         &self,
         auth_ref_ctx: AuthRefreshContext,
+        input: AuthRefreshInput,
     ) -> Result<AuthTokenPair, AppError> {
         // Refresh Authentication Tokens
         let revoke_token_pair_id =
             UniqueAccessTokenIdentifier::Id(auth_ref_ctx.refresh_token.access_token_id);
+        let session = AuthTokenSession::Rotate {
+            revoke_token_pair_id,
+            client_info: input.client_info,
+        };
         let token_pair = self
             .authenticator
-            .generate_auth_tokens(auth_ref_ctx.user.id(), Some(revoke_token_pair_id))
+            .generate_auth_tokens(auth_ref_ctx.user.id(), session)
             .await?;
         Ok(token_pair)
     }
 }
+
+#[derive(Debug)]
+pub struct AuthRefreshInput {
+    pub client_info: AuthClientInfo,
+}
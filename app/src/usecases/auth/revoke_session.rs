@@ -0,0 +1,33 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{auth::context::AuthAccessContext, repos::auth_token::AuthTokenRepository};
+use common::error::AppError;
+
+#[derive(Clone)]
+pub struct RevokeAuthSessionUseCase {
+    token_repo: Arc<dyn AuthTokenRepository + Send + Sync>,
+}
+
+impl RevokeAuthSessionUseCase {
+    pub fn new(token_repo: Arc<dyn AuthTokenRepository + Send + Sync>) -> Self {
+        Self { token_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: RevokeAuthSessionInput,
+    ) -> Result<(), AppError> {
+        // Other users' sessions are reported as nonexistent
+        self.token_repo
+            .delete_session(None, input.session_id, Some(auth_ctx.user.id()))
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct RevokeAuthSessionInput {
+    pub session_id: Uuid,
+}
@@ -1,3 +1,10 @@
+pub mod attachment;
 pub mod auth;
 pub mod note;
+pub mod note_import_job;
+pub mod note_public_link;
+pub mod note_share;
+pub mod notebook;
+pub mod tag;
 pub mod user;
+pub mod webhook;
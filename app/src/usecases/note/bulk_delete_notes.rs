@@ -0,0 +1,81 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::bulk_note_operations::{dedup_note_ids, ensure_bulk_size};
+use crate::auth::context::AuthAccessContext;
+use common::{
+    error::AppError,
+    tx::{TransactionResult, UnitOfWork},
+};
+use dmn::{
+    events::DomainEvent,
+    repos::{note::NoteRepository, note_share::NoteShareRepository, outbox::OutboxRepository},
+};
+
+#[derive(Clone)]
+pub struct BulkDeleteNotesUseCase {
+    unit_of_work: Arc<dyn UnitOfWork>,
+    note_repo: Arc<dyn NoteRepository + Send + Sync>,
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+    outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+}
+
+impl BulkDeleteNotesUseCase {
+    pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        note_repo: Arc<dyn NoteRepository + Send + Sync>,
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+        outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            unit_of_work,
+            note_repo,
+            note_share_repo,
+            outbox_repo,
+        }
+    }
+
+    /// Moves all the notes to the trash, failing as a whole if any of them can't be trashed.
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: BulkDeleteNotesInput,
+    ) -> Result<Vec<Uuid>, AppError> {
+        ensure_bulk_size(input.note_ids.len())?;
+        let note_ids = dedup_note_ids(input.note_ids);
+        let user_id = auth_ctx.user.id();
+
+        let note_repo = self.note_repo.clone();
+        let note_share_repo = self.note_share_repo.clone();
+        let outbox_repo = self.outbox_repo.clone();
+        let result = self
+            .unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    let mut events = Vec::with_capacity(note_ids.len());
+                    for &note_id in &note_ids {
+                        // Collaborators can't trash notes shared with them
+                        note_share_repo
+                            .get_note_access(Some(ctx), note_id, user_id)
+                            .await?
+                            .ensure_owner(note_id)?;
+                        note_repo
+                            .delete_note(Some(ctx), note_id, None, Some(user_id))
+                            .await?;
+                        events.push(DomainEvent::NoteDeleted { note_id, user_id });
+                    }
+                    outbox_repo.append_events(Some(ctx), &events).await?;
+                    Ok(TransactionResult::new(note_ids))
+                })
+            }))
+            .await?;
+
+        let note_ids = result.extract::<Vec<Uuid>>()?;
+        Ok(note_ids)
+    }
+}
+
+#[derive(Debug)]
+pub struct BulkDeleteNotesInput {
+    pub note_ids: Vec<Uuid>,
+}
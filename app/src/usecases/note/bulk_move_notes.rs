@@ -0,0 +1,97 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::bulk_note_operations::{dedup_note_ids, ensure_bulk_size};
+use crate::auth::context::AuthAccessContext;
+use common::{
+    error::AppError,
+    tx::{TransactionResult, UnitOfWork},
+};
+use dmn::{
+    entities::note::Note,
+    repos::{
+        note::NoteRepository, note_share::NoteShareRepository, notebook::NotebookRepository,
+        outbox::OutboxRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct BulkMoveNotesUseCase {
+    unit_of_work: Arc<dyn UnitOfWork>,
+    note_repo: Arc<dyn NoteRepository + Send + Sync>,
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+    notebook_repo: Arc<dyn NotebookRepository + Send + Sync>,
+    outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+}
+
+impl BulkMoveNotesUseCase {
+    pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        note_repo: Arc<dyn NoteRepository + Send + Sync>,
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+        notebook_repo: Arc<dyn NotebookRepository + Send + Sync>,
+        outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            unit_of_work,
+            note_repo,
+            note_share_repo,
+            notebook_repo,
+            outbox_repo,
+        }
+    }
+
+    /// Moves all the notes into the same notebook, failing as a whole if any of them can't be moved.
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: BulkMoveNotesInput,
+    ) -> Result<Vec<Note>, AppError> {
+        ensure_bulk_size(input.note_ids.len())?;
+        let note_ids = dedup_note_ids(input.note_ids);
+        let notebook_id = input.notebook_id;
+        let user_id = auth_ctx.user.id();
+        if let Some(notebook_id) = notebook_id {
+            // Target notebook has to belong to the requesting user
+            self.notebook_repo
+                .get_notebook(None, notebook_id, Some(user_id))
+                .await?;
+        }
+
+        let note_repo = self.note_repo.clone();
+        let note_share_repo = self.note_share_repo.clone();
+        let outbox_repo = self.outbox_repo.clone();
+        let result = self
+            .unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    let mut notes = Vec::with_capacity(note_ids.len());
+                    for note_id in note_ids {
+                        // Notebooks are personal, so collaborators can't file shared notes
+                        note_share_repo
+                            .get_note_access(Some(ctx), note_id, user_id)
+                            .await?
+                            .ensure_owner(note_id)?;
+                        let note = note_repo
+                            .move_note(Some(ctx), note_id, notebook_id, Some(user_id))
+                            .await?;
+                        notes.push(note);
+                    }
+                    let events: Vec<_> = notes.iter().map(|note| note.updated_event()).collect();
+                    outbox_repo.append_events(Some(ctx), &events).await?;
+                    Ok(TransactionResult::new(notes))
+                })
+            }))
+            .await?;
+
+        let notes = result.extract::<Vec<Note>>()?;
+        Ok(notes)
+    }
+}
+
+#[derive(Debug)]
+pub struct BulkMoveNotesInput {
+    pub note_ids: Vec<Uuid>,
+    /// Target notebook, `None` moves the notes back to the root level.
+    pub notebook_id: Option<Uuid>,
+}
@@ -0,0 +1,234 @@
+use std::{collections::HashSet, sync::Arc};
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::{
+    error::AppError,
+    tx::{ctx::TransactionContext, TransactionResult, UnitOfWork},
+};
+use dmn::{
+    entities::note::{CreateNoteData, Note, UpdateNoteData},
+    events::DomainEvent,
+    repos::{
+        note::NoteRepository, note_revision::NoteRevisionRepository,
+        note_share::NoteShareRepository, notebook::NotebookRepository, outbox::OutboxRepository,
+    },
+};
+
+use super::{
+    CreateNoteInput, CreateNoteUseCase, DeleteNoteInput, DeleteNoteUseCase, UpdateNoteInput,
+    UpdateNoteUseCase,
+};
+
+/// Upper bound on the number of operations, or note ids, of a single bulk request.
+pub const NOTE_BULK_MAX_OPERATIONS: usize = 500;
+
+#[derive(Clone)]
+pub struct BulkNoteOperationsUseCase {
+    unit_of_work: Arc<dyn UnitOfWork>,
+    outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+    create_note_use_case: CreateNoteUseCase,
+    update_note_use_case: UpdateNoteUseCase,
+    delete_note_use_case: DeleteNoteUseCase,
+}
+
+impl BulkNoteOperationsUseCase {
+    pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        note_repo: Arc<dyn NoteRepository + Send + Sync>,
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+        note_revision_repo: Arc<dyn NoteRevisionRepository + Send + Sync>,
+        notebook_repo: Arc<dyn NotebookRepository + Send + Sync>,
+        outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            create_note_use_case: CreateNoteUseCase::new(
+                unit_of_work.clone(),
+                note_repo.clone(),
+                notebook_repo,
+                outbox_repo.clone(),
+            ),
+            update_note_use_case: UpdateNoteUseCase::new(
+                unit_of_work.clone(),
+                note_repo.clone(),
+                note_share_repo.clone(),
+                note_revision_repo,
+                outbox_repo.clone(),
+            ),
+            delete_note_use_case: DeleteNoteUseCase::new(
+                unit_of_work.clone(),
+                note_repo,
+                note_share_repo,
+                outbox_repo.clone(),
+            ),
+            unit_of_work,
+            outbox_repo,
+        }
+    }
+
+    /// Runs all operations in order within a single transaction, either all of them are applied or none.<br />
+    /// Each operation behaves like its single note counterpart, errors are prefixed with the failing operation index.
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: BulkNoteOperationsInput,
+    ) -> Result<Vec<BulkNoteOperationResult>, AppError> {
+        ensure_bulk_size(input.operations.len())?;
+        let user_id = auth_ctx.user.id();
+        let operations = input
+            .operations
+            .into_iter()
+            .enumerate()
+            .map(|(index, operation)| {
+                operation
+                    .try_into_dmn(auth_ctx.clone())
+                    .map_err(|err| with_operation_index(index, err))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let use_case = self.clone();
+        let result = self
+            .unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    let mut results = Vec::with_capacity(operations.len());
+                    let mut events = Vec::with_capacity(operations.len());
+                    for (index, operation) in operations.into_iter().enumerate() {
+                        let (result, event) = use_case
+                            .run_operation(ctx, user_id, operation)
+                            .await
+                            .map_err(|err| with_operation_index(index, err))?;
+                        results.push(result);
+                        events.push(event);
+                    }
+                    use_case
+                        .outbox_repo
+                        .append_events(Some(ctx), &events)
+                        .await?;
+                    Ok(TransactionResult::new(results))
+                })
+            }))
+            .await?;
+
+        let results = result.extract::<Vec<BulkNoteOperationResult>>()?;
+        Ok(results)
+    }
+
+    async fn run_operation(
+        &self,
+        ctx: &mut dyn TransactionContext,
+        user_id: Uuid,
+        operation: BulkNoteOperationData,
+    ) -> Result<(BulkNoteOperationResult, DomainEvent), AppError> {
+        match operation {
+            BulkNoteOperationData::Create(note_data) => {
+                let note = self
+                    .create_note_use_case
+                    .create_note(ctx, note_data)
+                    .await?;
+                let event = note.created_event();
+                Ok((BulkNoteOperationResult::Created(note), event))
+            }
+            BulkNoteOperationData::Update {
+                note_id,
+                note_data,
+                expected_versions,
+            } => {
+                let note = self
+                    .update_note_use_case
+                    .update_note(ctx, user_id, note_id, note_data, &expected_versions)
+                    .await?;
+                let event = note.updated_event();
+                Ok((BulkNoteOperationResult::Updated(note), event))
+            }
+            BulkNoteOperationData::Delete {
+                note_id,
+                expected_versions,
+            } => {
+                let event = self
+                    .delete_note_use_case
+                    .delete_note(ctx, user_id, note_id, &expected_versions)
+                    .await?;
+                Ok((BulkNoteOperationResult::Deleted { note_id }, event))
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct BulkNoteOperationsInput {
+    pub operations: Vec<BulkNoteOperation>,
+}
+
+#[derive(Debug)]
+pub enum BulkNoteOperation {
+    Create(CreateNoteInput),
+    Update(UpdateNoteInput),
+    Delete(DeleteNoteInput),
+}
+
+impl BulkNoteOperation {
+    fn try_into_dmn(self, auth_ctx: AuthAccessContext) -> Result<BulkNoteOperationData, AppError> {
+        match self {
+            BulkNoteOperation::Create(input) => {
+                Ok(BulkNoteOperationData::Create(input.try_into_dmn(auth_ctx)?))
+            }
+            BulkNoteOperation::Update(mut input) => Ok(BulkNoteOperationData::Update {
+                note_id: input.note_id,
+                expected_versions: std::mem::take(&mut input.expected_versions),
+                note_data: input.try_into()?,
+            }),
+            BulkNoteOperation::Delete(input) => Ok(BulkNoteOperationData::Delete {
+                note_id: input.note_id,
+                expected_versions: input.expected_versions,
+            }),
+        }
+    }
+}
+
+/// Validated counterpart of [`BulkNoteOperation`].
+enum BulkNoteOperationData {
+    Create(CreateNoteData),
+    Update {
+        note_id: Uuid,
+        note_data: UpdateNoteData,
+        expected_versions: Vec<u32>,
+    },
+    Delete {
+        note_id: Uuid,
+        expected_versions: Vec<u32>,
+    },
+}
+
+#[derive(Debug)]
+pub enum BulkNoteOperationResult {
+    Created(Note),
+    Updated(Note),
+    Deleted { note_id: Uuid },
+}
+
+pub(super) fn ensure_bulk_size(len: usize) -> Result<(), AppError> {
+    if len == 0 {
+        return Err(AppError::bad_request("At least one operation is required!"));
+    }
+    if len > NOTE_BULK_MAX_OPERATIONS {
+        return Err(AppError::bad_request(format!(
+            "At most {NOTE_BULK_MAX_OPERATIONS} operations are allowed per request!"
+        )));
+    }
+    Ok(())
+}
+
+/// Drops repeated note ids, keeping the first occurrence of each.
+pub(super) fn dedup_note_ids(note_ids: Vec<Uuid>) -> Vec<Uuid> {
+    let mut seen = HashSet::with_capacity(note_ids.len());
+    note_ids
+        .into_iter()
+        .filter(|note_id| seen.insert(*note_id))
+        .collect()
+}
+
+fn with_operation_index(index: usize, err: AppError) -> AppError {
+    let public_info = format!("Operation #{index}: {}", err.public_info);
+    err.reword(public_info)
+}
@@ -0,0 +1,84 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::bulk_note_operations::{dedup_note_ids, ensure_bulk_size};
+use crate::auth::context::AuthAccessContext;
+use common::{
+    error::AppError,
+    tx::{TransactionResult, UnitOfWork},
+};
+use dmn::{
+    entities::note::Note,
+    repos::{note::NoteRepository, note_share::NoteShareRepository, tag::TagRepository},
+};
+
+#[derive(Clone)]
+pub struct BulkTagNotesUseCase {
+    unit_of_work: Arc<dyn UnitOfWork>,
+    note_repo: Arc<dyn NoteRepository + Send + Sync>,
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+    tag_repo: Arc<dyn TagRepository + Send + Sync>,
+}
+
+impl BulkTagNotesUseCase {
+    pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        note_repo: Arc<dyn NoteRepository + Send + Sync>,
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+        tag_repo: Arc<dyn TagRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            unit_of_work,
+            note_repo,
+            note_share_repo,
+            tag_repo,
+        }
+    }
+
+    /// Attaches the same tag to all the notes, failing as a whole if any of them can't be tagged.
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: BulkTagNotesInput,
+    ) -> Result<Vec<Note>, AppError> {
+        ensure_bulk_size(input.note_ids.len())?;
+        let note_ids = dedup_note_ids(input.note_ids);
+        let tag_id = input.tag_id;
+        let user_id = auth_ctx.user.id();
+        // Both the notes and the tag have to belong to the requesting user.
+        self.tag_repo.get_tag(None, tag_id, Some(user_id)).await?;
+
+        let note_repo = self.note_repo.clone();
+        let note_share_repo = self.note_share_repo.clone();
+        let tag_repo = self.tag_repo.clone();
+        let result = self
+            .unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    let mut notes = Vec::with_capacity(note_ids.len());
+                    for note_id in note_ids {
+                        note_share_repo
+                            .get_note_access(Some(ctx), note_id, user_id)
+                            .await?
+                            .ensure_owner(note_id)?;
+                        tag_repo.attach_note_tag(Some(ctx), note_id, tag_id).await?;
+                        let note = note_repo
+                            .get_note(Some(ctx), note_id, Some(user_id))
+                            .await?;
+                        notes.push(note);
+                    }
+                    Ok(TransactionResult::new(notes))
+                })
+            }))
+            .await?;
+
+        let notes = result.extract::<Vec<Note>>()?;
+        Ok(notes)
+    }
+}
+
+#[derive(Debug)]
+pub struct BulkTagNotesInput {
+    pub note_ids: Vec<Uuid>,
+    pub tag_id: Uuid,
+}
@@ -1,20 +1,37 @@
 use std::sync::Arc;
+use uuid::Uuid;
 
 use crate::auth::context::AuthAccessContext;
-use common::error::AppError;
+use common::{
+    error::AppError,
+    tx::{ctx::TransactionContext, TransactionResult, UnitOfWork},
+};
 use dmn::{
     entities::note::{CreateNoteData, Note},
-    repos::note::NoteRepository,
+    repos::{note::NoteRepository, notebook::NotebookRepository, outbox::OutboxRepository},
 };
 
 #[derive(Clone)]
 pub struct CreateNoteUseCase {
+    unit_of_work: Arc<dyn UnitOfWork>,
     note_repo: Arc<dyn NoteRepository + Send + Sync>,
+    notebook_repo: Arc<dyn NotebookRepository + Send + Sync>,
+    outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
 }
 
 impl CreateNoteUseCase {
-    pub fn new(note_repo: Arc<dyn NoteRepository + Send + Sync>) -> Self {
-        Self { note_repo }
+    pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        note_repo: Arc<dyn NoteRepository + Send + Sync>,
+        notebook_repo: Arc<dyn NotebookRepository + Send + Sync>,
+        outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            unit_of_work,
+            note_repo,
+            notebook_repo,
+            outbox_repo,
+        }
     }
 
     pub async fn execute(
@@ -23,13 +40,45 @@ impl CreateNoteUseCase {
         input: CreateNoteInput,
     ) -> Result<Note, AppError> {
         let note_data = input.try_into_dmn(auth_ctx)?;
-        let note = self.note_repo.create_note(None, note_data).await?;
+
+        let use_case = self.clone();
+        let result = self
+            .unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    let note = use_case.create_note(ctx, note_data).await?;
+                    use_case
+                        .outbox_repo
+                        .append_events(Some(ctx), &[note.created_event()])
+                        .await?;
+                    Ok(TransactionResult::new(note))
+                })
+            }))
+            .await?;
+
+        let note = result.extract::<Note>()?;
         Ok(note)
     }
+
+    /// Creates the note within an ongoing transaction, leaving its event to the caller.
+    pub(super) async fn create_note(
+        &self,
+        ctx: &mut dyn TransactionContext,
+        note_data: CreateNoteData,
+    ) -> Result<Note, AppError> {
+        if let Some(notebook_id) = note_data.notebook_id {
+            // Target notebook has to belong to the requesting user
+            self.notebook_repo
+                .get_notebook(Some(ctx), notebook_id, Some(note_data.user_id))
+                .await?;
+        }
+        self.note_repo.create_note(Some(ctx), note_data).await
+    }
 }
 
 #[derive(Debug)]
 pub struct CreateNoteInput {
+    pub notebook_id: Option<Uuid>,
     pub title: String,
     pub content: String,
 }
@@ -38,8 +87,11 @@ impl CreateNoteInput {
     pub fn try_into_dmn(self, auth_ctx: AuthAccessContext) -> Result<CreateNoteData, AppError> {
         let data = CreateNoteData {
             user_id: auth_ctx.user.id(),
+            notebook_id: self.notebook_id,
             title: self.title,
             content: self.content,
+            created_at: None,
+            updated_at: None,
         };
         let valid_data = data.validate()?;
         Ok(valid_data)
@@ -2,31 +2,98 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::auth::context::AuthAccessContext;
-use common::error::AppError;
-use dmn::repos::note::NoteRepository;
+use common::{
+    error::AppError,
+    tx::{ctx::TransactionContext, TransactionResult, UnitOfWork},
+};
+use dmn::{
+    events::DomainEvent,
+    repos::{note::NoteRepository, note_share::NoteShareRepository, outbox::OutboxRepository},
+};
 
 #[derive(Clone)]
 pub struct DeleteNoteUseCase {
+    unit_of_work: Arc<dyn UnitOfWork>,
     note_repo: Arc<dyn NoteRepository + Send + Sync>,
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+    outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
 }
 
 impl DeleteNoteUseCase {
-    pub fn new(note_repo: Arc<dyn NoteRepository + Send + Sync>) -> Self {
-        Self { note_repo }
+    pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        note_repo: Arc<dyn NoteRepository + Send + Sync>,
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+        outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            unit_of_work,
+            note_repo,
+            note_share_repo,
+            outbox_repo,
+        }
     }
 
+    /// Moves the note to the trash, see [`super::RestoreNoteUseCase`] and [`super::PurgeNoteUseCase`].
     pub async fn execute(
         &self,
         auth_ctx: AuthAccessContext,
         input: DeleteNoteInput,
     ) -> Result<(), AppError> {
+        let user_id = auth_ctx.user.id();
+
+        let use_case = self.clone();
+        self.unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    let event = use_case
+                        .delete_note(ctx, user_id, input.note_id, &input.expected_versions)
+                        .await?;
+                    use_case
+                        .outbox_repo
+                        .append_events(Some(ctx), &[event])
+                        .await?;
+                    Ok(TransactionResult::new(()))
+                })
+            }))
+            .await?;
+        Ok(())
+    }
+
+    /// Trashes the note within an ongoing transaction, returning the event for the caller to record.
+    pub(super) async fn delete_note(
+        &self,
+        ctx: &mut dyn TransactionContext,
+        user_id: Uuid,
+        note_id: Uuid,
+        expected_versions: &[u32],
+    ) -> Result<DomainEvent, AppError> {
+        // Collaborators can't trash notes shared with them
+        self.note_share_repo
+            .get_note_access(Some(ctx), note_id, user_id)
+            .await?
+            .ensure_owner(note_id)?;
+        let expected_version = match expected_versions {
+            [] => None,
+            [version] => Some(*version),
+            // Resolves the matching version, which is then kept until the end of the transaction
+            _ => {
+                self.note_repo.lock_note(Some(ctx), note_id).await?;
+                let note = self.note_repo.get_note(Some(ctx), note_id, None).await?;
+                note.ensure_version(expected_versions)?;
+                Some(note.version())
+            }
+        };
         self.note_repo
-            .delete_note(None, input.note_id, Some(auth_ctx.user.id()))
-            .await
+            .delete_note(Some(ctx), note_id, expected_version, Some(user_id))
+            .await?;
+        Ok(DomainEvent::NoteDeleted { note_id, user_id })
     }
 }
 
 #[derive(Debug)]
 pub struct DeleteNoteInput {
     pub note_id: Uuid,
+    /// Rejects the deletion unless the note is still at one of these versions, if any.
+    pub expected_versions: Vec<u32>,
 }
@@ -0,0 +1,24 @@
+use chrono::Utc;
+use std::sync::Arc;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::repos::note::NoteRepository;
+
+#[derive(Clone)]
+pub struct EmptyTrashUseCase {
+    note_repo: Arc<dyn NoteRepository + Send + Sync>,
+}
+
+impl EmptyTrashUseCase {
+    pub fn new(note_repo: Arc<dyn NoteRepository + Send + Sync>) -> Self {
+        Self { note_repo }
+    }
+
+    /// Permanently deletes every trashed note of the user, returning their count.
+    pub async fn execute(&self, auth_ctx: AuthAccessContext) -> Result<u32, AppError> {
+        self.note_repo
+            .purge_trashed_notes(None, Utc::now(), Some(auth_ctx.user.id()))
+            .await
+    }
+}
@@ -0,0 +1,85 @@
+use futures_util::{stream, StreamExt, TryStreamExt};
+use std::sync::Arc;
+
+use crate::{
+    archive::note_archive::{ArchivedNote, NoteArchiveCodec, NoteArchiveFormat, NoteArchiveStream},
+    auth::context::AuthAccessContext,
+};
+use common::{
+    error::AppError,
+    params::{CursorPaginationParams, PageCursor, SortDirection},
+};
+use dmn::repos::note::{ArchivedFilter, GetNotesFilter, NoteRepository, NoteSort, NoteSortField};
+
+/// Number of notes fetched at once while the archive is being produced.
+const EXPORT_PAGE_SIZE: u32 = 100;
+
+#[derive(Clone)]
+pub struct ExportNotesUseCase {
+    note_repo: Arc<dyn NoteRepository + Send + Sync>,
+    note_archive_codec: Arc<dyn NoteArchiveCodec + Send + Sync>,
+}
+
+impl ExportNotesUseCase {
+    pub fn new(
+        note_repo: Arc<dyn NoteRepository + Send + Sync>,
+        note_archive_codec: Arc<dyn NoteArchiveCodec + Send + Sync>,
+    ) -> Self {
+        Self {
+            note_repo,
+            note_archive_codec,
+        }
+    }
+
+    /// Streams an archive of all the user's notes, archived ones included, oldest first.<br />
+    /// Notes are fetched page by page while the archive is consumed, trashed notes are left out.
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: ExportNotesInput,
+    ) -> Result<NoteArchiveStream, AppError> {
+        let user_id = auth_ctx.user.id();
+        let note_repo = self.note_repo.clone();
+
+        // `None` once the last page has been fetched
+        let first_page: Option<Option<PageCursor>> = Some(None);
+        let pages = stream::try_unfold(first_page, move |page| {
+            let note_repo = note_repo.clone();
+            async move {
+                let Some(after) = page else {
+                    return Ok(None);
+                };
+                let pagination = CursorPaginationParams {
+                    after,
+                    limit: EXPORT_PAGE_SIZE,
+                }
+                .into();
+                let filter = GetNotesFilter {
+                    archived: ArchivedFilter::Include,
+                    ..Default::default()
+                };
+                let sort = NoteSort {
+                    field: NoteSortField::CreatedAt,
+                    direction: SortDirection::Asc,
+                };
+                let res = note_repo
+                    .get_notes(None, &pagination, &filter, &sort, Some(user_id))
+                    .await?;
+                let notes: Vec<ArchivedNote> = res.notes.iter().map(|note| note.into()).collect();
+                Ok::<_, AppError>(Some((notes, res.next_cursor.map(Some))))
+            }
+        });
+        let notes = pages
+            .map_ok(|notes| stream::iter(notes.into_iter().map(Ok)))
+            .try_flatten()
+            .boxed();
+
+        let archive = self.note_archive_codec.write_archive(input.format, notes);
+        Ok(archive)
+    }
+}
+
+#[derive(Debug)]
+pub struct ExportNotesInput {
+    pub format: NoteArchiveFormat,
+}
@@ -3,16 +3,26 @@ use uuid::Uuid;
 
 use crate::auth::context::AuthAccessContext;
 use common::error::AppError;
-use dmn::{entities::note::Note, repos::note::NoteRepository};
+use dmn::{
+    entities::note::Note,
+    repos::{note::NoteRepository, note_share::NoteShareRepository},
+};
 
 #[derive(Clone)]
 pub struct GetNoteUseCase {
     note_repo: Arc<dyn NoteRepository + Send + Sync>,
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
 }
 
 impl GetNoteUseCase {
-    pub fn new(note_repo: Arc<dyn NoteRepository + Send + Sync>) -> Self {
-        Self { note_repo }
+    pub fn new(
+        note_repo: Arc<dyn NoteRepository + Send + Sync>,
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            note_repo,
+            note_share_repo,
+        }
     }
 
     pub async fn execute(
@@ -20,10 +30,11 @@ impl GetNoteUseCase {
         auth_ctx: AuthAccessContext,
         input: GetNoteInput,
     ) -> Result<Note, AppError> {
-        let note = self
-            .note_repo
-            .get_note(None, input.note_id, Some(auth_ctx.user.id()))
+        // Owners and collaborators alike can read the note
+        self.note_share_repo
+            .get_note_access(None, input.note_id, auth_ctx.user.id())
             .await?;
+        let note = self.note_repo.get_note(None, input.note_id, None).await?;
         Ok(note)
     }
 }
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::repos::note::{NoteChanges, NoteRepository, SyncFrom};
+
+#[derive(Clone)]
+pub struct GetNoteChangesUseCase {
+    note_repo: Arc<dyn NoteRepository + Send + Sync>,
+}
+
+impl GetNoteChangesUseCase {
+    pub fn new(note_repo: Arc<dyn NoteRepository + Send + Sync>) -> Self {
+        Self { note_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: GetNoteChangesInput,
+    ) -> Result<NoteChanges, AppError> {
+        let changes = self
+            .note_repo
+            .get_note_changes(None, input.from, input.limit, auth_ctx.user.id())
+            .await?;
+        Ok(changes)
+    }
+}
+
+#[derive(Debug)]
+pub struct GetNoteChangesInput {
+    pub from: SyncFrom,
+    /// Maximum number of changes to return.
+    pub limit: u32,
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::{
+    entities::note_revision::{NoteRevision, NoteRevisionDiff},
+    repos::{
+        note::NoteRepository, note_revision::NoteRevisionRepository,
+        note_share::NoteShareRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct GetNoteRevisionUseCase {
+    note_repo: Arc<dyn NoteRepository + Send + Sync>,
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+    note_revision_repo: Arc<dyn NoteRevisionRepository + Send + Sync>,
+}
+
+impl GetNoteRevisionUseCase {
+    pub fn new(
+        note_repo: Arc<dyn NoteRepository + Send + Sync>,
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+        note_revision_repo: Arc<dyn NoteRevisionRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            note_repo,
+            note_share_repo,
+            note_revision_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: GetNoteRevisionInput,
+    ) -> Result<GetNoteRevisionOutput, AppError> {
+        self.note_share_repo
+            .get_note_access(None, input.note_id, auth_ctx.user.id())
+            .await?;
+        let note = self.note_repo.get_note(None, input.note_id, None).await?;
+        let revision = self
+            .note_revision_repo
+            .get_note_revision(None, input.note_id, input.revision)
+            .await?;
+        let diff = revision.diff(&note);
+        Ok(GetNoteRevisionOutput { revision, diff })
+    }
+}
+
+#[derive(Debug)]
+pub struct GetNoteRevisionInput {
+    pub note_id: Uuid,
+    pub revision: u32,
+}
+
+#[derive(Debug)]
+pub struct GetNoteRevisionOutput {
+    pub revision: NoteRevision,
+    /// Changes from the revision to the current version of the note.
+    pub diff: NoteRevisionDiff,
+}
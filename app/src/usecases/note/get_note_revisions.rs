@@ -0,0 +1,49 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::{error::AppError, params::PaginationParams};
+use dmn::repos::{
+    note_revision::{GetNoteRevisionsResponse, NoteRevisionRepository},
+    note_share::NoteShareRepository,
+};
+
+#[derive(Clone)]
+pub struct GetNoteRevisionsUseCase {
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+    note_revision_repo: Arc<dyn NoteRevisionRepository + Send + Sync>,
+}
+
+impl GetNoteRevisionsUseCase {
+    pub fn new(
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+        note_revision_repo: Arc<dyn NoteRevisionRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            note_share_repo,
+            note_revision_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: GetNoteRevisionsInput,
+    ) -> Result<GetNoteRevisionsResponse, AppError> {
+        // Note has to be accessible to the requesting user
+        self.note_share_repo
+            .get_note_access(None, input.note_id, auth_ctx.user.id())
+            .await?;
+        let revisions = self
+            .note_revision_repo
+            .get_note_revisions(None, input.note_id, &input.pagination)
+            .await?;
+        Ok(revisions)
+    }
+}
+
+#[derive(Debug)]
+pub struct GetNoteRevisionsInput {
+    pub note_id: Uuid,
+    pub pagination: PaginationParams,
+}
@@ -1,8 +1,8 @@
 use std::sync::Arc;
 
 use crate::auth::context::AuthAccessContext;
-use common::{error::AppError, params::PaginationParams};
-use dmn::repos::note::{GetNotesResponse, NoteRepository};
+use common::{error::AppError, params::Pagination};
+use dmn::repos::note::{GetNotesFilter, GetNotesResponse, NoteRepository, NoteSort};
 
 #[derive(Clone)]
 pub struct GetNotesUseCase {
@@ -21,7 +21,13 @@ impl GetNotesUseCase {
     ) -> Result<GetNotesResponse, AppError> {
         let notes = self
             .note_repo
-            .get_notes(None, &input.pagination, Some(auth_ctx.user.id()))
+            .get_notes(
+                None,
+                &input.pagination,
+                &input.filter,
+                &input.sort,
+                Some(auth_ctx.user.id()),
+            )
             .await?;
         Ok(notes)
     }
@@ -29,5 +35,7 @@ impl GetNotesUseCase {
 
 #[derive(Debug)]
 pub struct GetNotesInput {
-    pub pagination: PaginationParams,
+    pub pagination: Pagination,
+    pub filter: GetNotesFilter,
+    pub sort: NoteSort,
 }
@@ -0,0 +1,244 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+use uuid::Uuid;
+
+use crate::{
+    archive::note_archive::{ArchivedNote, NoteArchiveCodec, NoteArchiveFormat},
+    auth::context::AuthAccessContext,
+};
+use common::{
+    error::AppError,
+    tx::{ctx::TransactionContext, TransactionResult, UnitOfWork},
+};
+use dmn::{
+    entities::{
+        note::{CreateNoteData, Note},
+        tag::CreateTagData,
+    },
+    repos::{
+        note::NoteRepository, notebook::NotebookRepository, outbox::OutboxRepository,
+        tag::TagRepository,
+    },
+};
+
+use super::CreateNoteUseCase;
+
+/// Upper bound on the size of an uploaded archive.
+pub const NOTE_IMPORT_MAX_SIZE: usize = 50 * 1024 * 1024; // 50 MiB
+
+#[derive(Clone)]
+pub struct ImportNotesUseCase {
+    unit_of_work: Arc<dyn UnitOfWork>,
+    note_repo: Arc<dyn NoteRepository + Send + Sync>,
+    outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+    tag_repo: Arc<dyn TagRepository + Send + Sync>,
+    note_archive_codec: Arc<dyn NoteArchiveCodec + Send + Sync>,
+    create_note_use_case: CreateNoteUseCase,
+}
+
+impl ImportNotesUseCase {
+    pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        note_repo: Arc<dyn NoteRepository + Send + Sync>,
+        notebook_repo: Arc<dyn NotebookRepository + Send + Sync>,
+        outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+        tag_repo: Arc<dyn TagRepository + Send + Sync>,
+        note_archive_codec: Arc<dyn NoteArchiveCodec + Send + Sync>,
+    ) -> Self {
+        Self {
+            create_note_use_case: CreateNoteUseCase::new(
+                unit_of_work.clone(),
+                note_repo.clone(),
+                notebook_repo,
+                outbox_repo.clone(),
+            ),
+            unit_of_work,
+            note_repo,
+            outbox_repo,
+            tag_repo,
+            note_archive_codec,
+        }
+    }
+
+    /// Creates a new root level note for each valid file of the archive, all within a single transaction.<br />
+    /// Files are validated like [`super::CreateNoteUseCase`] input, invalid ones are reported and skipped.
+    /// Tags are matched by name, ignoring case, missing ones being created.
+    /// Notes keep the timestamps found in their front matter, if any.
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: ImportNotesInput,
+    ) -> Result<ImportNotesOutput, AppError> {
+        if input.data.len() > NOTE_IMPORT_MAX_SIZE {
+            return Err(AppError::bad_request(format!(
+                "Archive cannot exceed {NOTE_IMPORT_MAX_SIZE} bytes!"
+            )));
+        }
+        let user_id = auth_ctx.user.id();
+        let entries = self
+            .note_archive_codec
+            .read_archive(input.format, input.data)
+            .await?;
+
+        let mut files = Vec::new();
+        let mut failed = Vec::new();
+        for entry in entries {
+            match entry
+                .note
+                .and_then(|note| ImportNoteData::try_from_archived(note, user_id))
+            {
+                Ok(note_data) => files.push((entry.file_name, note_data)),
+                Err(error) => failed.push(FailedNoteFile {
+                    file_name: entry.file_name,
+                    error,
+                }),
+            }
+        }
+
+        let note_repo = self.note_repo.clone();
+        let outbox_repo = self.outbox_repo.clone();
+        let tag_repo = self.tag_repo.clone();
+        let create_note_use_case = self.create_note_use_case.clone();
+        let result = self
+            .unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    let mut writer =
+                        ImportedNoteWriter::new(ctx, tag_repo.as_ref(), user_id).await?;
+                    let mut imported = Vec::with_capacity(files.len());
+                    let mut events = Vec::with_capacity(files.len());
+                    for (file_name, note_data) in files {
+                        let note = writer
+                            .create_note(
+                                ctx,
+                                &create_note_use_case,
+                                note_repo.as_ref(),
+                                tag_repo.as_ref(),
+                                note_data,
+                            )
+                            .await?;
+                        events.push(note.created_event());
+                        imported.push(ImportedNoteFile { file_name, note });
+                    }
+                    outbox_repo.append_events(Some(ctx), &events).await?;
+                    Ok(TransactionResult::new(imported))
+                })
+            }))
+            .await?;
+
+        let imported = result.extract::<Vec<ImportedNoteFile>>()?;
+        Ok(ImportNotesOutput { imported, failed })
+    }
+}
+
+#[derive(Debug)]
+pub struct ImportNotesInput {
+    pub format: NoteArchiveFormat,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug)]
+pub struct ImportNotesOutput {
+    pub imported: Vec<ImportedNoteFile>,
+    pub failed: Vec<FailedNoteFile>,
+}
+
+#[derive(Debug)]
+pub struct ImportedNoteFile {
+    pub file_name: String,
+    pub note: Note,
+}
+
+#[derive(Debug)]
+pub struct FailedNoteFile {
+    pub file_name: String,
+    pub error: AppError,
+}
+
+/// Validated contents of an imported note.
+pub(crate) struct ImportNoteData {
+    note: CreateNoteData,
+    tags: Vec<CreateTagData>,
+}
+
+impl ImportNoteData {
+    /// Validates the note like [`super::CreateNoteUseCase`] input, keeping its original timestamps.
+    pub(crate) fn try_from_archived(note: ArchivedNote, user_id: Uuid) -> Result<Self, AppError> {
+        let note_data = CreateNoteData {
+            user_id,
+            notebook_id: None,
+            title: note.title,
+            content: note.content,
+            created_at: note.created_at,
+            updated_at: note.updated_at,
+        }
+        .validate()?;
+        let mut tags: Vec<CreateTagData> = note
+            .tags
+            .into_iter()
+            .map(|name| CreateTagData { user_id, name }.validate())
+            .collect::<Result<_, _>>()?;
+        let mut seen = HashSet::with_capacity(tags.len());
+        tags.retain(|tag| seen.insert(tag.name.to_lowercase()));
+        Ok(Self {
+            note: note_data,
+            tags,
+        })
+    }
+}
+
+/// Creates imported notes within a transaction.<br />
+/// Tags are matched by name, ignoring case, missing ones being created.
+pub(crate) struct ImportedNoteWriter {
+    user_id: Uuid,
+    tag_ids: HashMap<String, Uuid>,
+}
+
+impl ImportedNoteWriter {
+    pub(crate) async fn new(
+        ctx: &mut dyn TransactionContext,
+        tag_repo: &(dyn TagRepository + Send + Sync),
+        user_id: Uuid,
+    ) -> Result<Self, AppError> {
+        let tag_ids = tag_repo
+            .get_tags(Some(ctx), Some(user_id))
+            .await?
+            .into_iter()
+            .map(|tag| (tag.name().to_lowercase(), tag.id()))
+            .collect();
+        Ok(Self { user_id, tag_ids })
+    }
+
+    /// Creates the note through [`CreateNoteUseCase`], attaching its tags afterwards.
+    pub(crate) async fn create_note(
+        &mut self,
+        ctx: &mut dyn TransactionContext,
+        create_note_use_case: &CreateNoteUseCase,
+        note_repo: &(dyn NoteRepository + Send + Sync),
+        tag_repo: &(dyn TagRepository + Send + Sync),
+        note_data: ImportNoteData,
+    ) -> Result<Note, AppError> {
+        let note = create_note_use_case
+            .create_note(ctx, note_data.note)
+            .await?;
+        for tag_data in note_data.tags {
+            let key = tag_data.name.to_lowercase();
+            let tag_id = match self.tag_ids.get(&key) {
+                Some(tag_id) => *tag_id,
+                None => {
+                    let tag = tag_repo.create_tag(Some(ctx), tag_data).await?;
+                    self.tag_ids.insert(key, tag.id());
+                    tag.id()
+                }
+            };
+            tag_repo
+                .attach_note_tag(Some(ctx), note.id(), tag_id)
+                .await?;
+        }
+        note_repo
+            .get_note(Some(ctx), note.id(), Some(self.user_id))
+            .await
+    }
+}
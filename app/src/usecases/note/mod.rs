@@ -1,11 +1,51 @@
+mod bulk_delete_notes;
+mod bulk_move_notes;
+mod bulk_note_operations;
+mod bulk_tag_notes;
 mod create_note;
 mod delete_note;
+mod empty_trash;
+mod export_notes;
 mod get_note;
+mod get_note_changes;
+mod get_note_revision;
+mod get_note_revisions;
 mod get_notes;
+mod get_trashed_notes;
+mod import_notes;
+mod move_note;
+mod patch_note;
+mod purge_expired_notes;
+mod purge_note;
+mod restore_note;
+mod restore_note_revision;
+mod search_notes;
+mod set_note_flag;
+mod subscribe_note_events;
 mod update_note;
 
+pub use bulk_delete_notes::*;
+pub use bulk_move_notes::*;
+pub use bulk_note_operations::*;
+pub use bulk_tag_notes::*;
 pub use create_note::*;
 pub use delete_note::*;
+pub use empty_trash::*;
+pub use export_notes::*;
 pub use get_note::*;
+pub use get_note_changes::*;
+pub use get_note_revision::*;
+pub use get_note_revisions::*;
 pub use get_notes::*;
+pub use get_trashed_notes::*;
+pub use import_notes::*;
+pub use move_note::*;
+pub use patch_note::*;
+pub use purge_expired_notes::*;
+pub use purge_note::*;
+pub use restore_note::*;
+pub use restore_note_revision::*;
+pub use search_notes::*;
+pub use set_note_flag::*;
+pub use subscribe_note_events::*;
 pub use update_note::*;
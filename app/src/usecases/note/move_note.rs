@@ -0,0 +1,88 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::{
+    error::AppError,
+    tx::{TransactionResult, UnitOfWork},
+};
+use dmn::{
+    entities::note::Note,
+    repos::{
+        note::NoteRepository, note_share::NoteShareRepository, notebook::NotebookRepository,
+        outbox::OutboxRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct MoveNoteUseCase {
+    unit_of_work: Arc<dyn UnitOfWork>,
+    note_repo: Arc<dyn NoteRepository + Send + Sync>,
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+    notebook_repo: Arc<dyn NotebookRepository + Send + Sync>,
+    outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+}
+
+impl MoveNoteUseCase {
+    pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        note_repo: Arc<dyn NoteRepository + Send + Sync>,
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+        notebook_repo: Arc<dyn NotebookRepository + Send + Sync>,
+        outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            unit_of_work,
+            note_repo,
+            note_share_repo,
+            notebook_repo,
+            outbox_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: MoveNoteInput,
+    ) -> Result<Note, AppError> {
+        let user_id = Some(auth_ctx.user.id());
+        // Notebooks are personal, so collaborators can't file shared notes
+        self.note_share_repo
+            .get_note_access(None, input.note_id, auth_ctx.user.id())
+            .await?
+            .ensure_owner(input.note_id)?;
+        if let Some(notebook_id) = input.notebook_id {
+            // Target notebook has to belong to the requesting user
+            self.notebook_repo
+                .get_notebook(None, notebook_id, user_id)
+                .await?;
+        }
+
+        let note_repo = self.note_repo.clone();
+        let outbox_repo = self.outbox_repo.clone();
+        let result = self
+            .unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    let note = note_repo
+                        .move_note(Some(ctx), input.note_id, input.notebook_id, user_id)
+                        .await?;
+                    outbox_repo
+                        .append_events(Some(ctx), &[note.updated_event()])
+                        .await?;
+                    Ok(TransactionResult::new(note))
+                })
+            }))
+            .await?;
+
+        let note = result.extract::<Note>()?;
+        Ok(note)
+    }
+}
+
+#[derive(Debug)]
+pub struct MoveNoteInput {
+    pub note_id: Uuid,
+    /// Target notebook, `None` moves the note back to the root level.
+    pub notebook_id: Option<Uuid>,
+}
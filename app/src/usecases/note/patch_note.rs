@@ -0,0 +1,122 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::{
+    error::AppError,
+    tx::{TransactionResult, UnitOfWork},
+};
+use dmn::{
+    entities::note::{Note, PatchNoteData},
+    repos::{
+        note::NoteRepository, note_revision::NoteRevisionRepository,
+        note_share::NoteShareRepository, outbox::OutboxRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct PatchNoteUseCase {
+    unit_of_work: Arc<dyn UnitOfWork>,
+    note_repo: Arc<dyn NoteRepository + Send + Sync>,
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+    note_revision_repo: Arc<dyn NoteRevisionRepository + Send + Sync>,
+    outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+}
+
+impl PatchNoteUseCase {
+    pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        note_repo: Arc<dyn NoteRepository + Send + Sync>,
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+        note_revision_repo: Arc<dyn NoteRevisionRepository + Send + Sync>,
+        outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            unit_of_work,
+            note_repo,
+            note_share_repo,
+            note_revision_repo,
+            outbox_repo,
+        }
+    }
+
+    /// Updates the supplied note fields only, otherwise behaving like [`super::UpdateNoteUseCase`].
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        mut input: PatchNoteInput,
+    ) -> Result<Note, AppError> {
+        let note_id = input.note_id;
+        let expected_versions = std::mem::take(&mut input.expected_versions);
+        let note_data: PatchNoteData = input.try_into()?;
+        let user_id = auth_ctx.user.id();
+
+        let note_repo = self.note_repo.clone();
+        let note_share_repo = self.note_share_repo.clone();
+        let note_revision_repo = self.note_revision_repo.clone();
+        let outbox_repo = self.outbox_repo.clone();
+        let result = self
+            .unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    // Collaborators with write access can edit the note too
+                    note_share_repo
+                        .get_note_access(Some(ctx), note_id, user_id)
+                        .await?
+                        .ensure_can_write(note_id)?;
+                    note_repo.lock_note(Some(ctx), note_id).await?;
+                    let note = note_repo.get_note(Some(ctx), note_id, None).await?;
+                    note.ensure_version(&expected_versions)?;
+                    if note_data.is_empty() {
+                        return Ok(TransactionResult::new(note));
+                    }
+                    let title_changed = note_data
+                        .title
+                        .as_ref()
+                        .is_some_and(|title| note.title() != title.trim());
+                    let content_changed = note_data
+                        .content
+                        .as_ref()
+                        .is_some_and(|content| note.content() != content.trim());
+                    if title_changed || content_changed {
+                        note_revision_repo
+                            .create_note_revision(Some(ctx), (&note).into())
+                            .await?;
+                    }
+                    let note = note_repo
+                        .patch_note(Some(ctx), note_id, note_data, Some(note.version()), None)
+                        .await?;
+                    outbox_repo
+                        .append_events(Some(ctx), &[note.updated_event()])
+                        .await?;
+                    Ok(TransactionResult::new(note))
+                })
+            }))
+            .await?;
+
+        let note = result.extract::<Note>()?;
+        Ok(note)
+    }
+}
+
+#[derive(Debug)]
+pub struct PatchNoteInput {
+    pub note_id: Uuid,
+    pub title: Option<String>,
+    pub content: Option<String>,
+    /// Rejects the update unless the note is still at one of these versions, if any.
+    pub expected_versions: Vec<u32>,
+}
+
+impl TryFrom<PatchNoteInput> for PatchNoteData {
+    type Error = AppError;
+
+    fn try_from(input: PatchNoteInput) -> Result<Self, Self::Error> {
+        let data = Self {
+            title: input.title,
+            content: input.content,
+        };
+        let valid_data = data.validate()?;
+        Ok(valid_data)
+    }
+}
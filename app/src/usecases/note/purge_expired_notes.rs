@@ -0,0 +1,29 @@
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+use common::error::AppError;
+use dmn::repos::note::NoteRepository;
+
+/// System task permanently deleting notes that outlived the trash retention period.
+#[derive(Clone)]
+pub struct PurgeExpiredNotesUseCase {
+    note_repo: Arc<dyn NoteRepository + Send + Sync>,
+}
+
+impl PurgeExpiredNotesUseCase {
+    pub fn new(note_repo: Arc<dyn NoteRepository + Send + Sync>) -> Self {
+        Self { note_repo }
+    }
+
+    pub async fn execute(&self, input: PurgeExpiredNotesInput) -> Result<u32, AppError> {
+        let trashed_before = Utc::now() - Duration::days(input.retention_days.into());
+        self.note_repo
+            .purge_trashed_notes(None, trashed_before, None)
+            .await
+    }
+}
+
+#[derive(Debug)]
+pub struct PurgeExpiredNotesInput {
+    pub retention_days: u32,
+}
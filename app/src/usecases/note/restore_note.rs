@@ -0,0 +1,66 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::{
+    error::AppError,
+    tx::{TransactionResult, UnitOfWork},
+};
+use dmn::{
+    entities::note::Note,
+    repos::{note::NoteRepository, outbox::OutboxRepository},
+};
+
+#[derive(Clone)]
+pub struct RestoreNoteUseCase {
+    unit_of_work: Arc<dyn UnitOfWork>,
+    note_repo: Arc<dyn NoteRepository + Send + Sync>,
+    outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+}
+
+impl RestoreNoteUseCase {
+    pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        note_repo: Arc<dyn NoteRepository + Send + Sync>,
+        outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            unit_of_work,
+            note_repo,
+            outbox_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: RestoreNoteInput,
+    ) -> Result<Note, AppError> {
+        let user_id = auth_ctx.user.id();
+
+        let note_repo = self.note_repo.clone();
+        let outbox_repo = self.outbox_repo.clone();
+        let result = self
+            .unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    let note = note_repo
+                        .restore_note(Some(ctx), input.note_id, Some(user_id))
+                        .await?;
+                    outbox_repo
+                        .append_events(Some(ctx), &[note.updated_event()])
+                        .await?;
+                    Ok(TransactionResult::new(note))
+                })
+            }))
+            .await?;
+
+        let note = result.extract::<Note>()?;
+        Ok(note)
+    }
+}
+
+#[derive(Debug)]
+pub struct RestoreNoteInput {
+    pub note_id: Uuid,
+}
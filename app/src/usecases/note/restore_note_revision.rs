@@ -0,0 +1,99 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::{
+    error::AppError,
+    tx::{TransactionResult, UnitOfWork},
+};
+use dmn::{
+    entities::note::{Note, UpdateNoteData},
+    repos::{
+        note::NoteRepository, note_revision::NoteRevisionRepository,
+        note_share::NoteShareRepository, outbox::OutboxRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct RestoreNoteRevisionUseCase {
+    unit_of_work: Arc<dyn UnitOfWork>,
+    note_repo: Arc<dyn NoteRepository + Send + Sync>,
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+    note_revision_repo: Arc<dyn NoteRevisionRepository + Send + Sync>,
+    outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+}
+
+impl RestoreNoteRevisionUseCase {
+    pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        note_repo: Arc<dyn NoteRepository + Send + Sync>,
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+        note_revision_repo: Arc<dyn NoteRevisionRepository + Send + Sync>,
+        outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            unit_of_work,
+            note_repo,
+            note_share_repo,
+            note_revision_repo,
+            outbox_repo,
+        }
+    }
+
+    /// Restores the note to a revision, the current version is kept as a new revision.
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: RestoreNoteRevisionInput,
+    ) -> Result<Note, AppError> {
+        let RestoreNoteRevisionInput { note_id, revision } = input;
+        let user_id = auth_ctx.user.id();
+
+        let note_repo = self.note_repo.clone();
+        let note_share_repo = self.note_share_repo.clone();
+        let note_revision_repo = self.note_revision_repo.clone();
+        let outbox_repo = self.outbox_repo.clone();
+        let result = self
+            .unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    note_share_repo
+                        .get_note_access(Some(ctx), note_id, user_id)
+                        .await?
+                        .ensure_can_write(note_id)?;
+                    note_repo.lock_note(Some(ctx), note_id).await?;
+                    let note = note_repo.get_note(Some(ctx), note_id, None).await?;
+                    let revision = note_revision_repo
+                        .get_note_revision(Some(ctx), note_id, revision)
+                        .await?;
+                    if note.title() == revision.title() && note.content() == revision.content() {
+                        return Ok(TransactionResult::new(note));
+                    }
+                    note_revision_repo
+                        .create_note_revision(Some(ctx), (&note).into())
+                        .await?;
+                    let note_data = UpdateNoteData {
+                        title: revision.title().into(),
+                        content: revision.content().into(),
+                    };
+                    let note = note_repo
+                        .update_note(Some(ctx), note_id, note_data.validate()?, None, None)
+                        .await?;
+                    outbox_repo
+                        .append_events(Some(ctx), &[note.updated_event()])
+                        .await?;
+                    Ok(TransactionResult::new(note))
+                })
+            }))
+            .await?;
+
+        let note = result.extract::<Note>()?;
+        Ok(note)
+    }
+}
+
+#[derive(Debug)]
+pub struct RestoreNoteRevisionInput {
+    pub note_id: Uuid,
+    pub revision: u32,
+}
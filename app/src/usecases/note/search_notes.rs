@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use crate::auth::context::AuthAccessContext;
+use common::{error::AppError, params::PaginationParams};
+use dmn::repos::note::{NoteRepository, SearchNotesResponse};
+
+#[derive(Clone)]
+pub struct SearchNotesUseCase {
+    note_repo: Arc<dyn NoteRepository + Send + Sync>,
+}
+
+impl SearchNotesUseCase {
+    pub fn new(note_repo: Arc<dyn NoteRepository + Send + Sync>) -> Self {
+        Self { note_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: SearchNotesInput,
+    ) -> Result<SearchNotesResponse, AppError> {
+        let query = input.query.trim();
+        if query.is_empty() {
+            return Err(AppError::bad_request("Search query cannot be empty!"));
+        }
+
+        let results = self
+            .note_repo
+            .search_notes(None, query, &input.pagination, Some(auth_ctx.user.id()))
+            .await?;
+        Ok(results)
+    }
+}
+
+#[derive(Debug)]
+pub struct SearchNotesInput {
+    pub query: String,
+    pub pagination: PaginationParams,
+}
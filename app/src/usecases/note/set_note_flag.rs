@@ -0,0 +1,101 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::{
+    error::AppError,
+    tx::{TransactionResult, UnitOfWork},
+};
+use dmn::{
+    entities::note::Note,
+    repos::{note::NoteRepository, note_share::NoteShareRepository, outbox::OutboxRepository},
+};
+
+#[derive(Clone)]
+pub struct SetNoteFlagUseCase {
+    unit_of_work: Arc<dyn UnitOfWork>,
+    note_repo: Arc<dyn NoteRepository + Send + Sync>,
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+    outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+}
+
+impl SetNoteFlagUseCase {
+    pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        note_repo: Arc<dyn NoteRepository + Send + Sync>,
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+        outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            unit_of_work,
+            note_repo,
+            note_share_repo,
+            outbox_repo,
+        }
+    }
+
+    /// Pins, archives or favorites the note, or undoes it.<br />
+    /// Setting a flag to its current state leaves the note untouched.
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: SetNoteFlagInput,
+    ) -> Result<Note, AppError> {
+        let SetNoteFlagInput {
+            note_id,
+            flag,
+            value,
+        } = input;
+        let user_id = auth_ctx.user.id();
+
+        let note_repo = self.note_repo.clone();
+        let note_share_repo = self.note_share_repo.clone();
+        let outbox_repo = self.outbox_repo.clone();
+        let result = self
+            .unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    // Flags shape the owner's own listings, collaborators can't change them
+                    note_share_repo
+                        .get_note_access(Some(ctx), note_id, user_id)
+                        .await?
+                        .ensure_owner(note_id)?;
+                    let mut note = note_repo.get_note(Some(ctx), note_id, None).await?;
+                    let flags = note.flags();
+                    match flag {
+                        NoteFlag::Pinned => note.set_pinned(value),
+                        NoteFlag::Archived => note.set_archived(value),
+                        NoteFlag::Favorite => note.set_favorite(value),
+                    }
+                    if note.flags() == flags {
+                        return Ok(TransactionResult::new(note));
+                    }
+                    let note = note_repo
+                        .update_note_flags(Some(ctx), note_id, note.flags(), None)
+                        .await?;
+                    outbox_repo
+                        .append_events(Some(ctx), &[note.updated_event()])
+                        .await?;
+                    Ok(TransactionResult::new(note))
+                })
+            }))
+            .await?;
+
+        let note = result.extract::<Note>()?;
+        Ok(note)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum NoteFlag {
+    Pinned,
+    Archived,
+    Favorite,
+}
+
+#[derive(Debug)]
+pub struct SetNoteFlagInput {
+    pub note_id: Uuid,
+    pub flag: NoteFlag,
+    pub value: bool,
+}
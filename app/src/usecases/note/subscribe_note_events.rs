@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::{
+    auth::context::AuthAccessContext,
+    events::note_events::{NoteEventStream, NoteEventSubscriber},
+};
+use common::error::AppError;
+
+#[derive(Clone)]
+pub struct SubscribeNoteEventsUseCase {
+    note_event_subscriber: Arc<dyn NoteEventSubscriber + Send + Sync>,
+}
+
+impl SubscribeNoteEventsUseCase {
+    pub fn new(note_event_subscriber: Arc<dyn NoteEventSubscriber + Send + Sync>) -> Self {
+        Self {
+            note_event_subscriber,
+        }
+    }
+
+    pub async fn execute(&self, auth_ctx: AuthAccessContext) -> Result<NoteEventStream, AppError> {
+        self.note_event_subscriber
+            .subscribe(auth_ctx.user.id())
+            .await
+    }
+}
@@ -2,35 +2,104 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::auth::context::AuthAccessContext;
-use common::error::AppError;
+use common::{
+    error::AppError,
+    tx::{ctx::TransactionContext, TransactionResult, UnitOfWork},
+};
 use dmn::{
     entities::note::{Note, UpdateNoteData},
-    repos::note::NoteRepository,
+    repos::{
+        note::NoteRepository, note_revision::NoteRevisionRepository,
+        note_share::NoteShareRepository, outbox::OutboxRepository,
+    },
 };
 
 #[derive(Clone)]
 pub struct UpdateNoteUseCase {
+    unit_of_work: Arc<dyn UnitOfWork>,
     note_repo: Arc<dyn NoteRepository + Send + Sync>,
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+    note_revision_repo: Arc<dyn NoteRevisionRepository + Send + Sync>,
+    outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
 }
 
 impl UpdateNoteUseCase {
-    pub fn new(note_repo: Arc<dyn NoteRepository + Send + Sync>) -> Self {
-        Self { note_repo }
+    pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        note_repo: Arc<dyn NoteRepository + Send + Sync>,
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+        note_revision_repo: Arc<dyn NoteRevisionRepository + Send + Sync>,
+        outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            unit_of_work,
+            note_repo,
+            note_share_repo,
+            note_revision_repo,
+            outbox_repo,
+        }
     }
 
+    /// Updates the note, snapshotting its previous version as a revision whenever it changes.<br />
+    /// Concurrent edits are detected through the optional expected note versions.
     pub async fn execute(
         &self,
         auth_ctx: AuthAccessContext,
-        input: UpdateNoteInput,
+        mut input: UpdateNoteInput,
     ) -> Result<Note, AppError> {
         let note_id = input.note_id;
-        let note_data = input.try_into()?;
-        let note = self
-            .note_repo
-            .update_note(None, note_id, note_data, Some(auth_ctx.user.id()))
+        let expected_versions = std::mem::take(&mut input.expected_versions);
+        let note_data: UpdateNoteData = input.try_into()?;
+        let user_id = auth_ctx.user.id();
+
+        let use_case = self.clone();
+        let result = self
+            .unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    let note = use_case
+                        .update_note(ctx, user_id, note_id, note_data, &expected_versions)
+                        .await?;
+                    use_case
+                        .outbox_repo
+                        .append_events(Some(ctx), &[note.updated_event()])
+                        .await?;
+                    Ok(TransactionResult::new(note))
+                })
+            }))
             .await?;
+
+        let note = result.extract::<Note>()?;
         Ok(note)
     }
+
+    /// Updates the note within an ongoing transaction, leaving its event to the caller.
+    pub(super) async fn update_note(
+        &self,
+        ctx: &mut dyn TransactionContext,
+        user_id: Uuid,
+        note_id: Uuid,
+        note_data: UpdateNoteData,
+        expected_versions: &[u32],
+    ) -> Result<Note, AppError> {
+        // Collaborators with write access can edit the note too
+        self.note_share_repo
+            .get_note_access(Some(ctx), note_id, user_id)
+            .await?
+            .ensure_can_write(note_id)?;
+        // Keeps concurrent edits from basing their revisions on the same version
+        self.note_repo.lock_note(Some(ctx), note_id).await?;
+        let note = self.note_repo.get_note(Some(ctx), note_id, None).await?;
+        note.ensure_version(expected_versions)?;
+        if note.title() != note_data.title.trim() || note.content() != note_data.content.trim() {
+            self.note_revision_repo
+                .create_note_revision(Some(ctx), (&note).into())
+                .await?;
+        }
+        self.note_repo
+            .update_note(Some(ctx), note_id, note_data, Some(note.version()), None)
+            .await
+    }
 }
 
 #[derive(Debug)]
@@ -38,6 +107,8 @@ pub struct UpdateNoteInput {
     pub note_id: Uuid,
     pub title: String,
     pub content: String,
+    /// Rejects the update unless the note is still at one of these versions, if any.
+    pub expected_versions: Vec<u32>,
 }
 
 impl TryFrom<UpdateNoteInput> for UpdateNoteData {
@@ -0,0 +1,83 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    auth::context::AuthAccessContext, storage::blob_store::BlobStore,
+    usecases::note::NOTE_IMPORT_MAX_SIZE,
+};
+use common::{
+    error::AppError,
+    tx::{TransactionResult, UnitOfWork},
+};
+use dmn::{
+    entities::note_import_job::{CreateNoteImportJobData, NoteImportFormat, NoteImportJob},
+    repos::note_import_job::NoteImportJobRepository,
+};
+
+#[derive(Clone)]
+pub struct CreateNoteImportJobUseCase {
+    unit_of_work: Arc<dyn UnitOfWork>,
+    note_import_job_repo: Arc<dyn NoteImportJobRepository + Send + Sync>,
+    blob_store: Arc<dyn BlobStore + Send + Sync>,
+}
+
+impl CreateNoteImportJobUseCase {
+    pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        note_import_job_repo: Arc<dyn NoteImportJobRepository + Send + Sync>,
+        blob_store: Arc<dyn BlobStore + Send + Sync>,
+    ) -> Self {
+        Self {
+            unit_of_work,
+            note_import_job_repo,
+            blob_store,
+        }
+    }
+
+    /// Schedules the import of the uploaded file, its notes being imported in the background.<br />
+    /// The job is only committed once the file has been stored.
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: CreateNoteImportJobInput,
+    ) -> Result<NoteImportJob, AppError> {
+        if input.data.is_empty() {
+            return Err(AppError::bad_request("Imported file cannot be empty!"));
+        }
+        if input.data.len() > NOTE_IMPORT_MAX_SIZE {
+            return Err(AppError::bad_request(format!(
+                "Imported file cannot exceed {NOTE_IMPORT_MAX_SIZE} bytes!"
+            )));
+        }
+        let job_data = CreateNoteImportJobData {
+            id: Uuid::new_v4(),
+            user_id: auth_ctx.user.id(),
+            format: input.format,
+        };
+        let data = input.data;
+
+        let note_import_job_repo = self.note_import_job_repo.clone();
+        let blob_store = self.blob_store.clone();
+        let result = self
+            .unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    let job = note_import_job_repo
+                        .create_note_import_job(Some(ctx), job_data)
+                        .await?;
+                    blob_store.put_blob(&job.blob_key(), data).await?;
+                    Ok(TransactionResult::new(job))
+                })
+            }))
+            .await?;
+
+        let job = result.extract::<NoteImportJob>()?;
+        Ok(job)
+    }
+}
+
+#[derive(Debug)]
+pub struct CreateNoteImportJobInput {
+    pub format: NoteImportFormat,
+    pub data: Vec<u8>,
+}
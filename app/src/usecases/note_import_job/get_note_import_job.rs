@@ -0,0 +1,38 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::{
+    entities::note_import_job::NoteImportJob, repos::note_import_job::NoteImportJobRepository,
+};
+
+#[derive(Clone)]
+pub struct GetNoteImportJobUseCase {
+    note_import_job_repo: Arc<dyn NoteImportJobRepository + Send + Sync>,
+}
+
+impl GetNoteImportJobUseCase {
+    pub fn new(note_import_job_repo: Arc<dyn NoteImportJobRepository + Send + Sync>) -> Self {
+        Self {
+            note_import_job_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: GetNoteImportJobInput,
+    ) -> Result<NoteImportJob, AppError> {
+        let job = self
+            .note_import_job_repo
+            .get_note_import_job(None, input.job_id, Some(auth_ctx.user.id()))
+            .await?;
+        Ok(job)
+    }
+}
+
+#[derive(Debug)]
+pub struct GetNoteImportJobInput {
+    pub job_id: Uuid,
+}
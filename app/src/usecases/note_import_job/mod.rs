@@ -0,0 +1,7 @@
+mod create_note_import_job;
+mod get_note_import_job;
+mod process_note_import_job;
+
+pub use create_note_import_job::*;
+pub use get_note_import_job::*;
+pub use process_note_import_job::*;
@@ -0,0 +1,172 @@
+use chrono::TimeDelta;
+use std::sync::Arc;
+
+use crate::{
+    archive::note_import::NoteImportReader,
+    storage::blob_store::BlobStore,
+    usecases::note::{CreateNoteUseCase, ImportNoteData, ImportedNoteWriter},
+};
+use common::{
+    error::AppError,
+    tx::{TransactionResult, UnitOfWork},
+};
+use dmn::{
+    entities::note_import_job::{NoteImportFailure, NoteImportJob},
+    repos::{
+        note::NoteRepository, note_import_job::NoteImportJobRepository,
+        notebook::NotebookRepository, outbox::OutboxRepository, tag::TagRepository,
+    },
+};
+
+/// Time a claimed job has to be processed in before it can be claimed again.
+const IMPORT_LEASE_SECS: i64 = 30 * 60; // 30 minutes
+/// Number of notes created between two progress reports.
+const PROGRESS_REPORT_INTERVAL: u32 = 50;
+
+/// System task importing the notes of pending jobs, interrupted ones included.
+#[derive(Clone)]
+pub struct ProcessNoteImportJobUseCase {
+    unit_of_work: Arc<dyn UnitOfWork>,
+    note_repo: Arc<dyn NoteRepository + Send + Sync>,
+    note_import_job_repo: Arc<dyn NoteImportJobRepository + Send + Sync>,
+    outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+    tag_repo: Arc<dyn TagRepository + Send + Sync>,
+    blob_store: Arc<dyn BlobStore + Send + Sync>,
+    note_import_reader: Arc<dyn NoteImportReader + Send + Sync>,
+    create_note_use_case: CreateNoteUseCase,
+}
+
+impl ProcessNoteImportJobUseCase {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        note_repo: Arc<dyn NoteRepository + Send + Sync>,
+        notebook_repo: Arc<dyn NotebookRepository + Send + Sync>,
+        note_import_job_repo: Arc<dyn NoteImportJobRepository + Send + Sync>,
+        outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+        tag_repo: Arc<dyn TagRepository + Send + Sync>,
+        blob_store: Arc<dyn BlobStore + Send + Sync>,
+        note_import_reader: Arc<dyn NoteImportReader + Send + Sync>,
+    ) -> Self {
+        Self {
+            create_note_use_case: CreateNoteUseCase::new(
+                unit_of_work.clone(),
+                note_repo.clone(),
+                notebook_repo,
+                outbox_repo.clone(),
+            ),
+            unit_of_work,
+            note_repo,
+            note_import_job_repo,
+            outbox_repo,
+            tag_repo,
+            blob_store,
+            note_import_reader,
+        }
+    }
+
+    /// Processes the oldest pending job, returning whether there was one.<br />
+    /// Valid notes are imported all at once, invalid ones being recorded as failures.
+    /// The uploaded file is deleted once the job has either completed or failed.
+    pub async fn execute(&self) -> Result<bool, AppError> {
+        let Some(mut job) = self
+            .note_import_job_repo
+            .claim_note_import_job(None, TimeDelta::seconds(IMPORT_LEASE_SECS))
+            .await?
+        else {
+            return Ok(false);
+        };
+
+        if job.has_attempts_left() {
+            if let Err(err) = self.import(&mut job).await {
+                err.log();
+                job.fail(err.public_info.clone());
+            } else {
+                job.complete();
+            }
+        } else {
+            job.fail("Note import was interrupted too many times!".to_string());
+        }
+        self.note_import_job_repo
+            .update_note_import_job(None, job.id(), (&job).into())
+            .await?;
+        self.blob_store.delete_blob(&job.blob_key()).await?;
+        Ok(true)
+    }
+
+    async fn import(&self, job: &mut NoteImportJob) -> Result<(), AppError> {
+        let user_id = job.user_id();
+        let data = self.blob_store.get_blob(&job.blob_key()).await?;
+        let entries = self
+            .note_import_reader
+            .read_notes(job.format(), data)
+            .await?;
+
+        let total_count = entries.len() as u32;
+        let mut notes = Vec::new();
+        let mut failures = Vec::new();
+        for entry in entries {
+            match entry
+                .note
+                .and_then(|note| ImportNoteData::try_from_archived(note, user_id))
+            {
+                Ok(note_data) => notes.push(note_data),
+                Err(err) => failures.push(NoteImportFailure {
+                    item: entry.item,
+                    error: err.public_info.clone(),
+                }),
+            }
+        }
+        job.start(total_count, failures);
+        self.note_import_job_repo
+            .update_note_import_job(None, job.id(), (&*job).into())
+            .await?;
+
+        let mut progress_job = job.clone();
+        let note_repo = self.note_repo.clone();
+        let note_import_job_repo = self.note_import_job_repo.clone();
+        let outbox_repo = self.outbox_repo.clone();
+        let tag_repo = self.tag_repo.clone();
+        let create_note_use_case = self.create_note_use_case.clone();
+        let result = self
+            .unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    let mut writer =
+                        ImportedNoteWriter::new(ctx, tag_repo.as_ref(), user_id).await?;
+                    let mut events = Vec::with_capacity(notes.len());
+                    for note_data in notes {
+                        let note = writer
+                            .create_note(
+                                ctx,
+                                &create_note_use_case,
+                                note_repo.as_ref(),
+                                tag_repo.as_ref(),
+                                note_data,
+                            )
+                            .await?;
+                        events.push(note.created_event());
+                        let created_count = events.len() as u32;
+                        if created_count.is_multiple_of(PROGRESS_REPORT_INTERVAL) {
+                            // Reported outside of the transaction, so that it can be followed meanwhile
+                            progress_job.record_processed(created_count);
+                            note_import_job_repo
+                                .update_note_import_job(
+                                    None,
+                                    progress_job.id(),
+                                    (&progress_job).into(),
+                                )
+                                .await?;
+                        }
+                    }
+                    outbox_repo.append_events(Some(ctx), &events).await?;
+                    Ok(TransactionResult::new(events.len() as u32))
+                })
+            }))
+            .await?;
+
+        let imported_count = result.extract::<u32>()?;
+        job.record_imported(imported_count);
+        Ok(())
+    }
+}
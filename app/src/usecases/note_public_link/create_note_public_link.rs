@@ -0,0 +1,54 @@
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::{
+    entities::note_public_link::{CreateNotePublicLinkData, NotePublicLink},
+    repos::{note_public_link::NotePublicLinkRepository, note_share::NoteShareRepository},
+};
+
+#[derive(Clone)]
+pub struct CreateNotePublicLinkUseCase {
+    note_public_link_repo: Arc<dyn NotePublicLinkRepository + Send + Sync>,
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+}
+
+impl CreateNotePublicLinkUseCase {
+    pub fn new(
+        note_public_link_repo: Arc<dyn NotePublicLinkRepository + Send + Sync>,
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            note_public_link_repo,
+            note_share_repo,
+        }
+    }
+
+    /// Generates a fresh public link for the note, invalidating the previous one if any.
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: CreateNotePublicLinkInput,
+    ) -> Result<NotePublicLink, AppError> {
+        self.note_share_repo
+            .get_note_access(None, input.note_id, auth_ctx.user.id())
+            .await?
+            .ensure_owner(input.note_id)?;
+        let link_data = CreateNotePublicLinkData {
+            note_id: input.note_id,
+            expires_at: input.expires_at,
+        }
+        .validate(Utc::now())?;
+        self.note_public_link_repo
+            .create_note_public_link(None, link_data)
+            .await
+    }
+}
+
+#[derive(Debug)]
+pub struct CreateNotePublicLinkInput {
+    pub note_id: Uuid,
+    pub expires_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,43 @@
+use chrono::Utc;
+use std::sync::Arc;
+
+use common::error::AppError;
+use dmn::{
+    entities::note::Note,
+    repos::{note::NoteRepository, note_public_link::NotePublicLinkRepository},
+};
+
+#[derive(Clone)]
+pub struct GetPublicNoteUseCase {
+    note_repo: Arc<dyn NoteRepository + Send + Sync>,
+    note_public_link_repo: Arc<dyn NotePublicLinkRepository + Send + Sync>,
+}
+
+impl GetPublicNoteUseCase {
+    pub fn new(
+        note_repo: Arc<dyn NoteRepository + Send + Sync>,
+        note_public_link_repo: Arc<dyn NotePublicLinkRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            note_repo,
+            note_public_link_repo,
+        }
+    }
+
+    /// Anonymously resolves a note through its public link token.
+    pub async fn execute(&self, input: GetPublicNoteInput) -> Result<Note, AppError> {
+        let link = self
+            .note_public_link_repo
+            .get_note_public_link_by_token(None, &input.token)
+            .await?;
+        if link.is_expired(Utc::now()) {
+            return Err(AppError::not_found("Public link has expired!"));
+        }
+        self.note_repo.get_note(None, link.note_id(), None).await
+    }
+}
+
+#[derive(Debug)]
+pub struct GetPublicNoteInput {
+    pub token: String,
+}
@@ -0,0 +1,9 @@
+mod create_note_public_link;
+mod get_note_public_link;
+mod get_public_note;
+mod revoke_note_public_link;
+
+pub use create_note_public_link::*;
+pub use get_note_public_link::*;
+pub use get_public_note::*;
+pub use revoke_note_public_link::*;
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::repos::{note_public_link::NotePublicLinkRepository, note_share::NoteShareRepository};
+
+#[derive(Clone)]
+pub struct RevokeNotePublicLinkUseCase {
+    note_public_link_repo: Arc<dyn NotePublicLinkRepository + Send + Sync>,
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+}
+
+impl RevokeNotePublicLinkUseCase {
+    pub fn new(
+        note_public_link_repo: Arc<dyn NotePublicLinkRepository + Send + Sync>,
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            note_public_link_repo,
+            note_share_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: RevokeNotePublicLinkInput,
+    ) -> Result<(), AppError> {
+        self.note_share_repo
+            .get_note_access(None, input.note_id, auth_ctx.user.id())
+            .await?
+            .ensure_owner(input.note_id)?;
+        self.note_public_link_repo
+            .delete_note_public_link(None, input.note_id)
+            .await
+    }
+}
+
+#[derive(Debug)]
+pub struct RevokeNotePublicLinkInput {
+    pub note_id: Uuid,
+}
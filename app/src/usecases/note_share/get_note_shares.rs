@@ -0,0 +1,36 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::{entities::note_share::NoteShare, repos::note_share::NoteShareRepository};
+
+#[derive(Clone)]
+pub struct GetNoteSharesUseCase {
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+}
+
+impl GetNoteSharesUseCase {
+    pub fn new(note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>) -> Self {
+        Self { note_share_repo }
+    }
+
+    /// Lists the note's collaborators, visible to anyone with access to the note.
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: GetNoteSharesInput,
+    ) -> Result<Vec<NoteShare>, AppError> {
+        self.note_share_repo
+            .get_note_access(None, input.note_id, auth_ctx.user.id())
+            .await?;
+        self.note_share_repo
+            .get_note_shares(None, input.note_id)
+            .await
+    }
+}
+
+#[derive(Debug)]
+pub struct GetNoteSharesInput {
+    pub note_id: Uuid,
+}
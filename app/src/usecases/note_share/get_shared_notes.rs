@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use crate::auth::context::AuthAccessContext;
+use common::{error::AppError, params::PaginationParams};
+use dmn::repos::note::{GetNotesResponse, NoteRepository};
+
+#[derive(Clone)]
+pub struct GetSharedNotesUseCase {
+    note_repo: Arc<dyn NoteRepository + Send + Sync>,
+}
+
+impl GetSharedNotesUseCase {
+    pub fn new(note_repo: Arc<dyn NoteRepository + Send + Sync>) -> Self {
+        Self { note_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: GetSharedNotesInput,
+    ) -> Result<GetNotesResponse, AppError> {
+        let notes = self
+            .note_repo
+            .get_shared_notes(None, &input.pagination, auth_ctx.user.id())
+            .await?;
+        Ok(notes)
+    }
+}
+
+#[derive(Debug)]
+pub struct GetSharedNotesInput {
+    pub pagination: PaginationParams,
+}
@@ -0,0 +1,11 @@
+mod get_note_shares;
+mod get_shared_notes;
+mod revoke_note_share;
+mod share_note;
+mod update_note_share;
+
+pub use get_note_shares::*;
+pub use get_shared_notes::*;
+pub use revoke_note_share::*;
+pub use share_note::*;
+pub use update_note_share::*;
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::repos::note_share::NoteShareRepository;
+
+#[derive(Clone)]
+pub struct RevokeNoteShareUseCase {
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+}
+
+impl RevokeNoteShareUseCase {
+    pub fn new(note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>) -> Self {
+        Self { note_share_repo }
+    }
+
+    /// Revokes a collaborator's access, owners can revoke anyone while collaborators can only leave.
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: RevokeNoteShareInput,
+    ) -> Result<(), AppError> {
+        let access = self
+            .note_share_repo
+            .get_note_access(None, input.note_id, auth_ctx.user.id())
+            .await?;
+        if input.user_id != auth_ctx.user.id() {
+            access.ensure_owner(input.note_id)?;
+        }
+        self.note_share_repo
+            .delete_note_share(None, input.note_id, input.user_id)
+            .await
+    }
+}
+
+#[derive(Debug)]
+pub struct RevokeNoteShareInput {
+    pub note_id: Uuid,
+    /// The collaborator losing access.
+    pub user_id: Uuid,
+}
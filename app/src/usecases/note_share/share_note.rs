@@ -0,0 +1,72 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::{
+    entities::{
+        note_share::{CreateNoteShareData, NoteShare, NoteShareRole},
+        user::UniqueUserIdentifier,
+    },
+    repos::{note_share::NoteShareRepository, user::UserRepository},
+};
+
+#[derive(Clone)]
+pub struct ShareNoteUseCase {
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+    user_repo: Arc<dyn UserRepository + Send + Sync>,
+}
+
+impl ShareNoteUseCase {
+    pub fn new(
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+        user_repo: Arc<dyn UserRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            note_share_repo,
+            user_repo,
+        }
+    }
+
+    /// Grants the user registered under the given email access to the note.
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: ShareNoteInput,
+    ) -> Result<NoteShare, AppError> {
+        self.note_share_repo
+            .get_note_access(None, input.note_id, auth_ctx.user.id())
+            .await?
+            .ensure_owner(input.note_id)?;
+
+        let email = input
+            .email
+            .try_into()
+            .map_err(|err: AppError| AppError::bad_request(err.public_info.clone()))?;
+        let user = self
+            .user_repo
+            .get_user(None, &UniqueUserIdentifier::Email(email))
+            .await?;
+        if user.id() == auth_ctx.user.id() {
+            return Err(AppError::bad_request(
+                "Notes can't be shared with their owner!",
+            ));
+        }
+
+        let share_data = CreateNoteShareData {
+            note_id: input.note_id,
+            user_id: user.id(),
+            role: input.role,
+        };
+        self.note_share_repo
+            .create_note_share(None, share_data)
+            .await
+    }
+}
+
+#[derive(Debug)]
+pub struct ShareNoteInput {
+    pub note_id: Uuid,
+    pub email: String,
+    pub role: NoteShareRole,
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::{
+    entities::note_share::{NoteShare, NoteShareRole},
+    repos::note_share::NoteShareRepository,
+};
+
+#[derive(Clone)]
+pub struct UpdateNoteShareUseCase {
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+}
+
+impl UpdateNoteShareUseCase {
+    pub fn new(note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>) -> Self {
+        Self { note_share_repo }
+    }
+
+    /// Changes the role of one of the note's collaborators.
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: UpdateNoteShareInput,
+    ) -> Result<NoteShare, AppError> {
+        self.note_share_repo
+            .get_note_access(None, input.note_id, auth_ctx.user.id())
+            .await?
+            .ensure_owner(input.note_id)?;
+        self.note_share_repo
+            .update_note_share(None, input.note_id, input.user_id, input.role)
+            .await
+    }
+}
+
+#[derive(Debug)]
+pub struct UpdateNoteShareInput {
+    pub note_id: Uuid,
+    /// The collaborator whose role changes.
+    pub user_id: Uuid,
+    pub role: NoteShareRole,
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::{
+    entities::notebook::{CreateNotebookData, Notebook},
+    repos::notebook::NotebookRepository,
+};
+
+#[derive(Clone)]
+pub struct CreateNotebookUseCase {
+    notebook_repo: Arc<dyn NotebookRepository + Send + Sync>,
+}
+
+impl CreateNotebookUseCase {
+    pub fn new(notebook_repo: Arc<dyn NotebookRepository + Send + Sync>) -> Self {
+        Self { notebook_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: CreateNotebookInput,
+    ) -> Result<Notebook, AppError> {
+        if let Some(parent_id) = input.parent_id {
+            // Parent has to belong to the requesting user
+            self.notebook_repo
+                .get_notebook(None, parent_id, Some(auth_ctx.user.id()))
+                .await?;
+        }
+        let notebook_data = input.try_into_dmn(auth_ctx)?;
+        let notebook = self
+            .notebook_repo
+            .create_notebook(None, notebook_data)
+            .await?;
+        Ok(notebook)
+    }
+}
+
+#[derive(Debug)]
+pub struct CreateNotebookInput {
+    pub parent_id: Option<Uuid>,
+    pub name: String,
+}
+
+impl CreateNotebookInput {
+    pub fn try_into_dmn(self, auth_ctx: AuthAccessContext) -> Result<CreateNotebookData, AppError> {
+        let data = CreateNotebookData {
+            user_id: auth_ctx.user.id(),
+            parent_id: self.parent_id,
+            name: self.name,
+        };
+        let valid_data = data.validate()?;
+        Ok(valid_data)
+    }
+}
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::repos::notebook::NotebookRepository;
+
+#[derive(Clone)]
+pub struct DeleteNotebookUseCase {
+    notebook_repo: Arc<dyn NotebookRepository + Send + Sync>,
+}
+
+impl DeleteNotebookUseCase {
+    pub fn new(notebook_repo: Arc<dyn NotebookRepository + Send + Sync>) -> Self {
+        Self { notebook_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: DeleteNotebookInput,
+    ) -> Result<(), AppError> {
+        self.notebook_repo
+            .delete_notebook(None, input.notebook_id, Some(auth_ctx.user.id()))
+            .await
+    }
+}
+
+#[derive(Debug)]
+pub struct DeleteNotebookInput {
+    pub notebook_id: Uuid,
+}
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::{entities::notebook::Notebook, repos::notebook::NotebookRepository};
+
+#[derive(Clone)]
+pub struct GetNotebookUseCase {
+    notebook_repo: Arc<dyn NotebookRepository + Send + Sync>,
+}
+
+impl GetNotebookUseCase {
+    pub fn new(notebook_repo: Arc<dyn NotebookRepository + Send + Sync>) -> Self {
+        Self { notebook_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: GetNotebookInput,
+    ) -> Result<Notebook, AppError> {
+        let notebook = self
+            .notebook_repo
+            .get_notebook(None, input.notebook_id, Some(auth_ctx.user.id()))
+            .await?;
+        Ok(notebook)
+    }
+}
+
+#[derive(Debug)]
+pub struct GetNotebookInput {
+    pub notebook_id: Uuid,
+}
@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::{entities::notebook::Notebook, repos::notebook::NotebookRepository};
+
+#[derive(Clone)]
+pub struct GetNotebooksUseCase {
+    notebook_repo: Arc<dyn NotebookRepository + Send + Sync>,
+}
+
+impl GetNotebooksUseCase {
+    pub fn new(notebook_repo: Arc<dyn NotebookRepository + Send + Sync>) -> Self {
+        Self { notebook_repo }
+    }
+
+    pub async fn execute(&self, auth_ctx: AuthAccessContext) -> Result<Vec<Notebook>, AppError> {
+        let notebooks = self
+            .notebook_repo
+            .get_notebooks(None, Some(auth_ctx.user.id()))
+            .await?;
+        Ok(notebooks)
+    }
+}
@@ -0,0 +1,11 @@
+mod create_notebook;
+mod delete_notebook;
+mod get_notebook;
+mod get_notebooks;
+mod update_notebook;
+
+pub use create_notebook::*;
+pub use delete_notebook::*;
+pub use get_notebook::*;
+pub use get_notebooks::*;
+pub use update_notebook::*;
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::{
+    error::AppError,
+    tx::{ctx::TransactionContext, TransactionResult, UnitOfWork},
+};
+use dmn::{
+    entities::notebook::{Notebook, UpdateNotebookData},
+    repos::notebook::NotebookRepository,
+};
+
+#[derive(Clone)]
+pub struct UpdateNotebookUseCase {
+    unit_of_work: Arc<dyn UnitOfWork>,
+    notebook_repo: Arc<dyn NotebookRepository + Send + Sync>,
+}
+
+impl UpdateNotebookUseCase {
+    pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        notebook_repo: Arc<dyn NotebookRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            unit_of_work,
+            notebook_repo,
+        }
+    }
+
+    /// Renames the notebook and/or moves it under another parent.
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: UpdateNotebookInput,
+    ) -> Result<Notebook, AppError> {
+        let user_id = Some(auth_ctx.user.id());
+
+        let notebook_repo = self.notebook_repo.clone();
+        let result = self
+            .unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    let mut notebook = notebook_repo
+                        .get_notebook(Some(ctx), input.notebook_id, user_id)
+                        .await?;
+
+                    notebook.set_name(input.name)?;
+                    if input.parent_id != notebook.parent_id() {
+                        match input.parent_id {
+                            Some(parent_id) => {
+                                let parent = notebook_repo
+                                    .get_notebook(Some(ctx), parent_id, user_id)
+                                    .await?;
+                                let parent_path = lock_notebook_path(
+                                    ctx,
+                                    notebook_repo.as_ref(),
+                                    parent_id,
+                                    notebook.id(),
+                                )
+                                .await?;
+                                notebook.set_parent(Some(&parent), &parent_path)?;
+                            }
+                            None => notebook.set_parent(None, &[])?,
+                        }
+                    }
+
+                    let notebook_data = UpdateNotebookData::from(&notebook);
+                    let notebook = notebook_repo
+                        .update_notebook(Some(ctx), notebook.id(), notebook_data, user_id)
+                        .await?;
+                    Ok(TransactionResult::new(notebook))
+                })
+            }))
+            .await?;
+
+        let notebook = result.extract::<Notebook>()?;
+        Ok(notebook)
+    }
+}
+
+/// Returns the path of the new parent, locked along with the moved notebook until the end of the
+/// transaction.<br />
+/// Concurrent moves would otherwise each pass the cycle check, e.g. when moving two notebooks under
+/// one another. A path changed before being locked is locked anew.
+async fn lock_notebook_path(
+    ctx: &mut dyn TransactionContext,
+    notebook_repo: &(dyn NotebookRepository + Send + Sync),
+    parent_id: Uuid,
+    notebook_id: Uuid,
+) -> Result<Vec<Uuid>, AppError> {
+    let mut parent_path = notebook_repo
+        .get_notebook_path(Some(ctx), parent_id)
+        .await?;
+    loop {
+        let mut notebook_ids = parent_path.clone();
+        notebook_ids.push(notebook_id);
+        notebook_repo
+            .lock_notebooks(Some(ctx), &notebook_ids)
+            .await?;
+        let locked_path = notebook_repo
+            .get_notebook_path(Some(ctx), parent_id)
+            .await?;
+        if locked_path == parent_path {
+            return Ok(parent_path);
+        }
+        parent_path = locked_path;
+    }
+}
+
+#[derive(Debug)]
+pub struct UpdateNotebookInput {
+    pub notebook_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub name: String,
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::{
+    entities::note::Note,
+    repos::{note::NoteRepository, note_share::NoteShareRepository, tag::TagRepository},
+};
+
+#[derive(Clone)]
+pub struct AttachNoteTagUseCase {
+    note_repo: Arc<dyn NoteRepository + Send + Sync>,
+    note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+    tag_repo: Arc<dyn TagRepository + Send + Sync>,
+}
+
+impl AttachNoteTagUseCase {
+    pub fn new(
+        note_repo: Arc<dyn NoteRepository + Send + Sync>,
+        note_share_repo: Arc<dyn NoteShareRepository + Send + Sync>,
+        tag_repo: Arc<dyn TagRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            note_repo,
+            note_share_repo,
+            tag_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: AttachNoteTagInput,
+    ) -> Result<Note, AppError> {
+        let user_id = Some(auth_ctx.user.id());
+        // Both the note and the tag have to belong to the requesting user.
+        self.note_share_repo
+            .get_note_access(None, input.note_id, auth_ctx.user.id())
+            .await?
+            .ensure_owner(input.note_id)?;
+        self.tag_repo.get_tag(None, input.tag_id, user_id).await?;
+        self.tag_repo
+            .attach_note_tag(None, input.note_id, input.tag_id)
+            .await?;
+        let note = self
+            .note_repo
+            .get_note(None, input.note_id, user_id)
+            .await?;
+        Ok(note)
+    }
+}
+
+#[derive(Debug)]
+pub struct AttachNoteTagInput {
+    pub note_id: Uuid,
+    pub tag_id: Uuid,
+}
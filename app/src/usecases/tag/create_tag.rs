@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::{
+    entities::tag::{CreateTagData, Tag},
+    repos::tag::TagRepository,
+};
+
+#[derive(Clone)]
+pub struct CreateTagUseCase {
+    tag_repo: Arc<dyn TagRepository + Send + Sync>,
+}
+
+impl CreateTagUseCase {
+    pub fn new(tag_repo: Arc<dyn TagRepository + Send + Sync>) -> Self {
+        Self { tag_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: CreateTagInput,
+    ) -> Result<Tag, AppError> {
+        let tag_data = input.try_into_dmn(auth_ctx)?;
+        let tag = self.tag_repo.create_tag(None, tag_data).await?;
+        Ok(tag)
+    }
+}
+
+#[derive(Debug)]
+pub struct CreateTagInput {
+    pub name: String,
+}
+
+impl CreateTagInput {
+    pub fn try_into_dmn(self, auth_ctx: AuthAccessContext) -> Result<CreateTagData, AppError> {
+        let data = CreateTagData {
+            user_id: auth_ctx.user.id(),
+            name: self.name,
+        };
+        let valid_data = data.validate()?;
+        Ok(valid_data)
+    }
+}
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::repos::tag::TagRepository;
+
+#[derive(Clone)]
+pub struct DeleteTagUseCase {
+    tag_repo: Arc<dyn TagRepository + Send + Sync>,
+}
+
+impl DeleteTagUseCase {
+    pub fn new(tag_repo: Arc<dyn TagRepository + Send + Sync>) -> Self {
+        Self { tag_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: DeleteTagInput,
+    ) -> Result<(), AppError> {
+        self.tag_repo
+            .delete_tag(None, input.tag_id, Some(auth_ctx.user.id()))
+            .await
+    }
+}
+
+#[derive(Debug)]
+pub struct DeleteTagInput {
+    pub tag_id: Uuid,
+}
@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::{entities::tag::Tag, repos::tag::TagRepository};
+
+#[derive(Clone)]
+pub struct GetTagsUseCase {
+    tag_repo: Arc<dyn TagRepository + Send + Sync>,
+}
+
+impl GetTagsUseCase {
+    pub fn new(tag_repo: Arc<dyn TagRepository + Send + Sync>) -> Self {
+        Self { tag_repo }
+    }
+
+    pub async fn execute(&self, auth_ctx: AuthAccessContext) -> Result<Vec<Tag>, AppError> {
+        let tags = self
+            .tag_repo
+            .get_tags(None, Some(auth_ctx.user.id()))
+            .await?;
+        Ok(tags)
+    }
+}
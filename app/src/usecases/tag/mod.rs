@@ -0,0 +1,13 @@
+mod attach_note_tag;
+mod create_tag;
+mod delete_tag;
+mod detach_note_tag;
+mod get_tags;
+mod rename_tag;
+
+pub use attach_note_tag::*;
+pub use create_tag::*;
+pub use delete_tag::*;
+pub use detach_note_tag::*;
+pub use get_tags::*;
+pub use rename_tag::*;
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::{
+    entities::tag::{Tag, UpdateTagData},
+    repos::tag::TagRepository,
+};
+
+#[derive(Clone)]
+pub struct RenameTagUseCase {
+    tag_repo: Arc<dyn TagRepository + Send + Sync>,
+}
+
+impl RenameTagUseCase {
+    pub fn new(tag_repo: Arc<dyn TagRepository + Send + Sync>) -> Self {
+        Self { tag_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: RenameTagInput,
+    ) -> Result<Tag, AppError> {
+        let tag_id = input.tag_id;
+        let tag_data = input.try_into()?;
+        let tag = self
+            .tag_repo
+            .update_tag(None, tag_id, tag_data, Some(auth_ctx.user.id()))
+            .await?;
+        Ok(tag)
+    }
+}
+
+#[derive(Debug)]
+pub struct RenameTagInput {
+    pub tag_id: Uuid,
+    pub name: String,
+}
+
+impl TryFrom<RenameTagInput> for UpdateTagData {
+    type Error = AppError;
+
+    fn try_from(input: RenameTagInput) -> Result<Self, Self::Error> {
+        let data = Self { name: input.name };
+        let valid_data = data.validate()?;
+        Ok(valid_data)
+    }
+}
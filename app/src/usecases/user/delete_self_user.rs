@@ -1,21 +1,33 @@
 use std::sync::Arc;
 
 use crate::auth::{context::AuthAccessContext, pass_service::PasswordService};
-use common::error::AppError;
-use dmn::{entities::user::UniqueUserIdentifier, repos::user::UserRepository};
+use common::{
+    error::AppError,
+    tx::{TransactionResult, UnitOfWork},
+};
+use dmn::{
+    entities::user::UniqueUserIdentifier,
+    repos::{outbox::OutboxRepository, user::UserRepository},
+};
 
 #[derive(Clone)]
 pub struct DeleteSelfUserUseCase {
+    unit_of_work: Arc<dyn UnitOfWork>,
+    outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
     user_repo: Arc<dyn UserRepository + Send + Sync>,
     pass_service: Arc<dyn PasswordService + Send + Sync>,
 }
 
 impl DeleteSelfUserUseCase {
     pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
         user_repo: Arc<dyn UserRepository + Send + Sync>,
         pass_service: Arc<dyn PasswordService + Send + Sync>,
     ) -> Self {
         Self {
+            unit_of_work,
+            outbox_repo,
             user_repo,
             pass_service,
         }
@@ -34,7 +46,19 @@ impl DeleteSelfUserUseCase {
             .verify_password(&user_id, &input.password)
             .await?;
 
-        self.user_repo.delete_user(None, &user_id).await?;
+        let event = auth_ctx.user.deleted_event();
+
+        let user_repo = self.user_repo.clone();
+        let outbox_repo = self.outbox_repo.clone();
+        self.unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    user_repo.delete_user(Some(ctx), &user_id).await?;
+                    outbox_repo.append_events(Some(ctx), &[event]).await?;
+                    Ok(TransactionResult::new(()))
+                })
+            }))
+            .await?;
         Ok(())
     }
 }
@@ -2,7 +2,7 @@ use crate::auth::context::AuthAccessContext;
 use common::error::AppError;
 use dmn::entities::user::User;
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct GetSelfUserUseCase;
 
 impl GetSelfUserUseCase {
@@ -1,24 +1,33 @@
 use std::sync::Arc;
 
 use crate::auth::pass_service::PasswordService;
-use common::error::AppError;
+use common::{
+    error::AppError,
+    tx::{TransactionResult, UnitOfWork},
+};
 use dmn::{
     entities::user::{CreateUserData, UniqueUserIdentifier, User},
-    repos::user::UserRepository,
+    repos::{outbox::OutboxRepository, user::UserRepository},
 };
 
 #[derive(Clone)]
 pub struct RegisterUserUseCase {
+    unit_of_work: Arc<dyn UnitOfWork>,
+    outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
     user_repo: Arc<dyn UserRepository + Send + Sync>,
     pass_service: Arc<dyn PasswordService + Send + Sync>,
 }
 
 impl RegisterUserUseCase {
     pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
         user_repo: Arc<dyn UserRepository + Send + Sync>,
         pass_service: Arc<dyn PasswordService + Send + Sync>,
     ) -> Self {
         Self {
+            unit_of_work,
+            outbox_repo,
             user_repo,
             pass_service,
         }
@@ -27,9 +36,9 @@ impl RegisterUserUseCase {
     pub async fn execute(&self, input: RegisterUserInput) -> Result<User, AppError> {
         // Validate Password
         self.pass_service
-            .validate_password_strength(&input.password)?;
+            .validate_password_strength(&input.password, &input.email)?;
 
-        let password_hash = self.pass_service.hash(&input.password)?;
+        let password_hash = self.pass_service.hash(&input.password).await?;
         let user_data = input.try_into_dmn(password_hash)?;
 
         let user_id = UniqueUserIdentifier::Email(user_data.email.clone());
@@ -42,7 +51,22 @@ impl RegisterUserUseCase {
             Err(err) => Err(err.reword("Couldn't verify email availability!".to_string())),
         }?;
 
-        let user = self.user_repo.create_user(None, user_data).await?;
+        let user_repo = self.user_repo.clone();
+        let outbox_repo = self.outbox_repo.clone();
+        let result = self
+            .unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    let user = user_repo.create_user(Some(ctx), user_data).await?;
+                    outbox_repo
+                        .append_events(Some(ctx), &[user.registered_event()])
+                        .await?;
+                    Ok(TransactionResult::new(user))
+                })
+            }))
+            .await?;
+
+        let user = result.extract::<User>()?;
         Ok(user)
     }
 }
@@ -1,20 +1,33 @@
 use std::sync::Arc;
 
 use crate::auth::context::AuthAccessContext;
-use common::error::AppError;
+use common::{
+    error::AppError,
+    tx::{TransactionResult, UnitOfWork},
+};
 use dmn::{
     entities::user::{UniqueUserIdentifier, UpdateUserData, User},
-    repos::user::UserRepository,
+    repos::{outbox::OutboxRepository, user::UserRepository},
 };
 
 #[derive(Clone)]
 pub struct UpdateSelfUserUseCase {
+    unit_of_work: Arc<dyn UnitOfWork>,
+    outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
     user_repo: Arc<dyn UserRepository + Send + Sync>,
 }
 
 impl UpdateSelfUserUseCase {
-    pub fn new(user_repo: Arc<dyn UserRepository + Send + Sync>) -> Self {
-        Self { user_repo }
+    pub fn new(
+        unit_of_work: Arc<dyn UnitOfWork>,
+        outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+        user_repo: Arc<dyn UserRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            unit_of_work,
+            outbox_repo,
+            user_repo,
+        }
     }
 
     pub async fn execute(
@@ -44,10 +57,24 @@ impl UpdateSelfUserUseCase {
             }?;
         }
 
-        let user = self
-            .user_repo
-            .update_user(None, &user_id, user_data)
+        let user_repo = self.user_repo.clone();
+        let outbox_repo = self.outbox_repo.clone();
+        let result = self
+            .unit_of_work
+            .run_in_transaction(Box::new(move |ctx| {
+                Box::pin(async move {
+                    let user = user_repo
+                        .update_user(Some(ctx), &user_id, user_data)
+                        .await?;
+                    outbox_repo
+                        .append_events(Some(ctx), &[user.updated_event()])
+                        .await?;
+                    Ok(TransactionResult::new(user))
+                })
+            }))
             .await?;
+
+        let user = result.extract::<User>()?;
         Ok(user)
     }
 }
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use crate::{auth::context::AuthAccessContext, webhooks::webhook_sender::WebhookSender};
+use common::error::AppError;
+use dmn::{
+    entities::webhook::{CreateWebhookData, Webhook, WebhookEventType},
+    repos::webhook::WebhookRepository,
+};
+
+#[derive(Clone)]
+pub struct CreateWebhookUseCase {
+    webhook_repo: Arc<dyn WebhookRepository + Send + Sync>,
+    webhook_sender: Arc<dyn WebhookSender + Send + Sync>,
+}
+
+impl CreateWebhookUseCase {
+    pub fn new(
+        webhook_repo: Arc<dyn WebhookRepository + Send + Sync>,
+        webhook_sender: Arc<dyn WebhookSender + Send + Sync>,
+    ) -> Self {
+        Self {
+            webhook_repo,
+            webhook_sender,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: CreateWebhookInput,
+    ) -> Result<Webhook, AppError> {
+        let webhook_data = input.try_into_dmn(auth_ctx)?;
+        self.webhook_sender.check_url(&webhook_data.url).await?;
+        let webhook = self.webhook_repo.create_webhook(None, webhook_data).await?;
+        Ok(webhook)
+    }
+}
+
+#[derive(Debug)]
+pub struct CreateWebhookInput {
+    pub url: String,
+    pub secret: String,
+    pub event_types: Vec<WebhookEventType>,
+}
+
+impl CreateWebhookInput {
+    pub fn try_into_dmn(self, auth_ctx: AuthAccessContext) -> Result<CreateWebhookData, AppError> {
+        let data = CreateWebhookData {
+            user_id: auth_ctx.user.id(),
+            url: self.url,
+            secret: self.secret,
+            event_types: self.event_types,
+        };
+        let valid_data = data.validate()?;
+        Ok(valid_data)
+    }
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::repos::webhook::WebhookRepository;
+
+#[derive(Clone)]
+pub struct DeleteWebhookUseCase {
+    webhook_repo: Arc<dyn WebhookRepository + Send + Sync>,
+}
+
+impl DeleteWebhookUseCase {
+    pub fn new(webhook_repo: Arc<dyn WebhookRepository + Send + Sync>) -> Self {
+        Self { webhook_repo }
+    }
+
+    /// Pending deliveries are dropped along with the webhook.
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: DeleteWebhookInput,
+    ) -> Result<(), AppError> {
+        self.webhook_repo
+            .delete_webhook(None, input.webhook_id, Some(auth_ctx.user.id()))
+            .await
+    }
+}
+
+#[derive(Debug)]
+pub struct DeleteWebhookInput {
+    pub webhook_id: Uuid,
+}
@@ -0,0 +1,80 @@
+use chrono::TimeDelta;
+use futures_util::future::join_all;
+use std::sync::Arc;
+
+use crate::webhooks::webhook_sender::WebhookSender;
+use common::error::AppError;
+use dmn::{
+    entities::webhook_delivery::WebhookDelivery,
+    repos::{webhook::WebhookRepository, webhook_delivery::WebhookDeliveryRepository},
+};
+
+/// Time a claimed batch has to be sent in before it can be claimed again.
+const DELIVERY_LEASE_SECS: i64 = 5 * 60; // 5 minutes
+
+/// System task sending the webhook deliveries that are due, retried ones included.
+#[derive(Clone)]
+pub struct DeliverWebhooksUseCase {
+    webhook_repo: Arc<dyn WebhookRepository + Send + Sync>,
+    webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository + Send + Sync>,
+    webhook_sender: Arc<dyn WebhookSender + Send + Sync>,
+}
+
+impl DeliverWebhooksUseCase {
+    pub fn new(
+        webhook_repo: Arc<dyn WebhookRepository + Send + Sync>,
+        webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository + Send + Sync>,
+        webhook_sender: Arc<dyn WebhookSender + Send + Sync>,
+    ) -> Self {
+        Self {
+            webhook_repo,
+            webhook_delivery_repo,
+            webhook_sender,
+        }
+    }
+
+    /// Sends a batch of due deliveries concurrently, returning how many were claimed.<br />
+    /// Deliveries whose outcome couldn't be recorded are sent again once their lease expires.
+    pub async fn execute(&self, input: DeliverWebhooksInput) -> Result<u32, AppError> {
+        let deliveries = self
+            .webhook_delivery_repo
+            .claim_due_webhook_deliveries(
+                None,
+                input.limit,
+                TimeDelta::seconds(DELIVERY_LEASE_SECS),
+            )
+            .await?;
+        let count = deliveries.len() as u32;
+
+        let results = join_all(
+            deliveries
+                .into_iter()
+                .map(|delivery| self.deliver(delivery)),
+        )
+        .await;
+        for result in results {
+            if let Err(err) = result {
+                err.log();
+            }
+        }
+        Ok(count)
+    }
+
+    async fn deliver(&self, mut delivery: WebhookDelivery) -> Result<(), AppError> {
+        let webhook = self
+            .webhook_repo
+            .get_webhook(None, delivery.webhook_id(), None)
+            .await?;
+        let outcome = self.webhook_sender.send(&webhook, &delivery).await;
+        delivery.record_attempt(outcome);
+        self.webhook_delivery_repo
+            .update_webhook_delivery(None, delivery.id(), (&delivery).into())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct DeliverWebhooksInput {
+    pub limit: u32,
+}
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::{entities::webhook::Webhook, repos::webhook::WebhookRepository};
+
+#[derive(Clone)]
+pub struct GetWebhookUseCase {
+    webhook_repo: Arc<dyn WebhookRepository + Send + Sync>,
+}
+
+impl GetWebhookUseCase {
+    pub fn new(webhook_repo: Arc<dyn WebhookRepository + Send + Sync>) -> Self {
+        Self { webhook_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: GetWebhookInput,
+    ) -> Result<Webhook, AppError> {
+        let webhook = self
+            .webhook_repo
+            .get_webhook(None, input.webhook_id, Some(auth_ctx.user.id()))
+            .await?;
+        Ok(webhook)
+    }
+}
+
+#[derive(Debug)]
+pub struct GetWebhookInput {
+    pub webhook_id: Uuid,
+}
@@ -0,0 +1,49 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::auth::context::AuthAccessContext;
+use common::{error::AppError, params::PaginationParams};
+use dmn::repos::{
+    webhook::WebhookRepository,
+    webhook_delivery::{GetWebhookDeliveriesResponse, WebhookDeliveryRepository},
+};
+
+#[derive(Clone)]
+pub struct GetWebhookDeliveriesUseCase {
+    webhook_repo: Arc<dyn WebhookRepository + Send + Sync>,
+    webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository + Send + Sync>,
+}
+
+impl GetWebhookDeliveriesUseCase {
+    pub fn new(
+        webhook_repo: Arc<dyn WebhookRepository + Send + Sync>,
+        webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            webhook_repo,
+            webhook_delivery_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: GetWebhookDeliveriesInput,
+    ) -> Result<GetWebhookDeliveriesResponse, AppError> {
+        // Webhook has to belong to the requesting user
+        self.webhook_repo
+            .get_webhook(None, input.webhook_id, Some(auth_ctx.user.id()))
+            .await?;
+        let deliveries = self
+            .webhook_delivery_repo
+            .get_webhook_deliveries(None, input.webhook_id, &input.pagination)
+            .await?;
+        Ok(deliveries)
+    }
+}
+
+#[derive(Debug)]
+pub struct GetWebhookDeliveriesInput {
+    pub webhook_id: Uuid,
+    pub pagination: PaginationParams,
+}
@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use crate::auth::context::AuthAccessContext;
+use common::error::AppError;
+use dmn::{entities::webhook::Webhook, repos::webhook::WebhookRepository};
+
+#[derive(Clone)]
+pub struct GetWebhooksUseCase {
+    webhook_repo: Arc<dyn WebhookRepository + Send + Sync>,
+}
+
+impl GetWebhooksUseCase {
+    pub fn new(webhook_repo: Arc<dyn WebhookRepository + Send + Sync>) -> Self {
+        Self { webhook_repo }
+    }
+
+    pub async fn execute(&self, auth_ctx: AuthAccessContext) -> Result<Vec<Webhook>, AppError> {
+        let webhooks = self
+            .webhook_repo
+            .get_webhooks(None, Some(auth_ctx.user.id()))
+            .await?;
+        Ok(webhooks)
+    }
+}
@@ -0,0 +1,17 @@
+mod create_webhook;
+mod delete_webhook;
+mod deliver_webhooks;
+mod get_webhook;
+mod get_webhook_deliveries;
+mod get_webhooks;
+mod send_webhook_test_event;
+mod update_webhook;
+
+pub use create_webhook::*;
+pub use delete_webhook::*;
+pub use deliver_webhooks::*;
+pub use get_webhook::*;
+pub use get_webhook_deliveries::*;
+pub use get_webhooks::*;
+pub use send_webhook_test_event::*;
+pub use update_webhook::*;
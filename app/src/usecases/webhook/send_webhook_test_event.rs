@@ -0,0 +1,70 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{auth::context::AuthAccessContext, webhooks::webhook_sender::WebhookSender};
+use common::error::AppError;
+use dmn::{
+    entities::{
+        webhook::WebhookEventType,
+        webhook_delivery::{CreateWebhookDeliveryData, WebhookDelivery},
+    },
+    repos::{webhook::WebhookRepository, webhook_delivery::WebhookDeliveryRepository},
+};
+
+#[derive(Clone)]
+pub struct SendWebhookTestEventUseCase {
+    webhook_repo: Arc<dyn WebhookRepository + Send + Sync>,
+    webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository + Send + Sync>,
+    webhook_sender: Arc<dyn WebhookSender + Send + Sync>,
+}
+
+impl SendWebhookTestEventUseCase {
+    pub fn new(
+        webhook_repo: Arc<dyn WebhookRepository + Send + Sync>,
+        webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository + Send + Sync>,
+        webhook_sender: Arc<dyn WebhookSender + Send + Sync>,
+    ) -> Self {
+        Self {
+            webhook_repo,
+            webhook_delivery_repo,
+            webhook_sender,
+        }
+    }
+
+    /// Sends a test event right away, without retries, and returns its logged delivery.
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: SendWebhookTestEventInput,
+    ) -> Result<WebhookDelivery, AppError> {
+        let webhook = self
+            .webhook_repo
+            .get_webhook(None, input.webhook_id, Some(auth_ctx.user.id()))
+            .await?;
+        let delivery_data = CreateWebhookDeliveryData {
+            webhook_id: webhook.id(),
+            event_id: Uuid::new_v4(),
+            event_type: WebhookEventType::Test,
+            note_id: None,
+            next_attempt_at: None,
+        };
+        let mut delivery = self
+            .webhook_delivery_repo
+            .create_webhook_delivery(None, delivery_data)
+            .await?
+            .ok_or_else(|| AppError::internal("Failed to create webhook test delivery!"))?;
+
+        let outcome = self.webhook_sender.send(&webhook, &delivery).await;
+        delivery.record_attempt(outcome);
+        let delivery = self
+            .webhook_delivery_repo
+            .update_webhook_delivery(None, delivery.id(), (&delivery).into())
+            .await?;
+        Ok(delivery)
+    }
+}
+
+#[derive(Debug)]
+pub struct SendWebhookTestEventInput {
+    pub webhook_id: Uuid,
+}
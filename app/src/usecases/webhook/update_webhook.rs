@@ -0,0 +1,65 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{auth::context::AuthAccessContext, webhooks::webhook_sender::WebhookSender};
+use common::error::AppError;
+use dmn::{
+    entities::webhook::{UpdateWebhookData, Webhook, WebhookEventType},
+    repos::webhook::WebhookRepository,
+};
+
+#[derive(Clone)]
+pub struct UpdateWebhookUseCase {
+    webhook_repo: Arc<dyn WebhookRepository + Send + Sync>,
+    webhook_sender: Arc<dyn WebhookSender + Send + Sync>,
+}
+
+impl UpdateWebhookUseCase {
+    pub fn new(
+        webhook_repo: Arc<dyn WebhookRepository + Send + Sync>,
+        webhook_sender: Arc<dyn WebhookSender + Send + Sync>,
+    ) -> Self {
+        Self {
+            webhook_repo,
+            webhook_sender,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        auth_ctx: AuthAccessContext,
+        input: UpdateWebhookInput,
+    ) -> Result<Webhook, AppError> {
+        let webhook_id = input.webhook_id;
+        let webhook_data: UpdateWebhookData = input.try_into()?;
+        self.webhook_sender.check_url(&webhook_data.url).await?;
+        let webhook = self
+            .webhook_repo
+            .update_webhook(None, webhook_id, webhook_data, Some(auth_ctx.user.id()))
+            .await?;
+        Ok(webhook)
+    }
+}
+
+#[derive(Debug)]
+pub struct UpdateWebhookInput {
+    pub webhook_id: Uuid,
+    pub url: String,
+    /// `None` keeps the current secret.
+    pub secret: Option<String>,
+    pub event_types: Vec<WebhookEventType>,
+}
+
+impl TryFrom<UpdateWebhookInput> for UpdateWebhookData {
+    type Error = AppError;
+
+    fn try_from(input: UpdateWebhookInput) -> Result<Self, Self::Error> {
+        let data = Self {
+            url: input.url,
+            secret: input.secret,
+            event_types: input.event_types,
+        };
+        let valid_data = data.validate()?;
+        Ok(valid_data)
+    }
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use common::error::AppError;
+use dmn::entities::{
+    webhook::Webhook,
+    webhook_delivery::{WebhookAttemptOutcome, WebhookDelivery},
+};
+
+/// Transport of webhook deliveries to their endpoints.
+#[async_trait]
+pub trait WebhookSender: Send + Sync {
+    /// Ensures the URL targets an address deliveries may be posted to.
+    async fn check_url(&self, url: &str) -> Result<(), AppError>;
+
+    /// Posts the signed delivery payload to the webhook's URL.
+    async fn send(&self, webhook: &Webhook, delivery: &WebhookDelivery) -> WebhookAttemptOutcome;
+}
@@ -5,6 +5,8 @@ use thiserror::Error;
 pub struct BaseError {
     pub public_info: String,
     pub private_info: Option<String>,
+    /// Public breakdown of the error, e.g. every violated validation rule.
+    pub details: Vec<String>,
 }
 
 impl BaseError {
@@ -12,11 +14,15 @@ impl BaseError {
         Self {
             public_info,
             private_info,
+            details: Vec::new(),
         }
     }
 
     pub fn log(&self, error_type: &str) {
         let mut log_msg = format!("{}:\n{}", error_type, self.public_info);
+        for detail in &self.details {
+            log_msg.push_str(&format!("\n- {}", detail));
+        }
         if let Some(ref internal) = self.private_info {
             log_msg.push_str(&format!("\n{}", internal));
         }
@@ -52,6 +58,9 @@ pub enum AppError {
     #[error("Conflict: {0}")]
     Conflict(BaseError),
 
+    #[error("Precondition Failed: {0}")]
+    PreconditionFailed(BaseError),
+
     #[error("Unauthorized: {0}")]
     Unauthorized(BaseError),
 
@@ -66,6 +75,7 @@ impl AppError {
             AppError::BadRequest(_) => "BadRequest",
             AppError::NotFound(_) => "NotFound",
             AppError::Conflict(_) => "Conflict",
+            AppError::PreconditionFailed(_) => "PreconditionFailed",
             AppError::Unauthorized(_) => "Unauthorized",
             AppError::Forbidden(_) => "Forbidden",
         }
@@ -77,6 +87,7 @@ impl AppError {
             AppError::BadRequest(_) => "Bad Request",
             AppError::NotFound(_) => "Not Found",
             AppError::Conflict(_) => "Conflict",
+            AppError::PreconditionFailed(_) => "Precondition Failed",
             AppError::Unauthorized(_) => "Unauthorized",
             AppError::Forbidden(_) => "Forbidden",
         };
@@ -89,6 +100,7 @@ impl AppError {
             | AppError::BadRequest(ref mut base_error)
             | AppError::NotFound(ref mut base_error)
             | AppError::Conflict(ref mut base_error)
+            | AppError::PreconditionFailed(ref mut base_error)
             | AppError::Unauthorized(ref mut base_error)
             | AppError::Forbidden(ref mut base_error) => {
                 base_error.public_info = public_info;
@@ -135,6 +147,15 @@ impl AppError {
         ))
     }
 
+    pub fn bad_request_with_details<P>(public_info: P, details: Vec<String>) -> Self
+    where
+        P: AsRef<str>,
+    {
+        let mut base_error = BaseError::new(public_info.as_ref().to_string(), None);
+        base_error.details = details;
+        Self::BadRequest(base_error)
+    }
+
     pub fn not_found<P>(public_info: P) -> Self
     where
         P: AsRef<str>,
@@ -171,6 +192,24 @@ impl AppError {
         ))
     }
 
+    pub fn precondition_failed<P>(public_info: P) -> Self
+    where
+        P: AsRef<str>,
+    {
+        Self::PreconditionFailed(BaseError::new(public_info.as_ref().to_string(), None))
+    }
+
+    pub fn precondition_failed_with_private<P, R>(public_info: P, private_info: R) -> Self
+    where
+        P: AsRef<str>,
+        R: AsRef<str>,
+    {
+        Self::PreconditionFailed(BaseError::new(
+            public_info.as_ref().to_string(),
+            Some(private_info.as_ref().to_string()),
+        ))
+    }
+
     pub fn unauthorized<P>(public_info: P) -> Self
     where
         P: AsRef<str>,
@@ -217,6 +256,7 @@ impl Deref for AppError {
             | AppError::BadRequest(base_error)
             | AppError::NotFound(base_error)
             | AppError::Conflict(base_error)
+            | AppError::PreconditionFailed(base_error)
             | AppError::Unauthorized(base_error)
             | AppError::Forbidden(base_error) => base_error,
         }
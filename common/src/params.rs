@@ -1,5 +1,46 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
 #[derive(Debug)]
 pub struct PaginationParams {
     pub skip: u32,
     pub limit: u32,
 }
+
+/// Keyset pagination resuming right after the row identified by `after`, if any.
+#[derive(Debug)]
+pub struct CursorPaginationParams {
+    pub after: Option<PageCursor>,
+    pub limit: u32,
+}
+
+/// `(created_at, id)` key of the last row of a page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageCursor {
+    pub created_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+#[derive(Debug)]
+pub enum Pagination {
+    Offset(PaginationParams),
+    Cursor(CursorPaginationParams),
+}
+
+impl From<PaginationParams> for Pagination {
+    fn from(pagination: PaginationParams) -> Self {
+        Self::Offset(pagination)
+    }
+}
+
+impl From<CursorPaginationParams> for Pagination {
+    fn from(pagination: CursorPaginationParams) -> Self {
+        Self::Cursor(pagination)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
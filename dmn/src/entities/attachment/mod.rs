@@ -0,0 +1,97 @@
+mod validation;
+
+pub use validation::ATTACHMENT_MAX_SIZE;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// File attached to a [`crate::entities::note::Note`], whose contents live in a blob store.
+#[derive(Debug, Clone)]
+pub struct Attachment {
+    id: Uuid,
+    note_id: Uuid,
+    file_name: String,
+    mime_type: String,
+    size: u64,
+    sha256: String,
+    created_at: DateTime<Utc>,
+}
+
+impl From<AttachmentData> for Attachment {
+    fn from(data: AttachmentData) -> Self {
+        Self {
+            id: data.id,
+            note_id: data.note_id,
+            file_name: data.file_name,
+            mime_type: data.mime_type,
+            size: data.size,
+            sha256: data.sha256,
+            created_at: data.created_at,
+        }
+    }
+}
+
+impl Attachment {
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn note_id(&self) -> Uuid {
+        self.note_id
+    }
+
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    pub fn mime_type(&self) -> &str {
+        &self.mime_type
+    }
+
+    /// Size of the file in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Hex encoded SHA-256 digest of the file.
+    pub fn sha256(&self) -> &str {
+        &self.sha256
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    /// Key of the file contents within the blob store.
+    pub fn blob_key(&self) -> String {
+        attachment_blob_key(self.id)
+    }
+}
+
+pub fn attachment_blob_key(attachment_id: Uuid) -> String {
+    attachment_id.to_string()
+}
+
+/// [`Attachment`] entity pre-validation data struct.
+pub struct AttachmentData {
+    pub id: Uuid,
+    pub note_id: Uuid,
+    pub file_name: String,
+    pub mime_type: String,
+    pub size: u64,
+    pub sha256: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// [`Attachment`] entity creation pre-validation utility data struct.
+#[derive(Debug)]
+pub struct CreateAttachmentData {
+    pub note_id: Uuid,
+    pub file_name: String,
+    pub mime_type: String,
+    pub size: u64,
+    pub sha256: String,
+}
+
+#[cfg(test)]
+mod tests;
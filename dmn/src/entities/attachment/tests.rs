@@ -0,0 +1,29 @@
+use uuid::Uuid;
+
+use super::*;
+
+#[test]
+fn test_attachment_creation() {
+    let id = Uuid::new_v4();
+    let note_id = Uuid::new_v4();
+    let created_at = Utc::now();
+    let data = AttachmentData {
+        id,
+        note_id,
+        file_name: "diagram.png".to_string(),
+        mime_type: "image/png".to_string(),
+        size: 1024,
+        sha256: "f".repeat(64),
+        created_at,
+    };
+    let attachment = Attachment::from(data);
+
+    assert_eq!(attachment.id(), id);
+    assert_eq!(attachment.note_id(), note_id);
+    assert_eq!(attachment.file_name(), "diagram.png");
+    assert_eq!(attachment.mime_type(), "image/png");
+    assert_eq!(attachment.size(), 1024);
+    assert_eq!(attachment.sha256(), "f".repeat(64));
+    assert_eq!(attachment.created_at(), created_at);
+    assert_eq!(attachment.blob_key(), id.to_string());
+}
@@ -0,0 +1,76 @@
+use super::CreateAttachmentData;
+use common::error::AppError;
+
+/// Largest accepted attachment, in bytes.
+pub const ATTACHMENT_MAX_SIZE: u64 = 25 * 1024 * 1024; // 25 MiB
+pub(crate) const ATTACHMENT_FILE_NAME_MAX_LENGTH: usize = 255;
+pub(crate) const DEFAULT_MIME_TYPE: &str = "application/octet-stream";
+
+impl CreateAttachmentData {
+    pub fn validate(self) -> Result<Self, AppError> {
+        let file_name = validate_file_name(&self.file_name)?;
+        let mime_type = validate_mime_type(&self.mime_type);
+        validate_size(self.size)?;
+        Ok(Self {
+            file_name,
+            mime_type,
+            ..self
+        })
+    }
+}
+
+/// Keeps the last path component only, clients may send the full path of the file.
+pub(crate) fn validate_file_name(file_name: &str) -> Result<String, AppError> {
+    let file_name = file_name
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_string();
+    if file_name.is_empty() || file_name == "." || file_name == ".." {
+        return Err(AppError::bad_request(
+            "Attachment file name cannot be empty!",
+        ));
+    }
+    if file_name.chars().any(char::is_control) {
+        return Err(AppError::bad_request(
+            "Attachment file name cannot contain control characters!",
+        ));
+    }
+    if file_name.chars().count() > ATTACHMENT_FILE_NAME_MAX_LENGTH {
+        return Err(AppError::bad_request(format!(
+            "Attachment file name cannot exceed {ATTACHMENT_FILE_NAME_MAX_LENGTH} characters!"
+        )));
+    }
+    Ok(file_name)
+}
+
+/// Falls back to a generic binary type when the given one isn't a plain `type/subtype` pair.
+pub(crate) fn validate_mime_type(mime_type: &str) -> String {
+    let mime_type = mime_type.trim().to_ascii_lowercase();
+    let is_token = |part: &str| {
+        !part.is_empty()
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "!#$&^_.+-".contains(c))
+    };
+    match mime_type.split_once('/') {
+        Some((kind, subtype)) if is_token(kind) && is_token(subtype) => mime_type,
+        _ => DEFAULT_MIME_TYPE.to_string(),
+    }
+}
+
+pub(crate) fn validate_size(size: u64) -> Result<(), AppError> {
+    if size == 0 {
+        return Err(AppError::bad_request("Attachment cannot be empty!"));
+    }
+    if size > ATTACHMENT_MAX_SIZE {
+        return Err(AppError::bad_request(format!(
+            "Attachment cannot exceed {ATTACHMENT_MAX_SIZE} bytes!"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
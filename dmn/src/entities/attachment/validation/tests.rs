@@ -0,0 +1,81 @@
+use uuid::Uuid;
+
+use super::*;
+
+#[test]
+fn test_validate_file_name_strips_path() {
+    assert_eq!(validate_file_name("/tmp/report.pdf").unwrap(), "report.pdf");
+    assert_eq!(
+        validate_file_name("C:\\Users\\me\\photo.png").unwrap(),
+        "photo.png"
+    );
+    assert_eq!(validate_file_name("  notes.txt ").unwrap(), "notes.txt");
+}
+
+#[test]
+fn test_validate_file_name_rejects_empty_name() {
+    for file_name in ["", "   ", "dir/", "..", "../.."] {
+        let result = validate_file_name(file_name);
+        assert!(result.is_err());
+        if let Err(AppError::BadRequest(base_error)) = result {
+            assert_eq!(
+                base_error.public_info,
+                "Attachment file name cannot be empty!"
+            );
+        } else {
+            panic!("Expected BadRequest error for empty file name");
+        }
+    }
+}
+
+#[test]
+fn test_validate_file_name_rejects_control_characters() {
+    let result = validate_file_name("evil\r\nname.txt");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_validate_file_name_rejects_too_long_name() {
+    let file_name = "a".repeat(ATTACHMENT_FILE_NAME_MAX_LENGTH + 1);
+    let result = validate_file_name(&file_name);
+    assert!(result.is_err());
+    let file_name = "a".repeat(ATTACHMENT_FILE_NAME_MAX_LENGTH);
+    assert!(validate_file_name(&file_name).is_ok());
+}
+
+#[test]
+fn test_validate_mime_type() {
+    assert_eq!(validate_mime_type("image/png"), "image/png");
+    assert_eq!(validate_mime_type(" Text/Markdown "), "text/markdown");
+    assert_eq!(
+        validate_mime_type("application/vnd.ms-excel"),
+        "application/vnd.ms-excel"
+    );
+    for mime_type in ["", "image", "text/html\r\nX-Evil: 1", "/png", "text/"] {
+        assert_eq!(validate_mime_type(mime_type), DEFAULT_MIME_TYPE);
+    }
+}
+
+#[test]
+fn test_validate_size() {
+    assert!(validate_size(1).is_ok());
+    assert!(validate_size(ATTACHMENT_MAX_SIZE).is_ok());
+    assert!(validate_size(0).is_err());
+    assert!(validate_size(ATTACHMENT_MAX_SIZE + 1).is_err());
+}
+
+#[test]
+fn test_create_attachment_data_validate() {
+    let data = CreateAttachmentData {
+        note_id: Uuid::new_v4(),
+        file_name: "docs/spec.md".to_string(),
+        mime_type: "bogus".to_string(),
+        size: 42,
+        sha256: "0".repeat(64),
+    };
+    let data = data.validate().unwrap();
+
+    assert_eq!(data.file_name, "spec.md");
+    assert_eq!(data.mime_type, DEFAULT_MIME_TYPE);
+    assert_eq!(data.size, 42);
+}
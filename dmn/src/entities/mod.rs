@@ -1,2 +1,11 @@
+pub mod attachment;
 pub mod note;
+pub mod note_import_job;
+pub mod note_public_link;
+pub mod note_revision;
+pub mod note_share;
+pub mod notebook;
+pub mod tag;
 pub mod user;
+pub mod webhook;
+pub mod webhook_delivery;
@@ -3,16 +3,24 @@ mod validation;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+use crate::{entities::tag::Tag, events::DomainEvent};
 use common::error::AppError;
 
 #[derive(Debug, Clone)]
 pub struct Note {
     id: Uuid,
     user_id: Uuid,
+    notebook_id: Option<Uuid>,
     title: String,
     content: String,
+    tags: Vec<Tag>,
+    version: u32,
+    pinned: bool,
+    archived: bool,
+    favorite: bool,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
 }
 
 impl TryFrom<NoteData> for Note {
@@ -25,10 +33,17 @@ impl TryFrom<NoteData> for Note {
         Ok(Self {
             id: data.id,
             user_id: data.user_id,
+            notebook_id: data.notebook_id,
             title,
             content,
+            tags: data.tags,
+            version: data.version,
+            pinned: data.pinned,
+            archived: data.archived,
+            favorite: data.favorite,
             created_at: data.created_at,
             updated_at: data.updated_at,
+            deleted_at: data.deleted_at,
         })
     }
 }
@@ -42,6 +57,10 @@ impl Note {
         self.user_id
     }
 
+    pub fn notebook_id(&self) -> Option<Uuid> {
+        self.notebook_id
+    }
+
     pub fn title(&self) -> &str {
         &self.title
     }
@@ -50,6 +69,37 @@ impl Note {
         &self.content
     }
 
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+
+    /// Incremented on every change, used for optimistic concurrency control.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Pinned notes are listed before any other.
+    pub fn is_pinned(&self) -> bool {
+        self.pinned
+    }
+
+    /// Archived notes are left out of listings unless explicitly requested.
+    pub fn is_archived(&self) -> bool {
+        self.archived
+    }
+
+    pub fn is_favorite(&self) -> bool {
+        self.favorite
+    }
+
+    pub fn flags(&self) -> NoteFlags {
+        NoteFlags {
+            pinned: self.pinned,
+            archived: self.archived,
+            favorite: self.favorite,
+        }
+    }
+
     pub fn created_at(&self) -> DateTime<Utc> {
         self.created_at
     }
@@ -57,12 +107,53 @@ impl Note {
     pub fn updated_at(&self) -> DateTime<Utc> {
         self.updated_at
     }
+
+    /// Point in time the note was moved to the trash, if any.
+    pub fn deleted_at(&self) -> Option<DateTime<Utc>> {
+        self.deleted_at
+    }
+
+    pub fn is_trashed(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    /// Fails unless the note is still at one of the `expected_versions`, any version passing when
+    /// there are none.
+    pub fn ensure_version(&self, expected_versions: &[u32]) -> Result<(), AppError> {
+        if expected_versions.is_empty() || expected_versions.contains(&self.version) {
+            return Ok(());
+        }
+        let expected_versions = expected_versions
+            .iter()
+            .map(u32::to_string)
+            .collect::<Vec<_>>()
+            .join(" or ");
+        Err(AppError::precondition_failed(format!(
+            "Note ({}) is at version {}, expected version {}!",
+            self.id, self.version, expected_versions
+        )))
+    }
+
+    pub fn created_event(&self) -> DomainEvent {
+        DomainEvent::NoteCreated {
+            note_id: self.id,
+            user_id: self.user_id,
+        }
+    }
+
+    pub fn updated_event(&self) -> DomainEvent {
+        DomainEvent::NoteUpdated {
+            note_id: self.id,
+            user_id: self.user_id,
+        }
+    }
 }
 
 impl Note {
     pub fn set_title(&mut self, title: String) -> Result<(), AppError> {
         let title = validation::validate_title(&title)?;
         self.title = title;
+        self.version += 1;
         self.updated_at = Utc::now();
         Ok(())
     }
@@ -70,27 +161,74 @@ impl Note {
     pub fn set_content(&mut self, content: String) -> Result<(), AppError> {
         let content = validation::validate_content(&content)?;
         self.content = content;
+        self.version += 1;
         self.updated_at = Utc::now();
         Ok(())
     }
+
+    /// Leaves the note untouched when it's already in the requested state, as do the other flag setters.
+    pub fn set_pinned(&mut self, pinned: bool) {
+        if self.pinned != pinned {
+            self.pinned = pinned;
+            self.touch();
+        }
+    }
+
+    pub fn set_archived(&mut self, archived: bool) {
+        if self.archived != archived {
+            self.archived = archived;
+            self.touch();
+        }
+    }
+
+    pub fn set_favorite(&mut self, favorite: bool) {
+        if self.favorite != favorite {
+            self.favorite = favorite;
+            self.touch();
+        }
+    }
+
+    fn touch(&mut self) {
+        self.version += 1;
+        self.updated_at = Utc::now();
+    }
 }
 
 /// [`Note`] entity pre-validation data struct.
 pub struct NoteData {
     pub id: Uuid,
     pub user_id: Uuid,
+    pub notebook_id: Option<Uuid>,
     pub title: String,
     pub content: String,
+    pub tags: Vec<Tag>,
+    pub version: u32,
+    pub pinned: bool,
+    pub archived: bool,
+    pub favorite: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// Organizational state of a [`Note`], changed independently of its contents.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NoteFlags {
+    pub pinned: bool,
+    pub archived: bool,
+    pub favorite: bool,
 }
 
-/// [`Note`] entity creation pre-validation utility data struct.
+/// [`Note`] entity creation pre-validation utility data struct.<br />
+/// Timestamps are only set when carrying over notes from elsewhere (e.g. imports), they default to now otherwise.
 #[derive(Debug)]
 pub struct CreateNoteData {
     pub user_id: Uuid,
+    pub notebook_id: Option<Uuid>,
     pub title: String,
     pub content: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
 }
 
 /// [`Note`] entity update pre-validation utility data struct.
@@ -100,5 +238,19 @@ pub struct UpdateNoteData {
     pub content: String,
 }
 
+/// [`Note`] entity partial update pre-validation utility data struct.<br />
+/// Fields left as `None` are kept untouched.
+#[derive(Debug, Default)]
+pub struct PatchNoteData {
+    pub title: Option<String>,
+    pub content: Option<String>,
+}
+
+impl PatchNoteData {
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none() && self.content.is_none()
+    }
+}
+
 #[cfg(test)]
 mod tests;
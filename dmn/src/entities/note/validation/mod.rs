@@ -1,10 +1,13 @@
-use super::{CreateNoteData, UpdateNoteData};
+use chrono::{DateTime, Utc};
+
+use super::{CreateNoteData, PatchNoteData, UpdateNoteData};
 use common::error::AppError;
 
 impl CreateNoteData {
     pub fn validate(self) -> Result<Self, AppError> {
         validate_title(&self.title)?;
         validate_content(&self.content)?;
+        validate_timestamps(self.created_at, self.updated_at)?;
         Ok(self)
     }
 }
@@ -15,6 +18,17 @@ impl UpdateNoteData {
         Ok(self)
     }
 }
+impl PatchNoteData {
+    pub fn validate(self) -> Result<Self, AppError> {
+        if let Some(ref title) = self.title {
+            validate_title(title)?;
+        }
+        if let Some(ref content) = self.content {
+            validate_content(content)?;
+        }
+        Ok(self)
+    }
+}
 
 pub(crate) fn validate_title(title: &str) -> Result<String, AppError> {
     let title = title.trim().to_string();
@@ -38,5 +52,29 @@ pub(crate) fn validate_content(content: &str) -> Result<String, AppError> {
     Ok(content)
 }
 
+pub(crate) fn validate_timestamps(
+    created_at: Option<DateTime<Utc>>,
+    updated_at: Option<DateTime<Utc>>,
+) -> Result<(), AppError> {
+    let now = Utc::now();
+    if created_at.is_some_and(|created_at| created_at > now)
+        || updated_at.is_some_and(|updated_at| updated_at > now)
+    {
+        return Err(AppError::BadRequest(common::error::BaseError::new(
+            "Note timestamps cannot be in the future!".to_string(),
+            None,
+        )));
+    }
+    if let (Some(created_at), Some(updated_at)) = (created_at, updated_at) {
+        if updated_at < created_at {
+            return Err(AppError::BadRequest(common::error::BaseError::new(
+                "Note cannot be updated before being created!".to_string(),
+                None,
+            )));
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests;
@@ -38,15 +38,48 @@ fn test_validate_content_accepts_non_empty_content() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_patch_note_data_accepts_partial_data() {
+    let data = PatchNoteData {
+        title: Some("Title".to_string()),
+        content: None,
+    };
+    let result = data.validate();
+    assert!(result.is_ok());
+    assert!(!result.unwrap().is_empty());
+}
+
+#[test]
+fn test_patch_note_data_rejects_empty_content() {
+    let data = PatchNoteData {
+        title: None,
+        content: Some("   ".to_string()),
+    };
+    let result = data.validate();
+    assert!(result.is_err());
+    if let Err(AppError::BadRequest(base_error)) = result {
+        assert_eq!(base_error.public_info, "Note content cannot be empty!");
+    } else {
+        panic!("Expected BadRequest error for empty content");
+    }
+}
+
 #[test]
 fn test_try_from_valid_data_creates_note() {
     let data = NoteData {
         id: Uuid::new_v4(),
         user_id: Uuid::new_v4(),
+        notebook_id: None,
         title: "Valid Title".to_string(),
         content: "Valid content".to_string(),
+        tags: Vec::new(),
+        version: 1,
+        pinned: false,
+        archived: false,
+        favorite: false,
         created_at: Utc::now(),
         updated_at: Utc::now(),
+        deleted_at: None,
     };
 
     let note = Note::try_from(data);
@@ -61,10 +94,17 @@ fn test_try_from_invalid_title_returns_error() {
     let data = NoteData {
         id: Uuid::new_v4(),
         user_id: Uuid::new_v4(),
+        notebook_id: None,
         title: "".to_string(),
         content: "Valid content".to_string(),
+        tags: Vec::new(),
+        version: 1,
+        pinned: false,
+        archived: false,
+        favorite: false,
         created_at: Utc::now(),
         updated_at: Utc::now(),
+        deleted_at: None,
     };
 
     let err = Note::try_from(data).unwrap_err();
@@ -81,10 +121,17 @@ fn test_try_from_invalid_content_returns_error() {
     let data = NoteData {
         id: Uuid::new_v4(),
         user_id: Uuid::new_v4(),
+        notebook_id: None,
         title: "Valid Title".to_string(),
         content: "".to_string(),
+        tags: Vec::new(),
+        version: 1,
+        pinned: false,
+        archived: false,
+        favorite: false,
         created_at: Utc::now(),
         updated_at: Utc::now(),
+        deleted_at: None,
     };
 
     let err = Note::try_from(data).unwrap_err();
@@ -95,3 +142,61 @@ fn test_try_from_invalid_content_returns_error() {
         _ => panic!("Expected BadRequest error"),
     }
 }
+
+fn create_note_data() -> CreateNoteData {
+    CreateNoteData {
+        user_id: Uuid::new_v4(),
+        notebook_id: None,
+        title: "Title".to_string(),
+        content: "Content".to_string(),
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+#[test]
+fn test_create_note_data_accepts_original_timestamps() {
+    let created_at = Utc::now() - chrono::Duration::days(30);
+    let data = CreateNoteData {
+        created_at: Some(created_at),
+        updated_at: Some(created_at + chrono::Duration::days(1)),
+        ..create_note_data()
+    };
+    assert!(data.validate().is_ok());
+}
+
+#[test]
+fn test_create_note_data_rejects_updated_at_before_created_at() {
+    let created_at = Utc::now() - chrono::Duration::days(30);
+    let data = CreateNoteData {
+        created_at: Some(created_at),
+        updated_at: Some(created_at - chrono::Duration::days(1)),
+        ..create_note_data()
+    };
+    let result = data.validate();
+    if let Err(AppError::BadRequest(base_error)) = result {
+        assert_eq!(
+            base_error.public_info,
+            "Note cannot be updated before being created!"
+        );
+    } else {
+        panic!("Expected BadRequest error for updated_at before created_at");
+    }
+}
+
+#[test]
+fn test_create_note_data_rejects_future_timestamps() {
+    let data = CreateNoteData {
+        created_at: Some(Utc::now() + chrono::Duration::days(1)),
+        ..create_note_data()
+    };
+    let result = data.validate();
+    if let Err(AppError::BadRequest(base_error)) = result {
+        assert_eq!(
+            base_error.public_info,
+            "Note timestamps cannot be in the future!"
+        );
+    } else {
+        panic!("Expected BadRequest error for future timestamps");
+    }
+}
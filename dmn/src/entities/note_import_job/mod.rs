@@ -0,0 +1,289 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use common::error::AppError;
+
+/// Attempts made at processing a job before it is given up on, e.g. when the server keeps
+/// stopping while importing it.
+pub const NOTE_IMPORT_JOB_MAX_ATTEMPTS: u32 = 3;
+
+/// Background import of an uploaded file holding notes, along with its progress report.
+#[derive(Debug, Clone)]
+pub struct NoteImportJob {
+    id: Uuid,
+    user_id: Uuid,
+    format: NoteImportFormat,
+    status: NoteImportJobStatus,
+    total_count: Option<u32>,
+    processed_count: u32,
+    imported_count: u32,
+    failures: Vec<NoteImportFailure>,
+    error: Option<String>,
+    attempts: u32,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    finished_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<NoteImportJobData> for NoteImportJob {
+    type Error = AppError;
+
+    fn try_from(data: NoteImportJobData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: data.id,
+            user_id: data.user_id,
+            format: data.format.as_str().try_into()?,
+            status: data.status.as_str().try_into()?,
+            total_count: data.total_count,
+            processed_count: data.processed_count,
+            imported_count: data.imported_count,
+            failures: data.failures,
+            error: data.error,
+            attempts: data.attempts,
+            created_at: data.created_at,
+            updated_at: data.updated_at,
+            finished_at: data.finished_at,
+        })
+    }
+}
+
+impl NoteImportJob {
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn format(&self) -> NoteImportFormat {
+        self.format
+    }
+
+    pub fn status(&self) -> NoteImportJobStatus {
+        self.status
+    }
+
+    /// Number of notes found in the file, `None` until it has been read.
+    pub fn total_count(&self) -> Option<u32> {
+        self.total_count
+    }
+
+    /// Number of notes handled so far, failed ones included.
+    pub fn processed_count(&self) -> u32 {
+        self.processed_count
+    }
+
+    pub fn imported_count(&self) -> u32 {
+        self.imported_count
+    }
+
+    /// Notes that couldn't be imported, the others being imported regardless.
+    pub fn failures(&self) -> &[NoteImportFailure] {
+        &self.failures
+    }
+
+    /// Reason the whole job failed, if it did.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    pub fn finished_at(&self) -> Option<DateTime<Utc>> {
+        self.finished_at
+    }
+
+    /// Key of the uploaded file within the blob store, kept until the job finishes.
+    pub fn blob_key(&self) -> String {
+        format!("note-import-{}", self.id)
+    }
+}
+
+impl NoteImportJob {
+    /// Whether the ongoing attempt, counted when the job was claimed, is within the limit.
+    pub fn has_attempts_left(&self) -> bool {
+        self.attempts <= NOTE_IMPORT_JOB_MAX_ATTEMPTS
+    }
+
+    /// Records the outcome of reading the file, the notes that couldn't be read counting as processed.<br />
+    /// Restarts the progress report from scratch when the job is retried.
+    pub fn start(&mut self, total_count: u32, failures: Vec<NoteImportFailure>) {
+        self.status = NoteImportJobStatus::Running;
+        self.total_count = Some(total_count);
+        self.processed_count = failures.len() as u32;
+        self.imported_count = 0;
+        self.failures = failures;
+        self.error = None;
+    }
+
+    /// Reports the notes created so far, which only count as imported once they're all committed.
+    pub fn record_processed(&mut self, created_count: u32) {
+        self.processed_count = self.failures.len() as u32 + created_count;
+    }
+
+    pub fn record_imported(&mut self, imported_count: u32) {
+        self.imported_count = imported_count;
+        self.processed_count = self.failures.len() as u32 + imported_count;
+    }
+
+    pub fn complete(&mut self) {
+        self.status = NoteImportJobStatus::Completed;
+        if let Some(total_count) = self.total_count {
+            self.processed_count = total_count;
+        }
+        self.finished_at = Some(Utc::now());
+    }
+
+    /// Notes are imported all at once, none of them are kept when the job fails.
+    pub fn fail(&mut self, error: String) {
+        self.status = NoteImportJobStatus::Failed;
+        self.processed_count = self.failures.len() as u32;
+        self.imported_count = 0;
+        self.error = Some(error);
+        self.finished_at = Some(Utc::now());
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteImportFormat {
+    /// ZIP archive holding one Markdown file with front matter per note.
+    Markdown,
+    /// Evernote export, notes being written in ENML.
+    Enex,
+    /// JSON array of notes.
+    Json,
+}
+
+impl NoteImportFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NoteImportFormat::Markdown => "markdown",
+            NoteImportFormat::Enex => "enex",
+            NoteImportFormat::Json => "json",
+        }
+    }
+}
+
+impl TryFrom<&str> for NoteImportFormat {
+    type Error = AppError;
+
+    fn try_from(format: &str) -> Result<Self, Self::Error> {
+        match format {
+            "markdown" => Ok(NoteImportFormat::Markdown),
+            "enex" => Ok(NoteImportFormat::Enex),
+            "json" => Ok(NoteImportFormat::Json),
+            _ => Err(AppError::internal(format!(
+                "Unknown note import format ({format})!"
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteImportJobStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
+
+impl NoteImportJobStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NoteImportJobStatus::Pending => "pending",
+            NoteImportJobStatus::Running => "running",
+            NoteImportJobStatus::Completed => "completed",
+            NoteImportJobStatus::Failed => "failed",
+        }
+    }
+}
+
+impl TryFrom<&str> for NoteImportJobStatus {
+    type Error = AppError;
+
+    fn try_from(status: &str) -> Result<Self, Self::Error> {
+        match status {
+            "pending" => Ok(NoteImportJobStatus::Pending),
+            "running" => Ok(NoteImportJobStatus::Running),
+            "completed" => Ok(NoteImportJobStatus::Completed),
+            "failed" => Ok(NoteImportJobStatus::Failed),
+            _ => Err(AppError::internal(format!(
+                "Unknown note import job status ({status})!"
+            ))),
+        }
+    }
+}
+
+/// Note of the imported file that was skipped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteImportFailure {
+    /// Where the note was found (e.g. file name within an archive, or position within the file).
+    pub item: String,
+    pub error: String,
+}
+
+/// [`NoteImportJob`] entity pre-validation data struct.
+pub struct NoteImportJobData {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub format: String,
+    pub status: String,
+    pub total_count: Option<u32>,
+    pub processed_count: u32,
+    pub imported_count: u32,
+    pub failures: Vec<NoteImportFailure>,
+    pub error: Option<String>,
+    pub attempts: u32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+/// [`NoteImportJob`] entity creation utility data struct, jobs start out pending.
+#[derive(Debug)]
+pub struct CreateNoteImportJobData {
+    /// Chosen upfront so that the uploaded file can be stored under the job's blob key.
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub format: NoteImportFormat,
+}
+
+/// [`NoteImportJob`] entity update utility data struct.
+#[derive(Debug)]
+pub struct UpdateNoteImportJobData {
+    pub status: NoteImportJobStatus,
+    pub total_count: Option<u32>,
+    pub processed_count: u32,
+    pub imported_count: u32,
+    pub failures: Vec<NoteImportFailure>,
+    pub error: Option<String>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+impl From<&NoteImportJob> for UpdateNoteImportJobData {
+    fn from(job: &NoteImportJob) -> Self {
+        Self {
+            status: job.status(),
+            total_count: job.total_count(),
+            processed_count: job.processed_count(),
+            imported_count: job.imported_count(),
+            failures: job.failures().to_vec(),
+            error: job.error().map(|err| err.into()),
+            finished_at: job.finished_at(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,128 @@
+use uuid::Uuid;
+
+use super::*;
+
+#[test]
+fn test_valid_note_import_job_creation() {
+    let data = create_pending_job_data("enex");
+    let id = data.id;
+    let user_id = data.user_id;
+    let result = NoteImportJob::try_from(data);
+
+    assert!(result.is_ok());
+    let job = result.unwrap();
+
+    assert_eq!(job.id(), id);
+    assert_eq!(job.user_id(), user_id);
+    assert_eq!(job.format(), NoteImportFormat::Enex);
+    assert_eq!(job.status(), NoteImportJobStatus::Pending);
+    assert_eq!(job.total_count(), None);
+    assert_eq!(job.blob_key(), format!("note-import-{id}"));
+}
+
+#[test]
+fn test_invalid_note_import_job_with_unknown_format() {
+    let data = create_pending_job_data("docx");
+    let result = NoteImportJob::try_from(data);
+
+    assert!(result.is_err());
+    if let Err(AppError::Internal(base_error)) = result {
+        assert_eq!(base_error.public_info, "Unknown note import format (docx)!");
+    } else {
+        panic!("Expected Internal error for unknown format");
+    }
+}
+
+#[test]
+fn test_note_import_job_progress() {
+    let mut job = create_pending_job("json");
+
+    job.start(
+        5,
+        vec![NoteImportFailure {
+            item: "Note #2".to_string(),
+            error: "Note title cannot be empty!".to_string(),
+        }],
+    );
+    assert_eq!(job.status(), NoteImportJobStatus::Running);
+    assert_eq!(job.total_count(), Some(5));
+    assert_eq!(job.processed_count(), 1);
+
+    // Created notes aren't imported until committed
+    job.record_processed(2);
+    assert_eq!(job.imported_count(), 0);
+    assert_eq!(job.processed_count(), 3);
+
+    job.record_imported(2);
+    assert_eq!(job.imported_count(), 2);
+    assert_eq!(job.processed_count(), 3);
+
+    job.record_imported(4);
+    job.complete();
+    assert_eq!(job.status(), NoteImportJobStatus::Completed);
+    assert_eq!(job.processed_count(), 5);
+    assert_eq!(job.imported_count(), 4);
+    assert_eq!(job.failures().len(), 1);
+    assert!(job.finished_at().is_some());
+}
+
+#[test]
+fn test_failed_note_import_job_keeps_no_note() {
+    let mut job = create_pending_job("enex");
+
+    job.start(3, Vec::new());
+    job.record_processed(2);
+    job.fail("Failed to create note!".to_string());
+
+    assert_eq!(job.status(), NoteImportJobStatus::Failed);
+    assert_eq!(job.processed_count(), 0);
+    assert_eq!(job.imported_count(), 0);
+    assert_eq!(job.error(), Some("Failed to create note!"));
+    assert!(job.finished_at().is_some());
+}
+
+#[test]
+fn test_restarted_note_import_job_resets_progress() {
+    let mut job = create_pending_job("json");
+
+    job.start(3, Vec::new());
+    job.record_imported(2);
+    job.start(3, Vec::new());
+
+    assert_eq!(job.processed_count(), 0);
+    assert_eq!(job.imported_count(), 0);
+}
+
+#[test]
+fn test_note_import_job_attempts_limit() {
+    let mut data = create_pending_job_data("json");
+    data.attempts = NOTE_IMPORT_JOB_MAX_ATTEMPTS;
+    assert!(NoteImportJob::try_from(data).unwrap().has_attempts_left());
+
+    let mut data = create_pending_job_data("json");
+    data.attempts = NOTE_IMPORT_JOB_MAX_ATTEMPTS + 1;
+    assert!(!NoteImportJob::try_from(data).unwrap().has_attempts_left());
+}
+
+fn create_pending_job(format: &str) -> NoteImportJob {
+    NoteImportJob::try_from(create_pending_job_data(format)).unwrap()
+}
+
+fn create_pending_job_data(format: &str) -> NoteImportJobData {
+    let created_at = Utc::now();
+    NoteImportJobData {
+        id: Uuid::new_v4(),
+        user_id: Uuid::new_v4(),
+        format: format.to_string(),
+        status: "pending".to_string(),
+        total_count: None,
+        processed_count: 0,
+        imported_count: 0,
+        failures: Vec::new(),
+        error: None,
+        attempts: 0,
+        created_at,
+        updated_at: created_at,
+        finished_at: None,
+    }
+}
@@ -0,0 +1,76 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use common::error::AppError;
+
+/// Anonymous read-only link to a [`crate::entities::note::Note`].
+#[derive(Debug, Clone)]
+pub struct NotePublicLink {
+    note_id: Uuid,
+    token: String,
+    expires_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<NotePublicLinkData> for NotePublicLink {
+    fn from(data: NotePublicLinkData) -> Self {
+        Self {
+            note_id: data.note_id,
+            token: data.token,
+            expires_at: data.expires_at,
+            created_at: data.created_at,
+        }
+    }
+}
+
+impl NotePublicLink {
+    pub fn note_id(&self) -> Uuid {
+        self.note_id
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Links without an expiry stay valid until revoked.
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.expires_at
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+/// [`NotePublicLink`] entity pre-validation data struct.
+pub struct NotePublicLinkData {
+    pub note_id: Uuid,
+    pub token: String,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// [`NotePublicLink`] entity creation pre-validation utility data struct.
+#[derive(Debug)]
+pub struct CreateNotePublicLinkData {
+    pub note_id: Uuid,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl CreateNotePublicLinkData {
+    pub fn validate(self, now: DateTime<Utc>) -> Result<Self, AppError> {
+        if self.expires_at.is_some_and(|expires_at| expires_at <= now) {
+            return Err(AppError::bad_request(
+                "Public link expiry must be in the future!",
+            ));
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests;
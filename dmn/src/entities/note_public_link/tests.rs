@@ -0,0 +1,78 @@
+use chrono::Duration;
+use uuid::Uuid;
+
+use super::*;
+
+fn public_link(expires_at: Option<DateTime<Utc>>) -> NotePublicLink {
+    NotePublicLink::from(NotePublicLinkData {
+        note_id: Uuid::new_v4(),
+        token: "a".repeat(64),
+        expires_at,
+        created_at: Utc::now(),
+    })
+}
+
+#[test]
+fn test_note_public_link_creation() {
+    let note_id = Uuid::new_v4();
+    let created_at = Utc::now();
+    let expires_at = created_at + Duration::days(7);
+    let data = NotePublicLinkData {
+        note_id,
+        token: "b".repeat(64),
+        expires_at: Some(expires_at),
+        created_at,
+    };
+    let link = NotePublicLink::from(data);
+
+    assert_eq!(link.note_id(), note_id);
+    assert_eq!(link.token(), "b".repeat(64));
+    assert_eq!(link.expires_at(), Some(expires_at));
+    assert_eq!(link.created_at(), created_at);
+}
+
+#[test]
+fn test_note_public_link_without_expiry_never_expires() {
+    let link = public_link(None);
+
+    assert!(!link.is_expired(Utc::now() + Duration::days(3650)));
+}
+
+#[test]
+fn test_note_public_link_expiry() {
+    let now = Utc::now();
+    let link = public_link(Some(now));
+
+    assert!(!link.is_expired(now - Duration::seconds(1)));
+    assert!(link.is_expired(now));
+    assert!(link.is_expired(now + Duration::seconds(1)));
+}
+
+#[test]
+fn test_create_note_public_link_data_validation() {
+    let now = Utc::now();
+    let valid = [None, Some(now + Duration::hours(1))];
+    for expires_at in valid {
+        let data = CreateNotePublicLinkData {
+            note_id: Uuid::new_v4(),
+            expires_at,
+        };
+        assert!(data.validate(now).is_ok());
+    }
+
+    let data = CreateNotePublicLinkData {
+        note_id: Uuid::new_v4(),
+        expires_at: Some(now - Duration::hours(1)),
+    };
+    let result = data.validate(now);
+
+    assert!(result.is_err());
+    if let Err(AppError::BadRequest(base_error)) = result {
+        assert_eq!(
+            base_error.public_info,
+            "Public link expiry must be in the future!"
+        );
+    } else {
+        panic!("Expected BadRequest error");
+    }
+}
@@ -0,0 +1,102 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{entities::note::Note, value_objects::text_diff::TextDiff};
+
+/// Immutable snapshot of a [`Note`]'s title and content prior to a change.
+#[derive(Debug, Clone)]
+pub struct NoteRevision {
+    id: Uuid,
+    note_id: Uuid,
+    revision: u32,
+    title: String,
+    content: String,
+    created_at: DateTime<Utc>,
+}
+
+impl From<NoteRevisionData> for NoteRevision {
+    fn from(data: NoteRevisionData) -> Self {
+        Self {
+            id: data.id,
+            note_id: data.note_id,
+            revision: data.revision,
+            title: data.title,
+            content: data.content,
+            created_at: data.created_at,
+        }
+    }
+}
+
+impl NoteRevision {
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn note_id(&self) -> Uuid {
+        self.note_id
+    }
+
+    /// Per-note sequence number, starting at 1.
+    pub fn revision(&self) -> u32 {
+        self.revision
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+impl NoteRevision {
+    /// Diffs the revision (old) against the current version of the note (new).
+    pub fn diff(&self, note: &Note) -> NoteRevisionDiff {
+        NoteRevisionDiff {
+            title: TextDiff::from_lines(&self.title, note.title()),
+            content: TextDiff::from_lines(&self.content, note.content()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NoteRevisionDiff {
+    pub title: TextDiff,
+    pub content: TextDiff,
+}
+
+/// [`NoteRevision`] entity data struct.
+pub struct NoteRevisionData {
+    pub id: Uuid,
+    pub note_id: Uuid,
+    pub revision: u32,
+    pub title: String,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// [`NoteRevision`] entity creation utility data struct.
+#[derive(Debug)]
+pub struct CreateNoteRevisionData {
+    pub note_id: Uuid,
+    pub title: String,
+    pub content: String,
+}
+
+impl From<&Note> for CreateNoteRevisionData {
+    fn from(note: &Note) -> Self {
+        Self {
+            note_id: note.id(),
+            title: note.title().into(),
+            content: note.content().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,91 @@
+use uuid::Uuid;
+
+use super::*;
+use crate::{entities::note::NoteData, value_objects::text_diff::DiffLineKind};
+
+#[test]
+fn test_note_revision_creation() {
+    let id = Uuid::new_v4();
+    let note_id = Uuid::new_v4();
+    let created_at = Utc::now();
+    let data = NoteRevisionData {
+        id,
+        note_id,
+        revision: 3,
+        title: "Old title".to_string(),
+        content: "Old content".to_string(),
+        created_at,
+    };
+    let revision = NoteRevision::from(data);
+
+    assert_eq!(revision.id(), id);
+    assert_eq!(revision.note_id(), note_id);
+    assert_eq!(revision.revision(), 3);
+    assert_eq!(revision.title(), "Old title");
+    assert_eq!(revision.content(), "Old content");
+    assert_eq!(revision.created_at(), created_at);
+}
+
+#[test]
+fn test_note_revision_diff_against_note() {
+    let note = create_note("Title", "line 1\nline 2 changed\nline 3");
+    let revision = NoteRevision::from(NoteRevisionData {
+        id: Uuid::new_v4(),
+        note_id: note.id(),
+        revision: 1,
+        title: "Title".to_string(),
+        content: "line 1\nline 2\nline 3".to_string(),
+        created_at: Utc::now(),
+    });
+
+    let diff = revision.diff(&note);
+
+    assert!(!diff.title.has_changes());
+    assert!(diff.content.has_changes());
+    let removed: Vec<&str> = diff
+        .content
+        .lines()
+        .iter()
+        .filter(|line| line.kind == DiffLineKind::Removed)
+        .map(|line| line.content.as_str())
+        .collect();
+    let added: Vec<&str> = diff
+        .content
+        .lines()
+        .iter()
+        .filter(|line| line.kind == DiffLineKind::Added)
+        .map(|line| line.content.as_str())
+        .collect();
+    assert_eq!(removed, vec!["line 2"]);
+    assert_eq!(added, vec!["line 2 changed"]);
+}
+
+#[test]
+fn test_create_note_revision_data_from_note() {
+    let note = create_note("Snapshot title", "Snapshot content");
+
+    let data = CreateNoteRevisionData::from(&note);
+
+    assert_eq!(data.note_id, note.id());
+    assert_eq!(data.title, "Snapshot title");
+    assert_eq!(data.content, "Snapshot content");
+}
+
+fn create_note(title: &str, content: &str) -> Note {
+    let data = NoteData {
+        id: Uuid::new_v4(),
+        user_id: Uuid::new_v4(),
+        notebook_id: None,
+        title: title.to_string(),
+        content: content.to_string(),
+        tags: Vec::new(),
+        version: 1,
+        pinned: false,
+        archived: false,
+        favorite: false,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+        deleted_at: None,
+    };
+    Note::try_from(data).unwrap()
+}
@@ -0,0 +1,146 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::value_objects::email::Email;
+use common::error::AppError;
+
+/// Grant of access over a [`crate::entities::note::Note`] to a user other than its owner.
+#[derive(Debug, Clone)]
+pub struct NoteShare {
+    note_id: Uuid,
+    user_id: Uuid,
+    user_email: Email,
+    role: NoteShareRole,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<NoteShareData> for NoteShare {
+    fn from(data: NoteShareData) -> Self {
+        Self {
+            note_id: data.note_id,
+            user_id: data.user_id,
+            user_email: data.user_email,
+            role: data.role,
+            created_at: data.created_at,
+            updated_at: data.updated_at,
+        }
+    }
+}
+
+impl NoteShare {
+    pub fn note_id(&self) -> Uuid {
+        self.note_id
+    }
+
+    /// The collaborator the note is shared with.
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn user_email(&self) -> &Email {
+        &self.user_email
+    }
+
+    pub fn role(&self) -> NoteShareRole {
+        self.role
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteShareRole {
+    Read,
+    Write,
+}
+
+impl NoteShareRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NoteShareRole::Read => "read",
+            NoteShareRole::Write => "write",
+        }
+    }
+}
+
+impl TryFrom<&str> for NoteShareRole {
+    type Error = AppError;
+
+    fn try_from(role: &str) -> Result<Self, Self::Error> {
+        match role {
+            "read" => Ok(NoteShareRole::Read),
+            "write" => Ok(NoteShareRole::Write),
+            _ => Err(AppError::bad_request(format!(
+                "Invalid note share role ({role})!"
+            ))),
+        }
+    }
+}
+
+/// Access level of a user over a [`crate::entities::note::Note`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteAccess {
+    Owner,
+    Shared(NoteShareRole),
+}
+
+impl NoteAccess {
+    pub fn is_owner(&self) -> bool {
+        matches!(self, NoteAccess::Owner)
+    }
+
+    pub fn can_write(&self) -> bool {
+        matches!(
+            self,
+            NoteAccess::Owner | NoteAccess::Shared(NoteShareRole::Write)
+        )
+    }
+
+    pub fn ensure_can_write(&self, note_id: Uuid) -> Result<(), AppError> {
+        if self.can_write() {
+            Ok(())
+        } else {
+            Err(AppError::forbidden(format!(
+                "Note ({note_id}) is shared with read-only access!"
+            )))
+        }
+    }
+
+    pub fn ensure_owner(&self, note_id: Uuid) -> Result<(), AppError> {
+        if self.is_owner() {
+            Ok(())
+        } else {
+            Err(AppError::forbidden(format!(
+                "Only the owner of note ({note_id}) is allowed to do this!"
+            )))
+        }
+    }
+}
+
+/// [`NoteShare`] entity pre-validation data struct.
+pub struct NoteShareData {
+    pub note_id: Uuid,
+    pub user_id: Uuid,
+    pub user_email: Email,
+    pub role: NoteShareRole,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// [`NoteShare`] entity creation pre-validation utility data struct.
+#[derive(Debug)]
+pub struct CreateNoteShareData {
+    pub note_id: Uuid,
+    pub user_id: Uuid,
+    pub role: NoteShareRole,
+}
+
+#[cfg(test)]
+mod tests;
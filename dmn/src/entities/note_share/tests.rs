@@ -0,0 +1,81 @@
+use uuid::Uuid;
+
+use super::*;
+
+#[test]
+fn test_note_share_creation() {
+    let note_id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let created_at = Utc::now();
+    let data = NoteShareData {
+        note_id,
+        user_id,
+        user_email: Email::try_from("collaborator@example.com".to_string()).unwrap(),
+        role: NoteShareRole::Write,
+        created_at,
+        updated_at: created_at,
+    };
+    let share = NoteShare::from(data);
+
+    assert_eq!(share.note_id(), note_id);
+    assert_eq!(share.user_id(), user_id);
+    assert_eq!(share.user_email().as_ref(), "collaborator@example.com");
+    assert_eq!(share.role(), NoteShareRole::Write);
+    assert_eq!(share.created_at(), created_at);
+    assert_eq!(share.updated_at(), created_at);
+}
+
+#[test]
+fn test_note_share_role_round_trip() {
+    for role in [NoteShareRole::Read, NoteShareRole::Write] {
+        assert_eq!(NoteShareRole::try_from(role.as_str()).unwrap(), role);
+    }
+}
+
+#[test]
+fn test_note_share_role_rejects_unknown_role() {
+    let result = NoteShareRole::try_from("admin");
+
+    assert!(result.is_err());
+    if let Err(AppError::BadRequest(base_error)) = result {
+        assert_eq!(base_error.public_info, "Invalid note share role (admin)!");
+    } else {
+        panic!("Expected BadRequest error for unknown role");
+    }
+}
+
+#[test]
+fn test_note_access_owner_can_do_anything() {
+    let note_id = Uuid::new_v4();
+    let access = NoteAccess::Owner;
+
+    assert!(access.ensure_can_write(note_id).is_ok());
+    assert!(access.ensure_owner(note_id).is_ok());
+}
+
+#[test]
+fn test_note_access_write_share_can_write() {
+    let note_id = Uuid::new_v4();
+    let access = NoteAccess::Shared(NoteShareRole::Write);
+
+    assert!(access.ensure_can_write(note_id).is_ok());
+    assert!(matches!(
+        access.ensure_owner(note_id),
+        Err(AppError::Forbidden(_))
+    ));
+}
+
+#[test]
+fn test_note_access_read_share_cannot_write() {
+    let note_id = Uuid::new_v4();
+    let access = NoteAccess::Shared(NoteShareRole::Read);
+
+    assert!(matches!(
+        access.ensure_can_write(note_id),
+        Err(AppError::Forbidden(_))
+    ));
+    assert!(matches!(
+        access.ensure_owner(note_id),
+        Err(AppError::Forbidden(_))
+    ));
+}
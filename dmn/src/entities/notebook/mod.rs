@@ -0,0 +1,125 @@
+mod validation;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use common::error::AppError;
+
+/// A user-owned folder grouping notes, optionally nested under a parent [`Notebook`].
+#[derive(Debug, Clone)]
+pub struct Notebook {
+    id: Uuid,
+    user_id: Uuid,
+    parent_id: Option<Uuid>,
+    name: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<NotebookData> for Notebook {
+    type Error = AppError;
+
+    fn try_from(data: NotebookData) -> Result<Self, Self::Error> {
+        let name = validation::validate_name(&data.name)?;
+        if data.parent_id == Some(data.id) {
+            return Err(AppError::bad_request("Notebook cannot be its own parent!"));
+        }
+
+        Ok(Self {
+            id: data.id,
+            user_id: data.user_id,
+            parent_id: data.parent_id,
+            name,
+            created_at: data.created_at,
+            updated_at: data.updated_at,
+        })
+    }
+}
+
+impl Notebook {
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn parent_id(&self) -> Option<Uuid> {
+        self.parent_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+impl Notebook {
+    pub fn set_name(&mut self, name: String) -> Result<(), AppError> {
+        let name = validation::validate_name(&name)?;
+        self.name = name;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Moves the notebook under `parent`, or to the root level when `None`.<br />
+    /// `parent_path` lists the ids from `parent` itself up to its root notebook
+    /// and is used to reject moves that would introduce a cycle.
+    pub fn set_parent(
+        &mut self,
+        parent: Option<&Notebook>,
+        parent_path: &[Uuid],
+    ) -> Result<(), AppError> {
+        if let Some(parent) = parent {
+            validation::validate_parent(self, parent, parent_path)?;
+        }
+        self.parent_id = parent.map(|parent| parent.id());
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+}
+
+/// [`Notebook`] entity pre-validation data struct.
+pub struct NotebookData {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// [`Notebook`] entity creation pre-validation utility data struct.
+#[derive(Debug)]
+pub struct CreateNotebookData {
+    pub user_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub name: String,
+}
+
+/// [`Notebook`] entity update pre-validation utility data struct.
+#[derive(Debug)]
+pub struct UpdateNotebookData {
+    pub parent_id: Option<Uuid>,
+    pub name: String,
+}
+
+impl From<&Notebook> for UpdateNotebookData {
+    fn from(notebook: &Notebook) -> Self {
+        Self {
+            parent_id: notebook.parent_id(),
+            name: notebook.name().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,155 @@
+use std::ops::Sub;
+use uuid::Uuid;
+
+use super::*;
+
+#[test]
+fn test_valid_notebook_creation() {
+    let id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let parent_id = Uuid::new_v4();
+    let created_at = Utc::now();
+    let updated_at = created_at;
+    let data = NotebookData {
+        id,
+        user_id,
+        parent_id: Some(parent_id),
+        name: " Projects ".to_string(),
+        created_at,
+        updated_at,
+    };
+    let result = Notebook::try_from(data);
+
+    assert!(result.is_ok());
+    let notebook = result.unwrap();
+
+    assert_eq!(notebook.id(), id);
+    assert_eq!(notebook.user_id(), user_id);
+    assert_eq!(notebook.parent_id(), Some(parent_id));
+    assert_eq!(notebook.name(), "Projects");
+    assert_eq!(notebook.created_at(), created_at);
+    assert_eq!(notebook.updated_at(), updated_at);
+}
+
+#[test]
+fn test_invalid_notebook_being_its_own_parent() {
+    let mut data = create_valid_notebook_data();
+    data.parent_id = Some(data.id);
+    let result = Notebook::try_from(data);
+
+    assert!(result.is_err());
+    if let Err(AppError::BadRequest(base_error)) = result {
+        assert_eq!(base_error.public_info, "Notebook cannot be its own parent!");
+    } else {
+        panic!("Expected BadRequest error for self-parented notebook");
+    }
+}
+
+#[test]
+fn test_notebook_set_parent() {
+    let data = create_valid_notebook_data();
+    let original_updated_at = data.updated_at;
+    let mut notebook = Notebook::try_from(data).unwrap();
+    let parent = create_notebook_owned_by(notebook.user_id());
+
+    // Small delay to ensure timestamp difference
+    std::thread::sleep(std::time::Duration::from_millis(1));
+
+    let result = notebook.set_parent(Some(&parent), &[parent.id()]);
+
+    assert!(result.is_ok());
+    assert_eq!(notebook.parent_id(), Some(parent.id()));
+    assert!(notebook.updated_at() > original_updated_at);
+}
+
+#[test]
+fn test_notebook_set_parent_to_root() {
+    let mut data = create_valid_notebook_data();
+    data.parent_id = Some(Uuid::new_v4());
+    let mut notebook = Notebook::try_from(data).unwrap();
+
+    let result = notebook.set_parent(None, &[]);
+
+    assert!(result.is_ok());
+    assert_eq!(notebook.parent_id(), None);
+}
+
+#[test]
+fn test_notebook_set_parent_to_itself_fails() {
+    let data = create_valid_notebook_data();
+    let mut notebook = Notebook::try_from(data).unwrap();
+    let same_notebook = notebook.clone();
+
+    let result = notebook.set_parent(Some(&same_notebook), &[same_notebook.id()]);
+
+    assert!(result.is_err());
+    if let Err(AppError::BadRequest(base_error)) = result {
+        assert_eq!(
+            base_error.public_info,
+            "Notebook cannot be moved into itself or one of its descendants!"
+        );
+    } else {
+        panic!("Expected BadRequest error for self-parenting");
+    }
+    assert_eq!(notebook.parent_id(), None);
+}
+
+#[test]
+fn test_notebook_set_parent_to_descendant_fails() {
+    let data = create_valid_notebook_data();
+    let original_updated_at = data.updated_at;
+    let mut notebook = Notebook::try_from(data).unwrap();
+    // notebook <- child <- grandchild
+    let child_id = Uuid::new_v4();
+    let grandchild = create_notebook_owned_by(notebook.user_id());
+
+    let result = notebook.set_parent(
+        Some(&grandchild),
+        &[grandchild.id(), child_id, notebook.id()],
+    );
+
+    assert!(result.is_err());
+    if let Err(AppError::BadRequest(base_error)) = result {
+        assert_eq!(
+            base_error.public_info,
+            "Notebook cannot be moved into itself or one of its descendants!"
+        );
+    } else {
+        panic!("Expected BadRequest error for cyclic move");
+    }
+
+    // Notebook should remain unchanged
+    assert_eq!(notebook.parent_id(), None);
+    assert_eq!(notebook.updated_at(), original_updated_at);
+}
+
+#[test]
+fn test_notebook_set_parent_owned_by_another_user_fails() {
+    let data = create_valid_notebook_data();
+    let mut notebook = Notebook::try_from(data).unwrap();
+    let foreign_parent = create_notebook_owned_by(Uuid::new_v4());
+
+    let result = notebook.set_parent(Some(&foreign_parent), &[foreign_parent.id()]);
+
+    assert!(matches!(result, Err(AppError::Forbidden(_))));
+    assert_eq!(notebook.parent_id(), None);
+}
+
+fn create_notebook_owned_by(user_id: Uuid) -> Notebook {
+    let data = NotebookData {
+        user_id,
+        ..create_valid_notebook_data()
+    };
+    Notebook::try_from(data).unwrap()
+}
+
+fn create_valid_notebook_data() -> NotebookData {
+    NotebookData {
+        id: Uuid::new_v4(),
+        user_id: Uuid::new_v4(),
+        parent_id: None,
+        name: "Projects".to_string(),
+        created_at: Utc::now().sub(chrono::Duration::days(1)),
+        updated_at: Utc::now(),
+    }
+}
@@ -0,0 +1,54 @@
+use uuid::Uuid;
+
+use super::{CreateNotebookData, Notebook, UpdateNotebookData};
+use common::error::AppError;
+
+pub(crate) const NOTEBOOK_NAME_MAX_LENGTH: usize = 128;
+
+impl CreateNotebookData {
+    pub fn validate(self) -> Result<Self, AppError> {
+        let name = validate_name(&self.name)?;
+        Ok(Self { name, ..self })
+    }
+}
+
+impl UpdateNotebookData {
+    pub fn validate(self) -> Result<Self, AppError> {
+        let name = validate_name(&self.name)?;
+        Ok(Self { name, ..self })
+    }
+}
+
+pub(crate) fn validate_name(name: &str) -> Result<String, AppError> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err(AppError::bad_request("Notebook name cannot be empty!"));
+    }
+    if name.chars().count() > NOTEBOOK_NAME_MAX_LENGTH {
+        return Err(AppError::bad_request(format!(
+            "Notebook name cannot exceed {NOTEBOOK_NAME_MAX_LENGTH} characters!"
+        )));
+    }
+    Ok(name)
+}
+
+pub(crate) fn validate_parent(
+    notebook: &Notebook,
+    parent: &Notebook,
+    parent_path: &[Uuid],
+) -> Result<(), AppError> {
+    if parent.user_id() != notebook.user_id() {
+        return Err(AppError::forbidden(
+            "Notebook cannot be moved into another user's notebook!",
+        ));
+    }
+    if parent.id() == notebook.id() || parent_path.contains(&notebook.id()) {
+        return Err(AppError::bad_request(
+            "Notebook cannot be moved into itself or one of its descendants!",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
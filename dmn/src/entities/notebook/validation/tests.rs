@@ -0,0 +1,59 @@
+use uuid::Uuid;
+
+use super::*;
+
+#[test]
+fn test_validate_name_rejects_empty_name() {
+    let result = validate_name(" ");
+    assert!(result.is_err());
+    if let Err(AppError::BadRequest(base_error)) = result {
+        assert_eq!(base_error.public_info, "Notebook name cannot be empty!");
+    } else {
+        panic!("Expected BadRequest error for empty name");
+    }
+}
+
+#[test]
+fn test_validate_name_rejects_overlong_name() {
+    let name = "a".repeat(NOTEBOOK_NAME_MAX_LENGTH + 1);
+    let result = validate_name(&name);
+    assert!(result.is_err());
+    if let Err(AppError::BadRequest(base_error)) = result {
+        assert_eq!(
+            base_error.public_info,
+            "Notebook name cannot exceed 128 characters!"
+        );
+    } else {
+        panic!("Expected BadRequest error for overlong name");
+    }
+}
+
+#[test]
+fn test_create_notebook_data_validate_trims_name() {
+    let parent_id = Uuid::new_v4();
+    let data = CreateNotebookData {
+        user_id: Uuid::new_v4(),
+        parent_id: Some(parent_id),
+        name: " Work ".to_string(),
+    };
+
+    let valid_data = data.validate().unwrap();
+    assert_eq!(valid_data.name, "Work");
+    assert_eq!(valid_data.parent_id, Some(parent_id));
+}
+
+#[test]
+fn test_update_notebook_data_validate_rejects_empty_name() {
+    let data = UpdateNotebookData {
+        parent_id: None,
+        name: "".to_string(),
+    };
+
+    let err = data.validate().unwrap_err();
+    match err {
+        AppError::BadRequest(base_error) => {
+            assert_eq!(base_error.public_info, "Notebook name cannot be empty!");
+        }
+        _ => panic!("Expected BadRequest error"),
+    }
+}
@@ -0,0 +1,87 @@
+mod validation;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use common::error::AppError;
+
+#[derive(Debug, Clone)]
+pub struct Tag {
+    id: Uuid,
+    user_id: Uuid,
+    name: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<TagData> for Tag {
+    type Error = AppError;
+
+    fn try_from(data: TagData) -> Result<Self, Self::Error> {
+        let name = validation::validate_name(&data.name)?;
+
+        Ok(Self {
+            id: data.id,
+            user_id: data.user_id,
+            name,
+            created_at: data.created_at,
+            updated_at: data.updated_at,
+        })
+    }
+}
+
+impl Tag {
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+impl Tag {
+    pub fn set_name(&mut self, name: String) -> Result<(), AppError> {
+        let name = validation::validate_name(&name)?;
+        self.name = name;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+}
+
+/// [`Tag`] entity pre-validation data struct.
+pub struct TagData {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// [`Tag`] entity creation pre-validation utility data struct.
+#[derive(Debug)]
+pub struct CreateTagData {
+    pub user_id: Uuid,
+    pub name: String,
+}
+
+/// [`Tag`] entity update pre-validation utility data struct.
+#[derive(Debug)]
+pub struct UpdateTagData {
+    pub name: String,
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,98 @@
+use std::ops::Sub;
+use uuid::Uuid;
+
+use super::*;
+
+#[test]
+fn test_invalid_tag_with_empty_name() {
+    let data = TagData {
+        id: Uuid::new_v4(),
+        user_id: Uuid::new_v4(),
+        name: "   ".to_string(),
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    };
+    let result = Tag::try_from(data);
+
+    assert!(result.is_err());
+    if let Err(AppError::BadRequest(base_error)) = result {
+        assert_eq!(base_error.public_info, "Tag name cannot be empty!");
+    } else {
+        panic!("Expected BadRequest error for empty name");
+    }
+}
+
+#[test]
+fn test_valid_tag_creation() {
+    let id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let created_at = Utc::now();
+    let updated_at = created_at;
+    let data = TagData {
+        id,
+        user_id,
+        name: " work ".to_string(),
+        created_at,
+        updated_at,
+    };
+    let result = Tag::try_from(data);
+
+    assert!(result.is_ok());
+    let tag = result.unwrap();
+
+    assert_eq!(tag.id(), id);
+    assert_eq!(tag.user_id(), user_id);
+    assert_eq!(tag.name(), "work");
+    assert_eq!(tag.created_at(), created_at);
+    assert_eq!(tag.updated_at(), updated_at);
+}
+
+#[test]
+fn test_tag_set_name() {
+    let data = create_valid_tag_data();
+    let original_updated_at = data.updated_at;
+    let mut tag = Tag::try_from(data).unwrap();
+
+    // Small delay to ensure timestamp difference
+    std::thread::sleep(std::time::Duration::from_millis(1));
+
+    let result = tag.set_name("personal".to_string());
+
+    assert!(result.is_ok());
+    assert_eq!(tag.name(), "personal");
+    assert!(tag.updated_at() > original_updated_at);
+}
+
+#[test]
+fn test_tag_set_name_too_long_fails() {
+    let data = create_valid_tag_data();
+    let original_name = data.name.clone();
+    let original_updated_at = data.updated_at;
+    let mut tag = Tag::try_from(data).unwrap();
+
+    let result = tag.set_name("a".repeat(65));
+
+    assert!(result.is_err());
+    if let Err(AppError::BadRequest(base_error)) = result {
+        assert_eq!(
+            base_error.public_info,
+            "Tag name cannot exceed 64 characters!"
+        );
+    } else {
+        panic!("Expected BadRequest error for overlong name");
+    }
+
+    // Name should remain unchanged
+    assert_eq!(tag.name(), original_name);
+    assert_eq!(tag.updated_at(), original_updated_at);
+}
+
+fn create_valid_tag_data() -> TagData {
+    TagData {
+        id: Uuid::new_v4(),
+        user_id: Uuid::new_v4(),
+        name: "work".to_string(),
+        created_at: Utc::now().sub(chrono::Duration::days(1)),
+        updated_at: Utc::now(),
+    }
+}
@@ -0,0 +1,34 @@
+use super::{CreateTagData, UpdateTagData};
+use common::error::AppError;
+
+pub(crate) const TAG_NAME_MAX_LENGTH: usize = 64;
+
+impl CreateTagData {
+    pub fn validate(self) -> Result<Self, AppError> {
+        let name = validate_name(&self.name)?;
+        Ok(Self { name, ..self })
+    }
+}
+
+impl UpdateTagData {
+    pub fn validate(self) -> Result<Self, AppError> {
+        let name = validate_name(&self.name)?;
+        Ok(Self { name })
+    }
+}
+
+pub(crate) fn validate_name(name: &str) -> Result<String, AppError> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err(AppError::bad_request("Tag name cannot be empty!"));
+    }
+    if name.chars().count() > TAG_NAME_MAX_LENGTH {
+        return Err(AppError::bad_request(format!(
+            "Tag name cannot exceed {TAG_NAME_MAX_LENGTH} characters!"
+        )));
+    }
+    Ok(name)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,53 @@
+use uuid::Uuid;
+
+use super::*;
+
+#[test]
+fn test_validate_name_rejects_empty_name() {
+    let result = validate_name("");
+    assert!(result.is_err());
+    if let Err(AppError::BadRequest(base_error)) = result {
+        assert_eq!(base_error.public_info, "Tag name cannot be empty!");
+    } else {
+        panic!("Expected BadRequest error for empty name");
+    }
+}
+
+#[test]
+fn test_validate_name_accepts_max_length_name() {
+    let name = "a".repeat(TAG_NAME_MAX_LENGTH);
+    let result = validate_name(&name);
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_validate_name_trims_whitespace() {
+    let result = validate_name("  ideas \n");
+    assert_eq!(result.unwrap(), "ideas");
+}
+
+#[test]
+fn test_create_tag_data_validate_trims_name() {
+    let data = CreateTagData {
+        user_id: Uuid::new_v4(),
+        name: " reading list ".to_string(),
+    };
+
+    let valid_data = data.validate().unwrap();
+    assert_eq!(valid_data.name, "reading list");
+}
+
+#[test]
+fn test_update_tag_data_validate_rejects_empty_name() {
+    let data = UpdateTagData {
+        name: " ".to_string(),
+    };
+
+    let err = data.validate().unwrap_err();
+    match err {
+        AppError::BadRequest(base_error) => {
+            assert_eq!(base_error.public_info, "Tag name cannot be empty!");
+        }
+        _ => panic!("Expected BadRequest error"),
+    }
+}
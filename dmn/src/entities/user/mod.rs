@@ -4,7 +4,7 @@ use chrono::{DateTime, Utc};
 use common::error::AppError;
 use uuid::Uuid;
 
-use crate::value_objects::email::Email;
+use crate::{events::DomainEvent, value_objects::email::Email};
 
 #[derive(Debug, Clone)]
 pub struct User {
@@ -44,6 +44,25 @@ impl User {
     pub fn updated_at(&self) -> DateTime<Utc> {
         self.updated_at
     }
+
+    pub fn registered_event(&self) -> DomainEvent {
+        DomainEvent::UserRegistered {
+            user_id: self.id,
+            email: self.email.to_string(),
+        }
+    }
+
+    pub fn updated_event(&self) -> DomainEvent {
+        DomainEvent::UserUpdated { user_id: self.id }
+    }
+
+    pub fn deleted_event(&self) -> DomainEvent {
+        DomainEvent::UserDeleted { user_id: self.id }
+    }
+
+    pub fn password_changed_event(&self) -> DomainEvent {
+        DomainEvent::PasswordChanged { user_id: self.id }
+    }
 }
 
 impl User {
@@ -58,6 +58,24 @@ fn create_test_email() -> Email {
     Email::try_from("test@example.com".to_string()).unwrap()
 }
 
+#[test]
+fn test_user_events_carry_user() {
+    let user = User::try_from(create_valid_user_data()).unwrap();
+
+    assert_eq!(
+        user.registered_event(),
+        DomainEvent::UserRegistered {
+            user_id: user.id(),
+            email: user.email().to_string(),
+        }
+    );
+    assert_eq!(user.registered_event().name(), "UserRegistered");
+    assert_eq!(user.deleted_event().user_id(), user.id());
+    assert_eq!(user.updated_event().name(), "UserUpdated");
+    assert_eq!(user.updated_event().note_id(), None);
+    assert_eq!(user.password_changed_event().name(), "PasswordChanged");
+}
+
 fn create_valid_user_data() -> UserData {
     UserData {
         id: Uuid::new_v4(),
@@ -0,0 +1,166 @@
+mod validation;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::events::DomainEvent;
+use common::error::AppError;
+
+/// User-registered endpoint receiving signed notifications about the user's notes and account.
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    id: Uuid,
+    user_id: Uuid,
+    url: String,
+    secret: String,
+    event_types: Vec<WebhookEventType>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<WebhookData> for Webhook {
+    type Error = AppError;
+
+    fn try_from(data: WebhookData) -> Result<Self, Self::Error> {
+        let event_types = data
+            .event_types
+            .iter()
+            .map(|event_type| WebhookEventType::try_from(event_type.as_str()))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            id: data.id,
+            user_id: data.user_id,
+            url: data.url,
+            secret: data.secret,
+            event_types,
+            created_at: data.created_at,
+            updated_at: data.updated_at,
+        })
+    }
+}
+
+impl Webhook {
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Key signing the delivered payloads, never exposed after registration.
+    pub fn secret(&self) -> &str {
+        &self.secret
+    }
+
+    pub fn event_types(&self) -> &[WebhookEventType] {
+        &self.event_types
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    /// Whether the webhook has to be notified about events of the given type.<br />
+    /// Test events are always accepted.
+    pub fn accepts(&self, event_type: WebhookEventType) -> bool {
+        event_type == WebhookEventType::Test || self.event_types.contains(&event_type)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookEventType {
+    NoteCreated,
+    NoteUpdated,
+    /// The note was moved to the trash.
+    NoteDeleted,
+    UserUpdated,
+    PasswordChanged,
+    /// Sent on demand to check an endpoint, can't be subscribed to.
+    Test,
+}
+
+impl WebhookEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEventType::NoteCreated => "note.created",
+            WebhookEventType::NoteUpdated => "note.updated",
+            WebhookEventType::NoteDeleted => "note.deleted",
+            WebhookEventType::UserUpdated => "user.updated",
+            WebhookEventType::PasswordChanged => "user.password_changed",
+            WebhookEventType::Test => "webhook.test",
+        }
+    }
+
+    /// Webhook event type the domain event is published as, if any.
+    pub fn from_domain_event(event: &DomainEvent) -> Option<Self> {
+        match event {
+            DomainEvent::NoteCreated { .. } => Some(WebhookEventType::NoteCreated),
+            DomainEvent::NoteUpdated { .. } => Some(WebhookEventType::NoteUpdated),
+            DomainEvent::NoteDeleted { .. } => Some(WebhookEventType::NoteDeleted),
+            DomainEvent::UserUpdated { .. } => Some(WebhookEventType::UserUpdated),
+            DomainEvent::PasswordChanged { .. } => Some(WebhookEventType::PasswordChanged),
+            // Webhooks don't exist yet, or not anymore
+            DomainEvent::UserRegistered { .. } | DomainEvent::UserDeleted { .. } => None,
+        }
+    }
+}
+
+impl TryFrom<&str> for WebhookEventType {
+    type Error = AppError;
+
+    fn try_from(event_type: &str) -> Result<Self, Self::Error> {
+        match event_type {
+            "note.created" => Ok(WebhookEventType::NoteCreated),
+            "note.updated" => Ok(WebhookEventType::NoteUpdated),
+            "note.deleted" => Ok(WebhookEventType::NoteDeleted),
+            "user.updated" => Ok(WebhookEventType::UserUpdated),
+            "user.password_changed" => Ok(WebhookEventType::PasswordChanged),
+            "webhook.test" => Ok(WebhookEventType::Test),
+            _ => Err(AppError::bad_request(format!(
+                "Unknown webhook event type ({event_type})!"
+            ))),
+        }
+    }
+}
+
+/// [`Webhook`] entity pre-validation data struct.
+pub struct WebhookData {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub url: String,
+    pub secret: String,
+    pub event_types: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// [`Webhook`] entity creation pre-validation utility data struct.
+#[derive(Debug)]
+pub struct CreateWebhookData {
+    pub user_id: Uuid,
+    pub url: String,
+    pub secret: String,
+    pub event_types: Vec<WebhookEventType>,
+}
+
+/// [`Webhook`] entity update pre-validation utility data struct.
+#[derive(Debug)]
+pub struct UpdateWebhookData {
+    pub url: String,
+    /// `None` keeps the current secret.
+    pub secret: Option<String>,
+    pub event_types: Vec<WebhookEventType>,
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,104 @@
+use uuid::Uuid;
+
+use super::*;
+
+#[test]
+fn test_valid_webhook_creation() {
+    let data = create_valid_webhook_data();
+    let id = data.id;
+    let user_id = data.user_id;
+    let result = Webhook::try_from(data);
+
+    assert!(result.is_ok());
+    let webhook = result.unwrap();
+
+    assert_eq!(webhook.id(), id);
+    assert_eq!(webhook.user_id(), user_id);
+    assert_eq!(webhook.url(), "https://example.com/hooks");
+    assert_eq!(webhook.secret(), "0123456789abcdef");
+    assert_eq!(
+        webhook.event_types(),
+        &[
+            WebhookEventType::NoteCreated,
+            WebhookEventType::PasswordChanged
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_webhook_with_unknown_event_type() {
+    let mut data = create_valid_webhook_data();
+    data.event_types.push("note.shared".to_string());
+    let result = Webhook::try_from(data);
+
+    assert!(result.is_err());
+    if let Err(AppError::BadRequest(base_error)) = result {
+        assert_eq!(
+            base_error.public_info,
+            "Unknown webhook event type (note.shared)!"
+        );
+    } else {
+        panic!("Expected BadRequest error for unknown event type");
+    }
+}
+
+#[test]
+fn test_webhook_accepts_subscribed_and_test_events() {
+    let webhook = Webhook::try_from(create_valid_webhook_data()).unwrap();
+
+    assert!(webhook.accepts(WebhookEventType::NoteCreated));
+    assert!(webhook.accepts(WebhookEventType::Test));
+    assert!(!webhook.accepts(WebhookEventType::NoteUpdated));
+}
+
+#[test]
+fn test_webhook_event_type_round_trip() {
+    for event_type in [
+        WebhookEventType::NoteCreated,
+        WebhookEventType::NoteUpdated,
+        WebhookEventType::NoteDeleted,
+        WebhookEventType::UserUpdated,
+        WebhookEventType::PasswordChanged,
+        WebhookEventType::Test,
+    ] {
+        assert_eq!(
+            WebhookEventType::try_from(event_type.as_str()).unwrap(),
+            event_type
+        );
+    }
+}
+
+#[test]
+fn test_webhook_event_type_from_domain_event() {
+    let note_id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+
+    assert_eq!(
+        WebhookEventType::from_domain_event(&DomainEvent::NoteDeleted { note_id, user_id }),
+        Some(WebhookEventType::NoteDeleted)
+    );
+    assert_eq!(
+        WebhookEventType::from_domain_event(&DomainEvent::PasswordChanged { user_id }),
+        Some(WebhookEventType::PasswordChanged)
+    );
+    assert_eq!(
+        WebhookEventType::from_domain_event(&DomainEvent::UserDeleted { user_id }),
+        None
+    );
+}
+
+fn create_valid_webhook_data() -> WebhookData {
+    let created_at = Utc::now();
+    WebhookData {
+        id: Uuid::new_v4(),
+        user_id: Uuid::new_v4(),
+        url: "https://example.com/hooks".to_string(),
+        secret: "0123456789abcdef".to_string(),
+        event_types: vec![
+            "note.created".to_string(),
+            "user.password_changed".to_string(),
+        ],
+        created_at,
+        updated_at: created_at,
+    }
+}
@@ -0,0 +1,92 @@
+use super::{CreateWebhookData, UpdateWebhookData, WebhookEventType};
+use common::error::AppError;
+
+pub(crate) const WEBHOOK_URL_MAX_LENGTH: usize = 2048;
+pub(crate) const WEBHOOK_SECRET_MIN_LENGTH: usize = 16;
+pub(crate) const WEBHOOK_SECRET_MAX_LENGTH: usize = 256;
+
+impl CreateWebhookData {
+    pub fn validate(self) -> Result<Self, AppError> {
+        let url = validate_url(&self.url)?;
+        validate_secret(&self.secret)?;
+        let event_types = validate_event_types(self.event_types)?;
+        Ok(Self {
+            url,
+            event_types,
+            ..self
+        })
+    }
+}
+
+impl UpdateWebhookData {
+    pub fn validate(self) -> Result<Self, AppError> {
+        let url = validate_url(&self.url)?;
+        if let Some(secret) = &self.secret {
+            validate_secret(secret)?;
+        }
+        let event_types = validate_event_types(self.event_types)?;
+        Ok(Self {
+            url,
+            event_types,
+            ..self
+        })
+    }
+}
+
+/// Only absolute `http(s)` URLs are accepted.<br />
+/// The addresses their host resolves to are checked by the webhook sender, which knows whether local targets are allowed.
+pub(crate) fn validate_url(url: &str) -> Result<String, AppError> {
+    let url = url.trim().to_string();
+    if url.chars().count() > WEBHOOK_URL_MAX_LENGTH {
+        return Err(AppError::bad_request(format!(
+            "Webhook URL cannot exceed {WEBHOOK_URL_MAX_LENGTH} characters!"
+        )));
+    }
+    let host = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .and_then(|rest| rest.split(['/', '?', '#']).next())
+        .unwrap_or_default();
+    if host.is_empty() || url.contains(char::is_whitespace) {
+        return Err(AppError::bad_request(
+            "Webhook URL has to be an absolute http(s) URL!",
+        ));
+    }
+    Ok(url)
+}
+
+pub(crate) fn validate_secret(secret: &str) -> Result<(), AppError> {
+    let length = secret.chars().count();
+    if !(WEBHOOK_SECRET_MIN_LENGTH..=WEBHOOK_SECRET_MAX_LENGTH).contains(&length) {
+        return Err(AppError::bad_request(format!(
+            "Webhook secret has to be between {WEBHOOK_SECRET_MIN_LENGTH} and {WEBHOOK_SECRET_MAX_LENGTH} characters long!"
+        )));
+    }
+    Ok(())
+}
+
+/// Deduplicates the event types, keeping their order.
+pub(crate) fn validate_event_types(
+    event_types: Vec<WebhookEventType>,
+) -> Result<Vec<WebhookEventType>, AppError> {
+    if event_types.is_empty() {
+        return Err(AppError::bad_request(
+            "Webhook has to subscribe to at least one event type!",
+        ));
+    }
+    if event_types.contains(&WebhookEventType::Test) {
+        return Err(AppError::bad_request(
+            "Webhook cannot subscribe to test events!",
+        ));
+    }
+    let mut unique_event_types = Vec::with_capacity(event_types.len());
+    for event_type in event_types {
+        if !unique_event_types.contains(&event_type) {
+            unique_event_types.push(event_type);
+        }
+    }
+    Ok(unique_event_types)
+}
+
+#[cfg(test)]
+mod tests;
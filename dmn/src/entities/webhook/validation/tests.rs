@@ -0,0 +1,119 @@
+use uuid::Uuid;
+
+use super::*;
+
+#[test]
+fn test_validate_url_accepts_http_and_https_urls() {
+    assert_eq!(
+        validate_url(" https://example.com/hooks ").unwrap(),
+        "https://example.com/hooks"
+    );
+    assert_eq!(
+        validate_url("http://localhost:8080").unwrap(),
+        "http://localhost:8080"
+    );
+}
+
+#[test]
+fn test_validate_url_rejects_invalid_urls() {
+    for url in [
+        "",
+        "example.com",
+        "ftp://example.com",
+        "https://",
+        "https:///path",
+    ] {
+        let result = validate_url(url);
+        if let Err(AppError::BadRequest(base_error)) = result {
+            assert_eq!(
+                base_error.public_info,
+                "Webhook URL has to be an absolute http(s) URL!"
+            );
+        } else {
+            panic!("Expected BadRequest error for invalid URL ({url})");
+        }
+    }
+}
+
+#[test]
+fn test_validate_url_rejects_overlong_url() {
+    let url = format!("https://example.com/{}", "a".repeat(WEBHOOK_URL_MAX_LENGTH));
+    let result = validate_url(&url);
+    assert!(result.is_err());
+    if let Err(AppError::BadRequest(base_error)) = result {
+        assert_eq!(
+            base_error.public_info,
+            "Webhook URL cannot exceed 2048 characters!"
+        );
+    } else {
+        panic!("Expected BadRequest error for overlong URL");
+    }
+}
+
+#[test]
+fn test_validate_secret_enforces_length() {
+    assert!(validate_secret(&"s".repeat(WEBHOOK_SECRET_MIN_LENGTH)).is_ok());
+    assert!(validate_secret(&"s".repeat(WEBHOOK_SECRET_MIN_LENGTH - 1)).is_err());
+    assert!(validate_secret(&"s".repeat(WEBHOOK_SECRET_MAX_LENGTH + 1)).is_err());
+}
+
+#[test]
+fn test_validate_event_types_rejects_empty_and_test_event_types() {
+    let result = validate_event_types(vec![]);
+    if let Err(AppError::BadRequest(base_error)) = result {
+        assert_eq!(
+            base_error.public_info,
+            "Webhook has to subscribe to at least one event type!"
+        );
+    } else {
+        panic!("Expected BadRequest error for missing event types");
+    }
+
+    let result = validate_event_types(vec![WebhookEventType::Test]);
+    if let Err(AppError::BadRequest(base_error)) = result {
+        assert_eq!(
+            base_error.public_info,
+            "Webhook cannot subscribe to test events!"
+        );
+    } else {
+        panic!("Expected BadRequest error for test event type");
+    }
+}
+
+#[test]
+fn test_create_webhook_data_validate_deduplicates_event_types() {
+    let data = CreateWebhookData {
+        user_id: Uuid::new_v4(),
+        url: "https://example.com/hooks".to_string(),
+        secret: "s".repeat(WEBHOOK_SECRET_MIN_LENGTH),
+        event_types: vec![
+            WebhookEventType::NoteUpdated,
+            WebhookEventType::NoteCreated,
+            WebhookEventType::NoteUpdated,
+        ],
+    };
+
+    let valid_data = data.validate().unwrap();
+
+    assert_eq!(
+        valid_data.event_types,
+        vec![WebhookEventType::NoteUpdated, WebhookEventType::NoteCreated]
+    );
+}
+
+#[test]
+fn test_update_webhook_data_validate_checks_new_secret_only() {
+    let data = UpdateWebhookData {
+        url: "https://example.com/hooks".to_string(),
+        secret: None,
+        event_types: vec![WebhookEventType::NoteCreated],
+    };
+    assert!(data.validate().is_ok());
+
+    let data = UpdateWebhookData {
+        url: "https://example.com/hooks".to_string(),
+        secret: Some("short".to_string()),
+        event_types: vec![WebhookEventType::NoteCreated],
+    };
+    assert!(data.validate().is_err());
+}
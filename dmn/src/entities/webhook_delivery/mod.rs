@@ -0,0 +1,247 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use uuid::Uuid;
+
+use crate::entities::webhook::WebhookEventType;
+use common::error::AppError;
+
+/// Attempts made before a delivery is given up on.
+pub const WEBHOOK_DELIVERY_MAX_ATTEMPTS: u32 = 8;
+/// Delay before the first retry, doubled on every subsequent one.
+const RETRY_BASE_DELAY_SECS: i64 = 30;
+
+/// Notification of a single event to a [`crate::entities::webhook::Webhook`], also serving as
+/// its delivery log entry.
+#[derive(Debug, Clone)]
+pub struct WebhookDelivery {
+    id: Uuid,
+    webhook_id: Uuid,
+    event_id: Uuid,
+    event_type: WebhookEventType,
+    note_id: Option<Uuid>,
+    status: WebhookDeliveryStatus,
+    attempts: u32,
+    response_status: Option<u16>,
+    last_error: Option<String>,
+    next_attempt_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    delivered_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<WebhookDeliveryData> for WebhookDelivery {
+    type Error = AppError;
+
+    fn try_from(data: WebhookDeliveryData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: data.id,
+            webhook_id: data.webhook_id,
+            event_id: data.event_id,
+            event_type: data.event_type.as_str().try_into()?,
+            note_id: data.note_id,
+            status: data.status.as_str().try_into()?,
+            attempts: data.attempts,
+            response_status: data.response_status,
+            last_error: data.last_error,
+            next_attempt_at: data.next_attempt_at,
+            created_at: data.created_at,
+            delivered_at: data.delivered_at,
+        })
+    }
+}
+
+impl WebhookDelivery {
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn webhook_id(&self) -> Uuid {
+        self.webhook_id
+    }
+
+    /// Identifies the notified event, shared with its deliveries to other webhooks.
+    pub fn event_id(&self) -> Uuid {
+        self.event_id
+    }
+
+    pub fn event_type(&self) -> WebhookEventType {
+        self.event_type
+    }
+
+    /// The note the event is about, if any.
+    pub fn note_id(&self) -> Option<Uuid> {
+        self.note_id
+    }
+
+    pub fn status(&self) -> WebhookDeliveryStatus {
+        self.status
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// HTTP status the endpoint answered the last attempt with.
+    pub fn response_status(&self) -> Option<u16> {
+        self.response_status
+    }
+
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    /// When the delivery is retried, `None` once it succeeded or failed for good.
+    pub fn next_attempt_at(&self) -> Option<DateTime<Utc>> {
+        self.next_attempt_at
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn delivered_at(&self) -> Option<DateTime<Utc>> {
+        self.delivered_at
+    }
+}
+
+impl WebhookDelivery {
+    /// Records the outcome of a delivery attempt, scheduling a retry with exponential backoff
+    /// unless it succeeded or ran out of attempts.<br />
+    /// Test events are attempted only once.
+    pub fn record_attempt(&mut self, outcome: WebhookAttemptOutcome) {
+        let now = Utc::now();
+        self.attempts += 1;
+        match outcome {
+            WebhookAttemptOutcome::Response(status) if (200..300).contains(&status) => {
+                self.status = WebhookDeliveryStatus::Succeeded;
+                self.response_status = Some(status);
+                self.last_error = None;
+                self.next_attempt_at = None;
+                self.delivered_at = Some(now);
+                return;
+            }
+            WebhookAttemptOutcome::Response(status) => {
+                self.response_status = Some(status);
+                self.last_error = Some(format!("Endpoint responded with status {status}!"));
+            }
+            WebhookAttemptOutcome::Error(err) => {
+                self.response_status = None;
+                self.last_error = Some(err);
+            }
+        }
+        match retry_delay(self.attempts) {
+            Some(delay) if self.event_type != WebhookEventType::Test => {
+                self.status = WebhookDeliveryStatus::Pending;
+                self.next_attempt_at = Some(now + delay);
+            }
+            _ => {
+                self.status = WebhookDeliveryStatus::Failed;
+                self.next_attempt_at = None;
+            }
+        }
+    }
+}
+
+/// Delay before retrying a delivery after its `attempts`-th failed attempt, `None` when there are
+/// no attempts left.
+pub fn retry_delay(attempts: u32) -> Option<TimeDelta> {
+    if attempts == 0 || attempts >= WEBHOOK_DELIVERY_MAX_ATTEMPTS {
+        return None;
+    }
+    Some(TimeDelta::seconds(RETRY_BASE_DELAY_SECS << (attempts - 1)))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookDeliveryStatus {
+    Pending,
+    Succeeded,
+    /// Every attempt failed, the delivery won't be retried.
+    Failed,
+}
+
+impl WebhookDeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookDeliveryStatus::Pending => "pending",
+            WebhookDeliveryStatus::Succeeded => "succeeded",
+            WebhookDeliveryStatus::Failed => "failed",
+        }
+    }
+}
+
+impl TryFrom<&str> for WebhookDeliveryStatus {
+    type Error = AppError;
+
+    fn try_from(status: &str) -> Result<Self, Self::Error> {
+        match status {
+            "pending" => Ok(WebhookDeliveryStatus::Pending),
+            "succeeded" => Ok(WebhookDeliveryStatus::Succeeded),
+            "failed" => Ok(WebhookDeliveryStatus::Failed),
+            _ => Err(AppError::internal(format!(
+                "Unknown webhook delivery status ({status})!"
+            ))),
+        }
+    }
+}
+
+/// Result of sending a [`WebhookDelivery`] to its endpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebhookAttemptOutcome {
+    /// The endpoint answered with this HTTP status.
+    Response(u16),
+    /// The endpoint couldn't be reached (e.g. connection refused or timed out).
+    Error(String),
+}
+
+/// [`WebhookDelivery`] entity pre-validation data struct.
+pub struct WebhookDeliveryData {
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub note_id: Option<Uuid>,
+    pub status: String,
+    pub attempts: u32,
+    pub response_status: Option<u16>,
+    pub last_error: Option<String>,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+/// [`WebhookDelivery`] entity creation utility data struct, deliveries start out pending.
+#[derive(Debug)]
+pub struct CreateWebhookDeliveryData {
+    pub webhook_id: Uuid,
+    /// A single delivery is created per webhook and event.
+    pub event_id: Uuid,
+    pub event_type: WebhookEventType,
+    pub note_id: Option<Uuid>,
+    /// `None` keeps the delivery from being sent in the background, e.g. when sent right away.
+    pub next_attempt_at: Option<DateTime<Utc>>,
+}
+
+/// [`WebhookDelivery`] entity update utility data struct.
+#[derive(Debug)]
+pub struct UpdateWebhookDeliveryData {
+    pub status: WebhookDeliveryStatus,
+    pub attempts: u32,
+    pub response_status: Option<u16>,
+    pub last_error: Option<String>,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+impl From<&WebhookDelivery> for UpdateWebhookDeliveryData {
+    fn from(delivery: &WebhookDelivery) -> Self {
+        Self {
+            status: delivery.status(),
+            attempts: delivery.attempts(),
+            response_status: delivery.response_status(),
+            last_error: delivery.last_error().map(|err| err.into()),
+            next_attempt_at: delivery.next_attempt_at(),
+            delivered_at: delivery.delivered_at(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,134 @@
+use uuid::Uuid;
+
+use super::*;
+
+#[test]
+fn test_valid_webhook_delivery_creation() {
+    let data = create_pending_delivery_data("note.updated");
+    let id = data.id;
+    let webhook_id = data.webhook_id;
+    let note_id = data.note_id;
+    let result = WebhookDelivery::try_from(data);
+
+    assert!(result.is_ok());
+    let delivery = result.unwrap();
+
+    assert_eq!(delivery.id(), id);
+    assert_eq!(delivery.webhook_id(), webhook_id);
+    assert_eq!(delivery.event_type(), WebhookEventType::NoteUpdated);
+    assert_eq!(delivery.note_id(), note_id);
+    assert_eq!(delivery.status(), WebhookDeliveryStatus::Pending);
+    assert_eq!(delivery.attempts(), 0);
+}
+
+#[test]
+fn test_invalid_webhook_delivery_with_unknown_status() {
+    let mut data = create_pending_delivery_data("note.updated");
+    data.status = "lost".to_string();
+    let result = WebhookDelivery::try_from(data);
+
+    assert!(result.is_err());
+    if let Err(AppError::Internal(base_error)) = result {
+        assert_eq!(
+            base_error.public_info,
+            "Unknown webhook delivery status (lost)!"
+        );
+    } else {
+        panic!("Expected Internal error for unknown status");
+    }
+}
+
+#[test]
+fn test_record_successful_attempt() {
+    let mut delivery = create_pending_delivery("note.updated");
+
+    delivery.record_attempt(WebhookAttemptOutcome::Response(204));
+
+    assert_eq!(delivery.status(), WebhookDeliveryStatus::Succeeded);
+    assert_eq!(delivery.attempts(), 1);
+    assert_eq!(delivery.response_status(), Some(204));
+    assert_eq!(delivery.last_error(), None);
+    assert_eq!(delivery.next_attempt_at(), None);
+    assert!(delivery.delivered_at().is_some());
+}
+
+#[test]
+fn test_record_failed_attempt_schedules_retry() {
+    let mut delivery = create_pending_delivery("note.updated");
+    let before = Utc::now();
+
+    delivery.record_attempt(WebhookAttemptOutcome::Response(503));
+
+    assert_eq!(delivery.status(), WebhookDeliveryStatus::Pending);
+    assert_eq!(delivery.attempts(), 1);
+    assert_eq!(delivery.response_status(), Some(503));
+    assert_eq!(
+        delivery.last_error(),
+        Some("Endpoint responded with status 503!")
+    );
+    assert!(delivery.next_attempt_at().unwrap() >= before + TimeDelta::seconds(30));
+    assert_eq!(delivery.delivered_at(), None);
+
+    delivery.record_attempt(WebhookAttemptOutcome::Error(
+        "Connection refused".to_string(),
+    ));
+
+    assert_eq!(delivery.attempts(), 2);
+    assert_eq!(delivery.response_status(), None);
+    assert_eq!(delivery.last_error(), Some("Connection refused"));
+}
+
+#[test]
+fn test_record_last_failed_attempt_gives_up() {
+    let mut delivery = create_pending_delivery("note.updated");
+
+    for _ in 0..WEBHOOK_DELIVERY_MAX_ATTEMPTS {
+        assert_eq!(delivery.status(), WebhookDeliveryStatus::Pending);
+        delivery.record_attempt(WebhookAttemptOutcome::Response(500));
+    }
+
+    assert_eq!(delivery.status(), WebhookDeliveryStatus::Failed);
+    assert_eq!(delivery.attempts(), WEBHOOK_DELIVERY_MAX_ATTEMPTS);
+    assert_eq!(delivery.next_attempt_at(), None);
+}
+
+#[test]
+fn test_record_failed_test_attempt_gives_up() {
+    let mut delivery = create_pending_delivery("webhook.test");
+
+    delivery.record_attempt(WebhookAttemptOutcome::Response(404));
+
+    assert_eq!(delivery.status(), WebhookDeliveryStatus::Failed);
+    assert_eq!(delivery.next_attempt_at(), None);
+}
+
+#[test]
+fn test_retry_delay_doubles() {
+    assert_eq!(retry_delay(0), None);
+    assert_eq!(retry_delay(1), Some(TimeDelta::seconds(30)));
+    assert_eq!(retry_delay(2), Some(TimeDelta::seconds(60)));
+    assert_eq!(retry_delay(7), Some(TimeDelta::seconds(30 * 64)));
+    assert_eq!(retry_delay(WEBHOOK_DELIVERY_MAX_ATTEMPTS), None);
+}
+
+fn create_pending_delivery(event_type: &str) -> WebhookDelivery {
+    WebhookDelivery::try_from(create_pending_delivery_data(event_type)).unwrap()
+}
+
+fn create_pending_delivery_data(event_type: &str) -> WebhookDeliveryData {
+    let created_at = Utc::now();
+    WebhookDeliveryData {
+        id: Uuid::new_v4(),
+        webhook_id: Uuid::new_v4(),
+        event_id: Uuid::new_v4(),
+        event_type: event_type.to_string(),
+        note_id: Some(Uuid::new_v4()),
+        status: "pending".to_string(),
+        attempts: 0,
+        response_status: None,
+        last_error: None,
+        next_attempt_at: Some(created_at),
+        created_at,
+        delivered_at: None,
+    }
+}
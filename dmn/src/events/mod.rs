@@ -0,0 +1,73 @@
+use uuid::Uuid;
+
+/// Fact about a state change, recorded in the same transaction as the change itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DomainEvent {
+    NoteCreated {
+        note_id: Uuid,
+        user_id: Uuid,
+    },
+    NoteUpdated {
+        note_id: Uuid,
+        user_id: Uuid,
+    },
+    /// The note was moved to the trash.
+    NoteDeleted {
+        note_id: Uuid,
+        user_id: Uuid,
+    },
+    UserRegistered {
+        user_id: Uuid,
+        email: String,
+    },
+    /// The user's account details (e.g. email) changed.
+    UserUpdated {
+        user_id: Uuid,
+    },
+    UserDeleted {
+        user_id: Uuid,
+    },
+    PasswordChanged {
+        user_id: Uuid,
+    },
+}
+
+impl DomainEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DomainEvent::NoteCreated { .. } => "NoteCreated",
+            DomainEvent::NoteUpdated { .. } => "NoteUpdated",
+            DomainEvent::NoteDeleted { .. } => "NoteDeleted",
+            DomainEvent::UserRegistered { .. } => "UserRegistered",
+            DomainEvent::UserUpdated { .. } => "UserUpdated",
+            DomainEvent::UserDeleted { .. } => "UserDeleted",
+            DomainEvent::PasswordChanged { .. } => "PasswordChanged",
+        }
+    }
+
+    /// User the event is about, or whose resource it is about.
+    pub fn user_id(&self) -> Uuid {
+        match self {
+            DomainEvent::NoteCreated { user_id, .. }
+            | DomainEvent::NoteUpdated { user_id, .. }
+            | DomainEvent::NoteDeleted { user_id, .. }
+            | DomainEvent::UserRegistered { user_id, .. }
+            | DomainEvent::UserUpdated { user_id }
+            | DomainEvent::UserDeleted { user_id }
+            | DomainEvent::PasswordChanged { user_id } => *user_id,
+        }
+    }
+
+    /// Note the event is about, if any.
+    pub fn note_id(&self) -> Option<Uuid> {
+        match self {
+            DomainEvent::NoteCreated { note_id, .. }
+            | DomainEvent::NoteUpdated { note_id, .. }
+            | DomainEvent::NoteDeleted { note_id, .. } => Some(*note_id),
+            DomainEvent::UserRegistered { .. }
+            | DomainEvent::UserUpdated { .. }
+            | DomainEvent::UserDeleted { .. }
+            | DomainEvent::PasswordChanged { .. } => None,
+        }
+    }
+}
@@ -1,3 +1,4 @@
 pub mod entities;
+pub mod events;
 pub mod repos;
 pub mod value_objects;
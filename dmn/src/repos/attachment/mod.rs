@@ -0,0 +1,49 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::entities::attachment::{Attachment, CreateAttachmentData};
+use common::{error::AppError, tx::ctx::TransactionContext};
+
+#[async_trait]
+pub trait AttachmentRepository: Send + Sync {
+    async fn get_attachment(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        attachment_id: Uuid,
+    ) -> Result<Attachment, AppError>;
+
+    /// Returns the note's attachments, oldest first.
+    async fn get_note_attachments(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+    ) -> Result<Vec<Attachment>, AppError>;
+
+    async fn create_attachment(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        attachment_data: CreateAttachmentData,
+    ) -> Result<Attachment, AppError>;
+
+    /// Deletes the attachment, queueing its blob for removal.
+    async fn delete_attachment(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        attachment_id: Uuid,
+    ) -> Result<(), AppError>;
+
+    /// Returns up to `limit` ids of deleted attachments whose blobs are still to be removed.
+    async fn get_orphaned_attachment_blobs(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        limit: u32,
+    ) -> Result<Vec<Uuid>, AppError>;
+
+    async fn delete_orphaned_attachment_blob(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        attachment_id: Uuid,
+    ) -> Result<(), AppError>;
+}
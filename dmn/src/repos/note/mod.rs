@@ -1,8 +1,13 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use crate::entities::note::{CreateNoteData, Note, UpdateNoteData};
-use common::{error::AppError, params::PaginationParams, tx::ctx::TransactionContext};
+use crate::entities::note::{CreateNoteData, Note, NoteFlags, PatchNoteData, UpdateNoteData};
+use common::{
+    error::AppError,
+    params::{PageCursor, Pagination, PaginationParams, SortDirection},
+    tx::ctx::TransactionContext,
+};
 
 #[async_trait]
 pub trait NoteRepository: Send + Sync {
@@ -13,11 +18,40 @@ pub trait NoteRepository: Send + Sync {
         user_id: Option<Uuid>,
     ) -> Result<Note, AppError>;
 
+    /// Locks the note until the end of the transaction, e.g. to keep it from changing between
+    /// reading and updating it.
+    async fn lock_note(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+    ) -> Result<(), AppError>;
+
+    /// Returns pinned notes first, then the others in the requested order.<br />
+    /// Cursor pagination ignores pinning and the sort field, ordering notes by `(created_at, id)`
+    /// in the requested direction.
     async fn get_notes(
         &self,
         ctx: Option<&mut dyn TransactionContext>,
+        pagination: &Pagination,
+        filter: &GetNotesFilter,
+        sort: &NoteSort,
+        user_id: Option<Uuid>,
+    ) -> Result<GetNotesResponse, AppError>;
+
+    async fn search_notes(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        query: &str,
         pagination: &PaginationParams,
         user_id: Option<Uuid>,
+    ) -> Result<SearchNotesResponse, AppError>;
+
+    /// Returns notes shared with the user by their owners, most recently shared first.
+    async fn get_shared_notes(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        pagination: &PaginationParams,
+        user_id: Uuid,
     ) -> Result<GetNotesResponse, AppError>;
 
     async fn create_note(
@@ -26,24 +60,248 @@ pub trait NoteRepository: Send + Sync {
         note_data: CreateNoteData,
     ) -> Result<Note, AppError>;
 
+    /// Fails with a precondition error unless the note is still at the `expected_version`, if any.
     async fn update_note(
         &self,
         ctx: Option<&mut dyn TransactionContext>,
         note_id: Uuid,
         note_data: UpdateNoteData,
+        expected_version: Option<u32>,
+        user_id: Option<Uuid>,
+    ) -> Result<Note, AppError>;
+
+    /// Only updates the fields supplied in `note_data`.<br />
+    /// Fails with a precondition error unless the note is still at the `expected_version`, if any.
+    async fn patch_note(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        note_data: PatchNoteData,
+        expected_version: Option<u32>,
+        user_id: Option<Uuid>,
+    ) -> Result<Note, AppError>;
+
+    /// Moves a note into a notebook, or back to the root level when `notebook_id` is `None`.
+    async fn move_note(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        notebook_id: Option<Uuid>,
+        user_id: Option<Uuid>,
+    ) -> Result<Note, AppError>;
+
+    /// Overwrites the note's organizational flags, leaving its contents untouched.
+    async fn update_note_flags(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        flags: NoteFlags,
         user_id: Option<Uuid>,
     ) -> Result<Note, AppError>;
 
+    /// Moves a note to the trash. Trashed notes are excluded from every other query.<br />
+    /// Fails with a precondition error unless the note is still at the `expected_version`, if any.
     async fn delete_note(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        expected_version: Option<u32>,
+        user_id: Option<Uuid>,
+    ) -> Result<(), AppError>;
+
+    /// Returns trashed notes, most recently trashed first.
+    async fn get_trashed_notes(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        pagination: &PaginationParams,
+        user_id: Option<Uuid>,
+    ) -> Result<GetNotesResponse, AppError>;
+
+    async fn restore_note(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<Note, AppError>;
+
+    /// Permanently deletes a trashed note.
+    async fn purge_note(
         &self,
         ctx: Option<&mut dyn TransactionContext>,
         note_id: Uuid,
         user_id: Option<Uuid>,
     ) -> Result<(), AppError>;
+
+    /// Permanently deletes notes trashed before `trashed_before`, returning their count.
+    async fn purge_trashed_notes(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        trashed_before: DateTime<Utc>,
+        user_id: Option<Uuid>,
+    ) -> Result<u32, AppError>;
+
+    /// Returns up to `limit` of the user's notes changed since the `since` token, or all of them
+    /// without it.<br />
+    /// Trashed and purged notes are reported as tombstones, counting towards the limit too. Changes
+    /// may be reported more than once.
+    async fn get_note_changes(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        from: SyncFrom,
+        limit: u32,
+        user_id: Uuid,
+    ) -> Result<NoteChanges, AppError>;
+}
+
+/// Optional criteria narrowing down [`NoteRepository::get_notes`] results.
+#[derive(Debug, Default)]
+pub struct GetNotesFilter {
+    pub tags: Option<TagFilter>,
+    pub notebook: Option<NotebookFilter>,
+    pub archived: ArchivedFilter,
+    /// Matches notes whose favorite state is the specified one.
+    pub favorite: Option<bool>,
+    pub created: DateRangeFilter,
+    pub updated: DateRangeFilter,
+    /// Matches notes whose title starts with the specified text, ignoring case.
+    pub title_prefix: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DateRangeFilter {
+    /// Inclusive lower bound.
+    pub from: Option<DateTime<Utc>>,
+    /// Exclusive upper bound.
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ArchivedFilter {
+    /// Leaves archived notes out.
+    #[default]
+    Exclude,
+    Include,
+    /// Matches archived notes only.
+    Only,
+}
+
+#[derive(Debug, Clone)]
+pub enum TagFilter {
+    /// Matches notes carrying at least one of the specified tags.
+    AnyOf(Vec<Uuid>),
+    /// Matches notes carrying every one of the specified tags.
+    AllOf(Vec<Uuid>),
+}
+
+impl TagFilter {
+    pub fn tag_ids(&self) -> &[Uuid] {
+        match self {
+            TagFilter::AnyOf(tag_ids) | TagFilter::AllOf(tag_ids) => tag_ids,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NotebookFilter {
+    pub notebook_id: Uuid,
+    /// Also matches notes of every descendant notebook.
+    pub recursive: bool,
+}
+
+/// Ordering of [`NoteRepository::get_notes`] results, ties being broken by creation date.
+#[derive(Debug, Clone, Copy)]
+pub struct NoteSort {
+    pub field: NoteSortField,
+    pub direction: SortDirection,
+}
+
+impl Default for NoteSort {
+    fn default() -> Self {
+        Self {
+            field: NoteSortField::CreatedAt,
+            direction: SortDirection::Desc,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteSortField {
+    CreatedAt,
+    UpdatedAt,
+    /// Case insensitive.
+    Title,
 }
 
 #[derive(Debug)]
 pub struct GetNotesResponse {
     pub notes: Vec<Note>,
     pub count: u32,
+    /// Position of the next page with cursor pagination, `None` on the last page.
+    pub next_cursor: Option<PageCursor>,
+}
+
+#[derive(Debug)]
+pub struct SearchNotesResponse {
+    pub results: Vec<NoteSearchResult>,
+    pub count: u32,
+}
+
+/// A [`Note`] full-text search match, ranked by relevance.
+#[derive(Debug)]
+pub struct NoteSearchResult {
+    pub note: Note,
+    pub rank: f32,
+    /// HTML-escaped title excerpt with matching terms highlighted.
+    pub title_snippet: String,
+    /// HTML-escaped content excerpt with matching terms highlighted.
+    pub content_snippet: String,
+}
+
+/// Position in the note change history, handed out to syncing clients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncToken(pub u64);
+
+/// Where [`NoteRepository::get_note_changes`] starts reading changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncFrom {
+    /// First page of a sync, `None` for a full sync.
+    Start(Option<SyncToken>),
+    /// Following page of a sync.
+    Continuation(SyncContinuation),
+}
+
+/// Where to resume a sync spanning several pages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncContinuation {
+    /// `since` of the first page.
+    pub since: Option<SyncToken>,
+    /// Token taken by the first page, reported by the following ones as is.
+    pub next_token: SyncToken,
+    /// Last change returned so far.
+    pub after: SyncPosition,
+}
+
+/// Position of a change within a sync, changes being ordered by transaction and then note id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SyncPosition {
+    pub change_xid: u64,
+    pub note_id: Uuid,
+}
+
+#[derive(Debug)]
+pub struct NoteChanges {
+    /// Created, updated and restored notes.
+    pub notes: Vec<Note>,
+    /// Trashed and purged notes.
+    pub tombstones: Vec<NoteTombstone>,
+    /// Token to pass as `since` for the next sync, once every page has been read.
+    pub next_token: SyncToken,
+    /// Where to resume reading the changes, `None` on the last page.
+    pub continuation: Option<SyncContinuation>,
+}
+
+#[derive(Debug)]
+pub struct NoteTombstone {
+    pub note_id: Uuid,
+    pub deleted_at: DateTime<Utc>,
 }
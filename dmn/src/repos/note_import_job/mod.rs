@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use chrono::TimeDelta;
+use uuid::Uuid;
+
+use crate::entities::note_import_job::{
+    CreateNoteImportJobData, NoteImportJob, UpdateNoteImportJobData,
+};
+use common::{error::AppError, tx::ctx::TransactionContext};
+
+#[async_trait]
+pub trait NoteImportJobRepository: Send + Sync {
+    /// If `user_id` is provided, only returns the job if it belongs to that user.
+    async fn get_note_import_job(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        job_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<NoteImportJob, AppError>;
+
+    async fn create_note_import_job(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        job_data: CreateNoteImportJobData,
+    ) -> Result<NoteImportJob, AppError>;
+
+    /// Marks the oldest pending job, or running job whose lease expired, as running for `lease`
+    /// and returns it, counting the attempt.
+    async fn claim_note_import_job(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        lease: TimeDelta,
+    ) -> Result<Option<NoteImportJob>, AppError>;
+
+    async fn update_note_import_job(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        job_id: Uuid,
+        job_data: UpdateNoteImportJobData,
+    ) -> Result<NoteImportJob, AppError>;
+}
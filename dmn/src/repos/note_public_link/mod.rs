@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::entities::note_public_link::{CreateNotePublicLinkData, NotePublicLink};
+use common::{error::AppError, tx::ctx::TransactionContext};
+
+#[async_trait]
+pub trait NotePublicLinkRepository: Send + Sync {
+    async fn get_note_public_link(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+    ) -> Result<NotePublicLink, AppError>;
+
+    /// Looks a link up by its token, regardless of its expiry.
+    async fn get_note_public_link_by_token(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        token: &str,
+    ) -> Result<NotePublicLink, AppError>;
+
+    /// Creates the note's public link, replacing the previous one (and its token) if any.
+    async fn create_note_public_link(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        link_data: CreateNotePublicLinkData,
+    ) -> Result<NotePublicLink, AppError>;
+
+    async fn delete_note_public_link(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+    ) -> Result<(), AppError>;
+}
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::entities::note_revision::{CreateNoteRevisionData, NoteRevision};
+use common::{error::AppError, params::PaginationParams, tx::ctx::TransactionContext};
+
+#[async_trait]
+pub trait NoteRevisionRepository: Send + Sync {
+    async fn get_note_revision(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        revision: u32,
+    ) -> Result<NoteRevision, AppError>;
+
+    /// Returns the note's revisions, newest first.
+    async fn get_note_revisions(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        pagination: &PaginationParams,
+    ) -> Result<GetNoteRevisionsResponse, AppError>;
+
+    /// Persists a snapshot under the note's next revision number.
+    async fn create_note_revision(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        revision_data: CreateNoteRevisionData,
+    ) -> Result<NoteRevision, AppError>;
+}
+
+#[derive(Debug)]
+pub struct GetNoteRevisionsResponse {
+    pub revisions: Vec<NoteRevision>,
+    pub count: u32,
+}
@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::entities::note_share::{CreateNoteShareData, NoteAccess, NoteShare, NoteShareRole};
+use common::{error::AppError, tx::ctx::TransactionContext};
+
+#[async_trait]
+pub trait NoteShareRepository: Send + Sync {
+    /// Resolves the user's access over a note.<br />
+    /// Fails as not found when the note doesn't exist, is trashed or isn't accessible to the user,
+    /// so that inaccessible notes can't be told apart from missing ones.
+    async fn get_note_access(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<NoteAccess, AppError>;
+
+    /// Returns the note's collaborators, oldest share first.
+    async fn get_note_shares(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+    ) -> Result<Vec<NoteShare>, AppError>;
+
+    async fn create_note_share(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        share_data: CreateNoteShareData,
+    ) -> Result<NoteShare, AppError>;
+
+    async fn update_note_share(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        user_id: Uuid,
+        role: NoteShareRole,
+    ) -> Result<NoteShare, AppError>;
+
+    async fn delete_note_share(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), AppError>;
+}
@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::entities::notebook::{CreateNotebookData, Notebook, UpdateNotebookData};
+use common::{error::AppError, tx::ctx::TransactionContext};
+
+#[async_trait]
+pub trait NotebookRepository: Send + Sync {
+    async fn get_notebook(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        notebook_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<Notebook, AppError>;
+
+    /// Returns the flat list of notebooks, parents are referenced by id.
+    async fn get_notebooks(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        user_id: Option<Uuid>,
+    ) -> Result<Vec<Notebook>, AppError>;
+
+    /// Returns the ids from the notebook itself up to its root notebook.
+    async fn get_notebook_path(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        notebook_id: Uuid,
+    ) -> Result<Vec<Uuid>, AppError>;
+
+    /// Locks the notebooks until the end of the transaction, e.g. to keep their hierarchy from
+    /// changing meanwhile.
+    async fn lock_notebooks(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        notebook_ids: &[Uuid],
+    ) -> Result<(), AppError>;
+
+    async fn create_notebook(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        notebook_data: CreateNotebookData,
+    ) -> Result<Notebook, AppError>;
+
+    async fn update_notebook(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        notebook_id: Uuid,
+        notebook_data: UpdateNotebookData,
+        user_id: Option<Uuid>,
+    ) -> Result<Notebook, AppError>;
+
+    /// Deletes the notebook along with its descendants, contained notes are moved to the root level.
+    async fn delete_notebook(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        notebook_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<(), AppError>;
+}
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+
+use crate::events::DomainEvent;
+use common::{error::AppError, tx::ctx::TransactionContext};
+
+/// Durable queue of [`DomainEvent`]s awaiting delivery to their handlers.
+#[async_trait]
+pub trait OutboxRepository: Send + Sync {
+    /// Should run in the transaction of the state change the events describe.
+    async fn append_events(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        events: &[DomainEvent],
+    ) -> Result<(), AppError>;
+}
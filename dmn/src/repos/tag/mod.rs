@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::entities::tag::{CreateTagData, Tag, UpdateTagData};
+use common::{error::AppError, tx::ctx::TransactionContext};
+
+#[async_trait]
+pub trait TagRepository: Send + Sync {
+    async fn get_tag(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        tag_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<Tag, AppError>;
+
+    async fn get_tags(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        user_id: Option<Uuid>,
+    ) -> Result<Vec<Tag>, AppError>;
+
+    async fn create_tag(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        tag_data: CreateTagData,
+    ) -> Result<Tag, AppError>;
+
+    async fn update_tag(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        tag_id: Uuid,
+        tag_data: UpdateTagData,
+        user_id: Option<Uuid>,
+    ) -> Result<Tag, AppError>;
+
+    async fn delete_tag(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        tag_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<(), AppError>;
+
+    /// Idempotently associates a tag with a note.
+    async fn attach_note_tag(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        tag_id: Uuid,
+    ) -> Result<(), AppError>;
+
+    async fn detach_note_tag(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        tag_id: Uuid,
+    ) -> Result<(), AppError>;
+}
@@ -1,7 +1,11 @@
 use async_trait::async_trait;
 
 use crate::entities::user::{CreateUserData, UniqueUserIdentifier, UpdateUserData, User};
-use common::{error::AppError, params::PaginationParams, tx::ctx::TransactionContext};
+use common::{
+    error::AppError,
+    params::{PageCursor, Pagination},
+    tx::ctx::TransactionContext,
+};
 
 #[async_trait]
 pub trait UserRepository: Send + Sync {
@@ -11,10 +15,11 @@ pub trait UserRepository: Send + Sync {
         user_id: &UniqueUserIdentifier,
     ) -> Result<User, AppError>;
 
+    /// Returns users ordered by id, or by `(created_at, id)` with cursor pagination.
     async fn get_users(
         &self,
         ctx: Option<&mut dyn TransactionContext>,
-        pagination: &PaginationParams,
+        pagination: &Pagination,
     ) -> Result<GetUsersResponse, AppError>;
 
     async fn create_user(
@@ -47,4 +52,6 @@ pub trait UserRepository: Send + Sync {
 pub struct GetUsersResponse {
     pub users: Vec<User>,
     pub count: u32,
+    /// Position of the next page with cursor pagination, `None` on the last page.
+    pub next_cursor: Option<PageCursor>,
 }
@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::entities::webhook::{CreateWebhookData, UpdateWebhookData, Webhook};
+use common::{error::AppError, tx::ctx::TransactionContext};
+
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    async fn get_webhook(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        webhook_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<Webhook, AppError>;
+
+    /// Returns the webhooks, oldest first.
+    async fn get_webhooks(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        user_id: Option<Uuid>,
+    ) -> Result<Vec<Webhook>, AppError>;
+
+    async fn create_webhook(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        webhook_data: CreateWebhookData,
+    ) -> Result<Webhook, AppError>;
+
+    async fn update_webhook(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        webhook_id: Uuid,
+        webhook_data: UpdateWebhookData,
+        user_id: Option<Uuid>,
+    ) -> Result<Webhook, AppError>;
+
+    /// Deletes the webhook along with its delivery log.
+    async fn delete_webhook(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        webhook_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<(), AppError>;
+}
@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use chrono::TimeDelta;
+use uuid::Uuid;
+
+use crate::entities::webhook_delivery::{
+    CreateWebhookDeliveryData, UpdateWebhookDeliveryData, WebhookDelivery,
+};
+use common::{error::AppError, params::PaginationParams, tx::ctx::TransactionContext};
+
+#[async_trait]
+pub trait WebhookDeliveryRepository: Send + Sync {
+    /// Returns the webhook's deliveries, newest first.
+    async fn get_webhook_deliveries(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        webhook_id: Uuid,
+        pagination: &PaginationParams,
+    ) -> Result<GetWebhookDeliveriesResponse, AppError>;
+
+    /// Returns `None` when the event already has a delivery to the webhook.
+    async fn create_webhook_delivery(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        delivery_data: CreateWebhookDeliveryData,
+    ) -> Result<Option<WebhookDelivery>, AppError>;
+
+    /// Returns the oldest pending deliveries that are due, postponing their next attempt by
+    /// `lease` so that they aren't claimed again while being sent.
+    async fn claim_due_webhook_deliveries(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        limit: u32,
+        lease: TimeDelta,
+    ) -> Result<Vec<WebhookDelivery>, AppError>;
+
+    async fn update_webhook_delivery(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        delivery_id: Uuid,
+        delivery_data: UpdateWebhookDeliveryData,
+    ) -> Result<WebhookDelivery, AppError>;
+}
+
+#[derive(Debug)]
+pub struct GetWebhookDeliveriesResponse {
+    pub deliveries: Vec<WebhookDelivery>,
+    pub count: u32,
+}
@@ -1 +1,2 @@
 pub mod email;
+pub mod text_diff;
@@ -0,0 +1,72 @@
+use similar::{ChangeTag, TextDiff as SimilarTextDiff};
+use std::borrow::Cow;
+
+/// Line-based diff between an old and a new text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextDiff(Vec<DiffLine>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    /// Line content without its trailing line break.
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLineKind {
+    /// Line present in both texts.
+    Unchanged,
+    /// Line only present in the new text.
+    Added,
+    /// Line only present in the old text.
+    Removed,
+}
+
+impl TextDiff {
+    pub fn from_lines(old: &str, new: &str) -> Self {
+        // A missing final line break must not make the last line differ
+        let (old, new) = (with_final_line_break(old), with_final_line_break(new));
+        let lines = SimilarTextDiff::from_lines(&old, &new)
+            .iter_all_changes()
+            .map(|change| DiffLine {
+                kind: match change.tag() {
+                    ChangeTag::Equal => DiffLineKind::Unchanged,
+                    ChangeTag::Insert => DiffLineKind::Added,
+                    ChangeTag::Delete => DiffLineKind::Removed,
+                },
+                content: change.value().trim_end_matches(['\n', '\r']).to_string(),
+            })
+            .collect();
+        Self(lines)
+    }
+
+    pub fn lines(&self) -> &[DiffLine] {
+        &self.0
+    }
+
+    pub fn has_changes(&self) -> bool {
+        self.0
+            .iter()
+            .any(|line| line.kind != DiffLineKind::Unchanged)
+    }
+}
+
+fn with_final_line_break(text: &str) -> Cow<'_, str> {
+    if text.is_empty() || text.ends_with('\n') {
+        Cow::Borrowed(text)
+    } else {
+        Cow::Owned(format!("{text}\n"))
+    }
+}
+
+impl IntoIterator for TextDiff {
+    type Item = DiffLine;
+    type IntoIter = std::vec::IntoIter<DiffLine>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,79 @@
+use super::*;
+
+#[test]
+fn test_identical_texts_have_no_changes() {
+    let diff = TextDiff::from_lines("first\nsecond", "first\nsecond");
+
+    assert!(!diff.has_changes());
+    assert_eq!(diff.lines().len(), 2);
+    assert!(diff
+        .lines()
+        .iter()
+        .all(|line| line.kind == DiffLineKind::Unchanged));
+}
+
+#[test]
+fn test_changed_line_is_removed_and_added() {
+    let diff = TextDiff::from_lines("first\nsecond\nthird", "first\n2nd\nthird");
+
+    assert!(diff.has_changes());
+    let lines: Vec<(DiffLineKind, &str)> = diff
+        .lines()
+        .iter()
+        .map(|line| (line.kind, line.content.as_str()))
+        .collect();
+    assert_eq!(
+        lines,
+        vec![
+            (DiffLineKind::Unchanged, "first"),
+            (DiffLineKind::Removed, "second"),
+            (DiffLineKind::Added, "2nd"),
+            (DiffLineKind::Unchanged, "third"),
+        ]
+    );
+}
+
+#[test]
+fn test_appended_line_keeps_previous_last_line_unchanged() {
+    let diff = TextDiff::from_lines("a\nb", "a\nb\nc");
+
+    let lines: Vec<(DiffLineKind, &str)> = diff
+        .lines()
+        .iter()
+        .map(|line| (line.kind, line.content.as_str()))
+        .collect();
+    assert_eq!(
+        lines,
+        vec![
+            (DiffLineKind::Unchanged, "a"),
+            (DiffLineKind::Unchanged, "b"),
+            (DiffLineKind::Added, "c"),
+        ]
+    );
+}
+
+#[test]
+fn test_line_breaks_are_stripped() {
+    let diff = TextDiff::from_lines("a\r\n", "a\r\nb\n");
+
+    let contents: Vec<&str> = diff
+        .lines()
+        .iter()
+        .map(|line| line.content.as_str())
+        .collect();
+    assert_eq!(contents, vec!["a", "b"]);
+    assert_eq!(diff.lines()[1].kind, DiffLineKind::Added);
+}
+
+#[test]
+fn test_diff_from_empty_text() {
+    let diff = TextDiff::from_lines("", "new line");
+
+    assert_eq!(
+        diff.lines(),
+        &[DiffLine {
+            kind: DiffLineKind::Added,
+            content: "new line".to_string(),
+        }]
+    );
+}
@@ -0,0 +1,350 @@
+//! Evernote `.enex` exports, holding notes written in ENML (an XHTML subset):
+//!
+//! ```text
+//! <en-export>
+//!     <note>
+//!         <title>Groceries</title>
+//!         <content><![CDATA[<en-note><div>Note content.</div></en-note>]]></content>
+//!         <created>20250101T100000Z</created>
+//!         <updated>20250102T100000Z</updated>
+//!         <tag>home</tag>
+//!     </note>
+//! </en-export>
+//! ```
+//!
+//! Note contents are converted to Markdown, embedded resources (`<en-media>`) being left out.
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use roxmltree::{Document, Node, ParsingOptions};
+
+use app::archive::{note_archive::ArchivedNote, note_import::NoteImportEntry};
+use common::error::AppError;
+
+/// Upper bound on the number of XML nodes parsed out of an export or note.
+const XML_NODES_LIMIT: u32 = 5_000_000;
+/// Timestamp format used by Evernote, always in UTC.
+const ENEX_DATE_TIME_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+/// HTML entities Evernote may write, which XML parsers don't know of without the ENML DTD.
+const HTML_ENTITIES: [(&str, &str); 12] = [
+    ("&nbsp;", "&#160;"),
+    ("&ndash;", "&#8211;"),
+    ("&mdash;", "&#8212;"),
+    ("&hellip;", "&#8230;"),
+    ("&lsquo;", "&#8216;"),
+    ("&rsquo;", "&#8217;"),
+    ("&ldquo;", "&#8220;"),
+    ("&rdquo;", "&#8221;"),
+    ("&bull;", "&#8226;"),
+    ("&copy;", "&#169;"),
+    ("&reg;", "&#174;"),
+    ("&trade;", "&#8482;"),
+];
+
+pub(crate) fn read_notes(data: &[u8]) -> Result<Vec<NoteImportEntry>, AppError> {
+    const INVALID_EXPORT_ERR_STR: &str = "Invalid Evernote export!";
+
+    let xml = std::str::from_utf8(data)
+        .map_err(|_| AppError::bad_request("Evernote export has to be UTF-8 encoded!"))?;
+    let document = parse_xml(xml).map_err(|err| {
+        AppError::bad_request_with_private(INVALID_EXPORT_ERR_STR, err.to_string())
+    })?;
+    let root = document.root_element();
+    if root.tag_name().name() != "en-export" {
+        return Err(AppError::bad_request(INVALID_EXPORT_ERR_STR));
+    }
+
+    let entries = root
+        .children()
+        .filter(|node| node.has_tag_name("note"))
+        .enumerate()
+        .map(|(index, note)| NoteImportEntry {
+            item: format!("Note #{}", index + 1),
+            note: read_note(note),
+        })
+        .collect();
+    Ok(entries)
+}
+
+fn read_note(note: Node) -> Result<ArchivedNote, AppError> {
+    let mut archived_note = ArchivedNote {
+        id: None,
+        title: String::new(),
+        content: String::new(),
+        tags: Vec::new(),
+        created_at: None,
+        updated_at: None,
+    };
+    for field in note.children().filter(|node| node.is_element()) {
+        let text = field.text().unwrap_or_default();
+        match field.tag_name().name() {
+            "title" => archived_note.title = text.to_string(),
+            "content" => archived_note.content = enml_to_markdown(text)?,
+            "tag" => archived_note.tags.push(text.to_string()),
+            "created" => archived_note.created_at = Some(parse_date_time("created", text)?),
+            "updated" => archived_note.updated_at = Some(parse_date_time("updated", text)?),
+            // Resources, note attributes, etc.
+            _ => {}
+        }
+    }
+    Ok(archived_note)
+}
+
+fn parse_date_time(field: &str, value: &str) -> Result<DateTime<Utc>, AppError> {
+    NaiveDateTime::parse_from_str(value.trim(), ENEX_DATE_TIME_FORMAT)
+        .map(|date_time| date_time.and_utc())
+        .map_err(|err| {
+            AppError::bad_request_with_private(
+                format!("Invalid note {field} timestamp!"),
+                err.to_string(),
+            )
+        })
+}
+
+fn parse_xml(xml: &str) -> Result<Document<'_>, roxmltree::Error> {
+    let options = ParsingOptions {
+        // Exports and note contents both declare a DOCTYPE
+        allow_dtd: true,
+        nodes_limit: XML_NODES_LIMIT,
+        ..ParsingOptions::default()
+    };
+    Document::parse_with_options(xml, options)
+}
+
+/// Converts the ENML contents of a note to Markdown.
+fn enml_to_markdown(enml: &str) -> Result<String, AppError> {
+    let enml = HTML_ENTITIES
+        .iter()
+        .fold(enml.to_string(), |enml, (entity, reference)| {
+            enml.replace(entity, reference)
+        });
+    let document = parse_xml(&enml).map_err(|err| {
+        AppError::bad_request_with_private("Invalid note content!", err.to_string())
+    })?;
+
+    let mut writer = MarkdownWriter::default();
+    writer.write_children(document.root_element());
+    Ok(writer.finish())
+}
+
+/// Renders ENML nodes as Markdown, blocks being separated by line breaks.
+#[derive(Default)]
+struct MarkdownWriter {
+    markdown: String,
+}
+
+impl MarkdownWriter {
+    fn finish(self) -> String {
+        // Drops trailing whitespace and collapses consecutive blank lines
+        let mut markdown = String::with_capacity(self.markdown.len());
+        let mut blank_line = true;
+        for line in self.markdown.lines() {
+            let line = line.trim_end();
+            if line.is_empty() {
+                if blank_line {
+                    continue;
+                }
+                blank_line = true;
+            } else {
+                blank_line = false;
+            }
+            markdown.push_str(line);
+            markdown.push('\n');
+        }
+        markdown.trim_end().to_string()
+    }
+
+    /// Renders the node on its own, e.g. for it to be wrapped or indented.
+    fn render(node: Node) -> String {
+        let mut writer = MarkdownWriter::default();
+        writer.write_children(node);
+        writer.finish()
+    }
+
+    fn write_children(&mut self, node: Node) {
+        for child in node.children() {
+            self.write_node(child);
+        }
+    }
+
+    fn write_node(&mut self, node: Node) {
+        if node.is_text() {
+            self.push_text(node.text().unwrap_or_default());
+            return;
+        }
+        if !node.is_element() {
+            return;
+        }
+
+        let tag_name = node.tag_name().name().to_ascii_lowercase();
+        match tag_name.as_str() {
+            "br" => self.markdown.push('\n'),
+            "hr" => self.push_block("---"),
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                let level = tag_name[1..].parse().unwrap_or(1);
+                let heading = MarkdownWriter::render(node).replace('\n', " ");
+                if !heading.is_empty() {
+                    self.push_block(&format!("{} {heading}", "#".repeat(level)));
+                }
+            }
+            "p" => {
+                self.start_block(true);
+                self.write_children(node);
+                self.start_block(true);
+            }
+            "b" | "strong" => self.push_wrapped(node, "**"),
+            "i" | "em" => self.push_wrapped(node, "*"),
+            "s" | "strike" | "del" => self.push_wrapped(node, "~~"),
+            "code" => self.push_wrapped(node, "`"),
+            "pre" => {
+                let code = node
+                    .descendants()
+                    .filter(|descendant| descendant.is_text())
+                    .filter_map(|descendant| descendant.text())
+                    .collect::<String>();
+                self.push_block(&format!("```\n{}\n```", code.trim_end()));
+            }
+            "a" => {
+                let text = MarkdownWriter::render(node);
+                match node.attribute("href") {
+                    Some(href) if !href.is_empty() && !text.is_empty() => {
+                        self.push_inline(&format!("[{text}]({href})"))
+                    }
+                    _ => self.push_inline(&text),
+                }
+            }
+            "img" => {
+                if let Some(src) = node.attribute("src") {
+                    let alt = node.attribute("alt").unwrap_or_default();
+                    self.push_inline(&format!("![{alt}]({src})"));
+                }
+            }
+            "en-todo" => {
+                let checked = node.attribute("checked") == Some("true");
+                self.push_inline(if checked { "[x] " } else { "[ ] " });
+            }
+            "ul" => self.push_list(node, false),
+            "ol" => self.push_list(node, true),
+            "blockquote" => {
+                let quote = MarkdownWriter::render(node)
+                    .lines()
+                    .map(|line| format!("> {line}").trim_end().to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                self.push_block(&quote);
+            }
+            "td" | "th" => {
+                self.write_children(node);
+                if node.next_sibling_element().is_some() {
+                    self.push_inline(" | ");
+                }
+            }
+            // Attachments, encrypted sections and non-content elements
+            "en-media" | "en-crypt" | "object" | "script" | "style" | "title" | "head" => {}
+            "div" | "section" | "article" | "header" | "footer" | "center" | "table" | "tr"
+            | "tbody" | "thead" | "tfoot" => {
+                self.start_block(false);
+                self.write_children(node);
+                self.start_block(false);
+            }
+            _ => self.write_children(node),
+        }
+    }
+
+    /// Appends text, collapsing whitespace as HTML renderers do.
+    fn push_text(&mut self, text: &str) {
+        let mut collapsed = String::with_capacity(text.len());
+        for c in text.chars() {
+            if !c.is_whitespace() {
+                collapsed.push(c);
+            } else if !collapsed.ends_with(' ') {
+                collapsed.push(' ');
+            }
+        }
+        let after_space = self.markdown.is_empty()
+            || self.markdown.ends_with('\n')
+            || self.markdown.ends_with(' ');
+        if after_space {
+            self.markdown.push_str(collapsed.trim_start());
+        } else {
+            self.markdown.push_str(&collapsed);
+        }
+    }
+
+    fn push_inline(&mut self, markdown: &str) {
+        self.markdown.push_str(markdown);
+    }
+
+    fn push_wrapped(&mut self, node: Node, marker: &str) {
+        let text = MarkdownWriter::render(node);
+        if !text.is_empty() {
+            self.push_inline(&format!("{marker}{text}{marker}"));
+        }
+    }
+
+    fn push_block(&mut self, markdown: &str) {
+        self.start_block(true);
+        self.markdown.push_str(markdown);
+        self.start_block(true);
+    }
+
+    /// Starts a new line, or a new paragraph when `blank_line` is set, unless already at one.
+    fn start_block(&mut self, blank_line: bool) {
+        if self.markdown.is_empty() {
+            return;
+        }
+        let line_breaks = if blank_line { 2 } else { 1 };
+        let trailing_breaks = self
+            .markdown
+            .chars()
+            .rev()
+            .take_while(|c| *c == '\n')
+            .count();
+        for _ in trailing_breaks..line_breaks {
+            self.markdown.push('\n');
+        }
+    }
+
+    /// Renders list items, nested lists being indented under the preceding item.
+    fn push_list(&mut self, node: Node, ordered: bool) {
+        self.start_block(true);
+        let mut position = 1;
+        for child in node.children().filter(|node| node.is_element()) {
+            let content = MarkdownWriter::render(child);
+            let marker = if !child.has_tag_name("li") {
+                String::new()
+            } else if ordered {
+                format!("{position}. ")
+            } else {
+                "- ".to_string()
+            };
+            let indent = if marker.is_empty() {
+                "  ".to_string()
+            } else {
+                " ".repeat(marker.len())
+            };
+            let item = content
+                .lines()
+                .enumerate()
+                .map(|(index, line)| match (index, line.is_empty()) {
+                    (_, true) => String::new(),
+                    (0, false) if !marker.is_empty() => format!("{marker}{line}"),
+                    _ => format!("{indent}{line}"),
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            if item.is_empty() && marker.is_empty() {
+                continue;
+            }
+            self.start_block(false);
+            self.markdown
+                .push_str(if item.is_empty() { &marker } else { &item });
+            if child.has_tag_name("li") {
+                position += 1;
+            }
+        }
+        self.start_block(true);
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,155 @@
+use super::*;
+
+fn markdown(enml_body: &str) -> String {
+    let enml = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><!DOCTYPE en-note SYSTEM "http://xml.evernote.com/pub/enml2.dtd"><en-note>{enml_body}</en-note>"#
+    );
+    enml_to_markdown(&enml).unwrap()
+}
+
+#[test]
+fn test_enml_to_markdown_separates_blocks() {
+    assert_eq!(
+        markdown("<div>First</div><div>Second</div><p>Third</p><p>Fourth</p>"),
+        "First\nSecond\n\nThird\n\nFourth"
+    );
+}
+
+#[test]
+fn test_enml_to_markdown_collapses_blank_lines_and_whitespace() {
+    assert_eq!(
+        markdown("<p>  One \n  two  </p><div><br/></div><div><br/></div><p></p><p>Three</p>"),
+        "One two\n\nThree"
+    );
+}
+
+#[test]
+fn test_enml_to_markdown_renders_headings_and_rules() {
+    assert_eq!(
+        markdown("<h1>Title</h1><h3>Sub <b>title</b></h3><hr/><div>Body</div>"),
+        "# Title\n\n### Sub **title**\n\n---\n\nBody"
+    );
+}
+
+#[test]
+fn test_enml_to_markdown_renders_inline_formatting() {
+    assert_eq!(
+        markdown("<div><b>bold</b> <i>italic</i> <s>gone</s> <code>x</code> <b></b>end</div>"),
+        "**bold** *italic* ~~gone~~ `x` end"
+    );
+}
+
+#[test]
+fn test_enml_to_markdown_renders_links_and_images() {
+    assert_eq!(
+        markdown(
+            r#"<div><a href="https://example.com">Example</a> <a href="">bare</a> <a href="https://example.com"></a><img src="https://example.com/a.png" alt="A"/></div>"#
+        ),
+        "[Example](https://example.com) bare ![A](https://example.com/a.png)"
+    );
+}
+
+#[test]
+fn test_enml_to_markdown_renders_nested_lists() {
+    assert_eq!(
+        markdown("<ul><li>One</li><li>Two<ol><li>Nested</li><li>Again</li></ol></li></ul><div>After</div>"),
+        "- One\n- Two\n\n  1. Nested\n  2. Again\n\nAfter"
+    );
+}
+
+#[test]
+fn test_enml_to_markdown_numbers_ordered_lists() {
+    assert_eq!(
+        markdown("<ol><li>A</li><li>B</li><li>C</li></ol>"),
+        "1. A\n2. B\n3. C"
+    );
+}
+
+#[test]
+fn test_enml_to_markdown_renders_todos_quotes_and_code() {
+    assert_eq!(
+        markdown(
+            r#"<div><en-todo checked="true"/>Done</div><div><en-todo/>Open</div><blockquote><div>Quoted</div><div>Lines</div></blockquote><pre>let x = 1;
+let y = 2;</pre>"#
+        ),
+        "[x] Done\n[ ] Open\n\n> Quoted\n> Lines\n\n```\nlet x = 1;\nlet y = 2;\n```"
+    );
+}
+
+#[test]
+fn test_enml_to_markdown_replaces_html_entities() {
+    // Non-breaking spaces are collapsed like any other whitespace
+    assert_eq!(
+        markdown("<div>A&nbsp;&nbsp;B &mdash; C&hellip; &ldquo;D&rdquo; &amp; &lt;E&gt;</div>"),
+        "A B \u{2014} C\u{2026} \u{201c}D\u{201d} & <E>"
+    );
+}
+
+#[test]
+fn test_enml_to_markdown_skips_media_and_encrypted_content() {
+    assert_eq!(
+        markdown(
+            r#"<div>Before<en-media type="image/png" hash="abc"/></div><en-crypt>secret</en-crypt><div>After</div>"#
+        ),
+        "Before\nAfter"
+    );
+}
+
+#[test]
+fn test_enml_to_markdown_renders_tables_row_by_row() {
+    assert_eq!(
+        markdown("<table><tr><td>a</td><td>b</td></tr><tr><td>c</td><td>d</td></tr></table>"),
+        "a | b\nc | d"
+    );
+}
+
+#[test]
+fn test_enml_to_markdown_rejects_malformed_content() {
+    assert!(enml_to_markdown("<en-note><div>unclosed</en-note>").is_err());
+}
+
+#[test]
+fn test_read_notes_reads_note_fields() {
+    let export = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE en-export SYSTEM "http://xml.evernote.com/pub/evernote-export3.dtd">
+<en-export>
+    <note>
+        <title>Groceries</title>
+        <content><![CDATA[<en-note><div>Milk</div></en-note>]]></content>
+        <created>20250101T100000Z</created>
+        <updated>20250102T100000Z</updated>
+        <tag>home</tag>
+        <tag>shopping</tag>
+    </note>
+    <note>
+        <title>Broken</title>
+        <created>yesterday</created>
+    </note>
+</en-export>"#;
+    let entries = read_notes(export.as_bytes()).unwrap();
+    assert_eq!(entries.len(), 2);
+
+    assert_eq!(entries[0].item, "Note #1");
+    let note = entries[0].note.as_ref().unwrap();
+    assert_eq!(note.title, "Groceries");
+    assert_eq!(note.content, "Milk");
+    assert_eq!(note.tags, ["home", "shopping"]);
+    assert_eq!(
+        note.created_at.unwrap().to_rfc3339(),
+        "2025-01-01T10:00:00+00:00"
+    );
+    assert_eq!(
+        note.updated_at.unwrap().to_rfc3339(),
+        "2025-01-02T10:00:00+00:00"
+    );
+
+    assert_eq!(entries[1].item, "Note #2");
+    assert!(entries[1].note.is_err());
+}
+
+#[test]
+fn test_read_notes_rejects_other_documents() {
+    assert!(read_notes(b"<notes/>").is_err());
+    assert!(read_notes(b"<en-export>").is_err());
+    assert!(read_notes(&[0xff, 0xfe]).is_err());
+}
@@ -0,0 +1,63 @@
+//! Generic JSON dumps, holding an array of notes:
+//!
+//! ```text
+//! [
+//!     {
+//!         "title": "Groceries",
+//!         "content": "Note content.",
+//!         "tags": ["home", "todo"],
+//!         "created_at": "2025-01-01T10:00:00Z",
+//!         "updated_at": "2025-01-02T10:00:00Z"
+//!     }
+//! ]
+//! ```
+//!
+//! Only `title` is required, other fields being ignored.
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use app::archive::{note_archive::ArchivedNote, note_import::NoteImportEntry};
+use common::error::AppError;
+
+#[derive(Deserialize)]
+struct JsonNote {
+    title: String,
+    #[serde(default)]
+    content: String,
+    #[serde(default)]
+    tags: Vec<String>,
+    created_at: Option<DateTime<Utc>>,
+    updated_at: Option<DateTime<Utc>>,
+}
+
+/// Reads the array of notes, each item being parsed on its own so that a malformed note
+/// only fails its own entry.
+pub(crate) fn read_notes(data: &[u8]) -> Result<Vec<NoteImportEntry>, AppError> {
+    let items = serde_json::from_slice::<Vec<serde_json::Value>>(data).map_err(|err| {
+        AppError::bad_request_with_private("Invalid JSON array of notes!", err.to_string())
+    })?;
+
+    let entries = items
+        .into_iter()
+        .enumerate()
+        .map(|(index, item)| NoteImportEntry {
+            item: format!("Note #{}", index + 1),
+            note: read_note(item),
+        })
+        .collect();
+    Ok(entries)
+}
+
+fn read_note(item: serde_json::Value) -> Result<ArchivedNote, AppError> {
+    let note = serde_json::from_value::<JsonNote>(item)
+        .map_err(|err| AppError::bad_request_with_private("Invalid note!", err.to_string()))?;
+    Ok(ArchivedNote {
+        id: None,
+        title: note.title,
+        content: note.content,
+        tags: note.tags,
+        created_at: note.created_at,
+        updated_at: note.updated_at,
+    })
+}
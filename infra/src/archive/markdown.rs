@@ -0,0 +1,169 @@
+//! Markdown note files, carrying the note metadata in a leading front matter block:
+//!
+//! ```text
+//! ---
+//! id: 0b8a5e1c-...
+//! title: "Groceries"
+//! tags: ["home", "todo"]
+//! created_at: 2025-01-01T10:00:00Z
+//! updated_at: 2025-01-02T10:00:00Z
+//! ---
+//!
+//! Note content.
+//! ```
+//!
+//! Values are written as JSON, which is valid YAML, and read back leniently so that files
+//! edited by hand or produced by other tools can be imported too.
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use app::archive::note_archive::ArchivedNote;
+use common::error::AppError;
+
+const FRONT_MATTER_DELIMITER: &str = "---";
+
+pub(crate) fn write_note(note: &ArchivedNote) -> String {
+    let mut file = String::with_capacity(note.content.len() + 256);
+    file.push_str(FRONT_MATTER_DELIMITER);
+    file.push('\n');
+    if let Some(id) = note.id {
+        file.push_str(&format!("id: {id}\n"));
+    }
+    file.push_str(&format!("title: {}\n", json_string(&note.title)));
+    let tags: Vec<String> = note.tags.iter().map(|tag| json_string(tag)).collect();
+    file.push_str(&format!("tags: [{}]\n", tags.join(", ")));
+    if let Some(created_at) = note.created_at {
+        file.push_str(&format!("created_at: {}\n", created_at.to_rfc3339()));
+    }
+    if let Some(updated_at) = note.updated_at {
+        file.push_str(&format!("updated_at: {}\n", updated_at.to_rfc3339()));
+    }
+    file.push_str(FRONT_MATTER_DELIMITER);
+    file.push_str("\n\n");
+    file.push_str(&note.content);
+    file.push('\n');
+    file
+}
+
+/// Parses a note file, falling back to `default_title` when the front matter has no title.<br />
+/// Files without front matter are imported as is.
+pub(crate) fn read_note(file: &str, default_title: &str) -> Result<ArchivedNote, AppError> {
+    let mut note = ArchivedNote {
+        id: None,
+        title: default_title.to_string(),
+        content: file.to_string(),
+        tags: Vec::new(),
+        created_at: None,
+        updated_at: None,
+    };
+    let Some((front_matter, content)) = split_front_matter(file) else {
+        return Ok(note);
+    };
+    note.content = content.to_string();
+
+    for line in front_matter.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once(':') else {
+            return Err(AppError::bad_request(format!(
+                "Invalid front matter line ({line})!"
+            )));
+        };
+        let value = value.trim();
+        match key.trim() {
+            "id" => note.id = Some(parse_value(key, value, Uuid::parse_str)?),
+            "title" => note.title = parse_string(value),
+            "tags" => note.tags = parse_list(value),
+            "created_at" => note.created_at = Some(parse_value(key, value, parse_date_time)?),
+            "updated_at" => note.updated_at = Some(parse_value(key, value, parse_date_time)?),
+            // Other tools' metadata
+            _ => {}
+        }
+    }
+    Ok(note)
+}
+
+/// Splits the file into its front matter and the content following it, if it has front matter.
+fn split_front_matter(file: &str) -> Option<(&str, &str)> {
+    let file = file.strip_prefix('\u{feff}').unwrap_or(file);
+    let rest = file.strip_prefix(FRONT_MATTER_DELIMITER)?;
+    let rest = rest
+        .strip_prefix('\n')
+        .or_else(|| rest.strip_prefix("\r\n"))?;
+
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == FRONT_MATTER_DELIMITER {
+            let front_matter = &rest[..offset];
+            let content = &rest[offset + line.len()..];
+            // Blank line separating the front matter from the content
+            let content = content
+                .strip_prefix("\r\n")
+                .or_else(|| content.strip_prefix('\n'))
+                .unwrap_or(content);
+            let content = content.strip_suffix('\n').unwrap_or(content);
+            return Some((front_matter, content.strip_suffix('\r').unwrap_or(content)));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+fn parse_value<T, E>(
+    key: &str,
+    value: &str,
+    parse: impl Fn(&str) -> Result<T, E>,
+) -> Result<T, AppError>
+where
+    E: ToString,
+{
+    parse(&parse_string(value)).map_err(|err| {
+        AppError::bad_request_with_private(
+            format!("Invalid front matter value for {}!", key.trim()),
+            err.to_string(),
+        )
+    })
+}
+
+/// Reads a quoted (JSON or YAML single quoted) or plain string.
+fn parse_string(value: &str) -> String {
+    if value.starts_with('"') {
+        if let Ok(value) = serde_json::from_str::<String>(value) {
+            return value;
+        }
+    }
+    if value.len() >= 2 && value.starts_with('\'') && value.ends_with('\'') {
+        return value[1..value.len() - 1].replace("''", "'");
+    }
+    value.to_string()
+}
+
+/// Reads a JSON array of strings, or a YAML flow sequence of plain or quoted strings.
+fn parse_list(value: &str) -> Vec<String> {
+    if let Ok(values) = serde_json::from_str::<Vec<String>>(value) {
+        return values;
+    }
+    let value = value
+        .strip_prefix('[')
+        .and_then(|value| value.strip_suffix(']'))
+        .unwrap_or(value);
+    value
+        .split(',')
+        .map(|item| parse_string(item.trim()))
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+fn parse_date_time(value: &str) -> Result<DateTime<Utc>, chrono::ParseError> {
+    DateTime::parse_from_rfc3339(value).map(|date_time| date_time.with_timezone(&Utc))
+}
+
+fn json_string(value: &str) -> String {
+    serde_json::Value::String(value.to_string()).to_string()
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,168 @@
+use super::*;
+
+fn archived_note() -> ArchivedNote {
+    ArchivedNote {
+        id: Some(Uuid::new_v4()),
+        title: "Groceries: \"weekly\"".into(),
+        content: "- Milk\n- Eggs\n\n---\n\nAfter a rule".into(),
+        tags: vec!["home".into(), "to, do".into()],
+        created_at: Some(DateTime::from_timestamp(1_735_725_600, 0).unwrap()),
+        updated_at: Some(DateTime::from_timestamp(1_735_812_000, 0).unwrap()),
+    }
+}
+
+fn assert_same_note(actual: &ArchivedNote, expected: &ArchivedNote) {
+    assert_eq!(actual.id, expected.id);
+    assert_eq!(actual.title, expected.title);
+    assert_eq!(actual.content, expected.content);
+    assert_eq!(actual.tags, expected.tags);
+    assert_eq!(actual.created_at, expected.created_at);
+    assert_eq!(actual.updated_at, expected.updated_at);
+}
+
+#[test]
+fn test_write_note_round_trip() {
+    let note = archived_note();
+
+    let read = read_note(&write_note(&note), "Default").unwrap();
+
+    assert_same_note(&read, &note);
+}
+
+#[test]
+fn test_write_note_round_trip_without_metadata() {
+    let note = ArchivedNote {
+        id: None,
+        title: "Title".into(),
+        content: String::new(),
+        tags: Vec::new(),
+        created_at: None,
+        updated_at: None,
+    };
+
+    let read = read_note(&write_note(&note), "Default").unwrap();
+
+    assert_same_note(&read, &note);
+}
+
+#[test]
+fn test_read_note_without_front_matter() {
+    let file = "# Heading\n\nBody\n";
+
+    let note = read_note(file, "File name").unwrap();
+
+    assert_eq!(note.title, "File name");
+    assert_eq!(note.content, file);
+    assert!(note.tags.is_empty());
+    assert!(note.id.is_none());
+}
+
+#[test]
+fn test_read_note_defaults_missing_title() {
+    let note = read_note("---\ntags: [a]\n---\n\nBody", "File name").unwrap();
+
+    assert_eq!(note.title, "File name");
+    assert_eq!(note.tags, vec!["a"]);
+    assert_eq!(note.content, "Body");
+}
+
+#[test]
+fn test_read_note_ignores_comments_and_unknown_keys() {
+    let file = "---\n# comment\n\naliases: [x]\ntitle: Plain title\n---\nBody";
+
+    let note = read_note(file, "Default").unwrap();
+
+    assert_eq!(note.title, "Plain title");
+    assert_eq!(note.content, "Body");
+}
+
+#[test]
+fn test_read_note_rejects_invalid_front_matter() {
+    let invalid_files = vec![
+        "---\nnot a key value line\n---\n",
+        "---\nid: not-a-uuid\n---\n",
+        "---\ncreated_at: yesterday\n---\n",
+    ];
+
+    for file in invalid_files {
+        assert!(
+            read_note(file, "Default").is_err(),
+            "Accepted invalid file: {file:?}"
+        );
+    }
+}
+
+#[test]
+fn test_split_front_matter() {
+    assert_eq!(
+        split_front_matter("---\ntitle: A\n---\n\nBody\n"),
+        Some(("title: A\n", "Body"))
+    );
+    // Content right after the closing fence, without a blank line
+    assert_eq!(
+        split_front_matter("---\ntitle: A\n---\nBody"),
+        Some(("title: A\n", "Body"))
+    );
+    // Empty front matter and content
+    assert_eq!(split_front_matter("---\n---\n"), Some(("", "")));
+}
+
+#[test]
+fn test_split_front_matter_crlf() {
+    assert_eq!(
+        split_front_matter("---\r\ntitle: A\r\n---\r\n\r\nLine 1\r\nLine 2\r\n"),
+        Some(("title: A\r\n", "Line 1\r\nLine 2"))
+    );
+}
+
+#[test]
+fn test_split_front_matter_skips_bom() {
+    assert_eq!(
+        split_front_matter("\u{feff}---\ntitle: A\n---\nBody"),
+        Some(("title: A\n", "Body"))
+    );
+}
+
+#[test]
+fn test_split_front_matter_missing_fence() {
+    let files = vec![
+        "",
+        "Body",
+        "title: A\n---\nBody",
+        // Opening fence not on a line of its own
+        "--- title: A\n---\nBody",
+        "----\ntitle: A\n---\nBody",
+        // Opening fence not at the start of the file
+        "\n---\ntitle: A\n---\nBody",
+    ];
+
+    for file in files {
+        assert_eq!(split_front_matter(file), None, "Split file: {file:?}");
+    }
+}
+
+#[test]
+fn test_split_front_matter_unterminated_fence() {
+    assert_eq!(split_front_matter("---\ntitle: A\nBody\n"), None);
+    assert_eq!(split_front_matter("---\n"), None);
+}
+
+#[test]
+fn test_parse_string() {
+    assert_eq!(parse_string(r#""Quoted \"json\"""#), r#"Quoted "json""#);
+    assert_eq!(parse_string("'It''s single quoted'"), "It's single quoted");
+    assert_eq!(parse_string("Plain: text"), "Plain: text");
+    // Unterminated quotes are kept as is
+    assert_eq!(parse_string(r#""Unterminated"#), r#""Unterminated"#);
+    assert_eq!(parse_string("'"), "'");
+}
+
+#[test]
+fn test_parse_list() {
+    assert_eq!(parse_list(r#"["a", "b, c"]"#), vec!["a", "b, c"]);
+    assert_eq!(parse_list("[a, 'b', \"c\"]"), vec!["a", "b", "c"]);
+    assert_eq!(parse_list("a, b"), vec!["a", "b"]);
+    assert_eq!(parse_list("[a, , b,]"), vec!["a", "b"]);
+    assert!(parse_list("[]").is_empty());
+    assert!(parse_list("").is_empty());
+}
@@ -0,0 +1,5 @@
+mod enex;
+mod json;
+mod markdown;
+pub mod note_archive;
+pub mod note_import;
@@ -0,0 +1,233 @@
+use async_trait::async_trait;
+use async_zip::{
+    base::{read::mem::ZipFileReader, write::ZipFileWriter},
+    Compression, ZipEntryBuilder,
+};
+use futures_util::{io::AsyncReadExt, stream, StreamExt, TryStreamExt};
+use std::{collections::HashSet, path::Path};
+
+use super::markdown;
+use app::{
+    archive::note_archive::{
+        ArchiveEntry, ArchivedNote, ArchivedNoteStream, NoteArchiveCodec, NoteArchiveFormat,
+        NoteArchiveStream,
+    },
+    usecases::note::NOTE_IMPORT_MAX_SIZE,
+};
+use common::error::AppError;
+
+/// Upper bound on the size of a single note file read from an archive.
+const NOTE_FILE_MAX_SIZE: u64 = 10 * 1024 * 1024; // 10 MiB
+/// Upper bound on the total size of the files read from an archive, once decompressed.
+const ARCHIVE_MAX_DECOMPRESSED_SIZE: u64 = 4 * NOTE_IMPORT_MAX_SIZE as u64; // 200 MiB
+/// Upper bound on the number of files read from an archive.
+const ARCHIVE_MAX_ENTRIES: usize = 10_000;
+/// Longest file name stem given to exported notes, in characters.
+const FILE_STEM_MAX_LENGTH: usize = 100;
+
+/// [`NoteArchiveCodec`] producing and reading ZIP archives.
+pub struct ZipNoteArchiveCodec;
+
+#[async_trait]
+impl NoteArchiveCodec for ZipNoteArchiveCodec {
+    fn write_archive(
+        &self,
+        format: NoteArchiveFormat,
+        notes: ArchivedNoteStream,
+    ) -> NoteArchiveStream {
+        match format {
+            NoteArchiveFormat::Markdown => write_markdown_archive(notes),
+        }
+    }
+
+    async fn read_archive(
+        &self,
+        format: NoteArchiveFormat,
+        data: Vec<u8>,
+    ) -> Result<Vec<ArchiveEntry>, AppError> {
+        match format {
+            NoteArchiveFormat::Markdown => {
+                read_markdown_archive(data, ARCHIVE_MAX_DECOMPRESSED_SIZE).await
+            }
+        }
+    }
+}
+
+struct ArchiveWriteState {
+    /// `None` once the archive has been closed.
+    writer: Option<ZipFileWriter<Vec<u8>>>,
+    notes: ArchivedNoteStream,
+    file_names: HashSet<String>,
+}
+
+/// Writes one file per note, each chunk holding the archive bytes written for a single note.
+fn write_markdown_archive(notes: ArchivedNoteStream) -> NoteArchiveStream {
+    const INTERNAL_ERR_STR: &str = "Failed to write notes archive!";
+
+    let state = ArchiveWriteState {
+        writer: Some(ZipFileWriter::new(Vec::new())),
+        notes,
+        file_names: HashSet::new(),
+    };
+    stream::try_unfold(state, |mut state| async move {
+        let Some(mut writer) = state.writer.take() else {
+            return Ok(None);
+        };
+        let Some(note) = state.notes.try_next().await? else {
+            let chunk = writer.close().await.map_err(|err| {
+                AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string())
+            })?;
+            return Ok(Some((chunk, state)));
+        };
+
+        let file_name = unique_file_name(&mut state.file_names, &note.title, "md");
+        let mut entry = ZipEntryBuilder::new(file_name.into(), Compression::Deflate);
+        if let Some(updated_at) = note.updated_at {
+            entry = entry.last_modification_date(updated_at.into());
+        }
+        let file = markdown::write_note(&note);
+        writer
+            .write_entry_whole(entry, file.as_bytes())
+            .await
+            .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+        // The writer tracks offsets on its own, so written bytes can be handed out right away
+        let chunk = std::mem::take(writer.inner_mut());
+        state.writer = Some(writer);
+        Ok(Some((chunk, state)))
+    })
+    .boxed()
+}
+
+/// Reads the archive, failing once its files add up to more than `max_decompressed_size` bytes.
+async fn read_markdown_archive(
+    data: Vec<u8>,
+    max_decompressed_size: u64,
+) -> Result<Vec<ArchiveEntry>, AppError> {
+    const INVALID_ARCHIVE_ERR_STR: &str = "Invalid ZIP archive!";
+
+    let reader = ZipFileReader::new(data).await.map_err(|err| {
+        AppError::bad_request_with_private(INVALID_ARCHIVE_ERR_STR, err.to_string())
+    })?;
+    let file_count = reader.file().entries().len();
+    if file_count > ARCHIVE_MAX_ENTRIES {
+        return Err(AppError::bad_request(format!(
+            "Archive cannot hold more than {ARCHIVE_MAX_ENTRIES} files!"
+        )));
+    }
+
+    let mut entries = Vec::new();
+    let mut decompressed_size = 0;
+    for index in 0..file_count {
+        let entry = reader.file().entries()[index].clone();
+        let file_name = match entry.filename().as_str() {
+            Ok(file_name) => file_name.to_string(),
+            Err(_) => String::from_utf8_lossy(entry.filename().as_bytes()).into_owned(),
+        };
+        if entry.dir().unwrap_or(false) || is_hidden(&file_name) {
+            continue;
+        }
+        let note = read_markdown_entry(
+            &reader,
+            index,
+            &file_name,
+            entry.uncompressed_size(),
+            &mut decompressed_size,
+        )
+        .await;
+        // Guards against archives decompressing to far more than their own size
+        if decompressed_size > max_decompressed_size {
+            return Err(AppError::bad_request(format!(
+                "Archive contents cannot exceed {max_decompressed_size} bytes once decompressed!"
+            )));
+        }
+        entries.push(ArchiveEntry { file_name, note });
+    }
+    Ok(entries)
+}
+
+async fn read_markdown_entry(
+    reader: &ZipFileReader,
+    index: usize,
+    file_name: &str,
+    size: u64,
+    decompressed_size: &mut u64,
+) -> Result<ArchivedNote, AppError> {
+    const INVALID_FILE_ERR_STR: &str = "Invalid archive file!";
+
+    let path = Path::new(file_name);
+    let is_markdown = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| {
+            extension.eq_ignore_ascii_case("md") || extension.eq_ignore_ascii_case("markdown")
+        });
+    if !is_markdown {
+        return Err(AppError::bad_request(
+            "Only Markdown files can be imported!",
+        ));
+    }
+    if size > NOTE_FILE_MAX_SIZE {
+        return Err(AppError::bad_request(format!(
+            "Note file cannot exceed {NOTE_FILE_MAX_SIZE} bytes!"
+        )));
+    }
+
+    let entry_reader = reader
+        .reader_without_entry(index)
+        .await
+        .map_err(|err| AppError::bad_request_with_private(INVALID_FILE_ERR_STR, err.to_string()))?;
+    // Declared sizes can't be trusted, so reading stops right past the limit
+    let mut data = Vec::new();
+    entry_reader
+        .take(NOTE_FILE_MAX_SIZE + 1)
+        .read_to_end(&mut data)
+        .await
+        .map_err(|err| AppError::bad_request_with_private(INVALID_FILE_ERR_STR, err.to_string()))?;
+    *decompressed_size += data.len() as u64;
+    if data.len() as u64 > NOTE_FILE_MAX_SIZE {
+        return Err(AppError::bad_request(format!(
+            "Note file cannot exceed {NOTE_FILE_MAX_SIZE} bytes!"
+        )));
+    }
+    let file = String::from_utf8(data)
+        .map_err(|_| AppError::bad_request("Note file has to be UTF-8 encoded!"))?;
+
+    let default_title = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default();
+    markdown::read_note(&file, default_title)
+}
+
+/// Builds a file name out of the note title, unique within the archive.
+fn unique_file_name(file_names: &mut HashSet<String>, title: &str, extension: &str) -> String {
+    let stem: String = title
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .take(FILE_STEM_MAX_LENGTH)
+        .collect();
+    let stem = stem.trim().trim_start_matches('.');
+    let stem = if stem.is_empty() { "Untitled" } else { stem };
+
+    let mut file_name = format!("{stem}.{extension}");
+    let mut suffix = 1;
+    while !file_names.insert(file_name.to_lowercase()) {
+        suffix += 1;
+        file_name = format!("{stem} ({suffix}).{extension}");
+    }
+    file_name
+}
+
+/// Skips files added by archiving tools, e.g. `__MACOSX/` resource forks and `.DS_Store`.
+fn is_hidden(file_name: &str) -> bool {
+    file_name
+        .split('/')
+        .any(|part| part.starts_with('.') || part == "__MACOSX")
+}
+
+#[cfg(test)]
+mod tests;
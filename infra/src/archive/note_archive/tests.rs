@@ -0,0 +1,141 @@
+use super::*;
+
+/// Builds a ZIP archive out of `(file name, contents)` pairs.
+async fn zip(files: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut writer = ZipFileWriter::new(Vec::new());
+    for (file_name, data) in files {
+        let entry = ZipEntryBuilder::new((*file_name).into(), Compression::Deflate);
+        writer.write_entry_whole(entry, data).await.unwrap();
+    }
+    writer.close().await.unwrap()
+}
+
+fn archived_note(title: &str) -> ArchivedNote {
+    ArchivedNote {
+        id: None,
+        title: title.into(),
+        content: format!("Content of {title}"),
+        tags: vec!["tag".into()],
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+#[tokio::test]
+async fn test_markdown_archive_round_trip() {
+    let notes = vec![
+        archived_note("First"),
+        archived_note("Second"),
+        archived_note("first"),
+    ];
+    let chunks: Vec<Vec<u8>> = write_markdown_archive(stream::iter(notes).map(Ok).boxed())
+        .try_collect()
+        .await
+        .unwrap();
+
+    let entries = read_markdown_archive(chunks.concat(), ARCHIVE_MAX_DECOMPRESSED_SIZE)
+        .await
+        .unwrap();
+
+    // File names are unique regardless of case
+    let file_names: Vec<_> = entries
+        .iter()
+        .map(|entry| entry.file_name.as_str())
+        .collect();
+    assert_eq!(file_names, vec!["First.md", "Second.md", "first (2).md"]);
+    let notes: Vec<_> = entries
+        .into_iter()
+        .map(|entry| entry.note.unwrap())
+        .collect();
+    let titles: Vec<_> = notes.iter().map(|note| note.title.as_str()).collect();
+    assert_eq!(titles, vec!["First", "Second", "first"]);
+    assert_eq!(notes[1].content, "Content of Second");
+    assert_eq!(notes[1].tags, vec!["tag"]);
+}
+
+#[tokio::test]
+async fn test_read_markdown_archive_skips_hidden_files_and_directories() {
+    let data = zip(&[
+        ("notes/", b""),
+        ("notes/.DS_Store", b"\0"),
+        ("__MACOSX/notes/._Note.md", b"\0"),
+        ("notes/Note.md", b"Body"),
+    ])
+    .await;
+
+    let entries = read_markdown_archive(data, ARCHIVE_MAX_DECOMPRESSED_SIZE)
+        .await
+        .unwrap();
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].file_name, "notes/Note.md");
+    let note = entries[0].note.as_ref().unwrap();
+    assert_eq!(note.title, "Note");
+    assert_eq!(note.content, "Body");
+}
+
+#[tokio::test]
+async fn test_read_markdown_archive_fails_invalid_files_on_their_own() {
+    let data = zip(&[
+        ("Image.png", b"\x89PNG"),
+        ("Latin1.md", b"caf\xe9"),
+        ("Valid.markdown", b"Body"),
+    ])
+    .await;
+
+    let entries = read_markdown_archive(data, ARCHIVE_MAX_DECOMPRESSED_SIZE)
+        .await
+        .unwrap();
+
+    assert_eq!(entries.len(), 3);
+    assert!(entries[0].note.is_err());
+    assert!(entries[1].note.is_err());
+    assert_eq!(entries[2].note.as_ref().unwrap().title, "Valid");
+}
+
+#[tokio::test]
+async fn test_read_markdown_archive_caps_decompressed_size() {
+    let content = vec![b'a'; 1024];
+    let data = zip(&[("First.md", &content), ("Second.md", &content)]).await;
+
+    // Highly compressible contents take up far less room in the archive itself
+    assert!((data.len() as u64) < 1024);
+    assert!(read_markdown_archive(data.clone(), 2048).await.is_ok());
+    assert!(read_markdown_archive(data, 2047).await.is_err());
+}
+
+#[tokio::test]
+async fn test_read_markdown_archive_rejects_invalid_archive() {
+    assert!(
+        read_markdown_archive(b"not a zip".to_vec(), ARCHIVE_MAX_DECOMPRESSED_SIZE)
+            .await
+            .is_err()
+    );
+}
+
+#[test]
+fn test_unique_file_name_sanitizes_titles() {
+    let mut file_names = HashSet::new();
+
+    assert_eq!(
+        unique_file_name(&mut file_names, "a/b:c?", "md"),
+        "a_b_c_.md"
+    );
+    assert_eq!(
+        unique_file_name(&mut file_names, " .hidden ", "md"),
+        "hidden.md"
+    );
+    assert_eq!(
+        unique_file_name(&mut file_names, "...", "md"),
+        "Untitled.md"
+    );
+    assert_eq!(
+        unique_file_name(&mut file_names, "", "md"),
+        "Untitled (2).md"
+    );
+    let long_title = "x".repeat(FILE_STEM_MAX_LENGTH + 10);
+    assert_eq!(
+        unique_file_name(&mut file_names, &long_title, "md"),
+        format!("{}.md", "x".repeat(FILE_STEM_MAX_LENGTH))
+    );
+}
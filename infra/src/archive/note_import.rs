@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+
+use super::{enex, json, note_archive::ZipNoteArchiveCodec};
+use app::archive::{
+    note_archive::{NoteArchiveCodec, NoteArchiveFormat},
+    note_import::{NoteImportEntry, NoteImportReader},
+};
+use common::error::AppError;
+use dmn::entities::note_import_job::NoteImportFormat;
+
+/// [`NoteImportReader`] reading Markdown ZIP archives, Evernote exports and JSON dumps.
+pub struct DefaultNoteImportReader;
+
+#[async_trait]
+impl NoteImportReader for DefaultNoteImportReader {
+    async fn read_notes(
+        &self,
+        format: NoteImportFormat,
+        data: Vec<u8>,
+    ) -> Result<Vec<NoteImportEntry>, AppError> {
+        match format {
+            NoteImportFormat::Markdown => {
+                let entries = ZipNoteArchiveCodec
+                    .read_archive(NoteArchiveFormat::Markdown, data)
+                    .await?;
+                Ok(entries
+                    .into_iter()
+                    .map(|entry| NoteImportEntry {
+                        item: entry.file_name,
+                        note: entry.note,
+                    })
+                    .collect())
+            }
+            NoteImportFormat::Enex => run_blocking(move || enex::read_notes(&data)).await,
+            NoteImportFormat::Json => run_blocking(move || json::read_notes(&data)).await,
+        }
+    }
+}
+
+/// Runs a parser on the blocking thread pool, as parsing large dumps would stall the runtime.
+async fn run_blocking<F>(parse: F) -> Result<Vec<NoteImportEntry>, AppError>
+where
+    F: FnOnce() -> Result<Vec<NoteImportEntry>, AppError> + Send + 'static,
+{
+    tokio::task::spawn_blocking(parse).await.map_err(|err| {
+        AppError::internal_with_private("Note import parser task failed!", err.to_string())
+    })?
+}
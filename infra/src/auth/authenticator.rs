@@ -11,8 +11,9 @@ use app::{
     },
     repos::auth_token::AuthTokenRepository,
     types::auth_token::{
-        AccessToken, AuthTokenPair, JsonWebTokenData, JsonWebTokenDataVariant, RefreshToken,
-        UniqueAccessTokenIdentifier, UniqueRefreshTokenIdentifier,
+        AccessToken, AuthSession, AuthTokenPair, AuthTokenSession, JsonWebTokenData,
+        JsonWebTokenDataVariant, RefreshToken, RevokedRefreshToken, UniqueAccessTokenIdentifier,
+        UniqueRefreshTokenIdentifier,
     },
 };
 use common::{
@@ -86,11 +87,14 @@ impl JwtAuthenticator {
                 }
                 JsonWebTokenDataVariant::RefreshToken => {
                     let token_id = UniqueRefreshTokenIdentifier::Jwt(token);
-                    let token = self
-                        .token_repo
-                        .get_refresh_token(None, &token_id)
-                        .await
-                        .map_err(|err| err.reword(UNAUTHORIZED_ERR_STR.to_string()))?;
+                    let token = match self.token_repo.get_refresh_token(None, &token_id).await {
+                        Ok(token) => token,
+                        // Validly signed yet not stored, i.e. either revoked or unknown
+                        Err(AppError::NotFound(_)) => {
+                            return Err(self.reject_missing_refresh_token(&token_data).await);
+                        }
+                        Err(err) => return Err(err.reword(UNAUTHORIZED_ERR_STR.to_string())),
+                    };
                     (token.user_id, token.expires_at, None, Some(token))
                 }
             };
@@ -129,6 +133,48 @@ impl JwtAuthenticator {
 
         Ok((user, db_access_token, db_refresh_token))
     }
+
+    /// Presenting a refresh token that was already rotated means either the legitimate client or an attacker holds a stolen copy.<br />
+    /// As there's no telling which, the whole token family gets revoked (OAuth 2.0 Security BCP, section 4.14).
+    async fn reject_missing_refresh_token(&self, token_data: &JsonWebTokenData) -> AppError {
+        const UNAUTHORIZED_ERR_STR: &str = "Failed to authenticate user!";
+
+        let revoked_token = match self
+            .token_repo
+            .get_revoked_refresh_token(None, token_data.id)
+            .await
+        {
+            Ok(revoked_token) if revoked_token.user_id == token_data.user_id => revoked_token,
+            Ok(_) | Err(AppError::NotFound(_)) => {
+                return AppError::unauthorized_with_private(
+                    UNAUTHORIZED_ERR_STR,
+                    format!("Refresh token ({}) doesn't exist!", token_data.id),
+                );
+            }
+            Err(err) => return err.reword(UNAUTHORIZED_ERR_STR.to_string()),
+        };
+
+        let revoke_result = self
+            .token_repo
+            .delete_token_family(None, revoked_token.family_id)
+            .await;
+        log::error!(
+            "Security event: refresh token ({}) of user ({}), revoked at {}, was reused! Revoking token family ({}): {}",
+            revoked_token.id,
+            revoked_token.user_id,
+            revoked_token.revoked_at,
+            revoked_token.family_id,
+            match revoke_result {
+                Ok(deleted_count) => format!("{deleted_count} session(s) revoked"),
+                Err(err) => format!("failed to revoke sessions ({err})"),
+            },
+        );
+
+        AppError::unauthorized_with_private(
+            UNAUTHORIZED_ERR_STR,
+            format!("Revoked refresh token ({}) reused!", revoked_token.id),
+        )
+    }
 }
 
 #[async_trait]
@@ -136,9 +182,10 @@ impl Authenticator for JwtAuthenticator {
     async fn generate_auth_tokens(
         &self,
         user_id: Uuid,
-        revoke_token_pair_id: Option<UniqueAccessTokenIdentifier>,
+        session: AuthTokenSession,
     ) -> Result<AuthTokenPair, AppError> {
         const INTERNAL_ERR_STR: &str = "Failed to generate auth tokens!";
+        const UNAUTHORIZED_ERR_STR: &str = "Failed to authenticate user!";
 
         // Generate JWTs
         let access_token = JsonWebTokenData::new_access(user_id, self.access_token_duration);
@@ -151,26 +198,119 @@ impl Authenticator for JwtAuthenticator {
             .token_adapter
             .encode(refresh_token.clone())
             .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
-        let access_token = AccessToken {
-            id: access_token.id,
-            user_id: access_token.user_id,
-            jwt: access_token_jwt,
-            expires_at: access_token.expires_at,
-        };
-        let refresh_token = RefreshToken {
-            id: refresh_token.id,
-            user_id: refresh_token.user_id,
-            access_token_id: access_token.id,
-            jwt: refresh_token_jwt,
-            expires_at: refresh_token.expires_at,
-        };
 
         // Persist Tokens
         let token_repo = self.token_repo.clone();
         let result = self
             .unit_of_work
-            .run_in_transaction(Box::new(|ctx| {
+            .run_in_transaction(Box::new(move |ctx| {
                 Box::pin(async move {
+                    // Start or continue the session and token family the tokens belong to
+                    let (session_id, family_id) = match session {
+                        AuthTokenSession::Start(client_info) => {
+                            let now = Utc::now();
+                            let session = AuthSession {
+                                id: Uuid::new_v4(),
+                                user_id,
+                                ip_address: client_info.ip_address,
+                                user_agent: client_info.user_agent,
+                                created_at: now,
+                                last_refreshed_at: now,
+                            };
+                            let session = token_repo
+                                .create_session(Some(ctx), session)
+                                .await
+                                .map_err(|err| {
+                                    AppError::internal_with_private(
+                                        INTERNAL_ERR_STR,
+                                        err.to_string(),
+                                    )
+                                })?;
+                            (session.id, Uuid::new_v4())
+                        }
+                        AuthTokenSession::Rotate {
+                            revoke_token_pair_id,
+                            client_info,
+                        } => {
+                            // Revoke the previous token pair, e.g. racing refreshes fail here
+                            let map_revoke_err = |err: AppError| match err {
+                                AppError::NotFound(_) => AppError::unauthorized_with_private(
+                                    UNAUTHORIZED_ERR_STR,
+                                    err.to_string(),
+                                ),
+                                _ => AppError::internal_with_private(
+                                    INTERNAL_ERR_STR,
+                                    err.to_string(),
+                                ),
+                            };
+                            let revoked_access_token = token_repo
+                                .get_access_token(Some(ctx), &revoke_token_pair_id)
+                                .await
+                                .map_err(map_revoke_err)?;
+                            let revoked_refresh_token_id =
+                                UniqueRefreshTokenIdentifier::AccessTokenId(
+                                    revoked_access_token.id,
+                                );
+                            let revoked_refresh_token = token_repo
+                                .get_refresh_token(Some(ctx), &revoked_refresh_token_id)
+                                .await
+                                .map_err(map_revoke_err)?;
+                            token_repo
+                                .delete_token_pair(Some(ctx), &revoke_token_pair_id)
+                                .await
+                                .map_err(map_revoke_err)?;
+                            // Remember the replaced refresh token to detect its reuse
+                            let revoked_refresh_token = RevokedRefreshToken {
+                                id: revoked_refresh_token.id,
+                                user_id: revoked_refresh_token.user_id,
+                                family_id: revoked_refresh_token.family_id,
+                                expires_at: revoked_refresh_token.expires_at,
+                                revoked_at: Utc::now(),
+                            };
+                            let revoked_refresh_token = token_repo
+                                .create_revoked_refresh_token(Some(ctx), revoked_refresh_token)
+                                .await
+                                .map_err(|err| {
+                                    AppError::internal_with_private(
+                                        INTERNAL_ERR_STR,
+                                        err.to_string(),
+                                    )
+                                })?;
+                            token_repo
+                                .touch_session(
+                                    Some(ctx),
+                                    revoked_access_token.session_id,
+                                    Some(client_info),
+                                )
+                                .await
+                                .map_err(|err| {
+                                    AppError::internal_with_private(
+                                        INTERNAL_ERR_STR,
+                                        err.to_string(),
+                                    )
+                                })?;
+                            (
+                                revoked_access_token.session_id,
+                                revoked_refresh_token.family_id,
+                            )
+                        }
+                    };
+
+                    let access_token = AccessToken {
+                        id: access_token.id,
+                        user_id: access_token.user_id,
+                        session_id,
+                        jwt: access_token_jwt,
+                        expires_at: access_token.expires_at,
+                    };
+                    let refresh_token = RefreshToken {
+                        id: refresh_token.id,
+                        user_id: refresh_token.user_id,
+                        access_token_id: access_token.id,
+                        family_id,
+                        jwt: refresh_token_jwt,
+                        expires_at: refresh_token.expires_at,
+                    };
                     let access_token = token_repo
                         .create_access_token(Some(ctx), access_token)
                         .await
@@ -183,14 +323,6 @@ impl Authenticator for JwtAuthenticator {
                         .map_err(|err| {
                             AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string())
                         })?;
-                    if let Some(revoke_access_token_id) = revoke_token_pair_id {
-                        token_repo
-                            .delete_token_pair(Some(ctx), &revoke_access_token_id)
-                            .await
-                            .map_err(|err| {
-                                AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string())
-                            })?;
-                    }
                     let token_pair = AuthTokenPair {
                         access_token,
                         refresh_token,
@@ -0,0 +1,121 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
+use ring::{
+    rand::SystemRandom,
+    rsa::PublicKeyComponents,
+    signature::{self, EcdsaKeyPair, Ed25519KeyPair, KeyPair, RsaKeyPair},
+};
+
+use app::types::auth_token::{JsonWebKey, JsonWebKeyAlgorithm, JsonWebKeyParams};
+use common::error::AppError;
+
+/// Asymmetric key pair signing tokens, identified by the `kid` header of the tokens it signs.
+pub struct JwtKeyPair {
+    pub(crate) kid: String,
+    pub(crate) algorithm: JsonWebKeyAlgorithm,
+    /// When the key starts signing tokens, immediately if unset.<br />
+    /// Until then, and after a newer key takes over, it only verifies tokens.
+    pub(crate) active_from: Option<DateTime<Utc>>,
+    pub(crate) encoding_key: EncodingKey,
+    pub(crate) decoding_key: DecodingKey,
+    pub(crate) public_key: JsonWebKey,
+}
+
+impl JwtKeyPair {
+    /// Loads a PKCS#8 private key (PKCS#1 being accepted as well for RSA).
+    pub fn from_pem(
+        kid: String,
+        algorithm: JsonWebKeyAlgorithm,
+        pem: &[u8],
+        active_from: Option<DateTime<Utc>>,
+    ) -> Result<Self, AppError> {
+        #[allow(non_snake_case)]
+        let INVALID_KEY_ERR_STR = format!("Invalid JWT signing key ({kid})!");
+        let invalid_key_err =
+            |err: String| AppError::internal_with_private(&INVALID_KEY_ERR_STR, err);
+
+        let pem_key = pem::parse(pem).map_err(|err| invalid_key_err(err.to_string()))?;
+        let der = pem_key.contents();
+        let (encoding_key, decoding_key, params) = match algorithm {
+            JsonWebKeyAlgorithm::Rs256 => {
+                let key_pair = match pem_key.tag() {
+                    "RSA PRIVATE KEY" => RsaKeyPair::from_der(der),
+                    _ => RsaKeyPair::from_pkcs8(der),
+                }
+                .map_err(|err| invalid_key_err(err.to_string()))?;
+                let components = PublicKeyComponents::<Vec<u8>>::from(key_pair.public());
+                let params = JsonWebKeyParams::Rsa {
+                    modulus: URL_SAFE_NO_PAD.encode(&components.n),
+                    exponent: URL_SAFE_NO_PAD.encode(&components.e),
+                };
+                (
+                    EncodingKey::from_rsa_pem(pem),
+                    Ok(DecodingKey::from_rsa_raw_components(
+                        &components.n,
+                        &components.e,
+                    )),
+                    params,
+                )
+            }
+            JsonWebKeyAlgorithm::Es256 => {
+                let key_pair = EcdsaKeyPair::from_pkcs8(
+                    &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+                    der,
+                    &SystemRandom::new(),
+                )
+                .map_err(|err| invalid_key_err(err.to_string()))?;
+                // Uncompressed point, i.e. 0x04 || x || y
+                let point = key_pair.public_key().as_ref();
+                let (x, y) = point[1..].split_at((point.len() - 1) / 2);
+                let (x, y) = (URL_SAFE_NO_PAD.encode(x), URL_SAFE_NO_PAD.encode(y));
+                (
+                    EncodingKey::from_ec_pem(pem),
+                    DecodingKey::from_ec_components(&x, &y),
+                    JsonWebKeyParams::EllipticCurve {
+                        curve: "P-256".to_string(),
+                        x,
+                        y,
+                    },
+                )
+            }
+            JsonWebKeyAlgorithm::EdDsa => {
+                let key_pair = Ed25519KeyPair::from_pkcs8_maybe_unchecked(der)
+                    .map_err(|err| invalid_key_err(err.to_string()))?;
+                let x = URL_SAFE_NO_PAD.encode(key_pair.public_key().as_ref());
+                (
+                    EncodingKey::from_ed_pem(pem),
+                    DecodingKey::from_ed_components(&x),
+                    JsonWebKeyParams::OctetKeyPair {
+                        curve: "Ed25519".to_string(),
+                        x,
+                    },
+                )
+            }
+        };
+
+        Ok(Self {
+            encoding_key: encoding_key.map_err(|err| invalid_key_err(err.to_string()))?,
+            decoding_key: decoding_key.map_err(|err| invalid_key_err(err.to_string()))?,
+            public_key: JsonWebKey {
+                kid: kid.clone(),
+                algorithm,
+                params,
+            },
+            kid,
+            algorithm,
+            active_from,
+        })
+    }
+
+    pub(crate) fn jwt_algorithm(&self) -> Algorithm {
+        match self.algorithm {
+            JsonWebKeyAlgorithm::Rs256 => Algorithm::RS256,
+            JsonWebKeyAlgorithm::Es256 => Algorithm::ES256,
+            JsonWebKeyAlgorithm::EdDsa => Algorithm::EdDSA,
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests;
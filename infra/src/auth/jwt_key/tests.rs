@@ -0,0 +1,108 @@
+use ring::rand::SystemRandom;
+
+use super::*;
+
+// Ring can't generate RSA keys, hence the fixture, holding the same key in both encodings
+const RSA_PKCS1_PEM: &[u8] = include_bytes!("test_rsa_pkcs1.pem");
+const RSA_PKCS8_PEM: &[u8] = include_bytes!("test_rsa_pkcs8.pem");
+
+pub(crate) fn pkcs8_pem(der: &[u8]) -> Vec<u8> {
+    pem::encode(&pem::Pem::new("PRIVATE KEY", der.to_vec())).into_bytes()
+}
+
+pub(crate) fn generate_ed25519_pem() -> Vec<u8> {
+    let der = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+    pkcs8_pem(der.as_ref())
+}
+
+pub(crate) fn generate_es256_pem() -> Vec<u8> {
+    let der = EcdsaKeyPair::generate_pkcs8(
+        &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+        &SystemRandom::new(),
+    )
+    .unwrap();
+    pkcs8_pem(der.as_ref())
+}
+
+fn decoded_len(value: &str) -> usize {
+    URL_SAFE_NO_PAD.decode(value).unwrap().len()
+}
+
+#[test]
+fn test_from_pem_loads_ed25519_key() {
+    let pem = generate_ed25519_pem();
+    let key_pair =
+        JwtKeyPair::from_pem("ed".to_string(), JsonWebKeyAlgorithm::EdDsa, &pem, None).unwrap();
+
+    assert_eq!(key_pair.kid, "ed");
+    assert_eq!(key_pair.jwt_algorithm(), Algorithm::EdDSA);
+    assert_eq!(key_pair.public_key.kid, "ed");
+    assert_eq!(key_pair.public_key.algorithm, JsonWebKeyAlgorithm::EdDsa);
+    match &key_pair.public_key.params {
+        JsonWebKeyParams::OctetKeyPair { curve, x } => {
+            assert_eq!(curve, "Ed25519");
+            assert_eq!(decoded_len(x), 32);
+        }
+        params => panic!("Expected OKP parameters, got {params:?}"),
+    }
+}
+
+#[test]
+fn test_from_pem_loads_es256_key() {
+    let pem = generate_es256_pem();
+    let key_pair =
+        JwtKeyPair::from_pem("ec".to_string(), JsonWebKeyAlgorithm::Es256, &pem, None).unwrap();
+
+    assert_eq!(key_pair.jwt_algorithm(), Algorithm::ES256);
+    match &key_pair.public_key.params {
+        JsonWebKeyParams::EllipticCurve { curve, x, y } => {
+            assert_eq!(curve, "P-256");
+            assert_eq!(decoded_len(x), 32);
+            assert_eq!(decoded_len(y), 32);
+            assert_ne!(x, y);
+        }
+        params => panic!("Expected EC parameters, got {params:?}"),
+    }
+}
+
+#[test]
+fn test_from_pem_loads_rs256_key_in_either_encoding() {
+    let params = [RSA_PKCS1_PEM, RSA_PKCS8_PEM].map(|pem| {
+        let key_pair =
+            JwtKeyPair::from_pem("rsa".to_string(), JsonWebKeyAlgorithm::Rs256, pem, None).unwrap();
+        assert_eq!(key_pair.jwt_algorithm(), Algorithm::RS256);
+        match key_pair.public_key.params {
+            JsonWebKeyParams::Rsa { modulus, exponent } => (modulus, exponent),
+            params => panic!("Expected RSA parameters, got {params:?}"),
+        }
+    });
+
+    let (modulus, exponent) = &params[0];
+    assert_eq!(decoded_len(modulus), 256);
+    assert_eq!(exponent, "AQAB");
+    assert_eq!(params[0], params[1]);
+}
+
+#[test]
+fn test_from_pem_rejects_mismatching_algorithm() {
+    let pem = generate_ed25519_pem();
+    for algorithm in [JsonWebKeyAlgorithm::Es256, JsonWebKeyAlgorithm::Rs256] {
+        let result = JwtKeyPair::from_pem("ed".to_string(), algorithm, &pem, None);
+        if let Err(AppError::Internal(base_error)) = result {
+            assert_eq!(base_error.public_info, "Invalid JWT signing key (ed)!");
+        } else {
+            panic!("Expected Internal error for {algorithm:?} key");
+        }
+    }
+}
+
+#[test]
+fn test_from_pem_rejects_malformed_pem() {
+    let result = JwtKeyPair::from_pem(
+        "bad".to_string(),
+        JsonWebKeyAlgorithm::EdDsa,
+        b"not a pem",
+        None,
+    );
+    assert!(result.is_err());
+}
@@ -1,4 +1,6 @@
 pub mod authenticator;
+pub mod jwt_key;
 pub mod pass_hasher;
+pub mod pass_policy;
 pub mod pass_service;
 pub mod token_adapter;
@@ -1,4 +1,9 @@
+use argon2::{
+    password_hash::{rand_core::OsRng, PasswordHash, SaltString},
+    Algorithm, Argon2, Params, Version,
+};
 use bcrypt::{hash, verify, DEFAULT_COST};
+use std::sync::Arc;
 
 use app::auth::pass_hasher::PasswordHasher;
 use common::error::AppError;
@@ -17,4 +22,142 @@ impl PasswordHasher for BcryptPasswordHasher {
             AppError::internal_with_private("Failed to verify password!", err.to_string())
         })
     }
+
+    fn supports(&self, password_hash: &str) -> bool {
+        ["$2a$", "$2b$", "$2x$", "$2y$"]
+            .iter()
+            .any(|prefix| password_hash.starts_with(prefix))
+    }
+}
+
+/// Argon2id hasher, the parameters of which are embedded in the hashes it produces.
+pub struct Argon2PasswordHasher {
+    params: Params,
+}
+
+impl Argon2PasswordHasher {
+    /// OWASP recommended minimum: 19 MiB of memory, 2 iterations, 1 degree of parallelism.
+    pub const DEFAULT_MEMORY_KIB: u32 = 19 * 1024;
+    pub const DEFAULT_ITERATIONS: u32 = 2;
+    pub const DEFAULT_PARALLELISM: u32 = 1;
+
+    pub fn new(memory_kib: u32, iterations: u32, parallelism: u32) -> Result<Self, AppError> {
+        let params = Params::new(memory_kib, iterations, parallelism, None).map_err(|err| {
+            AppError::internal_with_private("Invalid Argon2 parameters!", err.to_string())
+        })?;
+        Ok(Self { params })
+    }
+
+    fn argon2(&self) -> Argon2<'_> {
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, self.params.clone())
+    }
+}
+
+impl Default for Argon2PasswordHasher {
+    fn default() -> Self {
+        Self::new(
+            Self::DEFAULT_MEMORY_KIB,
+            Self::DEFAULT_ITERATIONS,
+            Self::DEFAULT_PARALLELISM,
+        )
+        .expect("Default Argon2 parameters are valid")
+    }
+}
+
+impl PasswordHasher for Argon2PasswordHasher {
+    fn hash(&self, password: &str) -> Result<String, AppError> {
+        let salt = SaltString::generate(&mut OsRng);
+        let password_hash =
+            argon2::PasswordHasher::hash_password(&self.argon2(), password.as_bytes(), &salt)
+                .map_err(|err| {
+                    AppError::internal_with_private("Failed to hash password!", err.to_string())
+                })?;
+        Ok(password_hash.to_string())
+    }
+
+    fn verify(&self, password: &str, password_hash: &str) -> Result<bool, AppError> {
+        const INTERNAL_ERR_STR: &str = "Failed to verify password!";
+
+        let parsed_hash = PasswordHash::new(password_hash)
+            .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+        // Parameters are read from the hash, so hashes with outdated ones still verify
+        match argon2::PasswordVerifier::verify_password(
+            &self.argon2(),
+            password.as_bytes(),
+            &parsed_hash,
+        ) {
+            Ok(()) => Ok(true),
+            Err(argon2::password_hash::Error::Password) => Ok(false),
+            Err(err) => Err(AppError::internal_with_private(
+                INTERNAL_ERR_STR,
+                err.to_string(),
+            )),
+        }
+    }
+
+    fn supports(&self, password_hash: &str) -> bool {
+        password_hash.starts_with("$argon2")
+    }
+
+    fn needs_rehash(&self, password_hash: &str) -> bool {
+        let Ok(parsed_hash) = PasswordHash::new(password_hash) else {
+            return true;
+        };
+        let Ok(params) = Params::try_from(&parsed_hash) else {
+            return true;
+        };
+        parsed_hash.algorithm != Algorithm::Argon2id.ident()
+            || parsed_hash.version != Some(Version::V0x13.into())
+            || params.m_cost() != self.params.m_cost()
+            || params.t_cost() != self.params.t_cost()
+            || params.p_cost() != self.params.p_cost()
+    }
+}
+
+/// Hashes with the current algorithm, while still verifying hashes of legacy ones.<br />
+/// Hashes of legacy algorithms, or with outdated parameters, are flagged for rehashing.
+pub struct MultiAlgorithmPasswordHasher {
+    current: Arc<dyn PasswordHasher + Send + Sync>,
+    legacy: Vec<Arc<dyn PasswordHasher + Send + Sync>>,
 }
+
+impl MultiAlgorithmPasswordHasher {
+    pub fn new(
+        current: Arc<dyn PasswordHasher + Send + Sync>,
+        legacy: Vec<Arc<dyn PasswordHasher + Send + Sync>>,
+    ) -> Self {
+        Self { current, legacy }
+    }
+}
+
+impl PasswordHasher for MultiAlgorithmPasswordHasher {
+    fn hash(&self, password: &str) -> Result<String, AppError> {
+        self.current.hash(password)
+    }
+
+    fn verify(&self, password: &str, password_hash: &str) -> Result<bool, AppError> {
+        let hasher = std::iter::once(&self.current)
+            .chain(self.legacy.iter())
+            .find(|hasher| hasher.supports(password_hash))
+            .ok_or_else(|| {
+                AppError::internal_with_private(
+                    "Failed to verify password!",
+                    "Unsupported password hash algorithm!",
+                )
+            })?;
+        hasher.verify(password, password_hash)
+    }
+
+    fn supports(&self, password_hash: &str) -> bool {
+        std::iter::once(&self.current)
+            .chain(self.legacy.iter())
+            .any(|hasher| hasher.supports(password_hash))
+    }
+
+    fn needs_rehash(&self, password_hash: &str) -> bool {
+        !self.current.supports(password_hash) || self.current.needs_rehash(password_hash)
+    }
+}
+
+#[cfg(test)]
+mod tests;
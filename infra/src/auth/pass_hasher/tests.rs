@@ -0,0 +1,128 @@
+use super::*;
+
+const PASSWORD: &str = "correct horse battery staple";
+
+/// Tests use cheap parameters, keeping them fast.
+fn argon2_hasher(memory_kib: u32, iterations: u32, parallelism: u32) -> Argon2PasswordHasher {
+    Argon2PasswordHasher::new(memory_kib, iterations, parallelism).unwrap()
+}
+
+fn bcrypt_hash(password: &str) -> String {
+    bcrypt::hash(password, 4).unwrap()
+}
+
+fn multi_hasher(current: Argon2PasswordHasher) -> MultiAlgorithmPasswordHasher {
+    MultiAlgorithmPasswordHasher::new(Arc::new(current), vec![Arc::new(BcryptPasswordHasher)])
+}
+
+#[test]
+fn test_bcrypt_verifies_legacy_hash() {
+    let password_hash = bcrypt_hash(PASSWORD);
+    assert!(BcryptPasswordHasher
+        .verify(PASSWORD, &password_hash)
+        .unwrap());
+    assert!(!BcryptPasswordHasher
+        .verify("wrong", &password_hash)
+        .unwrap());
+}
+
+#[test]
+fn test_hashers_support_their_own_prefixes() {
+    let argon2 = argon2_hasher(64, 1, 1);
+    let argon2_hash = argon2.hash(PASSWORD).unwrap();
+    let bcrypt_hash = bcrypt_hash(PASSWORD);
+
+    assert!(argon2.supports(&argon2_hash));
+    assert!(!argon2.supports(&bcrypt_hash));
+    assert!(BcryptPasswordHasher.supports(&bcrypt_hash));
+    assert!(!BcryptPasswordHasher.supports(&argon2_hash));
+    for prefix in ["$2a$", "$2x$", "$2y$"] {
+        assert!(BcryptPasswordHasher.supports(&bcrypt_hash.replacen("$2b$", prefix, 1)));
+    }
+}
+
+#[test]
+fn test_argon2_hashes_and_verifies_password() {
+    let hasher = argon2_hasher(64, 1, 1);
+    let password_hash = hasher.hash(PASSWORD).unwrap();
+    assert!(password_hash.starts_with("$argon2id$v=19$m=64,t=1,p=1$"));
+    assert!(hasher.verify(PASSWORD, &password_hash).unwrap());
+    assert!(!hasher.verify("wrong", &password_hash).unwrap());
+}
+
+#[test]
+fn test_argon2_rejects_invalid_parameters() {
+    assert!(Argon2PasswordHasher::new(64, 0, 1).is_err());
+    assert!(Argon2PasswordHasher::new(64, 1, 0).is_err());
+}
+
+#[test]
+fn test_argon2_needs_rehash_on_parameter_change() {
+    let password_hash = argon2_hasher(64, 1, 1).hash(PASSWORD).unwrap();
+
+    assert!(!argon2_hasher(64, 1, 1).needs_rehash(&password_hash));
+    assert!(argon2_hasher(128, 1, 1).needs_rehash(&password_hash));
+    assert!(argon2_hasher(64, 2, 1).needs_rehash(&password_hash));
+    assert!(argon2_hasher(64, 1, 2).needs_rehash(&password_hash));
+}
+
+#[test]
+fn test_argon2_needs_rehash_on_other_variant() {
+    let hasher = argon2_hasher(64, 1, 1);
+    let password_hash = hasher.hash(PASSWORD).unwrap();
+    let argon2i_hash = password_hash.replacen("$argon2id$", "$argon2i$", 1);
+    assert!(hasher.needs_rehash(&argon2i_hash));
+    assert!(hasher.needs_rehash("$argon2id$garbage"));
+}
+
+#[test]
+fn test_argon2_verifies_hash_with_outdated_parameters() {
+    let password_hash = argon2_hasher(64, 1, 1).hash(PASSWORD).unwrap();
+    assert!(argon2_hasher(128, 2, 1)
+        .verify(PASSWORD, &password_hash)
+        .unwrap());
+}
+
+#[test]
+fn test_multi_hashes_with_current_algorithm() {
+    let hasher = multi_hasher(argon2_hasher(64, 1, 1));
+    let password_hash = hasher.hash(PASSWORD).unwrap();
+    assert!(password_hash.starts_with("$argon2id$"));
+    assert!(!hasher.needs_rehash(&password_hash));
+}
+
+#[test]
+fn test_multi_verifies_by_hash_prefix() {
+    let hasher = multi_hasher(argon2_hasher(64, 1, 1));
+    let argon2_hash = hasher.hash(PASSWORD).unwrap();
+    let bcrypt_hash = bcrypt_hash(PASSWORD);
+
+    for password_hash in [&argon2_hash, &bcrypt_hash] {
+        assert!(hasher.supports(password_hash));
+        assert!(hasher.verify(PASSWORD, password_hash).unwrap());
+        assert!(!hasher.verify("wrong", password_hash).unwrap());
+    }
+}
+
+#[test]
+fn test_multi_rejects_unsupported_hash() {
+    let hasher = multi_hasher(argon2_hasher(64, 1, 1));
+    let password_hash = "$1$legacy$md5hash";
+    assert!(!hasher.supports(password_hash));
+    match hasher.verify(PASSWORD, password_hash) {
+        Err(AppError::Internal(base_error)) => {
+            assert_eq!(base_error.public_info, "Failed to verify password!")
+        }
+        _ => panic!("Expected Internal error for unsupported hash"),
+    }
+}
+
+#[test]
+fn test_multi_flags_legacy_and_outdated_hashes_for_rehash() {
+    let hasher = multi_hasher(argon2_hasher(64, 1, 1));
+    assert!(hasher.needs_rehash(&bcrypt_hash(PASSWORD)));
+
+    let outdated_hash = argon2_hasher(64, 2, 1).hash(PASSWORD).unwrap();
+    assert!(hasher.verify(PASSWORD, &outdated_hash).unwrap());
+    assert!(hasher.needs_rehash(&outdated_hash));
+}
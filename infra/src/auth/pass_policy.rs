@@ -0,0 +1,154 @@
+use std::collections::HashSet;
+use zxcvbn::{zxcvbn, Score};
+
+use common::error::AppError;
+
+/// Rules new passwords are validated against.
+pub struct PasswordPolicy {
+    min_length: usize,
+    max_length: usize,
+    /// Number of character classes (lowercase, uppercase, digits, symbols) to be present.
+    min_char_classes: usize,
+    /// Minimum zxcvbn score, from 0 (too guessable) to 4 (very unguessable).
+    min_strength_score: u8,
+    /// Lowercased passwords known from data breaches.
+    breached_passwords: HashSet<String>,
+}
+
+impl PasswordPolicy {
+    pub const DEFAULT_MIN_LENGTH: usize = 8;
+    pub const DEFAULT_MAX_LENGTH: usize = 128;
+    pub const DEFAULT_MIN_CHAR_CLASSES: usize = 3;
+    pub const DEFAULT_MIN_STRENGTH_SCORE: u8 = 3;
+
+    pub fn new(
+        min_length: usize,
+        max_length: usize,
+        min_char_classes: usize,
+        min_strength_score: u8,
+    ) -> Result<Self, AppError> {
+        const INVALID_POLICY_ERR_STR: &str = "Invalid password policy!";
+        if min_length > max_length {
+            return Err(AppError::internal_with_private(
+                INVALID_POLICY_ERR_STR,
+                "Minimum length exceeds maximum length.",
+            ));
+        }
+        if min_char_classes > 4 {
+            return Err(AppError::internal_with_private(
+                INVALID_POLICY_ERR_STR,
+                "There are only 4 character classes.",
+            ));
+        }
+        if min_strength_score > u8::from(Score::Four) {
+            return Err(AppError::internal_with_private(
+                INVALID_POLICY_ERR_STR,
+                "Strength scores range from 0 to 4.",
+            ));
+        }
+        Ok(Self {
+            min_length,
+            max_length,
+            min_char_classes,
+            min_strength_score,
+            breached_passwords: HashSet::new(),
+        })
+    }
+
+    pub fn with_breached_passwords(mut self, breached_passwords: HashSet<String>) -> Self {
+        self.breached_passwords = breached_passwords;
+        self
+    }
+
+    /// Parses a breached password list, holding a password per line.<br />
+    /// Blank lines and lines starting with `#` are skipped.
+    pub fn parse_breached_passwords(list: &str) -> HashSet<String> {
+        list.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_lowercase)
+            .collect()
+    }
+
+    pub fn validate(&self, password: &str, email: &str) -> Result<(), AppError> {
+        let mut violations = Vec::new();
+
+        let length = password.chars().count();
+        if length < self.min_length {
+            violations.push(format!(
+                "Password must be at least {} characters long!",
+                self.min_length
+            ));
+        }
+        if length > self.max_length {
+            violations.push(format!(
+                "Password cannot exceed {} characters!",
+                self.max_length
+            ));
+        }
+
+        let char_classes = [
+            password.chars().any(|c| c.is_lowercase()),
+            password.chars().any(|c| c.is_uppercase()),
+            password.chars().any(|c| c.is_numeric()),
+            password.chars().any(|c| !c.is_alphanumeric()),
+        ];
+        if char_classes.iter().filter(|present| **present).count() < self.min_char_classes {
+            violations.push(format!(
+                "Password must contain at least {} of: lowercase letters, uppercase letters, digits, symbols!",
+                self.min_char_classes
+            ));
+        }
+
+        let lowercase_password = password.to_lowercase();
+        let lowercase_email = email.trim().to_lowercase();
+        let email_local_part = lowercase_email.split('@').next().unwrap_or_default();
+        if !lowercase_email.is_empty()
+            && (lowercase_password.contains(&lowercase_email)
+                || (email_local_part.chars().count() >= 3
+                    && lowercase_password.contains(email_local_part)))
+        {
+            violations.push("Password cannot contain your email address!".to_string());
+        }
+
+        if self.breached_passwords.contains(&lowercase_password) {
+            violations.push("Password has appeared in a data breach!".to_string());
+        }
+
+        // Scoring gets costly on lengthy inputs, which are rejected regardless
+        if length <= self.max_length {
+            let entropy = zxcvbn(password, &[email, email_local_part]);
+            if u8::from(entropy.score()) < self.min_strength_score {
+                let warning = entropy
+                    .feedback()
+                    .and_then(|feedback| feedback.warning())
+                    .map(|warning| format!(" ({warning})"))
+                    .unwrap_or_default();
+                violations.push(format!("Password is too easy to guess!{warning}"));
+            }
+        }
+
+        match violations.is_empty() {
+            true => Ok(()),
+            false => Err(AppError::bad_request_with_details(
+                "Password doesn't satisfy the password policy!",
+                violations,
+            )),
+        }
+    }
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self::new(
+            Self::DEFAULT_MIN_LENGTH,
+            Self::DEFAULT_MAX_LENGTH,
+            Self::DEFAULT_MIN_CHAR_CLASSES,
+            Self::DEFAULT_MIN_STRENGTH_SCORE,
+        )
+        .expect("Default password policy is valid")
+    }
+}
+
+#[cfg(test)]
+mod tests;
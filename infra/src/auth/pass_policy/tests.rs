@@ -0,0 +1,150 @@
+use super::*;
+
+const STRONG_PASSWORD: &str = "vT9#kq2!Lm8@zR";
+const EMAIL: &str = "johnsmith@example.com";
+
+/// Policy enforcing nothing but what each test sets up.
+fn lenient_policy() -> PasswordPolicy {
+    PasswordPolicy::new(0, 1024, 0, 0).unwrap()
+}
+
+fn violations(result: Result<(), AppError>) -> Vec<String> {
+    match result {
+        Err(AppError::BadRequest(base_error)) => {
+            assert_eq!(
+                base_error.public_info,
+                "Password doesn't satisfy the password policy!"
+            );
+            base_error.details.clone()
+        }
+        Err(err) => panic!("Expected BadRequest error, got {err}"),
+        Ok(()) => panic!("Expected BadRequest error, got Ok"),
+    }
+}
+
+#[test]
+fn test_new_rejects_invalid_policy() {
+    assert!(PasswordPolicy::new(16, 8, 0, 0).is_err());
+    assert!(PasswordPolicy::new(8, 16, 5, 0).is_err());
+    assert!(PasswordPolicy::new(8, 16, 0, 5).is_err());
+}
+
+#[test]
+fn test_default_policy_accepts_strong_password() {
+    assert!(PasswordPolicy::default()
+        .validate(STRONG_PASSWORD, EMAIL)
+        .is_ok());
+}
+
+#[test]
+fn test_validate_rejects_short_password() {
+    let policy = PasswordPolicy::new(8, 1024, 0, 0).unwrap();
+    assert_eq!(
+        violations(policy.validate("aB3$xyz", EMAIL)),
+        vec!["Password must be at least 8 characters long!"]
+    );
+    assert!(policy.validate("aB3$xyzw", EMAIL).is_ok());
+}
+
+#[test]
+fn test_validate_counts_characters_rather_than_bytes() {
+    let policy = PasswordPolicy::new(4, 4, 0, 0).unwrap();
+    assert!(policy.validate("äöüß", EMAIL).is_ok());
+}
+
+#[test]
+fn test_validate_rejects_long_password() {
+    let policy = PasswordPolicy::new(0, 16, 0, 0).unwrap();
+    assert_eq!(
+        violations(policy.validate(&"aB3$".repeat(5), EMAIL)),
+        vec!["Password cannot exceed 16 characters!"]
+    );
+}
+
+#[test]
+fn test_validate_requires_character_classes() {
+    let policy = PasswordPolicy::new(0, 1024, 3, 0).unwrap();
+    assert_eq!(
+        violations(policy.validate("lowercaseonly", EMAIL)),
+        vec![
+            "Password must contain at least 3 of: lowercase letters, uppercase letters, digits, symbols!"
+        ]
+    );
+    assert!(policy.validate("lower-UPPER", EMAIL).is_ok());
+    assert!(policy.validate("lower1234", EMAIL).is_err());
+    assert!(policy.validate("lower1234!", EMAIL).is_ok());
+}
+
+#[test]
+fn test_validate_rejects_password_containing_email() {
+    let policy = lenient_policy();
+    for password in [
+        "my-johnsmith@example.com-pass",
+        "JohnSmith2024!",
+        "xxJOHNSMITHxx",
+    ] {
+        assert_eq!(
+            violations(policy.validate(password, EMAIL)),
+            vec!["Password cannot contain your email address!"],
+            "{password}"
+        );
+    }
+}
+
+#[test]
+fn test_validate_ignores_short_email_local_part() {
+    let policy = lenient_policy();
+    assert!(policy.validate("jo-is-here", "jo@example.com").is_ok());
+    assert!(policy.validate("whatever", "").is_ok());
+}
+
+#[test]
+fn test_validate_rejects_breached_password_case_insensitively() {
+    let breached = PasswordPolicy::parse_breached_passwords("# comment\n\n  Hunter2  \nletmein\n");
+    assert_eq!(breached.len(), 2);
+    let policy = lenient_policy().with_breached_passwords(breached);
+    for password in ["hunter2", "HUNTER2", "LetMeIn"] {
+        assert_eq!(
+            violations(policy.validate(password, EMAIL)),
+            vec!["Password has appeared in a data breach!"],
+            "{password}"
+        );
+    }
+    assert!(policy.validate("# comment", EMAIL).is_ok());
+}
+
+#[test]
+fn test_validate_rejects_guessable_password() {
+    let policy = PasswordPolicy::new(0, 1024, 0, 3).unwrap();
+    let details = violations(policy.validate("Password123!", EMAIL));
+    assert_eq!(details.len(), 1);
+    assert!(details[0].starts_with("Password is too easy to guess!"));
+    assert!(policy.validate(STRONG_PASSWORD, EMAIL).is_ok());
+}
+
+#[test]
+fn test_validate_skips_scoring_overlong_password() {
+    let policy = PasswordPolicy::new(0, 8, 0, 4).unwrap();
+    assert_eq!(
+        violations(policy.validate(&"a".repeat(64), EMAIL)),
+        vec!["Password cannot exceed 8 characters!"]
+    );
+}
+
+#[test]
+fn test_validate_lists_every_violation() {
+    let policy = PasswordPolicy::default()
+        .with_breached_passwords(PasswordPolicy::parse_breached_passwords("john"));
+    let details = violations(policy.validate("john", "john@example.com"));
+    assert_eq!(
+        details[..4],
+        [
+            "Password must be at least 8 characters long!",
+            "Password must contain at least 3 of: lowercase letters, uppercase letters, digits, symbols!",
+            "Password cannot contain your email address!",
+            "Password has appeared in a data breach!",
+        ]
+    );
+    assert_eq!(details.len(), 5);
+    assert!(details[4].starts_with("Password is too easy to guess!"));
+}
@@ -1,31 +1,73 @@
 use async_trait::async_trait;
 use std::sync::Arc;
 
+use crate::auth::pass_policy::PasswordPolicy;
 use app::auth::{pass_hasher::PasswordHasher, pass_service::PasswordService};
 use common::error::AppError;
-use dmn::{entities::user::UniqueUserIdentifier, repos::user::UserRepository};
+use dmn::{
+    entities::user::{UniqueUserIdentifier, UpdateUserData},
+    repos::user::UserRepository,
+};
 
 pub struct DefaultPasswordService {
     user_repo: Arc<dyn UserRepository + Send + Sync>,
     pass_hasher: Arc<dyn PasswordHasher + Send + Sync>,
+    pass_policy: PasswordPolicy,
 }
 
 impl DefaultPasswordService {
     pub fn new(
         user_repo: Arc<dyn UserRepository + Send + Sync>,
         pass_hasher: Arc<dyn PasswordHasher + Send + Sync>,
+        pass_policy: PasswordPolicy,
     ) -> Self {
         Self {
             user_repo,
             pass_hasher,
+            pass_policy,
         }
     }
+
+    /// Runs a hasher operation on the blocking thread pool, as hashing is deliberately slow.
+    async fn run_blocking<T, F>(&self, operation: F) -> Result<T, AppError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&dyn PasswordHasher) -> Result<T, AppError> + Send + 'static,
+    {
+        let pass_hasher = self.pass_hasher.clone();
+        tokio::task::spawn_blocking(move || operation(pass_hasher.as_ref()))
+            .await
+            .map_err(|err| {
+                AppError::internal_with_private("Password hasher task failed!", err.to_string())
+            })?
+    }
+
+    /// Replaces the hash of a verified password if it's outdated.
+    async fn rehash_password(
+        &self,
+        user_id: &UniqueUserIdentifier,
+        password: &str,
+    ) -> Result<(), AppError> {
+        let password = password.to_string();
+        let password_hash = self
+            .run_blocking(move |pass_hasher| pass_hasher.hash(&password))
+            .await?;
+        let user_data = UpdateUserData {
+            email: None,
+            password_hash: Some(password_hash),
+        };
+        self.user_repo.update_user(None, user_id, user_data).await?;
+        Ok(())
+    }
 }
 
 #[async_trait]
 impl PasswordService for DefaultPasswordService {
-    fn hash(&self, password: &str) -> Result<String, AppError> {
-        let password_hash = self.pass_hasher.hash(password)?;
+    async fn hash(&self, password: &str) -> Result<String, AppError> {
+        let password = password.to_string();
+        let password_hash = self
+            .run_blocking(move |pass_hasher| pass_hasher.hash(&password))
+            .await?;
         Ok(password_hash)
     }
 
@@ -47,9 +89,11 @@ impl PasswordService for DefaultPasswordService {
                 )
             })?;
 
+        let (verified_password, verified_hash) =
+            (password.to_string(), current_password_hash.clone());
         let password_matches = self
-            .pass_hasher
-            .verify(password, &current_password_hash)
+            .run_blocking(move |pass_hasher| pass_hasher.verify(&verified_password, &verified_hash))
+            .await
             .map_err(|err| {
                 AppError::unauthorized_with_private(
                     UNAUTHORIZED_ERR_STR,
@@ -64,12 +108,17 @@ impl PasswordService for DefaultPasswordService {
             ));
         }
 
+        // Upgrade legacy hashes, without failing the verification over it
+        if self.pass_hasher.needs_rehash(&current_password_hash) {
+            if let Err(err) = self.rehash_password(user_id, password).await {
+                err.log();
+            }
+        }
+
         Ok(())
     }
 
-    #[allow(unused_variables, unreachable_code)]
-    fn validate_password_strength(&self, password: &str) -> Result<(), AppError> {
-        return Ok(());
-        todo!()
+    fn validate_password_strength(&self, password: &str, email: &str) -> Result<(), AppError> {
+        self.pass_policy.validate(password, email)
     }
 }
@@ -1,50 +1,147 @@
 use chrono::{DateTime, Utc};
-use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use jsonwebtoken::{
+    decode, decode_header, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use uuid::Uuid;
 
+use crate::auth::jwt_key::JwtKeyPair;
 use app::{
     auth::token_adapter::TokenAdapter,
-    types::auth_token::{JsonWebTokenData, JsonWebTokenDataVariant},
+    types::auth_token::{JsonWebKey, JsonWebTokenData, JsonWebTokenDataVariant},
 };
 use common::error::AppError;
 
+/// Signs tokens either with a shared HMAC secret, or with asymmetric key pairs.<br />
+/// Key pairs are rotated by adding a newer key with a later activation time:
+/// it signs tokens from then on, while older keys keep verifying previously issued tokens until removed.
 pub struct JwtTokenAdapter {
-    jwt_secret: String,
+    keys: JwtKeys,
+}
+
+enum JwtKeys {
+    Secret {
+        encoding_key: EncodingKey,
+        decoding_key: DecodingKey,
+    },
+    KeyPairs(Vec<JwtKeyPair>),
 }
 
 impl JwtTokenAdapter {
     pub fn new(jwt_secret: String) -> Self {
-        Self { jwt_secret }
+        let keys = JwtKeys::Secret {
+            encoding_key: EncodingKey::from_secret(jwt_secret.as_ref()),
+            decoding_key: DecodingKey::from_secret(jwt_secret.as_ref()),
+        };
+        Self { keys }
+    }
+
+    pub fn with_key_pairs(key_pairs: Vec<JwtKeyPair>) -> Result<Self, AppError> {
+        const INVALID_KEYS_ERR_STR: &str = "Invalid JWT signing keys!";
+
+        let mut kids = HashSet::new();
+        if let Some(key_pair) = key_pairs
+            .iter()
+            .find(|key_pair| !kids.insert(&key_pair.kid))
+        {
+            return Err(AppError::internal_with_private(
+                INVALID_KEYS_ERR_STR,
+                format!("Duplicate key id ({})!", key_pair.kid),
+            ));
+        }
+        let adapter = Self {
+            keys: JwtKeys::KeyPairs(key_pairs),
+        };
+        if adapter.signing_key_pair(Utc::now()).is_none() {
+            return Err(AppError::internal_with_private(
+                INVALID_KEYS_ERR_STR,
+                "No key is active yet!",
+            ));
+        }
+        Ok(adapter)
+    }
+
+    /// The most recently activated key pair.
+    fn signing_key_pair(&self, now: DateTime<Utc>) -> Option<&JwtKeyPair> {
+        let JwtKeys::KeyPairs(key_pairs) = &self.keys else {
+            return None;
+        };
+        key_pairs
+            .iter()
+            .filter(|key_pair| {
+                key_pair
+                    .active_from
+                    .is_none_or(|active_from| active_from <= now)
+            })
+            .max_by_key(|key_pair| key_pair.active_from)
     }
 }
 
 impl TokenAdapter for JwtTokenAdapter {
     fn encode(&self, token: JsonWebTokenData) -> Result<String, AppError> {
+        const INTERNAL_ERR_STR: &str = "Failed to encode token!";
+
+        let (header, encoding_key) = match &self.keys {
+            JwtKeys::Secret { encoding_key, .. } => (Header::default(), encoding_key),
+            JwtKeys::KeyPairs(_) => {
+                let key_pair = self.signing_key_pair(Utc::now()).ok_or_else(|| {
+                    AppError::internal_with_private(INTERNAL_ERR_STR, "No active signing key!")
+                })?;
+                let mut header = Header::new(key_pair.jwt_algorithm());
+                header.kid = Some(key_pair.kid.clone());
+                (header, &key_pair.encoding_key)
+            }
+        };
         let claims: JwtClaims = token.into();
-        let encoded_token = encode(
-            &Header::default(),
-            &claims,
-            &EncodingKey::from_secret(self.jwt_secret.as_ref()),
-        )
-        .map_err(|err| {
-            AppError::internal_with_private("Failed to encode token!", err.to_string())
-        })?;
+        let encoded_token = encode(&header, &claims, encoding_key)
+            .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
         Ok(encoded_token)
     }
 
     fn decode(&self, token: &str) -> Result<JsonWebTokenData, AppError> {
-        let token_data = decode::<JwtClaims>(
-            &token,
-            &DecodingKey::from_secret(self.jwt_secret.as_ref()),
-            &Validation::default(),
-        )
-        .map_err(|err| {
-            AppError::internal_with_private("Failed to decode token!", err.to_string())
-        })?;
+        const INTERNAL_ERR_STR: &str = "Failed to decode token!";
+
+        // Only the algorithm of the matching key is accepted, whatever the header claims
+        let (validation, decoding_key) = match &self.keys {
+            JwtKeys::Secret { decoding_key, .. } => {
+                (Validation::new(Algorithm::HS256), decoding_key)
+            }
+            JwtKeys::KeyPairs(key_pairs) => {
+                let header = decode_header(token).map_err(|err| {
+                    AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string())
+                })?;
+                let key_pair = key_pairs
+                    .iter()
+                    .find(|key_pair| header.kid.as_ref() == Some(&key_pair.kid))
+                    .ok_or_else(|| {
+                        AppError::internal_with_private(
+                            INTERNAL_ERR_STR,
+                            format!("Unknown key id ({:?})!", header.kid),
+                        )
+                    })?;
+                (
+                    Validation::new(key_pair.jwt_algorithm()),
+                    &key_pair.decoding_key,
+                )
+            }
+        };
+        let token_data = decode::<JwtClaims>(token, decoding_key, &validation)
+            .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
         let decoded_token = token_data.claims.into();
         Ok(decoded_token)
     }
+
+    fn public_keys(&self) -> Vec<JsonWebKey> {
+        match &self.keys {
+            JwtKeys::Secret { .. } => Vec::new(),
+            // Upcoming keys are published ahead of time, for verifiers to pick them up
+            JwtKeys::KeyPairs(key_pairs) => key_pairs
+                .iter()
+                .map(|key_pair| key_pair.public_key.clone())
+                .collect(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -106,3 +203,6 @@ impl From<JwtClaimsVariant> for JsonWebTokenDataVariant {
         }
     }
 }
+
+#[cfg(test)]
+mod tests;
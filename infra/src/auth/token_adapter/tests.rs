@@ -0,0 +1,203 @@
+use chrono::Duration;
+
+use super::*;
+use crate::auth::jwt_key::tests::{generate_ed25519_pem, generate_es256_pem};
+use app::types::auth_token::{JsonWebKeyAlgorithm, JsonWebKeyParams};
+
+fn ed25519_key(kid: &str, active_from: Option<DateTime<Utc>>) -> JwtKeyPair {
+    let pem = generate_ed25519_pem();
+    JwtKeyPair::from_pem(
+        kid.to_string(),
+        JsonWebKeyAlgorithm::EdDsa,
+        &pem,
+        active_from,
+    )
+    .unwrap()
+}
+
+fn es256_key(kid: &str, active_from: Option<DateTime<Utc>>) -> JwtKeyPair {
+    let pem = generate_es256_pem();
+    JwtKeyPair::from_pem(
+        kid.to_string(),
+        JsonWebKeyAlgorithm::Es256,
+        &pem,
+        active_from,
+    )
+    .unwrap()
+}
+
+fn token_data() -> JsonWebTokenData {
+    JsonWebTokenData {
+        id: Uuid::new_v4(),
+        user_id: Uuid::new_v4(),
+        expires_at: Utc::now() + Duration::hours(1),
+        variant: JsonWebTokenDataVariant::AccessToken,
+    }
+}
+
+fn header_kid(token: &str) -> Option<String> {
+    decode_header(token).unwrap().kid
+}
+
+#[test]
+fn test_with_key_pairs_rejects_duplicate_kids() {
+    let result =
+        JwtTokenAdapter::with_key_pairs(vec![ed25519_key("k1", None), es256_key("k1", None)]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_with_key_pairs_requires_an_active_key() {
+    let upcoming = Utc::now() + Duration::hours(1);
+    assert!(JwtTokenAdapter::with_key_pairs(vec![]).is_err());
+    assert!(JwtTokenAdapter::with_key_pairs(vec![ed25519_key("k1", Some(upcoming))]).is_err());
+}
+
+#[test]
+fn test_signing_key_pair_is_most_recently_activated_one() {
+    let now = Utc::now();
+    let adapter = JwtTokenAdapter::with_key_pairs(vec![
+        ed25519_key("initial", None),
+        es256_key("current", Some(now - Duration::days(1))),
+        ed25519_key("outdated", Some(now - Duration::days(2))),
+        ed25519_key("upcoming", Some(now + Duration::days(1))),
+    ])
+    .unwrap();
+
+    let kid_at = |at| {
+        adapter
+            .signing_key_pair(at)
+            .map(|key_pair| key_pair.kid.as_str())
+    };
+    assert_eq!(kid_at(now), Some("current"));
+    assert_eq!(kid_at(now - Duration::hours(36)), Some("outdated"));
+    assert_eq!(kid_at(now - Duration::days(3)), Some("initial"));
+    assert_eq!(kid_at(now + Duration::days(2)), Some("upcoming"));
+}
+
+#[test]
+fn test_encode_signs_with_active_key() {
+    let now = Utc::now();
+    let adapter = JwtTokenAdapter::with_key_pairs(vec![
+        ed25519_key("old", None),
+        es256_key("new", Some(now - Duration::minutes(1))),
+    ])
+    .unwrap();
+
+    let token = adapter.encode(token_data()).unwrap();
+    let header = decode_header(&token).unwrap();
+    assert_eq!(header.kid.as_deref(), Some("new"));
+    assert_eq!(header.alg, Algorithm::ES256);
+}
+
+#[test]
+fn test_decode_verifies_tokens_of_rotated_keys_by_kid() {
+    let now = Utc::now();
+    let old_key_pem = generate_ed25519_pem();
+    let old_key = |active_from| {
+        JwtKeyPair::from_pem(
+            "old".to_string(),
+            JsonWebKeyAlgorithm::EdDsa,
+            &old_key_pem,
+            active_from,
+        )
+        .unwrap()
+    };
+
+    let old_adapter = JwtTokenAdapter::with_key_pairs(vec![old_key(None)]).unwrap();
+    let data = token_data();
+    let old_token = old_adapter.encode(data.clone()).unwrap();
+
+    // A newer key took over signing, while the old one still verifies
+    let rotated_adapter = JwtTokenAdapter::with_key_pairs(vec![
+        old_key(None),
+        es256_key("new", Some(now - Duration::minutes(1))),
+    ])
+    .unwrap();
+    let new_token = rotated_adapter.encode(token_data()).unwrap();
+    assert_eq!(header_kid(&old_token).as_deref(), Some("old"));
+    assert_eq!(header_kid(&new_token).as_deref(), Some("new"));
+
+    let decoded = rotated_adapter.decode(&old_token).unwrap();
+    assert_eq!(decoded.id, data.id);
+    assert_eq!(decoded.user_id, data.user_id);
+    assert_eq!(decoded.variant, data.variant);
+    assert!(rotated_adapter.decode(&new_token).is_ok());
+}
+
+#[test]
+fn test_decode_rejects_unknown_kid() {
+    let signer = JwtTokenAdapter::with_key_pairs(vec![ed25519_key("removed", None)]).unwrap();
+    let verifier = JwtTokenAdapter::with_key_pairs(vec![ed25519_key("current", None)]).unwrap();
+
+    let token = signer.encode(token_data()).unwrap();
+    match verifier.decode(&token) {
+        Err(AppError::Internal(base_error)) => {
+            assert_eq!(base_error.public_info, "Failed to decode token!");
+            assert!(base_error
+                .private_info
+                .as_ref()
+                .unwrap()
+                .contains("Unknown key id"));
+        }
+        _ => panic!("Expected Internal error for unknown key id"),
+    }
+}
+
+#[test]
+fn test_decode_rejects_signature_of_other_key_with_same_kid() {
+    let signer = JwtTokenAdapter::with_key_pairs(vec![ed25519_key("k1", None)]).unwrap();
+    let verifier = JwtTokenAdapter::with_key_pairs(vec![ed25519_key("k1", None)]).unwrap();
+
+    let token = signer.encode(token_data()).unwrap();
+    assert!(verifier.decode(&token).is_err());
+}
+
+#[test]
+fn test_decode_rejects_hmac_token_claiming_key_pair_kid() {
+    let adapter = JwtTokenAdapter::with_key_pairs(vec![ed25519_key("k1", None)]).unwrap();
+
+    let mut header = Header::new(Algorithm::HS256);
+    header.kid = Some("k1".to_string());
+    let claims: JwtClaims = token_data().into();
+    let token = encode(&header, &claims, &EncodingKey::from_secret(b"guessed")).unwrap();
+    assert!(adapter.decode(&token).is_err());
+}
+
+#[test]
+fn test_secret_adapter_round_trips_without_public_keys() {
+    let adapter = JwtTokenAdapter::new("s3cr3t".to_string());
+    let data = token_data();
+
+    let token = adapter.encode(data.clone()).unwrap();
+    assert_eq!(header_kid(&token), None);
+    assert_eq!(adapter.decode(&token).unwrap().id, data.id);
+    assert!(JwtTokenAdapter::new("other".to_string())
+        .decode(&token)
+        .is_err());
+    assert!(adapter.public_keys().is_empty());
+}
+
+#[test]
+fn test_public_keys_lists_every_key_pair() {
+    let now = Utc::now();
+    let adapter = JwtTokenAdapter::with_key_pairs(vec![
+        ed25519_key("old", None),
+        es256_key("current", Some(now - Duration::minutes(1))),
+        ed25519_key("upcoming", Some(now + Duration::days(1))),
+    ])
+    .unwrap();
+
+    let public_keys = adapter.public_keys();
+    let kids: Vec<_> = public_keys.iter().map(|key| key.kid.as_str()).collect();
+    assert_eq!(kids, ["old", "current", "upcoming"]);
+    assert_eq!(public_keys[1].algorithm, JsonWebKeyAlgorithm::Es256);
+    assert!(matches!(
+        public_keys[1].params,
+        JsonWebKeyParams::EllipticCurve { .. }
+    ));
+    assert!(matches!(
+        public_keys[0].params,
+        JsonWebKeyParams::OctetKeyPair { .. }
+    ));
+}
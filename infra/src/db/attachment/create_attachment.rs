@@ -0,0 +1,66 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::attachment::AttachmentPg;
+use common::error::AppError;
+
+pub(crate) async fn create_attachment<'a>(
+    db: impl PgExecutor<'a>,
+    attachment_data: CreateAttachmentDataPg,
+) -> Result<AttachmentPg, AppError> {
+    let CreateAttachmentDataPg {
+        note_id,
+        ref file_name,
+        ref mime_type,
+        size,
+        ref sha256,
+    } = attachment_data;
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to attach file to note ({note_id})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Note ({note_id}) doesn't exist!");
+
+    sqlx::query_as!(
+        AttachmentPg,
+        r#"
+        INSERT INTO attachments (
+            note_id,
+            file_name,
+            mime_type,
+            size,
+            sha256
+        )
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING
+            id,
+            note_id,
+            file_name,
+            mime_type,
+            size,
+            sha256,
+            created_at
+        "#,
+        note_id,
+        file_name,
+        mime_type,
+        size,
+        sha256,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::Database(ref db_err) if db_err.is_foreign_key_violation() => {
+            AppError::not_found(NOT_FOUND_ERR_STR)
+        }
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
+
+#[derive(Debug)]
+pub(crate) struct CreateAttachmentDataPg {
+    pub(crate) note_id: Uuid,
+    pub(crate) file_name: String,
+    pub(crate) mime_type: String,
+    pub(crate) size: i64,
+    pub(crate) sha256: String,
+}
@@ -0,0 +1,35 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use common::error::AppError;
+
+pub(crate) async fn delete_attachment<'a>(
+    db: impl PgExecutor<'a>,
+    note_id: Uuid,
+    attachment_id: Uuid,
+) -> Result<(), AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to delete attachment ({attachment_id})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Attachment ({attachment_id}) doesn't exist!");
+
+    let res = sqlx::query!(
+        r#"
+        DELETE FROM attachments
+        WHERE
+            id = $1 AND
+            note_id = $2
+        "#,
+        attachment_id,
+        note_id,
+    )
+    .execute(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+
+    if res.rows_affected() == 0 {
+        Err(AppError::not_found(NOT_FOUND_ERR_STR))
+    } else {
+        Ok(())
+    }
+}
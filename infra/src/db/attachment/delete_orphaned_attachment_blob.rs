@@ -0,0 +1,26 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use common::error::AppError;
+
+pub(crate) async fn delete_orphaned_attachment_blob<'a>(
+    db: impl PgExecutor<'a>,
+    attachment_id: Uuid,
+) -> Result<(), AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR =
+        format!("Failed to delete orphaned attachment ({attachment_id}) blob entry!");
+
+    sqlx::query!(
+        r#"
+        DELETE FROM orphaned_attachment_blobs
+        WHERE attachment_id = $1
+        "#,
+        attachment_id,
+    )
+    .execute(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+
+    Ok(())
+}
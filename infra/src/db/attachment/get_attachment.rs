@@ -0,0 +1,42 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::attachment::AttachmentPg;
+use common::error::AppError;
+
+pub(crate) async fn get_attachment<'a>(
+    db: impl PgExecutor<'a>,
+    note_id: Uuid,
+    attachment_id: Uuid,
+) -> Result<AttachmentPg, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to retrieve attachment ({attachment_id})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Attachment ({attachment_id}) doesn't exist!");
+
+    sqlx::query_as!(
+        AttachmentPg,
+        r#"
+        SELECT
+            id,
+            note_id,
+            file_name,
+            mime_type,
+            size,
+            sha256,
+            created_at
+        FROM attachments
+        WHERE
+            id = $1 AND
+            note_id = $2
+        "#,
+        attachment_id,
+        note_id,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::RowNotFound => AppError::not_found(NOT_FOUND_ERR_STR),
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
@@ -0,0 +1,34 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::attachment::AttachmentPg;
+use common::error::AppError;
+
+pub(crate) async fn get_note_attachments<'a>(
+    db: impl PgExecutor<'a>,
+    note_id: Uuid,
+) -> Result<Vec<AttachmentPg>, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to retrieve note ({note_id}) attachments!");
+
+    sqlx::query_as!(
+        AttachmentPg,
+        r#"
+        SELECT
+            id,
+            note_id,
+            file_name,
+            mime_type,
+            size,
+            sha256,
+            created_at
+        FROM attachments
+        WHERE note_id = $1
+        ORDER BY created_at, id
+        "#,
+        note_id,
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
@@ -0,0 +1,24 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use common::error::AppError;
+
+const INTERNAL_ERR_STR: &str = "Failed to retrieve orphaned attachment blobs!";
+
+pub(crate) async fn get_orphaned_attachment_blobs<'a>(
+    db: impl PgExecutor<'a>,
+    limit: u32,
+) -> Result<Vec<Uuid>, AppError> {
+    sqlx::query_scalar!(
+        r#"
+        SELECT attachment_id
+        FROM orphaned_attachment_blobs
+        ORDER BY orphaned_at
+        LIMIT $1
+        "#,
+        limit as i64,
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
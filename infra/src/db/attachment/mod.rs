@@ -0,0 +1,13 @@
+mod create_attachment;
+mod delete_attachment;
+mod delete_orphaned_attachment_blob;
+mod get_attachment;
+mod get_note_attachments;
+mod get_orphaned_attachment_blobs;
+
+pub(crate) use create_attachment::*;
+pub(crate) use delete_attachment::*;
+pub(crate) use delete_orphaned_attachment_blob::*;
+pub(crate) use get_attachment::*;
+pub(crate) use get_note_attachments::*;
+pub(crate) use get_orphaned_attachment_blobs::*;
@@ -17,18 +17,21 @@ pub(crate) async fn create_access_token<'a>(
         INSERT INTO access_tokens (
             id,
             user_id,
+            session_id,
             jwt,
             expires_at
         )
-        VALUES ($1, $2, $3, $4)
+        VALUES ($1, $2, $3, $4, $5)
         RETURNING
             id,
             user_id,
+            session_id,
             jwt,
             expires_at
         "#,
         token_data.id,
         token_data.user_id,
+        token_data.session_id,
         token_data.jwt,
         token_data.expires_at,
     )
@@ -41,6 +44,7 @@ pub(crate) async fn create_access_token<'a>(
 pub(crate) struct CreateAccessTokenDataPg {
     pub(crate) id: Uuid,
     pub(crate) user_id: Uuid,
+    pub(crate) session_id: Uuid,
     pub(crate) jwt: String,
     pub(crate) expires_at: DateTime<Utc>,
 }
@@ -18,20 +18,23 @@ pub(crate) async fn create_refresh_token<'a>(
             id,
             user_id,
             access_token_id,
+            family_id,
             jwt,
             expires_at
         )
-        VALUES ($1, $2, $3, $4, $5)
+        VALUES ($1, $2, $3, $4, $5, $6)
         RETURNING
             id,
             user_id,
             access_token_id,
+            family_id,
             jwt,
             expires_at
         "#,
         token_data.id,
         token_data.user_id,
         token_data.access_token_id,
+        token_data.family_id,
         token_data.jwt,
         token_data.expires_at,
     )
@@ -45,6 +48,7 @@ pub(crate) struct CreateRefreshTokenDataPg {
     pub(crate) id: Uuid,
     pub(crate) user_id: Uuid,
     pub(crate) access_token_id: Uuid,
+    pub(crate) family_id: Uuid,
     pub(crate) jwt: String,
     pub(crate) expires_at: DateTime<Utc>,
 }
@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use sqlx::{PgExecutor, Postgres};
+use uuid::Uuid;
+
+use crate::models::token::RevokedRefreshTokenPg;
+use common::error::AppError;
+
+pub(crate) async fn create_revoked_refresh_token<'a>(
+    db: impl PgExecutor<'a, Database = Postgres>,
+    token_data: CreateRevokedRefreshTokenDataPg,
+) -> Result<RevokedRefreshTokenPg, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to revoke refresh token!";
+
+    sqlx::query_as!(
+        RevokedRefreshTokenPg,
+        r#"
+        INSERT INTO revoked_refresh_tokens (
+            id,
+            user_id,
+            family_id,
+            expires_at,
+            revoked_at
+        )
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING
+            id,
+            user_id,
+            family_id,
+            expires_at,
+            revoked_at
+        "#,
+        token_data.id,
+        token_data.user_id,
+        token_data.family_id,
+        token_data.expires_at,
+        token_data.revoked_at,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
+
+#[derive(Debug)]
+pub(crate) struct CreateRevokedRefreshTokenDataPg {
+    pub(crate) id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) family_id: Uuid,
+    pub(crate) expires_at: DateTime<Utc>,
+    pub(crate) revoked_at: DateTime<Utc>,
+}
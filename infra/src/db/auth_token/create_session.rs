@@ -0,0 +1,54 @@
+use chrono::{DateTime, Utc};
+use sqlx::{PgExecutor, Postgres};
+use uuid::Uuid;
+
+use crate::models::token::AuthSessionPg;
+use common::error::AppError;
+
+pub(crate) async fn create_session<'a>(
+    db: impl PgExecutor<'a, Database = Postgres>,
+    session_data: CreateSessionDataPg,
+) -> Result<AuthSessionPg, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to create auth session!";
+
+    sqlx::query_as!(
+        AuthSessionPg,
+        r#"
+        INSERT INTO auth_sessions (
+            id,
+            user_id,
+            ip_address,
+            user_agent,
+            created_at,
+            last_refreshed_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING
+            id,
+            user_id,
+            ip_address,
+            user_agent,
+            created_at,
+            last_refreshed_at
+        "#,
+        session_data.id,
+        session_data.user_id,
+        session_data.ip_address,
+        session_data.user_agent,
+        session_data.created_at,
+        session_data.last_refreshed_at,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
+
+#[derive(Debug)]
+pub(crate) struct CreateSessionDataPg {
+    pub(crate) id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) ip_address: Option<String>,
+    pub(crate) user_agent: Option<String>,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) last_refreshed_at: DateTime<Utc>,
+}
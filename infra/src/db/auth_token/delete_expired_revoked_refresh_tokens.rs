@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use sqlx::{PgExecutor, Postgres};
+
+use common::error::AppError;
+
+pub(crate) async fn delete_expired_revoked_refresh_tokens<'a>(
+    db: impl PgExecutor<'a, Database = Postgres>,
+    expired_before: DateTime<Utc>,
+) -> Result<u64, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to delete expired revoked refresh tokens!";
+
+    sqlx::query!(
+        r#"
+        DELETE FROM revoked_refresh_tokens
+        WHERE expires_at < $1
+        "#,
+        expired_before,
+    )
+    .execute(db)
+    .await
+    .map(|result| result.rows_affected())
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
@@ -0,0 +1,33 @@
+use sqlx::{PgExecutor, Postgres};
+use uuid::Uuid;
+
+use common::error::AppError;
+
+pub(crate) async fn delete_session<'a>(
+    db: impl PgExecutor<'a, Database = Postgres>,
+    session_id: Uuid,
+    user_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to delete auth session!";
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Auth session ({session_id}) doesn't exist!");
+
+    // Auto-cascades related access_tokens and refresh_tokens entries
+    sqlx::query!(
+        r#"
+        DELETE FROM auth_sessions
+        WHERE
+            id = $1 AND
+            ($2::uuid IS NULL OR user_id = $2)
+        "#,
+        session_id,
+        user_id,
+    )
+    .execute(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+    .and_then(|result| match result.rows_affected() {
+        0 => Err(AppError::not_found(NOT_FOUND_ERR_STR)),
+        _ => Ok(()),
+    })
+}
@@ -0,0 +1,31 @@
+use sqlx::{PgExecutor, Postgres};
+use uuid::Uuid;
+
+use common::error::AppError;
+
+pub(crate) async fn delete_token_family<'a>(
+    db: impl PgExecutor<'a, Database = Postgres>,
+    family_id: Uuid,
+) -> Result<u64, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to delete auth token family ({family_id})!");
+
+    // Auto-cascades related access_tokens and refresh_tokens entries
+    sqlx::query!(
+        r#"
+        DELETE FROM auth_sessions
+        WHERE id IN (
+            SELECT at.session_id
+            FROM refresh_tokens rt
+            INNER JOIN access_tokens at
+            ON rt.access_token_id = at.id
+            WHERE rt.family_id = $1
+        )
+        "#,
+        family_id,
+    )
+    .execute(db)
+    .await
+    .map(|result| result.rows_affected())
+    .map_err(|err| AppError::internal_with_private(&INTERNAL_ERR_STR, err.to_string()))
+}
@@ -0,0 +1,25 @@
+use sqlx::{PgExecutor, Postgres};
+use uuid::Uuid;
+
+use common::error::AppError;
+
+pub(crate) async fn delete_user_sessions<'a>(
+    db: impl PgExecutor<'a, Database = Postgres>,
+    user_id: Uuid,
+) -> Result<u64, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to delete auth sessions for user ({user_id})!");
+
+    // Auto-cascades related access_tokens and refresh_tokens entries
+    sqlx::query!(
+        r#"
+        DELETE FROM auth_sessions
+        WHERE user_id = $1
+        "#,
+        user_id,
+    )
+    .execute(db)
+    .await
+    .map(|result| result.rows_affected())
+    .map_err(|err| AppError::internal_with_private(&INTERNAL_ERR_STR, err.to_string()))
+}
@@ -23,6 +23,7 @@ pub(crate) async fn get_access_token<'a>(
         SELECT
             id,
             user_id,
+            session_id,
             jwt,
             expires_at
         FROM access_tokens
@@ -27,6 +27,7 @@ pub(crate) async fn get_refresh_token<'a>(
             id,
             user_id,
             access_token_id,
+            family_id,
             jwt,
             expires_at
         FROM refresh_tokens
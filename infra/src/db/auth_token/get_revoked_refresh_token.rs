@@ -0,0 +1,35 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::token::RevokedRefreshTokenPg;
+use common::error::AppError;
+
+pub(crate) async fn get_revoked_refresh_token<'a>(
+    db: impl PgExecutor<'a>,
+    token_id: Uuid,
+) -> Result<RevokedRefreshTokenPg, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to retrieve revoked refresh token!";
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Revoked refresh token ({token_id}) doesn't exist!");
+
+    sqlx::query_as!(
+        RevokedRefreshTokenPg,
+        r#"
+        SELECT
+            id,
+            user_id,
+            family_id,
+            expires_at,
+            revoked_at
+        FROM revoked_refresh_tokens
+        WHERE id = $1
+        "#,
+        token_id,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::RowNotFound => AppError::not_found(NOT_FOUND_ERR_STR),
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
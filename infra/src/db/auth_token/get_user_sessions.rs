@@ -0,0 +1,44 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::token::AuthSessionPg;
+use common::error::AppError;
+
+/// Sessions whose refresh token has expired can no longer be used and are left out.
+pub(crate) async fn get_user_sessions<'a>(
+    db: impl PgExecutor<'a>,
+    user_id: Uuid,
+) -> Result<Vec<AuthSessionPg>, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to retrieve auth sessions for user ({user_id})!");
+
+    sqlx::query_as!(
+        AuthSessionPg,
+        r#"
+        SELECT
+            s.id,
+            s.user_id,
+            s.ip_address,
+            s.user_agent,
+            s.created_at,
+            s.last_refreshed_at
+        FROM auth_sessions s
+        WHERE
+            s.user_id = $1 AND
+            EXISTS (
+                SELECT 1
+                FROM access_tokens at
+                INNER JOIN refresh_tokens rt
+                ON rt.access_token_id = at.id
+                WHERE
+                    at.session_id = s.id AND
+                    rt.expires_at > now()
+            )
+        ORDER BY s.last_refreshed_at DESC
+        "#,
+        user_id,
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(&INTERNAL_ERR_STR, err.to_string()))
+}
@@ -19,9 +19,11 @@ pub(crate) async fn get_user_tokens<'a>(
         SELECT
             at.user_id as user_id,
             at.id as at_id,
+            at.session_id as at_session_id,
             at.jwt as at_jwt,
             at.expires_at as at_expires_at,
             rt.id as rt_id,
+            rt.family_id as rt_family_id,
             rt.jwt as rt_jwt,
             rt.expires_at as rt_expires_at
         FROM refresh_tokens rt
@@ -34,9 +36,7 @@ pub(crate) async fn get_user_tokens<'a>(
     )
     .fetch_all(db)
     .await
-    .map_err(|err| match err {
-        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
-    })?
+    .map_err(|err| AppError::internal_with_private(&INTERNAL_ERR_STR, err.to_string()))?
     .into_iter()
     .map(|token| token.into())
     .collect();
@@ -47,9 +47,11 @@ pub(crate) async fn get_user_tokens<'a>(
 struct GetUserTokensDbRowInner {
     user_id: Uuid,
     at_id: Uuid,
+    at_session_id: Uuid,
     at_jwt: String,
     at_expires_at: DateTime<Utc>,
     rt_id: Uuid,
+    rt_family_id: Uuid,
     rt_jwt: String,
     rt_expires_at: DateTime<Utc>,
 }
@@ -60,6 +62,7 @@ impl From<GetUserTokensDbRowInner> for AuthTokenPairPg {
             access_token: AccessTokenPg {
                 id: db_res.at_id,
                 user_id: db_res.user_id,
+                session_id: db_res.at_session_id,
                 jwt: db_res.at_jwt,
                 expires_at: db_res.at_expires_at,
             },
@@ -67,6 +70,7 @@ impl From<GetUserTokensDbRowInner> for AuthTokenPairPg {
                 id: db_res.rt_id,
                 user_id: db_res.user_id,
                 access_token_id: db_res.at_id,
+                family_id: db_res.rt_family_id,
                 jwt: db_res.rt_jwt,
                 expires_at: db_res.rt_expires_at,
             },
@@ -1,13 +1,31 @@
 mod create_access_token;
 mod create_refresh_token;
+mod create_revoked_refresh_token;
+mod create_session;
+mod delete_expired_revoked_refresh_tokens;
+mod delete_session;
+mod delete_token_family;
 mod delete_token_pair;
+mod delete_user_sessions;
 mod get_access_token;
 mod get_refresh_token;
+mod get_revoked_refresh_token;
+mod get_user_sessions;
 mod get_user_tokens;
+mod touch_session;
 
 pub(crate) use create_access_token::*;
 pub(crate) use create_refresh_token::*;
+pub(crate) use create_revoked_refresh_token::*;
+pub(crate) use create_session::*;
+pub(crate) use delete_expired_revoked_refresh_tokens::*;
+pub(crate) use delete_session::*;
+pub(crate) use delete_token_family::*;
 pub(crate) use delete_token_pair::*;
+pub(crate) use delete_user_sessions::*;
 pub(crate) use get_access_token::*;
 pub(crate) use get_refresh_token::*;
+pub(crate) use get_revoked_refresh_token::*;
+pub(crate) use get_user_sessions::*;
 pub(crate) use get_user_tokens::*;
+pub(crate) use touch_session::*;
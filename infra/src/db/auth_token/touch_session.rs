@@ -0,0 +1,37 @@
+use sqlx::{PgExecutor, Postgres};
+use uuid::Uuid;
+
+use common::error::AppError;
+
+/// Client details are only overwritten when provided.
+pub(crate) async fn touch_session<'a>(
+    db: impl PgExecutor<'a, Database = Postgres>,
+    session_id: Uuid,
+    ip_address: Option<String>,
+    user_agent: Option<String>,
+) -> Result<(), AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to update auth session!";
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Auth session ({session_id}) doesn't exist!");
+
+    sqlx::query!(
+        r#"
+        UPDATE auth_sessions
+        SET
+            ip_address = COALESCE($2, ip_address),
+            user_agent = COALESCE($3, user_agent),
+            last_refreshed_at = now()
+        WHERE id = $1
+        "#,
+        session_id,
+        ip_address,
+        user_agent,
+    )
+    .execute(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+    .and_then(|result| match result.rows_affected() {
+        0 => Err(AppError::not_found(NOT_FOUND_ERR_STR)),
+        _ => Ok(()),
+    })
+}
@@ -1,10 +1,26 @@
+use chrono::{DateTime, Utc};
 use sqlx::{postgres::PgPoolOptions, PgPool};
+use uuid::Uuid;
 
-use common::error::AppError;
+use common::{
+    error::AppError,
+    params::{PageCursor, Pagination},
+};
 
+pub(crate) mod attachment;
 pub(crate) mod auth_token;
 pub(crate) mod note;
+pub(crate) mod note_event;
+pub(crate) mod note_import_job;
+pub(crate) mod note_public_link;
+pub(crate) mod note_revision;
+pub(crate) mod note_share;
+pub(crate) mod notebook;
+pub(crate) mod outbox;
+pub(crate) mod tag;
 pub(crate) mod user;
+pub(crate) mod webhook;
+pub(crate) mod webhook_delivery;
 
 pub async fn get_pg_pool(db_url: &str) -> Result<PgPool, AppError> {
     PgPoolOptions::new()
@@ -18,3 +34,52 @@ pub async fn get_pg_pool(db_url: &str) -> Result<PgPool, AppError> {
             )
         })
 }
+
+/// Flattened [`Pagination`] query arguments.
+pub(crate) struct PaginationArgs {
+    pub(crate) skip: i64,
+    /// Cursor pages fetch one extra row, later trimmed by [`take_next_cursor`].
+    pub(crate) limit: i64,
+    pub(crate) keyset: bool,
+    pub(crate) after_created_at: Option<DateTime<Utc>>,
+    pub(crate) after_id: Option<Uuid>,
+}
+
+pub(crate) fn get_pagination_args(pagination: &Pagination) -> PaginationArgs {
+    match pagination {
+        Pagination::Offset(pagination) => PaginationArgs {
+            skip: pagination.skip as i64,
+            limit: pagination.limit as i64,
+            keyset: false,
+            after_created_at: None,
+            after_id: None,
+        },
+        Pagination::Cursor(pagination) => PaginationArgs {
+            skip: 0,
+            limit: pagination.limit as i64 + 1,
+            keyset: true,
+            after_created_at: pagination.after.map(|cursor| cursor.created_at),
+            after_id: pagination.after.map(|cursor| cursor.id),
+        },
+    }
+}
+
+/// Trims the extra row of a cursor page, returning the position of the next page if it exists.
+pub(crate) fn take_next_cursor<T>(
+    rows: &mut Vec<T>,
+    pagination: &Pagination,
+    get_cursor: impl Fn(&T) -> PageCursor,
+) -> Option<PageCursor> {
+    let Pagination::Cursor(pagination) = pagination else {
+        return None;
+    };
+    let limit = pagination.limit as usize;
+    if rows.len() <= limit {
+        return None;
+    }
+    rows.truncate(limit);
+    rows.last().map(get_cursor)
+}
+
+#[cfg(test)]
+mod tests;
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use sqlx::PgExecutor;
 use uuid::Uuid;
 
@@ -15,21 +16,33 @@ pub(crate) async fn create_note<'a>(
         r#"
         INSERT INTO notes (
             user_id,
+            notebook_id,
             title,
-            content
+            content,
+            created_at,
+            updated_at
         )
-        VALUES ($1, $2, $3)
+        VALUES ($1, $2, $3, $4, COALESCE($5, now()), COALESCE($6, $5, now()))
         RETURNING
             id,
             user_id,
+            notebook_id,
             title,
             content,
+            version,
+            pinned,
+            archived,
+            favorite,
             created_at,
-            updated_at
+            updated_at,
+            deleted_at
         "#,
         note_data.user_id,
+        note_data.notebook_id,
         note_data.title,
         note_data.content,
+        note_data.created_at,
+        note_data.updated_at,
     )
     .fetch_one(db)
     .await
@@ -39,6 +52,9 @@ pub(crate) async fn create_note<'a>(
 #[derive(Debug)]
 pub(crate) struct CreateNoteDataPg {
     pub(crate) user_id: Uuid,
+    pub(crate) notebook_id: Option<Uuid>,
     pub(crate) title: String,
     pub(crate) content: String,
+    pub(crate) created_at: Option<DateTime<Utc>>,
+    pub(crate) updated_at: Option<DateTime<Utc>>,
 }
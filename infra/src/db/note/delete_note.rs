@@ -6,30 +6,41 @@ use uuid::Uuid;
 pub(crate) async fn delete_note<'a>(
     db: impl PgExecutor<'a>,
     note_id: Uuid,
+    expected_version: Option<u32>,
     user_id: Option<Uuid>,
 ) -> Result<(), AppError> {
     #[allow(non_snake_case)]
     let INTERNAL_ERR_STR = format!("Failed to delete note ({note_id})!");
     #[allow(non_snake_case)]
     let NOT_FOUND_ERR_STR = format!("Note ({note_id}) doesn't exist!");
+    #[allow(non_snake_case)]
+    let PRECONDITION_ERR_STR = format!(
+        "Note ({note_id}) doesn't exist or isn't at the expected version ({})!",
+        expected_version.unwrap_or_default()
+    );
 
+    // Soft delete, see purge_note for permanent removal
     let res = sqlx::query!(
         r#"
-        DELETE FROM notes
+        UPDATE notes
+        SET deleted_at = now()
         WHERE
             id = $1 AND
-            ($2::uuid IS NULL OR user_id = $2)
+            ($2::uuid IS NULL OR user_id = $2) AND
+            ($3::int IS NULL OR version = $3) AND
+            deleted_at IS NULL
         "#,
         note_id,
         user_id,
+        expected_version.map(|version| version as i32),
     )
     .execute(db)
     .await
     .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
 
-    if res.rows_affected() == 0 {
-        Err(AppError::not_found(NOT_FOUND_ERR_STR))
-    } else {
-        Ok(())
+    match (res.rows_affected(), expected_version) {
+        (0, Some(_)) => Err(AppError::precondition_failed(PRECONDITION_ERR_STR)),
+        (0, None) => Err(AppError::not_found(NOT_FOUND_ERR_STR)),
+        _ => Ok(()),
     }
 }
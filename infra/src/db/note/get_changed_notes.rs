@@ -0,0 +1,54 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::note::ChangedNotePg;
+use common::error::AppError;
+
+/// Returns up to `limit` changed notes, ordered by change transaction and then id, starting right
+/// after the `after` position if any.
+pub(crate) async fn get_changed_notes<'a>(
+    db: impl PgExecutor<'a>,
+    since: Option<i64>,
+    after: Option<(i64, Uuid)>,
+    limit: i64,
+    user_id: Uuid,
+) -> Result<Vec<ChangedNotePg>, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to retrieve changed notes!";
+
+    let (after_xid, after_id) = after.unzip();
+    sqlx::query_as!(
+        ChangedNotePg,
+        r#"
+        SELECT
+            id,
+            user_id,
+            notebook_id,
+            title,
+            content,
+            version,
+            pinned,
+            archived,
+            favorite,
+            created_at,
+            updated_at,
+            deleted_at,
+            change_xid::text::bigint as "change_xid!"
+        FROM notes
+        WHERE
+            user_id = $1 AND
+            deleted_at IS NULL AND
+            ($2::bigint IS NULL OR change_xid >= $2::bigint::text::xid8) AND
+            ($3::bigint IS NULL OR (change_xid, id) > ($3::bigint::text::xid8, $4::uuid))
+        ORDER BY change_xid ASC, id ASC
+        LIMIT $5
+        "#,
+        user_id,
+        since,
+        after_xid,
+        after_id,
+        limit,
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
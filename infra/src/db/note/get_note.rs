@@ -20,14 +20,21 @@ pub(crate) async fn get_note<'a>(
         SELECT
             id,
             user_id,
+            notebook_id,
             title,
             content,
+            version,
+            pinned,
+            archived,
+            favorite,
             created_at,
-            updated_at
+            updated_at,
+            deleted_at
         FROM notes
         WHERE
             id = $1 AND
-            ($2::uuid IS NULL OR user_id = $2)
+            ($2::uuid IS NULL OR user_id = $2) AND
+            deleted_at IS NULL
         "#,
         note_id,
         user_id,
@@ -1,21 +1,67 @@
 use sqlx::PgExecutor;
 use uuid::Uuid;
 
+use super::{
+    get_archived_filter_arg, get_notebook_filter_args, get_tag_filter_args,
+    get_title_prefix_filter_arg,
+};
 use common::error::AppError;
+use dmn::repos::note::GetNotesFilter;
 
 pub(crate) async fn get_note_count<'a>(
     db: impl PgExecutor<'a>,
+    filter: &GetNotesFilter,
     user_id: Option<Uuid>,
 ) -> Result<u32, AppError> {
     const INTERNAL_ERR_STR: &str = "Failed to retrieve note count!";
 
+    let (tag_ids, match_all_tags) = get_tag_filter_args(filter);
+    let (notebook_id, recursive) = get_notebook_filter_args(filter);
+    let archived = get_archived_filter_arg(filter);
+    let title_pattern = get_title_prefix_filter_arg(filter);
+
     sqlx::query_scalar!(
         r#"
         SELECT COUNT(*) as "total_count!"
         FROM notes
-        WHERE ($1::uuid IS NULL OR user_id = $1)
+        WHERE
+            ($1::uuid IS NULL OR user_id = $1) AND
+            deleted_at IS NULL AND
+            ($2::uuid[] IS NULL OR (
+                SELECT COUNT(*)
+                FROM note_tags nt
+                WHERE nt.note_id = notes.id AND nt.tag_id = ANY($2)
+            ) >= CASE WHEN $3 THEN cardinality($2) ELSE 1 END) AND
+            ($4::uuid IS NULL OR notebook_id = $4 OR ($5 AND notebook_id IN (
+                WITH RECURSIVE descendants AS (
+                    SELECT id FROM notebooks WHERE parent_id = $4
+                    UNION ALL
+                    SELECT nb.id
+                    FROM notebooks nb
+                    JOIN descendants d ON nb.parent_id = d.id
+                )
+                SELECT id FROM descendants
+            ))) AND
+            ($6::bool IS NULL OR archived = $6) AND
+            ($7::bool IS NULL OR favorite = $7) AND
+            ($8::timestamptz IS NULL OR created_at >= $8) AND
+            ($9::timestamptz IS NULL OR created_at < $9) AND
+            ($10::timestamptz IS NULL OR updated_at >= $10) AND
+            ($11::timestamptz IS NULL OR updated_at < $11) AND
+            ($12::text IS NULL OR title ILIKE $12)
         "#,
         user_id,
+        tag_ids.as_deref(),
+        match_all_tags,
+        notebook_id,
+        recursive,
+        archived,
+        filter.favorite,
+        filter.created.from,
+        filter.created.to,
+        filter.updated.from,
+        filter.updated.to,
+        title_pattern,
     )
     .fetch_one(db)
     .await
@@ -0,0 +1,58 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::note::NoteTombstonePg;
+use common::error::AppError;
+
+/// Merges trashed notes with tombstones of purged ones.<br />
+/// Returns up to `limit` of them, ordered like [`super::get_changed_notes`].
+pub(crate) async fn get_note_tombstones<'a>(
+    db: impl PgExecutor<'a>,
+    since: i64,
+    after: Option<(i64, Uuid)>,
+    limit: i64,
+    user_id: Uuid,
+) -> Result<Vec<NoteTombstonePg>, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to retrieve note tombstones!";
+
+    let (after_xid, after_id) = after.unzip();
+    sqlx::query_as!(
+        NoteTombstonePg,
+        r#"
+        SELECT
+            note_id as "note_id!",
+            deleted_at as "deleted_at!",
+            change_xid::text::bigint as "change_xid!"
+        FROM (
+            SELECT
+                id as note_id,
+                deleted_at,
+                change_xid
+            FROM notes
+            WHERE
+                user_id = $1 AND
+                deleted_at IS NOT NULL
+            UNION ALL
+            SELECT
+                note_id,
+                deleted_at,
+                change_xid
+            FROM note_tombstones
+            WHERE user_id = $1
+        ) tombstones
+        WHERE
+            change_xid >= $2::bigint::text::xid8 AND
+            ($3::bigint IS NULL OR (change_xid, note_id) > ($3::bigint::text::xid8, $4::uuid))
+        ORDER BY change_xid ASC, note_id ASC
+        LIMIT $5
+        "#,
+        user_id,
+        since,
+        after_xid,
+        after_id,
+        limit,
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
@@ -1,37 +1,170 @@
 use sqlx::PgExecutor;
 use uuid::Uuid;
 
-use crate::models::note::NotePg;
-use common::{error::AppError, params::PaginationParams};
+use crate::{db::get_pagination_args, models::note::NotePg};
+use common::{
+    error::AppError,
+    params::{Pagination, SortDirection},
+};
+use dmn::repos::note::{
+    ArchivedFilter, GetNotesFilter, NoteSort, NoteSortField, NotebookFilter, TagFilter,
+};
 
 pub(crate) async fn get_notes<'a>(
     db: impl PgExecutor<'a>,
-    pagination: &PaginationParams,
+    pagination: &Pagination,
+    filter: &GetNotesFilter,
+    sort: &NoteSort,
     user_id: Option<Uuid>,
 ) -> Result<Vec<NotePg>, AppError> {
     const INTERNAL_ERR_STR: &str = "Failed to retrieve notes!";
 
+    let (tag_ids, match_all_tags) = get_tag_filter_args(filter);
+    let (notebook_id, recursive) = get_notebook_filter_args(filter);
+    let archived = get_archived_filter_arg(filter);
+    let title_pattern = get_title_prefix_filter_arg(filter);
+    let pagination = get_pagination_args(pagination);
+    let (sort_field, sort_desc) = if pagination.keyset {
+        ("created_at", sort.direction == SortDirection::Desc)
+    } else {
+        get_sort_args(sort)
+    };
+
     sqlx::query_as!(
         NotePg,
         r#"
         SELECT
             id,
             user_id,
+            notebook_id,
             title,
             content,
+            version,
+            pinned,
+            archived,
+            favorite,
             created_at,
-            updated_at
+            updated_at,
+            deleted_at
         FROM notes
-        WHERE ($1::uuid IS NULL OR user_id = $1)
-        ORDER BY created_at DESC
+        WHERE
+            ($1::uuid IS NULL OR user_id = $1) AND
+            deleted_at IS NULL AND
+            ($4::uuid[] IS NULL OR (
+                SELECT COUNT(*)
+                FROM note_tags nt
+                WHERE nt.note_id = notes.id AND nt.tag_id = ANY($4)
+            ) >= CASE WHEN $5 THEN cardinality($4) ELSE 1 END) AND
+            ($6::uuid IS NULL OR notebook_id = $6 OR ($7 AND notebook_id IN (
+                WITH RECURSIVE descendants AS (
+                    SELECT id FROM notebooks WHERE parent_id = $6
+                    UNION ALL
+                    SELECT nb.id
+                    FROM notebooks nb
+                    JOIN descendants d ON nb.parent_id = d.id
+                )
+                SELECT id FROM descendants
+            ))) AND
+            ($8::bool IS NULL OR archived = $8) AND
+            ($9::bool IS NULL OR favorite = $9) AND
+            ($10::timestamptz IS NULL OR created_at >= $10) AND
+            ($11::timestamptz IS NULL OR created_at < $11) AND
+            ($12::timestamptz IS NULL OR updated_at >= $12) AND
+            ($13::timestamptz IS NULL OR updated_at < $13) AND
+            ($14::text IS NULL OR title ILIKE $14) AND
+            ($18::timestamptz IS NULL OR CASE
+                WHEN $16 THEN (created_at, id) < ($18, $19::uuid)
+                ELSE (created_at, id) > ($18, $19::uuid)
+            END)
+        ORDER BY
+            CASE WHEN NOT $17 THEN pinned END DESC,
+            CASE WHEN $15::text = 'title' AND NOT $16 THEN lower(title) END ASC,
+            CASE WHEN $15::text = 'title' AND $16 THEN lower(title) END DESC,
+            CASE WHEN $15::text = 'updated_at' AND NOT $16 THEN updated_at END ASC,
+            CASE WHEN $15::text = 'updated_at' AND $16 THEN updated_at END DESC,
+            CASE WHEN $15::text = 'created_at' AND NOT $16 THEN created_at END ASC,
+            created_at DESC,
+            CASE WHEN $16 THEN id END DESC,
+            id
         OFFSET $2
         LIMIT $3
         "#,
         user_id,
-        pagination.skip as i64,
-        pagination.limit as i64,
+        pagination.skip,
+        pagination.limit,
+        tag_ids.as_deref(),
+        match_all_tags,
+        notebook_id,
+        recursive,
+        archived,
+        filter.favorite,
+        filter.created.from,
+        filter.created.to,
+        filter.updated.from,
+        filter.updated.to,
+        title_pattern,
+        sort_field,
+        sort_desc,
+        pagination.keyset,
+        pagination.after_created_at,
+        pagination.after_id,
     )
     .fetch_all(db)
     .await
     .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
 }
+
+/// Flattens a [`TagFilter`] into deduplicated tag ids and an all-of matching flag.
+pub(crate) fn get_tag_filter_args(filter: &GetNotesFilter) -> (Option<Vec<Uuid>>, bool) {
+    match filter.tags {
+        Some(ref tag_filter) => {
+            let mut tag_ids = tag_filter.tag_ids().to_vec();
+            tag_ids.sort_unstable();
+            tag_ids.dedup();
+            let match_all = matches!(tag_filter, TagFilter::AllOf(_));
+            (Some(tag_ids), match_all)
+        }
+        None => (None, false),
+    }
+}
+
+/// Flattens a [`NotebookFilter`] into a notebook id and a recursive matching flag.
+pub(crate) fn get_notebook_filter_args(filter: &GetNotesFilter) -> (Option<Uuid>, bool) {
+    match filter.notebook {
+        Some(NotebookFilter {
+            notebook_id,
+            recursive,
+        }) => (Some(notebook_id), recursive),
+        None => (None, false),
+    }
+}
+
+/// Flattens an [`ArchivedFilter`] into the expected archived state, if any.
+pub(crate) fn get_archived_filter_arg(filter: &GetNotesFilter) -> Option<bool> {
+    match filter.archived {
+        ArchivedFilter::Exclude => Some(false),
+        ArchivedFilter::Include => None,
+        ArchivedFilter::Only => Some(true),
+    }
+}
+
+/// Turns the title prefix into a case insensitive `ILIKE` pattern, escaping its wildcards.
+pub(crate) fn get_title_prefix_filter_arg(filter: &GetNotesFilter) -> Option<String> {
+    filter.title_prefix.as_ref().map(|prefix| {
+        let escaped_prefix = prefix
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_");
+        format!("{escaped_prefix}%")
+    })
+}
+
+/// Flattens a [`NoteSort`] into a sort column name and a descending flag.
+fn get_sort_args(sort: &NoteSort) -> (&'static str, bool) {
+    let sort_field = match sort.field {
+        NoteSortField::CreatedAt => "created_at",
+        NoteSortField::UpdatedAt => "updated_at",
+        NoteSortField::Title => "title",
+    };
+    (sort_field, sort.direction == SortDirection::Desc)
+}
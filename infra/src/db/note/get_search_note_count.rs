@@ -0,0 +1,29 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use common::error::AppError;
+
+pub(crate) async fn get_search_note_count<'a>(
+    db: impl PgExecutor<'a>,
+    query: &str,
+    user_id: Option<Uuid>,
+) -> Result<u32, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to retrieve note search count!";
+
+    sqlx::query_scalar!(
+        r#"
+        SELECT COUNT(*) as "total_count!"
+        FROM notes
+        WHERE
+            search_vector @@ websearch_to_tsquery('english', $1) AND
+            ($2::uuid IS NULL OR user_id = $2) AND
+            deleted_at IS NULL
+        "#,
+        query,
+        user_id,
+    )
+    .fetch_one(db)
+    .await
+    .map(|count| count as u32)
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
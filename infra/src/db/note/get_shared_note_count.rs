@@ -0,0 +1,27 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use common::error::AppError;
+
+pub(crate) async fn get_shared_note_count<'a>(
+    db: impl PgExecutor<'a>,
+    user_id: Uuid,
+) -> Result<u32, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to retrieve shared note count!";
+
+    sqlx::query_scalar!(
+        r#"
+        SELECT COUNT(*) as "total_count!"
+        FROM notes n
+        JOIN note_shares s ON s.note_id = n.id
+        WHERE
+            s.user_id = $1 AND
+            n.deleted_at IS NULL
+        "#,
+        user_id,
+    )
+    .fetch_one(db)
+    .await
+    .map(|count| count as u32)
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
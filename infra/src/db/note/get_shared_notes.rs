@@ -0,0 +1,46 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::note::NotePg;
+use common::{error::AppError, params::PaginationParams};
+
+pub(crate) async fn get_shared_notes<'a>(
+    db: impl PgExecutor<'a>,
+    pagination: &PaginationParams,
+    user_id: Uuid,
+) -> Result<Vec<NotePg>, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to retrieve shared notes!";
+
+    sqlx::query_as!(
+        NotePg,
+        r#"
+        SELECT
+            n.id,
+            n.user_id,
+            n.notebook_id,
+            n.title,
+            n.content,
+            n.version,
+            n.pinned,
+            n.archived,
+            n.favorite,
+            n.created_at,
+            n.updated_at,
+            n.deleted_at
+        FROM notes n
+        JOIN note_shares s ON s.note_id = n.id
+        WHERE
+            s.user_id = $1 AND
+            n.deleted_at IS NULL
+        ORDER BY s.created_at DESC
+        OFFSET $2
+        LIMIT $3
+        "#,
+        user_id,
+        pagination.skip as i64,
+        pagination.limit as i64,
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
@@ -0,0 +1,17 @@
+use sqlx::PgExecutor;
+
+use common::error::AppError;
+
+/// Returns the oldest transaction id still running, every older change being visible from now on.
+pub(crate) async fn get_sync_token<'a>(db: impl PgExecutor<'a>) -> Result<i64, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to retrieve sync token!";
+
+    sqlx::query_scalar!(
+        r#"
+        SELECT pg_snapshot_xmin(pg_current_snapshot())::text::bigint as "sync_token!"
+        "#
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
@@ -0,0 +1,45 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::note::NotePg;
+use common::{error::AppError, params::PaginationParams};
+
+pub(crate) async fn get_trashed_notes<'a>(
+    db: impl PgExecutor<'a>,
+    pagination: &PaginationParams,
+    user_id: Option<Uuid>,
+) -> Result<Vec<NotePg>, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to retrieve trashed notes!";
+
+    sqlx::query_as!(
+        NotePg,
+        r#"
+        SELECT
+            id,
+            user_id,
+            notebook_id,
+            title,
+            content,
+            version,
+            pinned,
+            archived,
+            favorite,
+            created_at,
+            updated_at,
+            deleted_at
+        FROM notes
+        WHERE
+            ($1::uuid IS NULL OR user_id = $1) AND
+            deleted_at IS NOT NULL
+        ORDER BY deleted_at DESC
+        OFFSET $2
+        LIMIT $3
+        "#,
+        user_id,
+        pagination.skip as i64,
+        pagination.limit as i64,
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
@@ -0,0 +1,24 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use common::error::AppError;
+
+/// Locks the note until the end of the transaction.
+pub(crate) async fn lock_note<'a>(db: impl PgExecutor<'a>, note_id: Uuid) -> Result<(), AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to lock note ({note_id})!");
+
+    sqlx::query!(
+        r#"
+        SELECT id
+        FROM notes
+        WHERE id = $1
+        FOR UPDATE
+        "#,
+        note_id,
+    )
+    .fetch_optional(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+    Ok(())
+}
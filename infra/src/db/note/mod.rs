@@ -1,13 +1,45 @@
 mod create_note;
 mod delete_note;
+mod get_changed_notes;
 mod get_note;
 mod get_note_count;
+mod get_note_tombstones;
 mod get_notes;
+mod get_search_note_count;
+mod get_shared_note_count;
+mod get_shared_notes;
+mod get_sync_token;
+mod get_trashed_note_count;
+mod get_trashed_notes;
+mod lock_note;
+mod move_note;
+mod patch_note;
+mod purge_note;
+mod purge_trashed_notes;
+mod restore_note;
+mod search_notes;
 mod update_note;
+mod update_note_flags;
 
 pub(crate) use create_note::*;
 pub(crate) use delete_note::*;
+pub(crate) use get_changed_notes::*;
 pub(crate) use get_note::*;
 pub(crate) use get_note_count::*;
+pub(crate) use get_note_tombstones::*;
 pub(crate) use get_notes::*;
+pub(crate) use get_search_note_count::*;
+pub(crate) use get_shared_note_count::*;
+pub(crate) use get_shared_notes::*;
+pub(crate) use get_sync_token::*;
+pub(crate) use get_trashed_note_count::*;
+pub(crate) use get_trashed_notes::*;
+pub(crate) use lock_note::*;
+pub(crate) use move_note::*;
+pub(crate) use patch_note::*;
+pub(crate) use purge_note::*;
+pub(crate) use purge_trashed_notes::*;
+pub(crate) use restore_note::*;
+pub(crate) use search_notes::*;
 pub(crate) use update_note::*;
+pub(crate) use update_note_flags::*;
@@ -0,0 +1,51 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::note::NotePg;
+use common::error::AppError;
+
+pub(crate) async fn move_note<'a>(
+    db: impl PgExecutor<'a>,
+    note_id: Uuid,
+    notebook_id: Option<Uuid>,
+    user_id: Option<Uuid>,
+) -> Result<NotePg, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to move note ({note_id})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Note ({note_id}) doesn't exist!");
+
+    sqlx::query_as!(
+        NotePg,
+        r#"
+        UPDATE notes
+        SET notebook_id = $3
+        WHERE
+            id = $1 AND
+            ($2::uuid IS NULL OR user_id = $2) AND
+            deleted_at IS NULL
+        RETURNING
+            id,
+            user_id,
+            notebook_id,
+            title,
+            content,
+            version,
+            pinned,
+            archived,
+            favorite,
+            created_at,
+            updated_at,
+            deleted_at
+        "#,
+        note_id,
+        user_id,
+        notebook_id,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::RowNotFound => AppError::not_found(NOT_FOUND_ERR_STR),
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
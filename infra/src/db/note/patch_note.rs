@@ -0,0 +1,142 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::note::NotePg;
+use common::error::AppError;
+
+pub(crate) async fn patch_note<'a>(
+    db: impl PgExecutor<'a>,
+    note_id: Uuid,
+    note_data: PatchNoteDataPg,
+    expected_version: Option<u32>,
+    user_id: Option<Uuid>,
+) -> Result<NotePg, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to update note ({note_id})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Note ({note_id}) doesn't exist!");
+    #[allow(non_snake_case)]
+    let PRECONDITION_ERR_STR = format!(
+        "Note ({note_id}) doesn't exist or isn't at the expected version ({})!",
+        expected_version.unwrap_or_default()
+    );
+
+    // Only the supplied columns are written
+    let expected_version = expected_version.map(|version| version as i32);
+    let result = match (note_data.title, note_data.content) {
+        (Some(title), Some(content)) => {
+            sqlx::query_as!(
+                NotePg,
+                r#"
+                UPDATE notes
+                SET title = $3, content = $4
+                WHERE
+                    id = $1 AND
+                    ($2::uuid IS NULL OR user_id = $2) AND
+                    ($5::int IS NULL OR version = $5) AND
+                    deleted_at IS NULL
+                RETURNING
+                    id,
+                    user_id,
+                    notebook_id,
+                    title,
+                    content,
+                    version,
+                    pinned,
+                    archived,
+                    favorite,
+                    created_at,
+                    updated_at,
+                    deleted_at
+                "#,
+                note_id,
+                user_id,
+                title,
+                content,
+                expected_version,
+            )
+            .fetch_one(db)
+            .await
+        }
+        (Some(title), None) => {
+            sqlx::query_as!(
+                NotePg,
+                r#"
+                UPDATE notes
+                SET title = $3
+                WHERE
+                    id = $1 AND
+                    ($2::uuid IS NULL OR user_id = $2) AND
+                    ($4::int IS NULL OR version = $4) AND
+                    deleted_at IS NULL
+                RETURNING
+                    id,
+                    user_id,
+                    notebook_id,
+                    title,
+                    content,
+                    version,
+                    pinned,
+                    archived,
+                    favorite,
+                    created_at,
+                    updated_at,
+                    deleted_at
+                "#,
+                note_id,
+                user_id,
+                title,
+                expected_version,
+            )
+            .fetch_one(db)
+            .await
+        }
+        (None, Some(content)) => {
+            sqlx::query_as!(
+                NotePg,
+                r#"
+                UPDATE notes
+                SET content = $3
+                WHERE
+                    id = $1 AND
+                    ($2::uuid IS NULL OR user_id = $2) AND
+                    ($4::int IS NULL OR version = $4) AND
+                    deleted_at IS NULL
+                RETURNING
+                    id,
+                    user_id,
+                    notebook_id,
+                    title,
+                    content,
+                    version,
+                    pinned,
+                    archived,
+                    favorite,
+                    created_at,
+                    updated_at,
+                    deleted_at
+                "#,
+                note_id,
+                user_id,
+                content,
+                expected_version,
+            )
+            .fetch_one(db)
+            .await
+        }
+        (None, None) => return Err(AppError::bad_request("No note fields to update!")),
+    };
+    result.map_err(|err| match err {
+        sqlx::Error::RowNotFound if expected_version.is_some() => {
+            AppError::precondition_failed(PRECONDITION_ERR_STR)
+        }
+        sqlx::Error::RowNotFound => AppError::not_found(NOT_FOUND_ERR_STR),
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
+
+#[derive(Debug)]
+pub(crate) struct PatchNoteDataPg {
+    pub(crate) title: Option<String>,
+    pub(crate) content: Option<String>,
+}
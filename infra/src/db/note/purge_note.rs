@@ -0,0 +1,37 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use common::error::AppError;
+
+pub(crate) async fn purge_note<'a>(
+    db: impl PgExecutor<'a>,
+    note_id: Uuid,
+    user_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to purge note ({note_id})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Trashed note ({note_id}) doesn't exist!");
+
+    // Auto-cascades related note_tags and note_revisions entries
+    let res = sqlx::query!(
+        r#"
+        DELETE FROM notes
+        WHERE
+            id = $1 AND
+            ($2::uuid IS NULL OR user_id = $2) AND
+            deleted_at IS NOT NULL
+        "#,
+        note_id,
+        user_id,
+    )
+    .execute(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+
+    if res.rows_affected() == 0 {
+        Err(AppError::not_found(NOT_FOUND_ERR_STR))
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use common::error::AppError;
+
+pub(crate) async fn purge_trashed_notes<'a>(
+    db: impl PgExecutor<'a>,
+    trashed_before: DateTime<Utc>,
+    user_id: Option<Uuid>,
+) -> Result<u32, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to purge trashed notes!";
+
+    // Auto-cascades related note_tags and note_revisions entries
+    let res = sqlx::query!(
+        r#"
+        DELETE FROM notes
+        WHERE
+            deleted_at < $1 AND
+            ($2::uuid IS NULL OR user_id = $2)
+        "#,
+        trashed_before,
+        user_id,
+    )
+    .execute(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+
+    Ok(res.rows_affected() as u32)
+}
@@ -0,0 +1,49 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::note::NotePg;
+use common::error::AppError;
+
+pub(crate) async fn restore_note<'a>(
+    db: impl PgExecutor<'a>,
+    note_id: Uuid,
+    user_id: Option<Uuid>,
+) -> Result<NotePg, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to restore note ({note_id})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Trashed note ({note_id}) doesn't exist!");
+
+    sqlx::query_as!(
+        NotePg,
+        r#"
+        UPDATE notes
+        SET deleted_at = NULL
+        WHERE
+            id = $1 AND
+            ($2::uuid IS NULL OR user_id = $2) AND
+            deleted_at IS NOT NULL
+        RETURNING
+            id,
+            user_id,
+            notebook_id,
+            title,
+            content,
+            version,
+            pinned,
+            archived,
+            favorite,
+            created_at,
+            updated_at,
+            deleted_at
+        "#,
+        note_id,
+        user_id,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::RowNotFound => AppError::not_found(NOT_FOUND_ERR_STR),
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
@@ -0,0 +1,64 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::note::NoteSearchResultPg;
+use common::{error::AppError, params::PaginationParams};
+
+pub(crate) async fn search_notes<'a>(
+    db: impl PgExecutor<'a>,
+    query: &str,
+    pagination: &PaginationParams,
+    user_id: Option<Uuid>,
+) -> Result<Vec<NoteSearchResultPg>, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to search notes!";
+
+    // Snippets are escaped before highlighting, leaving the highlight tags as their only markup
+    sqlx::query_as!(
+        NoteSearchResultPg,
+        r#"
+        SELECT
+            id,
+            user_id,
+            notebook_id,
+            title,
+            content,
+            version,
+            pinned,
+            archived,
+            favorite,
+            created_at,
+            updated_at,
+            deleted_at,
+            ts_rank(search_vector, search_query) as "rank!",
+            ts_headline(
+                'english',
+                html_escape(title),
+                search_query,
+                'HighlightAll=true, StartSel=<mark>, StopSel=</mark>'
+            ) as "title_snippet!",
+            ts_headline(
+                'english',
+                html_escape(content),
+                search_query,
+                'MaxFragments=2, StartSel=<mark>, StopSel=</mark>'
+            ) as "content_snippet!"
+        FROM notes, websearch_to_tsquery('english', $1) search_query
+        WHERE
+            search_vector @@ search_query AND
+            ($2::uuid IS NULL OR user_id = $2) AND
+            deleted_at IS NULL
+        ORDER BY
+            ts_rank(search_vector, search_query) DESC,
+            created_at DESC
+        OFFSET $3
+        LIMIT $4
+        "#,
+        query,
+        user_id,
+        pagination.skip as i64,
+        pagination.limit as i64,
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
@@ -8,12 +8,18 @@ pub(crate) async fn update_note<'a>(
     db: impl PgExecutor<'a>,
     note_id: Uuid,
     note_data: UpdateNoteDataPg,
+    expected_version: Option<u32>,
     user_id: Option<Uuid>,
 ) -> Result<NotePg, AppError> {
     #[allow(non_snake_case)]
     let INTERNAL_ERR_STR = format!("Failed to update note ({note_id})!");
     #[allow(non_snake_case)]
     let NOT_FOUND_ERR_STR = format!("Note ({note_id}) doesn't exist!");
+    #[allow(non_snake_case)]
+    let PRECONDITION_ERR_STR = format!(
+        "Note ({note_id}) doesn't exist or isn't at the expected version ({})!",
+        expected_version.unwrap_or_default()
+    );
 
     sqlx::query_as!(
         NotePg,
@@ -24,23 +30,35 @@ pub(crate) async fn update_note<'a>(
             content = $4
         WHERE
             id = $1 AND
-            ($2::uuid IS NULL OR user_id = $2)
+            ($2::uuid IS NULL OR user_id = $2) AND
+            ($5::int IS NULL OR version = $5) AND
+            deleted_at IS NULL
         RETURNING
             id,
             user_id,
+            notebook_id,
             title,
             content,
+            version,
+            pinned,
+            archived,
+            favorite,
             created_at,
-            updated_at
+            updated_at,
+            deleted_at
         "#,
         note_id,
         user_id,
         note_data.title,
         note_data.content,
+        expected_version.map(|version| version as i32),
     )
     .fetch_one(db)
     .await
     .map_err(|err| match err {
+        sqlx::Error::RowNotFound if expected_version.is_some() => {
+            AppError::precondition_failed(PRECONDITION_ERR_STR)
+        }
         sqlx::Error::RowNotFound => AppError::not_found(NOT_FOUND_ERR_STR),
         _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
     })
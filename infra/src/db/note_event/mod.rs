@@ -0,0 +1,3 @@
+mod notify_note_event;
+
+pub(crate) use notify_note_event::*;
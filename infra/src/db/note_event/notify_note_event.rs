@@ -0,0 +1,28 @@
+use sqlx::PgExecutor;
+
+use crate::models::note_event::NoteEventPg;
+use common::error::AppError;
+
+/// Channel every instance listens on for note events.
+pub(crate) const NOTE_EVENTS_CHANNEL: &str = "note_events";
+
+pub(crate) async fn notify_note_event<'a>(
+    db: impl PgExecutor<'a>,
+    event: &NoteEventPg,
+) -> Result<(), AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to publish note event!";
+
+    let payload = serde_json::to_string(event)
+        .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+    sqlx::query!(
+        r#"
+        SELECT pg_notify($1, $2)
+        "#,
+        NOTE_EVENTS_CHANNEL,
+        payload,
+    )
+    .execute(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgExecutor;
+
+use crate::models::note_import_job::NoteImportJobPg;
+use common::error::AppError;
+
+/// Marks the oldest pending job, or running job whose lease expired, as running until `lease_until`
+/// and returns it, skipping the ones concurrently being claimed.
+pub(crate) async fn claim_note_import_job<'a>(
+    db: impl PgExecutor<'a>,
+    lease_until: DateTime<Utc>,
+) -> Result<Option<NoteImportJobPg>, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to claim note import job!";
+
+    sqlx::query_as!(
+        NoteImportJobPg,
+        r#"
+        UPDATE note_import_jobs jobs
+        SET
+            status = 'running',
+            attempts = jobs.attempts + 1,
+            lease_expires_at = $1
+        FROM (
+            SELECT id
+            FROM note_import_jobs
+            WHERE
+                status = 'pending' OR
+                (status = 'running' AND lease_expires_at <= now())
+            ORDER BY created_at ASC
+            LIMIT 1
+            FOR UPDATE SKIP LOCKED
+        ) claimable
+        WHERE jobs.id = claimable.id
+        RETURNING
+            jobs.id,
+            jobs.user_id,
+            jobs.format,
+            jobs.status,
+            jobs.total_count,
+            jobs.processed_count,
+            jobs.imported_count,
+            jobs.failures::text AS "failures!",
+            jobs.error,
+            jobs.attempts,
+            jobs.created_at,
+            jobs.updated_at,
+            jobs.finished_at
+        "#,
+        lease_until,
+    )
+    .fetch_optional(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
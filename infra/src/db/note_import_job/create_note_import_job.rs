@@ -0,0 +1,51 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::note_import_job::NoteImportJobPg;
+use common::error::AppError;
+
+pub(crate) async fn create_note_import_job<'a>(
+    db: impl PgExecutor<'a>,
+    job_data: CreateNoteImportJobDataPg,
+) -> Result<NoteImportJobPg, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to create note import job!";
+
+    sqlx::query_as!(
+        NoteImportJobPg,
+        r#"
+        INSERT INTO note_import_jobs (
+            id,
+            user_id,
+            format
+        )
+        VALUES ($1, $2, $3)
+        RETURNING
+            id,
+            user_id,
+            format,
+            status,
+            total_count,
+            processed_count,
+            imported_count,
+            failures::text AS "failures!",
+            error,
+            attempts,
+            created_at,
+            updated_at,
+            finished_at
+        "#,
+        job_data.id,
+        job_data.user_id,
+        job_data.format,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
+
+#[derive(Debug)]
+pub(crate) struct CreateNoteImportJobDataPg {
+    pub(crate) id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) format: String,
+}
@@ -0,0 +1,48 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::note_import_job::NoteImportJobPg;
+use common::error::AppError;
+
+pub(crate) async fn get_note_import_job<'a>(
+    db: impl PgExecutor<'a>,
+    job_id: Uuid,
+    user_id: Option<Uuid>,
+) -> Result<NoteImportJobPg, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to retrieve note import job ({job_id})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Note import job ({job_id}) doesn't exist!");
+
+    sqlx::query_as!(
+        NoteImportJobPg,
+        r#"
+        SELECT
+            id,
+            user_id,
+            format,
+            status,
+            total_count,
+            processed_count,
+            imported_count,
+            failures::text AS "failures!",
+            error,
+            attempts,
+            created_at,
+            updated_at,
+            finished_at
+        FROM note_import_jobs
+        WHERE
+            id = $1 AND
+            ($2::uuid IS NULL OR user_id = $2)
+        "#,
+        job_id,
+        user_id,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::RowNotFound => AppError::not_found(NOT_FOUND_ERR_STR),
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
@@ -0,0 +1,9 @@
+mod claim_note_import_job;
+mod create_note_import_job;
+mod get_note_import_job;
+mod update_note_import_job;
+
+pub(crate) use claim_note_import_job::*;
+pub(crate) use create_note_import_job::*;
+pub(crate) use get_note_import_job::*;
+pub(crate) use update_note_import_job::*;
@@ -0,0 +1,76 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::note_import_job::{NoteImportFailurePg, NoteImportJobPg};
+use common::error::AppError;
+
+pub(crate) async fn update_note_import_job<'a>(
+    db: impl PgExecutor<'a>,
+    job_id: Uuid,
+    job_data: UpdateNoteImportJobDataPg,
+) -> Result<NoteImportJobPg, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to update note import job ({job_id})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Note import job ({job_id}) doesn't exist!");
+
+    let failures = serde_json::to_string(&job_data.failures)
+        .map_err(|err| AppError::internal_with_private(&INTERNAL_ERR_STR, err.to_string()))?;
+
+    sqlx::query_as!(
+        NoteImportJobPg,
+        r#"
+        UPDATE note_import_jobs
+        SET
+            status = $2,
+            total_count = $3,
+            processed_count = $4,
+            imported_count = $5,
+            failures = $6::text::jsonb,
+            error = $7,
+            finished_at = $8,
+            lease_expires_at = CASE WHEN $2 = 'running' THEN lease_expires_at END
+        WHERE id = $1
+        RETURNING
+            id,
+            user_id,
+            format,
+            status,
+            total_count,
+            processed_count,
+            imported_count,
+            failures::text AS "failures!",
+            error,
+            attempts,
+            created_at,
+            updated_at,
+            finished_at
+        "#,
+        job_id,
+        job_data.status,
+        job_data.total_count,
+        job_data.processed_count,
+        job_data.imported_count,
+        failures,
+        job_data.error,
+        job_data.finished_at,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::RowNotFound => AppError::not_found(NOT_FOUND_ERR_STR),
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
+
+#[derive(Debug)]
+pub(crate) struct UpdateNoteImportJobDataPg {
+    pub(crate) status: String,
+    pub(crate) total_count: Option<i32>,
+    pub(crate) processed_count: i32,
+    pub(crate) imported_count: i32,
+    pub(crate) failures: Vec<NoteImportFailurePg>,
+    pub(crate) error: Option<String>,
+    pub(crate) finished_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,56 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::note_public_link::NotePublicLinkPg;
+use common::error::AppError;
+
+pub(crate) async fn create_note_public_link<'a>(
+    db: impl PgExecutor<'a>,
+    link_data: CreateNotePublicLinkDataPg,
+) -> Result<NotePublicLinkPg, AppError> {
+    let CreateNotePublicLinkDataPg {
+        note_id,
+        expires_at,
+    } = link_data;
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to create note ({note_id}) public link!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Note ({note_id}) doesn't exist!");
+
+    sqlx::query_as!(
+        NotePublicLinkPg,
+        r#"
+        INSERT INTO note_public_links (
+            note_id,
+            expires_at
+        )
+        VALUES ($1, $2)
+        ON CONFLICT (note_id) DO UPDATE SET
+            token = DEFAULT,
+            expires_at = EXCLUDED.expires_at,
+            created_at = now()
+        RETURNING
+            note_id,
+            token,
+            expires_at,
+            created_at
+        "#,
+        note_id,
+        expires_at,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::Database(ref db_err) if db_err.is_foreign_key_violation() => {
+            AppError::not_found(NOT_FOUND_ERR_STR)
+        }
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
+
+#[derive(Debug)]
+pub(crate) struct CreateNotePublicLinkDataPg {
+    pub(crate) note_id: Uuid,
+    pub(crate) expires_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,31 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use common::error::AppError;
+
+pub(crate) async fn delete_note_public_link<'a>(
+    db: impl PgExecutor<'a>,
+    note_id: Uuid,
+) -> Result<(), AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to revoke note ({note_id}) public link!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Note ({note_id}) has no public link!");
+
+    let res = sqlx::query!(
+        r#"
+        DELETE FROM note_public_links
+        WHERE note_id = $1
+        "#,
+        note_id,
+    )
+    .execute(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+
+    if res.rows_affected() == 0 {
+        Err(AppError::not_found(NOT_FOUND_ERR_STR))
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::note_public_link::NotePublicLinkPg;
+use common::error::AppError;
+
+pub(crate) async fn get_note_public_link<'a>(
+    db: impl PgExecutor<'a>,
+    note_id: Uuid,
+) -> Result<NotePublicLinkPg, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to retrieve note ({note_id}) public link!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Note ({note_id}) has no public link!");
+
+    sqlx::query_as!(
+        NotePublicLinkPg,
+        r#"
+        SELECT
+            note_id,
+            token,
+            expires_at,
+            created_at
+        FROM note_public_links
+        WHERE note_id = $1
+        "#,
+        note_id,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::RowNotFound => AppError::not_found(NOT_FOUND_ERR_STR),
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
@@ -0,0 +1,36 @@
+use sqlx::PgExecutor;
+
+use crate::models::note_public_link::NotePublicLinkPg;
+use common::error::AppError;
+
+const INTERNAL_ERR_STR: &str = "Failed to retrieve public link!";
+const NOT_FOUND_ERR_STR: &str = "Public link doesn't exist!";
+
+/// Links of trashed notes are left out, as if they didn't exist.
+pub(crate) async fn get_note_public_link_by_token<'a>(
+    db: impl PgExecutor<'a>,
+    token: &str,
+) -> Result<NotePublicLinkPg, AppError> {
+    sqlx::query_as!(
+        NotePublicLinkPg,
+        r#"
+        SELECT
+            l.note_id,
+            l.token,
+            l.expires_at,
+            l.created_at
+        FROM note_public_links l
+        JOIN notes n ON n.id = l.note_id
+        WHERE
+            l.token = $1 AND
+            n.deleted_at IS NULL
+        "#,
+        token,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::RowNotFound => AppError::not_found(NOT_FOUND_ERR_STR),
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
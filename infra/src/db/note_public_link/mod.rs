@@ -0,0 +1,9 @@
+mod create_note_public_link;
+mod delete_note_public_link;
+mod get_note_public_link;
+mod get_note_public_link_by_token;
+
+pub(crate) use create_note_public_link::*;
+pub(crate) use delete_note_public_link::*;
+pub(crate) use get_note_public_link::*;
+pub(crate) use get_note_public_link_by_token::*;
@@ -0,0 +1,65 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::note_revision::NoteRevisionPg;
+use common::error::AppError;
+
+pub(crate) async fn create_note_revision<'a>(
+    db: impl PgExecutor<'a>,
+    revision_data: CreateNoteRevisionDataPg,
+) -> Result<NoteRevisionPg, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!(
+        "Failed to create note ({}) revision!",
+        revision_data.note_id
+    );
+    #[allow(non_snake_case)]
+    let CONFLICT_ERR_STR = format!(
+        "Note ({}) was modified concurrently, please retry!",
+        revision_data.note_id
+    );
+
+    sqlx::query_as!(
+        NoteRevisionPg,
+        r#"
+        INSERT INTO note_revisions (
+            note_id,
+            revision,
+            title,
+            content
+        )
+        SELECT
+            $1,
+            COALESCE(MAX(revision), 0) + 1,
+            $2,
+            $3
+        FROM note_revisions
+        WHERE note_id = $1
+        RETURNING
+            id,
+            note_id,
+            revision,
+            title,
+            content,
+            created_at
+        "#,
+        revision_data.note_id,
+        revision_data.title,
+        revision_data.content,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => {
+            AppError::conflict(CONFLICT_ERR_STR)
+        }
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
+
+#[derive(Debug)]
+pub(crate) struct CreateNoteRevisionDataPg {
+    pub(crate) note_id: Uuid,
+    pub(crate) title: String,
+    pub(crate) content: String,
+}
@@ -0,0 +1,41 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::note_revision::NoteRevisionPg;
+use common::error::AppError;
+
+pub(crate) async fn get_note_revision<'a>(
+    db: impl PgExecutor<'a>,
+    note_id: Uuid,
+    revision: u32,
+) -> Result<NoteRevisionPg, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to retrieve note ({note_id}) revision ({revision})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Note ({note_id}) revision ({revision}) doesn't exist!");
+
+    sqlx::query_as!(
+        NoteRevisionPg,
+        r#"
+        SELECT
+            id,
+            note_id,
+            revision,
+            title,
+            content,
+            created_at
+        FROM note_revisions
+        WHERE
+            note_id = $1 AND
+            revision = $2
+        "#,
+        note_id,
+        revision as i32,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::RowNotFound => AppError::not_found(NOT_FOUND_ERR_STR),
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
@@ -0,0 +1,25 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use common::error::AppError;
+
+pub(crate) async fn get_note_revision_count<'a>(
+    db: impl PgExecutor<'a>,
+    note_id: Uuid,
+) -> Result<u32, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to retrieve note ({note_id}) revision count!");
+
+    sqlx::query_scalar!(
+        r#"
+        SELECT COUNT(*) as "total_count!"
+        FROM note_revisions
+        WHERE note_id = $1
+        "#,
+        note_id,
+    )
+    .fetch_one(db)
+    .await
+    .map(|count| count as u32)
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
@@ -0,0 +1,38 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::note_revision::NoteRevisionPg;
+use common::{error::AppError, params::PaginationParams};
+
+pub(crate) async fn get_note_revisions<'a>(
+    db: impl PgExecutor<'a>,
+    note_id: Uuid,
+    pagination: &PaginationParams,
+) -> Result<Vec<NoteRevisionPg>, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to retrieve note ({note_id}) revisions!");
+
+    sqlx::query_as!(
+        NoteRevisionPg,
+        r#"
+        SELECT
+            id,
+            note_id,
+            revision,
+            title,
+            content,
+            created_at
+        FROM note_revisions
+        WHERE note_id = $1
+        ORDER BY revision DESC
+        OFFSET $2
+        LIMIT $3
+        "#,
+        note_id,
+        pagination.skip as i64,
+        pagination.limit as i64,
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
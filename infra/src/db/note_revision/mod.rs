@@ -0,0 +1,9 @@
+mod create_note_revision;
+mod get_note_revision;
+mod get_note_revision_count;
+mod get_note_revisions;
+
+pub(crate) use create_note_revision::*;
+pub(crate) use get_note_revision::*;
+pub(crate) use get_note_revision_count::*;
+pub(crate) use get_note_revisions::*;
@@ -0,0 +1,67 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::note_share::NoteSharePg;
+use common::error::AppError;
+
+pub(crate) async fn create_note_share<'a>(
+    db: impl PgExecutor<'a>,
+    share_data: CreateNoteShareDataPg,
+) -> Result<NoteSharePg, AppError> {
+    let CreateNoteShareDataPg {
+        note_id,
+        user_id,
+        ref role,
+    } = share_data;
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to share note ({note_id}) with user ({user_id})!");
+    #[allow(non_snake_case)]
+    let CONFLICT_ERR_STR = format!("Note ({note_id}) is already shared with user ({user_id})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Note ({note_id}) or user ({user_id}) doesn't exist!");
+
+    sqlx::query_as!(
+        NoteSharePg,
+        r#"
+        WITH inserted AS (
+            INSERT INTO note_shares (
+                note_id,
+                user_id,
+                role
+            )
+            VALUES ($1, $2, $3)
+            RETURNING *
+        )
+        SELECT
+            i.note_id as "note_id!",
+            i.user_id as "user_id!",
+            u.email as "user_email!",
+            i.role as "role!",
+            i.created_at as "created_at!",
+            i.updated_at as "updated_at!"
+        FROM inserted i
+        JOIN users u ON u.id = i.user_id
+        "#,
+        note_id,
+        user_id,
+        role,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => {
+            AppError::conflict(CONFLICT_ERR_STR)
+        }
+        sqlx::Error::Database(ref db_err) if db_err.is_foreign_key_violation() => {
+            AppError::not_found(NOT_FOUND_ERR_STR)
+        }
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
+
+#[derive(Debug)]
+pub(crate) struct CreateNoteShareDataPg {
+    pub(crate) note_id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) role: String,
+}
@@ -0,0 +1,36 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use common::error::AppError;
+
+pub(crate) async fn delete_note_share<'a>(
+    db: impl PgExecutor<'a>,
+    note_id: Uuid,
+    user_id: Uuid,
+) -> Result<(), AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR =
+        format!("Failed to revoke note ({note_id}) share with user ({user_id})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Note ({note_id}) isn't shared with user ({user_id})!");
+
+    let res = sqlx::query!(
+        r#"
+        DELETE FROM note_shares
+        WHERE
+            note_id = $1 AND
+            user_id = $2
+        "#,
+        note_id,
+        user_id,
+    )
+    .execute(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+
+    if res.rows_affected() == 0 {
+        Err(AppError::not_found(NOT_FOUND_ERR_STR))
+    } else {
+        Ok(())
+    }
+}
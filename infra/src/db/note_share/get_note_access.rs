@@ -0,0 +1,39 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::note_share::NoteAccessPg;
+use common::error::AppError;
+
+pub(crate) async fn get_note_access<'a>(
+    db: impl PgExecutor<'a>,
+    note_id: Uuid,
+    user_id: Uuid,
+) -> Result<NoteAccessPg, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to retrieve note ({note_id}) access!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Note ({note_id}) doesn't exist!");
+
+    sqlx::query_as!(
+        NoteAccessPg,
+        r#"
+        SELECT
+            n.user_id = $2 as "is_owner!",
+            s.role as "role?"
+        FROM notes n
+        LEFT JOIN note_shares s ON s.note_id = n.id AND s.user_id = $2
+        WHERE
+            n.id = $1 AND
+            n.deleted_at IS NULL AND
+            (n.user_id = $2 OR s.user_id IS NOT NULL)
+        "#,
+        note_id,
+        user_id,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::RowNotFound => AppError::not_found(NOT_FOUND_ERR_STR),
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
@@ -0,0 +1,34 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::note_share::NoteSharePg;
+use common::error::AppError;
+
+pub(crate) async fn get_note_shares<'a>(
+    db: impl PgExecutor<'a>,
+    note_id: Uuid,
+) -> Result<Vec<NoteSharePg>, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to retrieve note ({note_id}) shares!");
+
+    sqlx::query_as!(
+        NoteSharePg,
+        r#"
+        SELECT
+            s.note_id,
+            s.user_id,
+            u.email as user_email,
+            s.role,
+            s.created_at,
+            s.updated_at
+        FROM note_shares s
+        JOIN users u ON u.id = s.user_id
+        WHERE s.note_id = $1
+        ORDER BY s.created_at
+        "#,
+        note_id,
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
@@ -0,0 +1,11 @@
+mod create_note_share;
+mod delete_note_share;
+mod get_note_access;
+mod get_note_shares;
+mod update_note_share;
+
+pub(crate) use create_note_share::*;
+pub(crate) use delete_note_share::*;
+pub(crate) use get_note_access::*;
+pub(crate) use get_note_shares::*;
+pub(crate) use update_note_share::*;
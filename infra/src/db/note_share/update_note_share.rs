@@ -0,0 +1,50 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::note_share::NoteSharePg;
+use common::error::AppError;
+
+pub(crate) async fn update_note_share<'a>(
+    db: impl PgExecutor<'a>,
+    note_id: Uuid,
+    user_id: Uuid,
+    role: &str,
+) -> Result<NoteSharePg, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR =
+        format!("Failed to update note ({note_id}) share with user ({user_id})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Note ({note_id}) isn't shared with user ({user_id})!");
+
+    sqlx::query_as!(
+        NoteSharePg,
+        r#"
+        WITH updated AS (
+            UPDATE note_shares
+            SET role = $3
+            WHERE
+                note_id = $1 AND
+                user_id = $2
+            RETURNING *
+        )
+        SELECT
+            s.note_id as "note_id!",
+            s.user_id as "user_id!",
+            u.email as "user_email!",
+            s.role as "role!",
+            s.created_at as "created_at!",
+            s.updated_at as "updated_at!"
+        FROM updated s
+        JOIN users u ON u.id = s.user_id
+        "#,
+        note_id,
+        user_id,
+        role,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::RowNotFound => AppError::not_found(NOT_FOUND_ERR_STR),
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
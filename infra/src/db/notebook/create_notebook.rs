@@ -0,0 +1,50 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::notebook::NotebookPg;
+use common::error::AppError;
+
+pub(crate) async fn create_notebook<'a>(
+    db: impl PgExecutor<'a>,
+    notebook_data: CreateNotebookDataPg,
+) -> Result<NotebookPg, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to create notebook!";
+    const PARENT_NOT_FOUND_ERR_STR: &str = "Parent notebook doesn't exist!";
+
+    sqlx::query_as!(
+        NotebookPg,
+        r#"
+        INSERT INTO notebooks (
+            user_id,
+            parent_id,
+            name
+        )
+        VALUES ($1, $2, $3)
+        RETURNING
+            id,
+            user_id,
+            parent_id,
+            name,
+            created_at,
+            updated_at
+        "#,
+        notebook_data.user_id,
+        notebook_data.parent_id,
+        notebook_data.name,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::Database(ref db_err) if db_err.is_foreign_key_violation() => {
+            AppError::not_found(PARENT_NOT_FOUND_ERR_STR)
+        }
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
+
+#[derive(Debug)]
+pub(crate) struct CreateNotebookDataPg {
+    pub(crate) user_id: Uuid,
+    pub(crate) parent_id: Option<Uuid>,
+    pub(crate) name: String,
+}
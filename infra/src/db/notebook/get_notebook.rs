@@ -0,0 +1,41 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::notebook::NotebookPg;
+use common::error::AppError;
+
+pub(crate) async fn get_notebook<'a>(
+    db: impl PgExecutor<'a>,
+    notebook_id: Uuid,
+    user_id: Option<Uuid>,
+) -> Result<NotebookPg, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to retrieve notebook ({notebook_id})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Notebook ({notebook_id}) doesn't exist!");
+
+    sqlx::query_as!(
+        NotebookPg,
+        r#"
+        SELECT
+            id,
+            user_id,
+            parent_id,
+            name,
+            created_at,
+            updated_at
+        FROM notebooks
+        WHERE
+            id = $1 AND
+            ($2::uuid IS NULL OR user_id = $2)
+        "#,
+        notebook_id,
+        user_id,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::RowNotFound => AppError::not_found(NOT_FOUND_ERR_STR),
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
@@ -0,0 +1,36 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use common::error::AppError;
+
+/// Walks up the notebook hierarchy, starting at the notebook itself.
+pub(crate) async fn get_notebook_path<'a>(
+    db: impl PgExecutor<'a>,
+    notebook_id: Uuid,
+) -> Result<Vec<Uuid>, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to retrieve notebook ({notebook_id}) path!");
+
+    // UNION (rather than UNION ALL) stops the walk should a cycle ever exist
+    sqlx::query_scalar!(
+        r#"
+        WITH RECURSIVE ancestors AS (
+            SELECT id, parent_id, 0 AS depth
+            FROM notebooks
+            WHERE id = $1
+            UNION
+            SELECT nb.id, nb.parent_id, a.depth + 1
+            FROM notebooks nb
+            JOIN ancestors a ON nb.id = a.parent_id
+            WHERE a.depth < 1000
+        )
+        SELECT id as "id!"
+        FROM ancestors
+        ORDER BY depth ASC
+        "#,
+        notebook_id,
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
@@ -0,0 +1,32 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::notebook::NotebookPg;
+use common::error::AppError;
+
+pub(crate) async fn get_notebooks<'a>(
+    db: impl PgExecutor<'a>,
+    user_id: Option<Uuid>,
+) -> Result<Vec<NotebookPg>, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to retrieve notebooks!";
+
+    sqlx::query_as!(
+        NotebookPg,
+        r#"
+        SELECT
+            id,
+            user_id,
+            parent_id,
+            name,
+            created_at,
+            updated_at
+        FROM notebooks
+        WHERE ($1::uuid IS NULL OR user_id = $1)
+        ORDER BY lower(name) ASC
+        "#,
+        user_id,
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
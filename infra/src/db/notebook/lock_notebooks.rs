@@ -0,0 +1,28 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use common::error::AppError;
+
+/// Locks the notebooks until the end of the transaction.<br />
+/// Rows are locked in id order, so that transactions locking overlapping sets can't deadlock.
+pub(crate) async fn lock_notebooks<'a>(
+    db: impl PgExecutor<'a>,
+    notebook_ids: &[Uuid],
+) -> Result<(), AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to lock notebooks!";
+
+    sqlx::query!(
+        r#"
+        SELECT id
+        FROM notebooks
+        WHERE id = ANY($1)
+        ORDER BY id ASC
+        FOR UPDATE
+        "#,
+        notebook_ids,
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+    Ok(())
+}
@@ -0,0 +1,15 @@
+mod create_notebook;
+mod delete_notebook;
+mod get_notebook;
+mod get_notebook_path;
+mod get_notebooks;
+mod lock_notebooks;
+mod update_notebook;
+
+pub(crate) use create_notebook::*;
+pub(crate) use delete_notebook::*;
+pub(crate) use get_notebook::*;
+pub(crate) use get_notebook_path::*;
+pub(crate) use get_notebooks::*;
+pub(crate) use lock_notebooks::*;
+pub(crate) use update_notebook::*;
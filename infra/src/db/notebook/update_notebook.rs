@@ -0,0 +1,57 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::notebook::NotebookPg;
+use common::error::AppError;
+
+pub(crate) async fn update_notebook<'a>(
+    db: impl PgExecutor<'a>,
+    notebook_id: Uuid,
+    notebook_data: UpdateNotebookDataPg,
+    user_id: Option<Uuid>,
+) -> Result<NotebookPg, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to update notebook ({notebook_id})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Notebook ({notebook_id}) doesn't exist!");
+    const PARENT_NOT_FOUND_ERR_STR: &str = "Parent notebook doesn't exist!";
+
+    sqlx::query_as!(
+        NotebookPg,
+        r#"
+        UPDATE notebooks
+        SET
+            parent_id = $3,
+            name = $4
+        WHERE
+            id = $1 AND
+            ($2::uuid IS NULL OR user_id = $2)
+        RETURNING
+            id,
+            user_id,
+            parent_id,
+            name,
+            created_at,
+            updated_at
+        "#,
+        notebook_id,
+        user_id,
+        notebook_data.parent_id,
+        notebook_data.name,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::RowNotFound => AppError::not_found(NOT_FOUND_ERR_STR),
+        sqlx::Error::Database(ref db_err) if db_err.is_foreign_key_violation() => {
+            AppError::not_found(PARENT_NOT_FOUND_ERR_STR)
+        }
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
+
+#[derive(Debug)]
+pub(crate) struct UpdateNotebookDataPg {
+    pub(crate) parent_id: Option<Uuid>,
+    pub(crate) name: String,
+}
@@ -0,0 +1,33 @@
+use sqlx::PgExecutor;
+
+use crate::models::outbox::DomainEventPg;
+use common::error::AppError;
+use dmn::events::DomainEvent;
+
+pub(crate) async fn append_outbox_events<'a>(
+    db: impl PgExecutor<'a>,
+    events: &[DomainEvent],
+) -> Result<(), AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to record domain events!";
+
+    let event_types: Vec<String> = events.iter().map(|event| event.name().into()).collect();
+    let payloads = events
+        .iter()
+        .map(|event| serde_json::to_string(&DomainEventPg::from(event)))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO outbox (event_type, payload)
+        SELECT event_type, payload::jsonb
+        FROM UNNEST($1::text[], $2::text[]) AS events(event_type, payload)
+        "#,
+        &event_types,
+        &payloads,
+    )
+    .execute(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use sqlx::PgExecutor;
+
+use crate::models::outbox::OutboxEventPg;
+use common::error::AppError;
+
+/// Locks the oldest events due for delivery until the end of the transaction, skipping the ones
+/// already locked by other dispatchers.
+pub(crate) async fn claim_outbox_events<'a>(
+    db: impl PgExecutor<'a>,
+    limit: u32,
+) -> Result<Vec<OutboxEventPg>, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to claim outbox events!";
+
+    sqlx::query_as!(
+        OutboxEventPg,
+        r#"
+        SELECT
+            id,
+            event_id,
+            payload::text as "payload!",
+            attempts,
+            handled_by
+        FROM outbox
+        WHERE
+            failed_at IS NULL AND
+            next_attempt_at <= now()
+        ORDER BY id ASC
+        LIMIT $1
+        FOR UPDATE SKIP LOCKED
+        "#,
+        limit as i64,
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
@@ -0,0 +1,23 @@
+use sqlx::PgExecutor;
+
+use common::error::AppError;
+
+pub(crate) async fn delete_outbox_event<'a>(
+    db: impl PgExecutor<'a>,
+    event_id: i64,
+) -> Result<(), AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to delete outbox event ({event_id})!");
+
+    sqlx::query!(
+        r#"
+        DELETE FROM outbox
+        WHERE id = $1
+        "#,
+        event_id,
+    )
+    .execute(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use sqlx::PgExecutor;
+
+use common::error::AppError;
+
+/// Gives up on delivering the event, leaving it in the outbox for inspection.
+pub(crate) async fn fail_outbox_event<'a>(
+    db: impl PgExecutor<'a>,
+    event_id: i64,
+    last_error: &str,
+    handled_by: &[String],
+) -> Result<(), AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to mark outbox event ({event_id}) as failed!");
+
+    sqlx::query!(
+        r#"
+        UPDATE outbox
+        SET
+            attempts = attempts + 1,
+            failed_at = now(),
+            last_error = $2,
+            handled_by = $3
+        WHERE id = $1
+        "#,
+        event_id,
+        last_error,
+        handled_by,
+    )
+    .execute(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+    Ok(())
+}
@@ -0,0 +1,11 @@
+mod append_outbox_events;
+mod claim_outbox_events;
+mod delete_outbox_event;
+mod fail_outbox_event;
+mod reschedule_outbox_event;
+
+pub(crate) use append_outbox_events::*;
+pub(crate) use claim_outbox_events::*;
+pub(crate) use delete_outbox_event::*;
+pub(crate) use fail_outbox_event::*;
+pub(crate) use reschedule_outbox_event::*;
@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgExecutor;
+
+use common::error::AppError;
+
+pub(crate) async fn reschedule_outbox_event<'a>(
+    db: impl PgExecutor<'a>,
+    event_id: i64,
+    next_attempt_at: DateTime<Utc>,
+    last_error: &str,
+    handled_by: &[String],
+) -> Result<(), AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to reschedule outbox event ({event_id})!");
+
+    sqlx::query!(
+        r#"
+        UPDATE outbox
+        SET
+            attempts = attempts + 1,
+            next_attempt_at = $2,
+            last_error = $3,
+            handled_by = $4
+        WHERE id = $1
+        "#,
+        event_id,
+        next_attempt_at,
+        last_error,
+        handled_by,
+    )
+    .execute(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+    Ok(())
+}
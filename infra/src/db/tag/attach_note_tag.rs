@@ -0,0 +1,31 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use common::error::AppError;
+
+pub(crate) async fn attach_note_tag<'a>(
+    db: impl PgExecutor<'a>,
+    note_id: Uuid,
+    tag_id: Uuid,
+) -> Result<(), AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to attach tag ({tag_id}) to note ({note_id})!");
+
+    sqlx::query!(
+        r#"
+        INSERT INTO note_tags (
+            note_id,
+            tag_id
+        )
+        VALUES ($1, $2)
+        ON CONFLICT DO NOTHING
+        "#,
+        note_id,
+        tag_id,
+    )
+    .execute(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::tag::TagPg;
+use common::error::AppError;
+
+pub(crate) async fn create_tag<'a>(
+    db: impl PgExecutor<'a>,
+    tag_data: CreateTagDataPg,
+) -> Result<TagPg, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to create tag!";
+    #[allow(non_snake_case)]
+    let CONFLICT_ERR_STR = format!("Tag ({}) already exists!", tag_data.name);
+
+    sqlx::query_as!(
+        TagPg,
+        r#"
+        INSERT INTO tags (
+            user_id,
+            name
+        )
+        VALUES ($1, $2)
+        RETURNING
+            id,
+            user_id,
+            name,
+            created_at,
+            updated_at
+        "#,
+        tag_data.user_id,
+        tag_data.name,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => {
+            AppError::conflict(CONFLICT_ERR_STR)
+        }
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
+
+#[derive(Debug)]
+pub(crate) struct CreateTagDataPg {
+    pub(crate) user_id: Uuid,
+    pub(crate) name: String,
+}
@@ -0,0 +1,36 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use common::error::AppError;
+
+pub(crate) async fn delete_tag<'a>(
+    db: impl PgExecutor<'a>,
+    tag_id: Uuid,
+    user_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to delete tag ({tag_id})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Tag ({tag_id}) doesn't exist!");
+
+    // Auto-cascades related note_tags entries
+    let res = sqlx::query!(
+        r#"
+        DELETE FROM tags
+        WHERE
+            id = $1 AND
+            ($2::uuid IS NULL OR user_id = $2)
+        "#,
+        tag_id,
+        user_id,
+    )
+    .execute(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+
+    if res.rows_affected() == 0 {
+        Err(AppError::not_found(NOT_FOUND_ERR_STR))
+    } else {
+        Ok(())
+    }
+}
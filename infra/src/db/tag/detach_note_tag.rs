@@ -0,0 +1,35 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use common::error::AppError;
+
+pub(crate) async fn detach_note_tag<'a>(
+    db: impl PgExecutor<'a>,
+    note_id: Uuid,
+    tag_id: Uuid,
+) -> Result<(), AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to detach tag ({tag_id}) from note ({note_id})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Tag ({tag_id}) isn't attached to note ({note_id})!");
+
+    let res = sqlx::query!(
+        r#"
+        DELETE FROM note_tags
+        WHERE
+            note_id = $1 AND
+            tag_id = $2
+        "#,
+        note_id,
+        tag_id,
+    )
+    .execute(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+
+    if res.rows_affected() == 0 {
+        Err(AppError::not_found(NOT_FOUND_ERR_STR))
+    } else {
+        Ok(())
+    }
+}
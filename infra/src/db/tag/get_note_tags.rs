@@ -0,0 +1,34 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::tag::NoteTagPg;
+use common::error::AppError;
+
+pub(crate) async fn get_note_tags<'a>(
+    db: impl PgExecutor<'a>,
+    note_ids: &[Uuid],
+) -> Result<Vec<NoteTagPg>, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to retrieve note tags!";
+
+    sqlx::query_as!(
+        NoteTagPg,
+        r#"
+        SELECT
+            nt.note_id,
+            t.id,
+            t.user_id,
+            t.name,
+            t.created_at,
+            t.updated_at
+        FROM note_tags nt
+        INNER JOIN tags t
+        ON nt.tag_id = t.id
+        WHERE nt.note_id = ANY($1)
+        ORDER BY lower(t.name) ASC
+        "#,
+        note_ids,
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
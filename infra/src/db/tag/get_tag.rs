@@ -0,0 +1,40 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::tag::TagPg;
+use common::error::AppError;
+
+pub(crate) async fn get_tag<'a>(
+    db: impl PgExecutor<'a>,
+    tag_id: Uuid,
+    user_id: Option<Uuid>,
+) -> Result<TagPg, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to retrieve tag ({tag_id})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Tag ({tag_id}) doesn't exist!");
+
+    sqlx::query_as!(
+        TagPg,
+        r#"
+        SELECT
+            id,
+            user_id,
+            name,
+            created_at,
+            updated_at
+        FROM tags
+        WHERE
+            id = $1 AND
+            ($2::uuid IS NULL OR user_id = $2)
+        "#,
+        tag_id,
+        user_id,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::RowNotFound => AppError::not_found(NOT_FOUND_ERR_STR),
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
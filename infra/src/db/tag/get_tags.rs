@@ -0,0 +1,31 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::tag::TagPg;
+use common::error::AppError;
+
+pub(crate) async fn get_tags<'a>(
+    db: impl PgExecutor<'a>,
+    user_id: Option<Uuid>,
+) -> Result<Vec<TagPg>, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to retrieve tags!";
+
+    sqlx::query_as!(
+        TagPg,
+        r#"
+        SELECT
+            id,
+            user_id,
+            name,
+            created_at,
+            updated_at
+        FROM tags
+        WHERE ($1::uuid IS NULL OR user_id = $1)
+        ORDER BY lower(name) ASC
+        "#,
+        user_id,
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
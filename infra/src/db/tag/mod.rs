@@ -0,0 +1,17 @@
+mod attach_note_tag;
+mod create_tag;
+mod delete_tag;
+mod detach_note_tag;
+mod get_note_tags;
+mod get_tag;
+mod get_tags;
+mod update_tag;
+
+pub(crate) use attach_note_tag::*;
+pub(crate) use create_tag::*;
+pub(crate) use delete_tag::*;
+pub(crate) use detach_note_tag::*;
+pub(crate) use get_note_tags::*;
+pub(crate) use get_tag::*;
+pub(crate) use get_tags::*;
+pub(crate) use update_tag::*;
@@ -0,0 +1,54 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::tag::TagPg;
+use common::error::AppError;
+
+pub(crate) async fn update_tag<'a>(
+    db: impl PgExecutor<'a>,
+    tag_id: Uuid,
+    tag_data: UpdateTagDataPg,
+    user_id: Option<Uuid>,
+) -> Result<TagPg, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to update tag ({tag_id})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Tag ({tag_id}) doesn't exist!");
+    #[allow(non_snake_case)]
+    let CONFLICT_ERR_STR = format!("Tag ({}) already exists!", tag_data.name);
+
+    sqlx::query_as!(
+        TagPg,
+        r#"
+        UPDATE tags
+        SET
+            name = $3
+        WHERE
+            id = $1 AND
+            ($2::uuid IS NULL OR user_id = $2)
+        RETURNING
+            id,
+            user_id,
+            name,
+            created_at,
+            updated_at
+        "#,
+        tag_id,
+        user_id,
+        tag_data.name,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::RowNotFound => AppError::not_found(NOT_FOUND_ERR_STR),
+        sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => {
+            AppError::conflict(CONFLICT_ERR_STR)
+        }
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
+
+#[derive(Debug)]
+pub(crate) struct UpdateTagDataPg {
+    pub(crate) name: String,
+}
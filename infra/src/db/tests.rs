@@ -0,0 +1,87 @@
+use super::*;
+
+use common::params::{CursorPaginationParams, PaginationParams};
+
+fn cursor_at(index: usize) -> PageCursor {
+    PageCursor {
+        created_at: DateTime::from_timestamp(1_735_725_600 + index as i64, 0).unwrap(),
+        id: Uuid::from_u128(index as u128),
+    }
+}
+
+fn cursor_pagination(after: Option<PageCursor>, limit: u32) -> Pagination {
+    CursorPaginationParams { after, limit }.into()
+}
+
+#[test]
+fn test_get_pagination_args_offset() {
+    let pagination = PaginationParams {
+        skip: 40,
+        limit: 20,
+    }
+    .into();
+
+    let args = get_pagination_args(&pagination);
+
+    assert_eq!((args.skip, args.limit, args.keyset), (40, 20, false));
+    assert!(args.after_created_at.is_none());
+    assert!(args.after_id.is_none());
+}
+
+#[test]
+fn test_get_pagination_args_cursor_fetches_extra_row() {
+    let after = cursor_at(3);
+
+    let args = get_pagination_args(&cursor_pagination(Some(after), 20));
+
+    assert_eq!((args.skip, args.limit, args.keyset), (0, 21, true));
+    assert_eq!(args.after_created_at, Some(after.created_at));
+    assert_eq!(args.after_id, Some(after.id));
+}
+
+#[test]
+fn test_get_pagination_args_first_cursor_page() {
+    let args = get_pagination_args(&cursor_pagination(None, 20));
+
+    assert!(args.keyset);
+    assert!(args.after_created_at.is_none());
+    assert!(args.after_id.is_none());
+}
+
+#[test]
+fn test_take_next_cursor_with_extra_row() {
+    let mut rows: Vec<usize> = (0..4).collect();
+
+    let next_cursor = take_next_cursor(&mut rows, &cursor_pagination(None, 3), |row| {
+        cursor_at(*row)
+    });
+
+    // The page ends on its last kept row, the extra one starting the next page
+    assert_eq!(rows, vec![0, 1, 2]);
+    assert_eq!(next_cursor, Some(cursor_at(2)));
+}
+
+#[test]
+fn test_take_next_cursor_on_last_page() {
+    for row_count in [0, 2, 3] {
+        let mut rows: Vec<usize> = (0..row_count).collect();
+
+        let next_cursor = take_next_cursor(&mut rows, &cursor_pagination(None, 3), |row| {
+            cursor_at(*row)
+        });
+
+        assert_eq!(rows.len(), row_count);
+        assert_eq!(next_cursor, None);
+    }
+}
+
+#[test]
+fn test_take_next_cursor_offset() {
+    let mut rows: Vec<usize> = (0..4).collect();
+    let pagination = PaginationParams { skip: 0, limit: 3 }.into();
+
+    let next_cursor = take_next_cursor(&mut rows, &pagination, |row| cursor_at(*row));
+
+    assert_eq!(rows.len(), 4);
+    assert_eq!(next_cursor, None);
+}
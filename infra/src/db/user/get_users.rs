@@ -1,14 +1,16 @@
 use sqlx::PgExecutor;
 
-use crate::models::user::UserPg;
-use common::{error::AppError, params::PaginationParams};
+use crate::{db::get_pagination_args, models::user::UserPg};
+use common::{error::AppError, params::Pagination};
 
 pub(crate) async fn get_users<'a>(
     db: impl PgExecutor<'a>,
-    pagination: &PaginationParams,
+    pagination: &Pagination,
 ) -> Result<Vec<UserPg>, AppError> {
     const INTERNAL_ERR_STR: &str = "Failed to retrieve users!";
 
+    let pagination = get_pagination_args(pagination);
+
     sqlx::query_as!(
         UserPg,
         r#"
@@ -18,12 +20,18 @@ pub(crate) async fn get_users<'a>(
             created_at,
             updated_at
         FROM users
-        ORDER BY id ASC
+        WHERE $3::timestamptz IS NULL OR (created_at, id) > ($3, $4::uuid)
+        ORDER BY
+            CASE WHEN $5 THEN created_at END ASC,
+            id ASC
         OFFSET $1
         LIMIT $2
         "#,
-        pagination.skip as i64,
-        pagination.limit as i64,
+        pagination.skip,
+        pagination.limit,
+        pagination.after_created_at,
+        pagination.after_id,
+        pagination.keyset,
     )
     .fetch_all(db)
     .await
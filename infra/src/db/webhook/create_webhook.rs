@@ -0,0 +1,48 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::webhook::WebhookPg;
+use common::error::AppError;
+
+pub(crate) async fn create_webhook<'a>(
+    db: impl PgExecutor<'a>,
+    webhook_data: CreateWebhookDataPg,
+) -> Result<WebhookPg, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to create webhook!";
+
+    sqlx::query_as!(
+        WebhookPg,
+        r#"
+        INSERT INTO webhooks (
+            user_id,
+            url,
+            secret,
+            event_types
+        )
+        VALUES ($1, $2, $3, $4)
+        RETURNING
+            id,
+            user_id,
+            url,
+            secret,
+            event_types,
+            created_at,
+            updated_at
+        "#,
+        webhook_data.user_id,
+        webhook_data.url,
+        webhook_data.secret,
+        &webhook_data.event_types,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
+
+#[derive(Debug)]
+pub(crate) struct CreateWebhookDataPg {
+    pub(crate) user_id: Uuid,
+    pub(crate) url: String,
+    pub(crate) secret: String,
+    pub(crate) event_types: Vec<String>,
+}
@@ -0,0 +1,42 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::webhook::WebhookPg;
+use common::error::AppError;
+
+pub(crate) async fn get_webhook<'a>(
+    db: impl PgExecutor<'a>,
+    webhook_id: Uuid,
+    user_id: Option<Uuid>,
+) -> Result<WebhookPg, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to retrieve webhook ({webhook_id})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Webhook ({webhook_id}) doesn't exist!");
+
+    sqlx::query_as!(
+        WebhookPg,
+        r#"
+        SELECT
+            id,
+            user_id,
+            url,
+            secret,
+            event_types,
+            created_at,
+            updated_at
+        FROM webhooks
+        WHERE
+            id = $1 AND
+            ($2::uuid IS NULL OR user_id = $2)
+        "#,
+        webhook_id,
+        user_id,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::RowNotFound => AppError::not_found(NOT_FOUND_ERR_STR),
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
@@ -0,0 +1,33 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::webhook::WebhookPg;
+use common::error::AppError;
+
+pub(crate) async fn get_webhooks<'a>(
+    db: impl PgExecutor<'a>,
+    user_id: Option<Uuid>,
+) -> Result<Vec<WebhookPg>, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to retrieve webhooks!";
+
+    sqlx::query_as!(
+        WebhookPg,
+        r#"
+        SELECT
+            id,
+            user_id,
+            url,
+            secret,
+            event_types,
+            created_at,
+            updated_at
+        FROM webhooks
+        WHERE $1::uuid IS NULL OR user_id = $1
+        ORDER BY created_at ASC, id ASC
+        "#,
+        user_id,
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
@@ -0,0 +1,11 @@
+mod create_webhook;
+mod delete_webhook;
+mod get_webhook;
+mod get_webhooks;
+mod update_webhook;
+
+pub(crate) use create_webhook::*;
+pub(crate) use delete_webhook::*;
+pub(crate) use get_webhook::*;
+pub(crate) use get_webhooks::*;
+pub(crate) use update_webhook::*;
@@ -0,0 +1,57 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::webhook::WebhookPg;
+use common::error::AppError;
+
+pub(crate) async fn update_webhook<'a>(
+    db: impl PgExecutor<'a>,
+    webhook_id: Uuid,
+    webhook_data: UpdateWebhookDataPg,
+    user_id: Option<Uuid>,
+) -> Result<WebhookPg, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to update webhook ({webhook_id})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Webhook ({webhook_id}) doesn't exist!");
+
+    sqlx::query_as!(
+        WebhookPg,
+        r#"
+        UPDATE webhooks
+        SET
+            url = $3,
+            secret = COALESCE($4, secret),
+            event_types = $5
+        WHERE
+            id = $1 AND
+            ($2::uuid IS NULL OR user_id = $2)
+        RETURNING
+            id,
+            user_id,
+            url,
+            secret,
+            event_types,
+            created_at,
+            updated_at
+        "#,
+        webhook_id,
+        user_id,
+        webhook_data.url,
+        webhook_data.secret,
+        &webhook_data.event_types,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::RowNotFound => AppError::not_found(NOT_FOUND_ERR_STR),
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
+
+#[derive(Debug)]
+pub(crate) struct UpdateWebhookDataPg {
+    pub(crate) url: String,
+    pub(crate) secret: Option<String>,
+    pub(crate) event_types: Vec<String>,
+}
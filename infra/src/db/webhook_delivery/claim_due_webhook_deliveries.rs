@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgExecutor;
+
+use crate::models::webhook_delivery::WebhookDeliveryPg;
+use common::error::AppError;
+
+/// Postpones the oldest due pending deliveries to `lease_until` and returns them, skipping the ones
+/// concurrently being claimed.
+pub(crate) async fn claim_due_webhook_deliveries<'a>(
+    db: impl PgExecutor<'a>,
+    limit: u32,
+    lease_until: DateTime<Utc>,
+) -> Result<Vec<WebhookDeliveryPg>, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to claim webhook deliveries!";
+
+    sqlx::query_as!(
+        WebhookDeliveryPg,
+        r#"
+        UPDATE webhook_deliveries deliveries
+        SET next_attempt_at = $2
+        FROM (
+            SELECT id
+            FROM webhook_deliveries
+            WHERE
+                status = 'pending' AND
+                next_attempt_at <= now()
+            ORDER BY next_attempt_at ASC
+            LIMIT $1
+            FOR UPDATE SKIP LOCKED
+        ) due
+        WHERE deliveries.id = due.id
+        RETURNING
+            deliveries.id,
+            deliveries.webhook_id,
+            deliveries.event_id,
+            deliveries.event_type,
+            deliveries.note_id,
+            deliveries.status,
+            deliveries.attempts,
+            deliveries.response_status,
+            deliveries.last_error,
+            deliveries.next_attempt_at,
+            deliveries.created_at,
+            deliveries.delivered_at
+        "#,
+        limit as i64,
+        lease_until,
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::webhook_delivery::WebhookDeliveryPg;
+use common::error::AppError;
+
+pub(crate) async fn create_webhook_delivery<'a>(
+    db: impl PgExecutor<'a>,
+    delivery_data: CreateWebhookDeliveryDataPg,
+) -> Result<Option<WebhookDeliveryPg>, AppError> {
+    const INTERNAL_ERR_STR: &str = "Failed to create webhook delivery!";
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Webhook ({}) doesn't exist!", delivery_data.webhook_id);
+
+    sqlx::query_as!(
+        WebhookDeliveryPg,
+        r#"
+        INSERT INTO webhook_deliveries (
+            webhook_id,
+            event_id,
+            event_type,
+            note_id,
+            next_attempt_at
+        )
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (webhook_id, event_id) DO NOTHING
+        RETURNING
+            id,
+            webhook_id,
+            event_id,
+            event_type,
+            note_id,
+            status,
+            attempts,
+            response_status,
+            last_error,
+            next_attempt_at,
+            created_at,
+            delivered_at
+        "#,
+        delivery_data.webhook_id,
+        delivery_data.event_id,
+        delivery_data.event_type,
+        delivery_data.note_id,
+        delivery_data.next_attempt_at,
+    )
+    .fetch_optional(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::Database(ref db_err) if db_err.is_foreign_key_violation() => {
+            AppError::not_found(NOT_FOUND_ERR_STR)
+        }
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
+
+#[derive(Debug)]
+pub(crate) struct CreateWebhookDeliveryDataPg {
+    pub(crate) webhook_id: Uuid,
+    pub(crate) event_id: Uuid,
+    pub(crate) event_type: String,
+    pub(crate) note_id: Option<Uuid>,
+    pub(crate) next_attempt_at: Option<DateTime<Utc>>,
+}
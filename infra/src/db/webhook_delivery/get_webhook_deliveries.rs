@@ -0,0 +1,44 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::webhook_delivery::WebhookDeliveryPg;
+use common::{error::AppError, params::PaginationParams};
+
+pub(crate) async fn get_webhook_deliveries<'a>(
+    db: impl PgExecutor<'a>,
+    webhook_id: Uuid,
+    pagination: &PaginationParams,
+) -> Result<Vec<WebhookDeliveryPg>, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to retrieve webhook ({webhook_id}) deliveries!");
+
+    sqlx::query_as!(
+        WebhookDeliveryPg,
+        r#"
+        SELECT
+            id,
+            webhook_id,
+            event_id,
+            event_type,
+            note_id,
+            status,
+            attempts,
+            response_status,
+            last_error,
+            next_attempt_at,
+            created_at,
+            delivered_at
+        FROM webhook_deliveries
+        WHERE webhook_id = $1
+        ORDER BY created_at DESC, id DESC
+        OFFSET $2
+        LIMIT $3
+        "#,
+        webhook_id,
+        pagination.skip as i64,
+        pagination.limit as i64,
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
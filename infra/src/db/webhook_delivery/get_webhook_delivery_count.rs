@@ -0,0 +1,25 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use common::error::AppError;
+
+pub(crate) async fn get_webhook_delivery_count<'a>(
+    db: impl PgExecutor<'a>,
+    webhook_id: Uuid,
+) -> Result<u32, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to retrieve webhook ({webhook_id}) delivery count!");
+
+    sqlx::query_scalar!(
+        r#"
+        SELECT COUNT(*) as "total_count!"
+        FROM webhook_deliveries
+        WHERE webhook_id = $1
+        "#,
+        webhook_id,
+    )
+    .fetch_one(db)
+    .await
+    .map(|count| count as u32)
+    .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))
+}
@@ -0,0 +1,11 @@
+mod claim_due_webhook_deliveries;
+mod create_webhook_delivery;
+mod get_webhook_deliveries;
+mod get_webhook_delivery_count;
+mod update_webhook_delivery;
+
+pub(crate) use claim_due_webhook_deliveries::*;
+pub(crate) use create_webhook_delivery::*;
+pub(crate) use get_webhook_deliveries::*;
+pub(crate) use get_webhook_delivery_count::*;
+pub(crate) use update_webhook_delivery::*;
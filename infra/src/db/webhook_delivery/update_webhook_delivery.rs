@@ -0,0 +1,68 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::webhook_delivery::WebhookDeliveryPg;
+use common::error::AppError;
+
+pub(crate) async fn update_webhook_delivery<'a>(
+    db: impl PgExecutor<'a>,
+    delivery_id: Uuid,
+    delivery_data: UpdateWebhookDeliveryDataPg,
+) -> Result<WebhookDeliveryPg, AppError> {
+    #[allow(non_snake_case)]
+    let INTERNAL_ERR_STR = format!("Failed to update webhook delivery ({delivery_id})!");
+    #[allow(non_snake_case)]
+    let NOT_FOUND_ERR_STR = format!("Webhook delivery ({delivery_id}) doesn't exist!");
+
+    sqlx::query_as!(
+        WebhookDeliveryPg,
+        r#"
+        UPDATE webhook_deliveries
+        SET
+            status = $2,
+            attempts = $3,
+            response_status = $4,
+            last_error = $5,
+            next_attempt_at = $6,
+            delivered_at = $7
+        WHERE id = $1
+        RETURNING
+            id,
+            webhook_id,
+            event_id,
+            event_type,
+            note_id,
+            status,
+            attempts,
+            response_status,
+            last_error,
+            next_attempt_at,
+            created_at,
+            delivered_at
+        "#,
+        delivery_id,
+        delivery_data.status,
+        delivery_data.attempts,
+        delivery_data.response_status,
+        delivery_data.last_error,
+        delivery_data.next_attempt_at,
+        delivery_data.delivered_at,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::RowNotFound => AppError::not_found(NOT_FOUND_ERR_STR),
+        _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+    })
+}
+
+#[derive(Debug)]
+pub(crate) struct UpdateWebhookDeliveryDataPg {
+    pub(crate) status: String,
+    pub(crate) attempts: i32,
+    pub(crate) response_status: Option<i32>,
+    pub(crate) last_error: Option<String>,
+    pub(crate) next_attempt_at: Option<DateTime<Utc>>,
+    pub(crate) delivered_at: Option<DateTime<Utc>>,
+}
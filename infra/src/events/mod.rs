@@ -0,0 +1,2 @@
+pub mod note_events;
+pub mod outbox_dispatcher;
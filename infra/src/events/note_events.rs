@@ -0,0 +1,99 @@
+use async_trait::async_trait;
+use futures_util::stream;
+use sqlx::{postgres::PgListener, PgPool};
+use std::{sync::Arc, time::Duration};
+use tokio::sync::broadcast::{self, error::RecvError};
+use uuid::Uuid;
+
+use crate::{
+    db::note_event::{self as db, NOTE_EVENTS_CHANNEL},
+    models::note_event::NoteEventPg,
+};
+use app::events::note_events::{
+    NoteEvent, NoteEventPublisher, NoteEventStream, NoteEventSubscriber,
+};
+use common::error::AppError;
+
+/// Events buffered per subscriber, the streams of slower subscribers get closed.
+const EVENT_BUFFER_SIZE: usize = 1024;
+
+/// Fans note events out through Postgres `LISTEN`/`NOTIFY`, reaching the subscribers of every
+/// instance sharing the database.
+pub struct PgNoteEventBus {
+    db_pool: Arc<PgPool>,
+    sender: broadcast::Sender<NoteEvent>,
+}
+
+impl PgNoteEventBus {
+    /// Starts listening for note events in the background.
+    pub async fn start(db_pool: Arc<PgPool>) -> Result<Self, AppError> {
+        const INTERNAL_ERR_STR: &str = "Failed to listen for note events!";
+
+        let mut listener = PgListener::connect_with(&db_pool)
+            .await
+            .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+        listener
+            .listen(NOTE_EVENTS_CHANNEL)
+            .await
+            .map_err(|err| AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()))?;
+
+        let (sender, _) = broadcast::channel(EVENT_BUFFER_SIZE);
+        let listener_sender = sender.clone();
+        tokio::spawn(async move {
+            const RETRY_DELAY: Duration = Duration::from_secs(1);
+            loop {
+                // Reconnects on its own after connection losses
+                let notification = match listener.recv().await {
+                    Ok(notification) => notification,
+                    Err(err) => {
+                        AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()).log();
+                        tokio::time::sleep(RETRY_DELAY).await;
+                        continue;
+                    }
+                };
+                match serde_json::from_str::<NoteEventPg>(notification.payload()) {
+                    // No receivers only means that nobody is subscribed
+                    Ok(pg_event) => _ = listener_sender.send(pg_event.into()),
+                    Err(err) => AppError::internal_with_private(
+                        "Failed to parse note event!",
+                        err.to_string(),
+                    )
+                    .log(),
+                }
+            }
+        });
+        Ok(Self { db_pool, sender })
+    }
+}
+
+#[async_trait]
+impl NoteEventPublisher for PgNoteEventBus {
+    async fn publish(&self, event: NoteEvent) -> Result<(), AppError> {
+        db::notify_note_event(&*self.db_pool, &event.into()).await
+    }
+}
+
+#[async_trait]
+impl NoteEventSubscriber for PgNoteEventBus {
+    async fn subscribe(&self, user_id: Uuid) -> Result<NoteEventStream, AppError> {
+        let receiver = self.sender.subscribe();
+        let events = stream::unfold(receiver, move |mut receiver| async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) if event.user_id == user_id => return Some((event, receiver)),
+                    Ok(_) => continue,
+                    // Ends the stream rather than silently skipping events, so that the
+                    // subscriber reconnects and catches up
+                    Err(RecvError::Lagged(skipped)) => {
+                        log::warn!(
+                            "Closing note event stream of user {user_id}, {skipped} events behind"
+                        );
+                        return None;
+                    }
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        });
+        Ok(Box::pin(events))
+    }
+}
@@ -0,0 +1,109 @@
+use chrono::{TimeDelta, Utc};
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::db::outbox as db;
+use app::events::domain_events::DomainEventHandler;
+use common::error::AppError;
+use dmn::events::DomainEvent;
+
+/// Events claimed per dispatch.
+pub const OUTBOX_BATCH_SIZE: u32 = 100;
+/// Delivery attempts after which an event is given up on.
+pub const OUTBOX_MAX_ATTEMPTS: i32 = 20;
+/// Upper bound of the exponential backoff between delivery attempts.
+const MAX_RETRY_DELAY_SECS: i64 = 60 * 60; // 1 hour
+
+/// Delivers the outbox events to every registered handler, at least once.<br />
+/// Events stay in the outbox until all handlers succeed, failed ones are retried with backoff for
+/// the handlers that failed only. Events still failing after [`OUTBOX_MAX_ATTEMPTS`] attempts are
+/// marked as failed and left in the outbox.
+pub struct PgOutboxDispatcher {
+    db_pool: Arc<PgPool>,
+    handlers: Vec<Arc<dyn DomainEventHandler>>,
+}
+
+impl PgOutboxDispatcher {
+    pub fn new(db_pool: Arc<PgPool>, handlers: Vec<Arc<dyn DomainEventHandler>>) -> Self {
+        Self { db_pool, handlers }
+    }
+
+    /// Dispatches a batch of due events, returning how many were claimed.<br />
+    /// Claimed events stay locked until the batch is done, so concurrent dispatchers (e.g. of other
+    /// instances) never deliver the same event simultaneously.
+    pub async fn dispatch_pending_events(&self) -> Result<u32, AppError> {
+        let mut tx = self.db_pool.begin().await.map_err(|err| {
+            AppError::internal_with_private("Failed to start transaction!", err.to_string())
+        })?;
+
+        let outbox_events = db::claim_outbox_events(&mut *tx, OUTBOX_BATCH_SIZE).await?;
+        for outbox_event in &outbox_events {
+            let mut handled_by = outbox_event.handled_by.clone();
+            let result = match outbox_event.to_dmn() {
+                Ok(event) => {
+                    self.handle_event(outbox_event.event_id, &event, &mut handled_by)
+                        .await
+                }
+                Err(err) => Err(err),
+            };
+            let Err(err) = result else {
+                db::delete_outbox_event(&mut *tx, outbox_event.id).await?;
+                continue;
+            };
+            err.log();
+            if outbox_event.attempts + 1 >= OUTBOX_MAX_ATTEMPTS {
+                log::error!(
+                    "Giving up on outbox event ({}) after {OUTBOX_MAX_ATTEMPTS} attempts!",
+                    outbox_event.event_id
+                );
+                db::fail_outbox_event(&mut *tx, outbox_event.id, &err.to_string(), &handled_by)
+                    .await?;
+                continue;
+            }
+            let retry_delay_secs = 2_i64
+                .saturating_pow(outbox_event.attempts.max(0) as u32)
+                .min(MAX_RETRY_DELAY_SECS);
+            let next_attempt_at = Utc::now() + TimeDelta::seconds(retry_delay_secs);
+            db::reschedule_outbox_event(
+                &mut *tx,
+                outbox_event.id,
+                next_attempt_at,
+                &err.to_string(),
+                &handled_by,
+            )
+            .await?;
+        }
+
+        tx.commit().await.map_err(|err| {
+            AppError::internal_with_private("Failed to commit transaction!", err.to_string())
+        })?;
+        Ok(outbox_events.len() as u32)
+    }
+
+    /// Runs every handler not in `handled_by` yet, adding the succeeding ones to it.<br />
+    /// A failing handler doesn't hold the others back.
+    async fn handle_event(
+        &self,
+        event_id: Uuid,
+        event: &DomainEvent,
+        handled_by: &mut Vec<String>,
+    ) -> Result<(), AppError> {
+        let mut first_err = None;
+        for handler in &self.handlers {
+            if handled_by.iter().any(|name| name == handler.name()) {
+                continue;
+            }
+            match handler.handle(event_id, event).await {
+                Ok(()) => handled_by.push(handler.name().to_string()),
+                Err(err) => {
+                    first_err.get_or_insert(err);
+                }
+            }
+        }
+        match first_err {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
@@ -1,7 +1,11 @@
+pub mod archive;
 pub mod auth;
 mod db;
+pub mod events;
 mod models;
 pub mod repos;
+pub mod storage;
 pub mod tx;
+pub mod webhooks;
 
 pub use db::get_pg_pool;
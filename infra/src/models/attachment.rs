@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use dmn::entities::attachment::AttachmentData;
+
+#[derive(Debug)]
+pub(crate) struct AttachmentPg {
+    pub(crate) id: Uuid,
+    pub(crate) note_id: Uuid,
+    pub(crate) file_name: String,
+    pub(crate) mime_type: String,
+    pub(crate) size: i64,
+    pub(crate) sha256: String,
+    pub(crate) created_at: DateTime<Utc>,
+}
+
+impl From<AttachmentPg> for AttachmentData {
+    fn from(pg_attachment: AttachmentPg) -> Self {
+        AttachmentData {
+            id: pg_attachment.id,
+            note_id: pg_attachment.note_id,
+            file_name: pg_attachment.file_name,
+            mime_type: pg_attachment.mime_type,
+            size: pg_attachment.size as u64,
+            sha256: pg_attachment.sha256,
+            created_at: pg_attachment.created_at,
+        }
+    }
+}
@@ -1,3 +1,14 @@
+pub(crate) mod attachment;
 pub(crate) mod note;
+pub(crate) mod note_event;
+pub(crate) mod note_import_job;
+pub(crate) mod note_public_link;
+pub(crate) mod note_revision;
+pub(crate) mod note_share;
+pub(crate) mod notebook;
+pub(crate) mod outbox;
+pub(crate) mod tag;
 pub(crate) mod token;
 pub(crate) mod user;
+pub(crate) mod webhook;
+pub(crate) mod webhook_delivery;
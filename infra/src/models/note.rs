@@ -1,16 +1,29 @@
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use dmn::entities::note::{Note, NoteData};
+use common::error::AppError;
+use dmn::{
+    entities::{
+        note::{Note, NoteData},
+        tag::Tag,
+    },
+    repos::note::{NoteSearchResult, NoteTombstone, SyncPosition},
+};
 
 #[derive(Debug)]
 pub(crate) struct NotePg {
     pub(crate) id: Uuid,
     pub(crate) user_id: Uuid,
+    pub(crate) notebook_id: Option<Uuid>,
     pub(crate) title: String,
     pub(crate) content: String,
+    pub(crate) version: i32,
+    pub(crate) pinned: bool,
+    pub(crate) archived: bool,
+    pub(crate) favorite: bool,
     pub(crate) created_at: DateTime<Utc>,
     pub(crate) updated_at: DateTime<Utc>,
+    pub(crate) deleted_at: Option<DateTime<Utc>>,
 }
 
 impl From<Note> for NotePg {
@@ -18,23 +31,154 @@ impl From<Note> for NotePg {
         Self {
             id: dmn_note.id(),
             user_id: dmn_note.user_id(),
+            notebook_id: dmn_note.notebook_id(),
             title: dmn_note.title().into(),
             content: dmn_note.content().into(),
+            version: dmn_note.version() as i32,
+            pinned: dmn_note.is_pinned(),
+            archived: dmn_note.is_archived(),
+            favorite: dmn_note.is_favorite(),
             created_at: dmn_note.created_at(),
             updated_at: dmn_note.updated_at(),
+            deleted_at: dmn_note.deleted_at(),
         }
     }
 }
 
+/// Tags live in a separate table and are attached by the repository afterwards.
 impl From<NotePg> for NoteData {
     fn from(pg_note: NotePg) -> Self {
         NoteData {
             id: pg_note.id,
             user_id: pg_note.user_id,
+            notebook_id: pg_note.notebook_id,
             title: pg_note.title,
             content: pg_note.content,
+            version: pg_note.version as u32,
+            pinned: pg_note.pinned,
+            archived: pg_note.archived,
+            favorite: pg_note.favorite,
+            tags: Vec::new(),
             created_at: pg_note.created_at,
             updated_at: pg_note.updated_at,
+            deleted_at: pg_note.deleted_at,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct NoteSearchResultPg {
+    pub(crate) id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) notebook_id: Option<Uuid>,
+    pub(crate) title: String,
+    pub(crate) content: String,
+    pub(crate) version: i32,
+    pub(crate) pinned: bool,
+    pub(crate) archived: bool,
+    pub(crate) favorite: bool,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) updated_at: DateTime<Utc>,
+    pub(crate) deleted_at: Option<DateTime<Utc>>,
+    pub(crate) rank: f32,
+    pub(crate) title_snippet: String,
+    pub(crate) content_snippet: String,
+}
+
+impl NoteSearchResultPg {
+    pub(crate) fn into_dmn(self, tags: Vec<Tag>) -> Result<NoteSearchResult, AppError> {
+        let note_data = NoteData {
+            id: self.id,
+            user_id: self.user_id,
+            notebook_id: self.notebook_id,
+            title: self.title,
+            content: self.content,
+            version: self.version as u32,
+            pinned: self.pinned,
+            archived: self.archived,
+            favorite: self.favorite,
+            tags,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            deleted_at: self.deleted_at,
+        };
+        Ok(NoteSearchResult {
+            note: note_data.try_into()?,
+            rank: self.rank,
+            title_snippet: self.title_snippet,
+            content_snippet: self.content_snippet,
+        })
+    }
+}
+
+/// [`NotePg`] along with its position in the change history.
+#[derive(Debug)]
+pub(crate) struct ChangedNotePg {
+    pub(crate) id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) notebook_id: Option<Uuid>,
+    pub(crate) title: String,
+    pub(crate) content: String,
+    pub(crate) version: i32,
+    pub(crate) pinned: bool,
+    pub(crate) archived: bool,
+    pub(crate) favorite: bool,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) updated_at: DateTime<Utc>,
+    pub(crate) deleted_at: Option<DateTime<Utc>>,
+    pub(crate) change_xid: i64,
+}
+
+impl ChangedNotePg {
+    pub(crate) fn position(&self) -> SyncPosition {
+        SyncPosition {
+            change_xid: self.change_xid as u64,
+            note_id: self.id,
+        }
+    }
+}
+
+impl From<ChangedNotePg> for NoteData {
+    fn from(pg_note: ChangedNotePg) -> Self {
+        NotePg {
+            id: pg_note.id,
+            user_id: pg_note.user_id,
+            notebook_id: pg_note.notebook_id,
+            title: pg_note.title,
+            content: pg_note.content,
+            version: pg_note.version,
+            pinned: pg_note.pinned,
+            archived: pg_note.archived,
+            favorite: pg_note.favorite,
+            created_at: pg_note.created_at,
+            updated_at: pg_note.updated_at,
+            deleted_at: pg_note.deleted_at,
+        }
+        .into()
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct NoteTombstonePg {
+    pub(crate) note_id: Uuid,
+    pub(crate) deleted_at: DateTime<Utc>,
+    pub(crate) change_xid: i64,
+}
+
+impl NoteTombstonePg {
+    pub(crate) fn position(&self) -> SyncPosition {
+        SyncPosition {
+            change_xid: self.change_xid as u64,
+            note_id: self.note_id,
+        }
+    }
+}
+
+impl From<NoteTombstonePg> for NoteTombstone {
+    fn from(pg_tombstone: NoteTombstonePg) -> Self {
+        Self {
+            note_id: pg_tombstone.note_id,
+            deleted_at: pg_tombstone.deleted_at,
         }
     }
 }
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use app::events::note_events::{NoteEvent, NoteEventKind};
+
+/// `NOTIFY` payload of a [`NoteEvent`].
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct NoteEventPg {
+    pub(crate) kind: NoteEventKindPg,
+    pub(crate) note_id: Uuid,
+    pub(crate) user_id: Uuid,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum NoteEventKindPg {
+    Created,
+    Updated,
+    Deleted,
+}
+
+impl From<NoteEvent> for NoteEventPg {
+    fn from(event: NoteEvent) -> Self {
+        let kind = match event.kind {
+            NoteEventKind::Created => NoteEventKindPg::Created,
+            NoteEventKind::Updated => NoteEventKindPg::Updated,
+            NoteEventKind::Deleted => NoteEventKindPg::Deleted,
+        };
+        Self {
+            kind,
+            note_id: event.note_id,
+            user_id: event.user_id,
+        }
+    }
+}
+
+impl From<NoteEventPg> for NoteEvent {
+    fn from(pg_event: NoteEventPg) -> Self {
+        let kind = match pg_event.kind {
+            NoteEventKindPg::Created => NoteEventKind::Created,
+            NoteEventKindPg::Updated => NoteEventKind::Updated,
+            NoteEventKindPg::Deleted => NoteEventKind::Deleted,
+        };
+        Self {
+            kind,
+            note_id: pg_event.note_id,
+            user_id: pg_event.user_id,
+        }
+    }
+}
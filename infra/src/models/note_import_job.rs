@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use common::error::AppError;
+use dmn::entities::note_import_job::{NoteImportFailure, NoteImportJobData};
+
+#[derive(Debug)]
+pub(crate) struct NoteImportJobPg {
+    pub(crate) id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) format: String,
+    pub(crate) status: String,
+    pub(crate) total_count: Option<i32>,
+    pub(crate) processed_count: i32,
+    pub(crate) imported_count: i32,
+    /// JSON array of [`NoteImportFailurePg`].
+    pub(crate) failures: String,
+    pub(crate) error: Option<String>,
+    pub(crate) attempts: i32,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) updated_at: DateTime<Utc>,
+    pub(crate) finished_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<NoteImportJobPg> for NoteImportJobData {
+    type Error = AppError;
+
+    fn try_from(pg_job: NoteImportJobPg) -> Result<Self, Self::Error> {
+        let failures =
+            serde_json::from_str::<Vec<NoteImportFailurePg>>(&pg_job.failures).map_err(|err| {
+                AppError::internal_with_private(
+                    format!("Failed to parse note import job ({}) failures!", pg_job.id),
+                    err.to_string(),
+                )
+            })?;
+        Ok(NoteImportJobData {
+            id: pg_job.id,
+            user_id: pg_job.user_id,
+            format: pg_job.format,
+            status: pg_job.status,
+            total_count: pg_job.total_count.map(|count| count as u32),
+            processed_count: pg_job.processed_count as u32,
+            imported_count: pg_job.imported_count as u32,
+            failures: failures.into_iter().map(|failure| failure.into()).collect(),
+            error: pg_job.error,
+            attempts: pg_job.attempts as u32,
+            created_at: pg_job.created_at,
+            updated_at: pg_job.updated_at,
+            finished_at: pg_job.finished_at,
+        })
+    }
+}
+
+/// JSON representation of a [`NoteImportFailure`].
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct NoteImportFailurePg {
+    pub(crate) item: String,
+    pub(crate) error: String,
+}
+
+impl From<NoteImportFailure> for NoteImportFailurePg {
+    fn from(failure: NoteImportFailure) -> Self {
+        Self {
+            item: failure.item,
+            error: failure.error,
+        }
+    }
+}
+
+impl From<NoteImportFailurePg> for NoteImportFailure {
+    fn from(pg_failure: NoteImportFailurePg) -> Self {
+        Self {
+            item: pg_failure.item,
+            error: pg_failure.error,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use dmn::entities::note_public_link::NotePublicLinkData;
+
+#[derive(Debug)]
+pub(crate) struct NotePublicLinkPg {
+    pub(crate) note_id: Uuid,
+    pub(crate) token: String,
+    pub(crate) expires_at: Option<DateTime<Utc>>,
+    pub(crate) created_at: DateTime<Utc>,
+}
+
+impl From<NotePublicLinkPg> for NotePublicLinkData {
+    fn from(pg_link: NotePublicLinkPg) -> Self {
+        NotePublicLinkData {
+            note_id: pg_link.note_id,
+            token: pg_link.token,
+            expires_at: pg_link.expires_at,
+            created_at: pg_link.created_at,
+        }
+    }
+}
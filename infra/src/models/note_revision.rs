@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use dmn::entities::note_revision::NoteRevisionData;
+
+#[derive(Debug)]
+pub(crate) struct NoteRevisionPg {
+    pub(crate) id: Uuid,
+    pub(crate) note_id: Uuid,
+    pub(crate) revision: i32,
+    pub(crate) title: String,
+    pub(crate) content: String,
+    pub(crate) created_at: DateTime<Utc>,
+}
+
+impl From<NoteRevisionPg> for NoteRevisionData {
+    fn from(pg_revision: NoteRevisionPg) -> Self {
+        NoteRevisionData {
+            id: pg_revision.id,
+            note_id: pg_revision.note_id,
+            revision: pg_revision.revision as u32,
+            title: pg_revision.title,
+            content: pg_revision.content,
+            created_at: pg_revision.created_at,
+        }
+    }
+}
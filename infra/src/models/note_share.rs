@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use common::error::AppError;
+use dmn::entities::note_share::{NoteAccess, NoteShareData};
+
+#[derive(Debug)]
+pub(crate) struct NoteSharePg {
+    pub(crate) note_id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) user_email: String,
+    pub(crate) role: String,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<NoteSharePg> for NoteShareData {
+    type Error = AppError;
+
+    fn try_from(pg_share: NoteSharePg) -> Result<Self, Self::Error> {
+        Ok(NoteShareData {
+            note_id: pg_share.note_id,
+            user_id: pg_share.user_id,
+            user_email: pg_share.user_email.try_into()?,
+            role: pg_share.role.as_str().try_into()?,
+            created_at: pg_share.created_at,
+            updated_at: pg_share.updated_at,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct NoteAccessPg {
+    pub(crate) is_owner: bool,
+    pub(crate) role: Option<String>,
+}
+
+impl TryFrom<NoteAccessPg> for NoteAccess {
+    type Error = AppError;
+
+    fn try_from(pg_access: NoteAccessPg) -> Result<Self, Self::Error> {
+        match (pg_access.is_owner, pg_access.role) {
+            (true, _) => Ok(NoteAccess::Owner),
+            (false, Some(role)) => Ok(NoteAccess::Shared(role.as_str().try_into()?)),
+            (false, None) => Err(AppError::internal("Note access without a share role!")),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use dmn::entities::notebook::{Notebook, NotebookData};
+
+#[derive(Debug)]
+pub(crate) struct NotebookPg {
+    pub(crate) id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) parent_id: Option<Uuid>,
+    pub(crate) name: String,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) updated_at: DateTime<Utc>,
+}
+
+impl From<Notebook> for NotebookPg {
+    fn from(dmn_notebook: Notebook) -> Self {
+        Self {
+            id: dmn_notebook.id(),
+            user_id: dmn_notebook.user_id(),
+            parent_id: dmn_notebook.parent_id(),
+            name: dmn_notebook.name().into(),
+            created_at: dmn_notebook.created_at(),
+            updated_at: dmn_notebook.updated_at(),
+        }
+    }
+}
+
+impl From<NotebookPg> for NotebookData {
+    fn from(pg_notebook: NotebookPg) -> Self {
+        NotebookData {
+            id: pg_notebook.id,
+            user_id: pg_notebook.user_id,
+            parent_id: pg_notebook.parent_id,
+            name: pg_notebook.name,
+            created_at: pg_notebook.created_at,
+            updated_at: pg_notebook.updated_at,
+        }
+    }
+}
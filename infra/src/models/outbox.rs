@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use common::error::AppError;
+use dmn::events::DomainEvent;
+
+#[derive(Debug)]
+pub(crate) struct OutboxEventPg {
+    pub(crate) id: i64,
+    pub(crate) event_id: Uuid,
+    pub(crate) payload: String,
+    pub(crate) attempts: i32,
+    pub(crate) handled_by: Vec<String>,
+}
+
+impl OutboxEventPg {
+    pub(crate) fn to_dmn(&self) -> Result<DomainEvent, AppError> {
+        serde_json::from_str::<DomainEventPg>(&self.payload)
+            .map(|pg_event| pg_event.into())
+            .map_err(|err| {
+                AppError::internal_with_private(
+                    format!("Failed to parse outbox event ({})!", self.id),
+                    err.to_string(),
+                )
+            })
+    }
+}
+
+/// JSON payload of an outbox [`DomainEvent`].
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub(crate) enum DomainEventPg {
+    NoteCreated { note_id: Uuid, user_id: Uuid },
+    NoteUpdated { note_id: Uuid, user_id: Uuid },
+    NoteDeleted { note_id: Uuid, user_id: Uuid },
+    UserRegistered { user_id: Uuid, email: String },
+    UserUpdated { user_id: Uuid },
+    UserDeleted { user_id: Uuid },
+    PasswordChanged { user_id: Uuid },
+}
+
+impl From<&DomainEvent> for DomainEventPg {
+    fn from(event: &DomainEvent) -> Self {
+        match event.clone() {
+            DomainEvent::NoteCreated { note_id, user_id } => Self::NoteCreated { note_id, user_id },
+            DomainEvent::NoteUpdated { note_id, user_id } => Self::NoteUpdated { note_id, user_id },
+            DomainEvent::NoteDeleted { note_id, user_id } => Self::NoteDeleted { note_id, user_id },
+            DomainEvent::UserRegistered { user_id, email } => {
+                Self::UserRegistered { user_id, email }
+            }
+            DomainEvent::UserUpdated { user_id } => Self::UserUpdated { user_id },
+            DomainEvent::UserDeleted { user_id } => Self::UserDeleted { user_id },
+            DomainEvent::PasswordChanged { user_id } => Self::PasswordChanged { user_id },
+        }
+    }
+}
+
+impl From<DomainEventPg> for DomainEvent {
+    fn from(pg_event: DomainEventPg) -> Self {
+        match pg_event {
+            DomainEventPg::NoteCreated { note_id, user_id } => {
+                Self::NoteCreated { note_id, user_id }
+            }
+            DomainEventPg::NoteUpdated { note_id, user_id } => {
+                Self::NoteUpdated { note_id, user_id }
+            }
+            DomainEventPg::NoteDeleted { note_id, user_id } => {
+                Self::NoteDeleted { note_id, user_id }
+            }
+            DomainEventPg::UserRegistered { user_id, email } => {
+                Self::UserRegistered { user_id, email }
+            }
+            DomainEventPg::UserUpdated { user_id } => Self::UserUpdated { user_id },
+            DomainEventPg::UserDeleted { user_id } => Self::UserDeleted { user_id },
+            DomainEventPg::PasswordChanged { user_id } => Self::PasswordChanged { user_id },
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use dmn::entities::tag::{Tag, TagData};
+
+#[derive(Debug)]
+pub(crate) struct TagPg {
+    pub(crate) id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) name: String,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) updated_at: DateTime<Utc>,
+}
+
+impl From<Tag> for TagPg {
+    fn from(dmn_tag: Tag) -> Self {
+        Self {
+            id: dmn_tag.id(),
+            user_id: dmn_tag.user_id(),
+            name: dmn_tag.name().into(),
+            created_at: dmn_tag.created_at(),
+            updated_at: dmn_tag.updated_at(),
+        }
+    }
+}
+
+impl From<TagPg> for TagData {
+    fn from(pg_tag: TagPg) -> Self {
+        TagData {
+            id: pg_tag.id,
+            user_id: pg_tag.user_id,
+            name: pg_tag.name,
+            created_at: pg_tag.created_at,
+            updated_at: pg_tag.updated_at,
+        }
+    }
+}
+
+/// A [`TagPg`] paired with the note it's attached to.
+#[derive(Debug)]
+pub(crate) struct NoteTagPg {
+    pub(crate) note_id: Uuid,
+    pub(crate) id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) name: String,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) updated_at: DateTime<Utc>,
+}
+
+impl From<NoteTagPg> for TagPg {
+    fn from(pg_note_tag: NoteTagPg) -> Self {
+        Self {
+            id: pg_note_tag.id,
+            user_id: pg_note_tag.user_id,
+            name: pg_note_tag.name,
+            created_at: pg_note_tag.created_at,
+            updated_at: pg_note_tag.updated_at,
+        }
+    }
+}
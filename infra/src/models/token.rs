@@ -1,4 +1,6 @@
-use app::types::auth_token::{AccessToken, AuthTokenPair, RefreshToken};
+use app::types::auth_token::{
+    AccessToken, AuthSession, AuthTokenPair, RefreshToken, RevokedRefreshToken,
+};
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
@@ -6,6 +8,7 @@ use uuid::Uuid;
 pub(crate) struct AccessTokenPg {
     pub(crate) id: Uuid,
     pub(crate) user_id: Uuid,
+    pub(crate) session_id: Uuid,
     pub(crate) jwt: String,
     pub(crate) expires_at: DateTime<Utc>,
 }
@@ -15,6 +18,7 @@ impl From<AccessTokenPg> for AccessToken {
         Self {
             id: pg_access_token.id,
             user_id: pg_access_token.user_id,
+            session_id: pg_access_token.session_id,
             jwt: pg_access_token.jwt,
             expires_at: pg_access_token.expires_at,
         }
@@ -26,6 +30,7 @@ pub(crate) struct RefreshTokenPg {
     pub(crate) id: Uuid,
     pub(crate) user_id: Uuid,
     pub(crate) access_token_id: Uuid,
+    pub(crate) family_id: Uuid,
     pub(crate) jwt: String,
     pub(crate) expires_at: DateTime<Utc>,
 }
@@ -36,6 +41,7 @@ impl From<RefreshTokenPg> for RefreshToken {
             id: pg_refresh_token.id,
             user_id: pg_refresh_token.user_id,
             access_token_id: pg_refresh_token.access_token_id,
+            family_id: pg_refresh_token.family_id,
             jwt: pg_refresh_token.jwt,
             expires_at: pg_refresh_token.expires_at,
         }
@@ -56,3 +62,47 @@ impl From<AuthTokenPairPg> for AuthTokenPair {
         }
     }
 }
+
+#[derive(Debug)]
+pub(crate) struct AuthSessionPg {
+    pub(crate) id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) ip_address: Option<String>,
+    pub(crate) user_agent: Option<String>,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) last_refreshed_at: DateTime<Utc>,
+}
+
+impl From<AuthSessionPg> for AuthSession {
+    fn from(pg_session: AuthSessionPg) -> Self {
+        Self {
+            id: pg_session.id,
+            user_id: pg_session.user_id,
+            ip_address: pg_session.ip_address,
+            user_agent: pg_session.user_agent,
+            created_at: pg_session.created_at,
+            last_refreshed_at: pg_session.last_refreshed_at,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct RevokedRefreshTokenPg {
+    pub(crate) id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) family_id: Uuid,
+    pub(crate) expires_at: DateTime<Utc>,
+    pub(crate) revoked_at: DateTime<Utc>,
+}
+
+impl From<RevokedRefreshTokenPg> for RevokedRefreshToken {
+    fn from(pg_revoked_token: RevokedRefreshTokenPg) -> Self {
+        Self {
+            id: pg_revoked_token.id,
+            user_id: pg_revoked_token.user_id,
+            family_id: pg_revoked_token.family_id,
+            expires_at: pg_revoked_token.expires_at,
+            revoked_at: pg_revoked_token.revoked_at,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use dmn::entities::webhook::WebhookData;
+
+#[derive(Debug)]
+pub(crate) struct WebhookPg {
+    pub(crate) id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) url: String,
+    pub(crate) secret: String,
+    pub(crate) event_types: Vec<String>,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) updated_at: DateTime<Utc>,
+}
+
+impl From<WebhookPg> for WebhookData {
+    fn from(pg_webhook: WebhookPg) -> Self {
+        WebhookData {
+            id: pg_webhook.id,
+            user_id: pg_webhook.user_id,
+            url: pg_webhook.url,
+            secret: pg_webhook.secret,
+            event_types: pg_webhook.event_types,
+            created_at: pg_webhook.created_at,
+            updated_at: pg_webhook.updated_at,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use dmn::entities::webhook_delivery::WebhookDeliveryData;
+
+#[derive(Debug)]
+pub(crate) struct WebhookDeliveryPg {
+    pub(crate) id: Uuid,
+    pub(crate) webhook_id: Uuid,
+    pub(crate) event_id: Uuid,
+    pub(crate) event_type: String,
+    pub(crate) note_id: Option<Uuid>,
+    pub(crate) status: String,
+    pub(crate) attempts: i32,
+    pub(crate) response_status: Option<i32>,
+    pub(crate) last_error: Option<String>,
+    pub(crate) next_attempt_at: Option<DateTime<Utc>>,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) delivered_at: Option<DateTime<Utc>>,
+}
+
+impl From<WebhookDeliveryPg> for WebhookDeliveryData {
+    fn from(pg_delivery: WebhookDeliveryPg) -> Self {
+        WebhookDeliveryData {
+            id: pg_delivery.id,
+            webhook_id: pg_delivery.webhook_id,
+            event_id: pg_delivery.event_id,
+            event_type: pg_delivery.event_type,
+            note_id: pg_delivery.note_id,
+            status: pg_delivery.status,
+            attempts: pg_delivery.attempts as u32,
+            response_status: pg_delivery.response_status.map(|status| status as u16),
+            last_error: pg_delivery.last_error,
+            next_attempt_at: pg_delivery.next_attempt_at,
+            created_at: pg_delivery.created_at,
+            delivered_at: pg_delivery.delivered_at,
+        }
+    }
+}
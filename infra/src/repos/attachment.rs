@@ -0,0 +1,142 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{db::attachment as db, tx::ctx::PgTransactionContextExt};
+use common::{error::AppError, tx::ctx::TransactionContext};
+use dmn::{
+    entities::attachment::{Attachment, AttachmentData, CreateAttachmentData},
+    repos::attachment::AttachmentRepository,
+};
+
+pub struct PgAttachmentRepository {
+    db_pool: Arc<PgPool>,
+}
+
+impl PgAttachmentRepository {
+    pub fn new(db_pool: Arc<PgPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl AttachmentRepository for PgAttachmentRepository {
+    async fn get_attachment(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        attachment_id: Uuid,
+    ) -> Result<Attachment, AppError> {
+        let db_attachment = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::get_attachment(&mut **pg_tx, note_id, attachment_id).await?
+            }
+            None => db::get_attachment(&*self.db_pool, note_id, attachment_id).await?,
+        };
+        Ok(AttachmentData::from(db_attachment).into())
+    }
+
+    async fn get_note_attachments(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+    ) -> Result<Vec<Attachment>, AppError> {
+        let db_attachments = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::get_note_attachments(&mut **pg_tx, note_id).await?
+            }
+            None => db::get_note_attachments(&*self.db_pool, note_id).await?,
+        };
+        Ok(db_attachments
+            .into_iter()
+            .map(|db_attachment| AttachmentData::from(db_attachment).into())
+            .collect())
+    }
+
+    async fn create_attachment(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        attachment_data: CreateAttachmentData,
+    ) -> Result<Attachment, AppError> {
+        let db_attachment = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::create_attachment(&mut **pg_tx, attachment_data.into()).await?
+            }
+            None => db::create_attachment(&*self.db_pool, attachment_data.into()).await?,
+        };
+        Ok(AttachmentData::from(db_attachment).into())
+    }
+
+    async fn delete_attachment(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        attachment_id: Uuid,
+    ) -> Result<(), AppError> {
+        match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::delete_attachment(&mut **pg_tx, note_id, attachment_id).await?
+            }
+            None => db::delete_attachment(&*self.db_pool, note_id, attachment_id).await?,
+        };
+        Ok(())
+    }
+
+    async fn get_orphaned_attachment_blobs(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        limit: u32,
+    ) -> Result<Vec<Uuid>, AppError> {
+        match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::get_orphaned_attachment_blobs(&mut **pg_tx, limit).await
+            }
+            None => db::get_orphaned_attachment_blobs(&*self.db_pool, limit).await,
+        }
+    }
+
+    async fn delete_orphaned_attachment_blob(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        attachment_id: Uuid,
+    ) -> Result<(), AppError> {
+        match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::delete_orphaned_attachment_blob(&mut **pg_tx, attachment_id).await?
+            }
+            None => db::delete_orphaned_attachment_blob(&*self.db_pool, attachment_id).await?,
+        };
+        Ok(())
+    }
+}
+
+impl From<CreateAttachmentData> for db::CreateAttachmentDataPg {
+    fn from(dmn_attachment_data: CreateAttachmentData) -> Self {
+        Self {
+            note_id: dmn_attachment_data.note_id,
+            file_name: dmn_attachment_data.file_name,
+            mime_type: dmn_attachment_data.mime_type,
+            size: dmn_attachment_data.size as i64,
+            sha256: dmn_attachment_data.sha256,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use std::sync::Arc;
 use uuid::Uuid;
@@ -7,8 +8,8 @@ use crate::{db::auth_token as db, tx::ctx::PgTransactionContextExt};
 use app::{
     repos::auth_token::AuthTokenRepository,
     types::auth_token::{
-        AccessToken, AuthTokenPair, RefreshToken, UniqueAccessTokenIdentifier,
-        UniqueRefreshTokenIdentifier,
+        AccessToken, AuthClientInfo, AuthSession, AuthTokenPair, RefreshToken, RevokedRefreshToken,
+        UniqueAccessTokenIdentifier, UniqueRefreshTokenIdentifier,
     },
 };
 use common::{error::AppError, tx::ctx::TransactionContext};
@@ -134,6 +135,174 @@ impl AuthTokenRepository for PgAuthTokenRepository {
         };
         Ok(())
     }
+
+    async fn get_user_sessions(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        user_id: Uuid,
+    ) -> Result<Vec<AuthSession>, AppError> {
+        let db_sessions = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::get_user_sessions(&mut **pg_tx, user_id).await?
+            }
+            None => db::get_user_sessions(&*self.db_pool, user_id).await?,
+        };
+        let sessions = db_sessions
+            .into_iter()
+            .map(|session| session.into())
+            .collect();
+        Ok(sessions)
+    }
+
+    async fn create_session(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        session: AuthSession,
+    ) -> Result<AuthSession, AppError> {
+        let db_session = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::create_session(&mut **pg_tx, session.into()).await?
+            }
+            None => db::create_session(&*self.db_pool, session.into()).await?,
+        };
+        let session = db_session.into();
+        Ok(session)
+    }
+
+    async fn touch_session(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        session_id: Uuid,
+        client_info: Option<AuthClientInfo>,
+    ) -> Result<(), AppError> {
+        let AuthClientInfo {
+            ip_address,
+            user_agent,
+        } = client_info.unwrap_or_default();
+        match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::touch_session(&mut **pg_tx, session_id, ip_address, user_agent).await?
+            }
+            None => db::touch_session(&*self.db_pool, session_id, ip_address, user_agent).await?,
+        };
+        Ok(())
+    }
+
+    async fn delete_session(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        session_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<(), AppError> {
+        match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::delete_session(&mut **pg_tx, session_id, user_id).await?
+            }
+            None => db::delete_session(&*self.db_pool, session_id, user_id).await?,
+        };
+        Ok(())
+    }
+
+    async fn delete_user_sessions(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        user_id: Uuid,
+    ) -> Result<u64, AppError> {
+        let deleted_count = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::delete_user_sessions(&mut **pg_tx, user_id).await?
+            }
+            None => db::delete_user_sessions(&*self.db_pool, user_id).await?,
+        };
+        Ok(deleted_count)
+    }
+
+    async fn get_revoked_refresh_token(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        token_id: Uuid,
+    ) -> Result<RevokedRefreshToken, AppError> {
+        let db_revoked_token = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::get_revoked_refresh_token(&mut **pg_tx, token_id).await?
+            }
+            None => db::get_revoked_refresh_token(&*self.db_pool, token_id).await?,
+        };
+        let revoked_token = db_revoked_token.into();
+        Ok(revoked_token)
+    }
+
+    async fn create_revoked_refresh_token(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        revoked_token: RevokedRefreshToken,
+    ) -> Result<RevokedRefreshToken, AppError> {
+        let db_revoked_token = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::create_revoked_refresh_token(&mut **pg_tx, revoked_token.into()).await?
+            }
+            None => db::create_revoked_refresh_token(&*self.db_pool, revoked_token.into()).await?,
+        };
+        let revoked_token = db_revoked_token.into();
+        Ok(revoked_token)
+    }
+
+    async fn delete_expired_revoked_refresh_tokens(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        expired_before: DateTime<Utc>,
+    ) -> Result<u64, AppError> {
+        let deleted_count = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::delete_expired_revoked_refresh_tokens(&mut **pg_tx, expired_before).await?
+            }
+            None => {
+                db::delete_expired_revoked_refresh_tokens(&*self.db_pool, expired_before).await?
+            }
+        };
+        Ok(deleted_count)
+    }
+
+    async fn delete_token_family(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        family_id: Uuid,
+    ) -> Result<u64, AppError> {
+        let deleted_count = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::delete_token_family(&mut **pg_tx, family_id).await?
+            }
+            None => db::delete_token_family(&*self.db_pool, family_id).await?,
+        };
+        Ok(deleted_count)
+    }
 }
 
 impl From<AccessToken> for db::CreateAccessTokenDataPg {
@@ -141,6 +310,7 @@ impl From<AccessToken> for db::CreateAccessTokenDataPg {
         Self {
             id: dmn_access_token.id,
             user_id: dmn_access_token.user_id,
+            session_id: dmn_access_token.session_id,
             jwt: dmn_access_token.jwt,
             expires_at: dmn_access_token.expires_at,
         }
@@ -153,8 +323,34 @@ impl From<RefreshToken> for db::CreateRefreshTokenDataPg {
             id: dmn_refresh_token.id,
             user_id: dmn_refresh_token.user_id,
             access_token_id: dmn_refresh_token.access_token_id,
+            family_id: dmn_refresh_token.family_id,
             jwt: dmn_refresh_token.jwt,
             expires_at: dmn_refresh_token.expires_at,
         }
     }
 }
+
+impl From<AuthSession> for db::CreateSessionDataPg {
+    fn from(dmn_session: AuthSession) -> Self {
+        Self {
+            id: dmn_session.id,
+            user_id: dmn_session.user_id,
+            ip_address: dmn_session.ip_address,
+            user_agent: dmn_session.user_agent,
+            created_at: dmn_session.created_at,
+            last_refreshed_at: dmn_session.last_refreshed_at,
+        }
+    }
+}
+
+impl From<RevokedRefreshToken> for db::CreateRevokedRefreshTokenDataPg {
+    fn from(dmn_revoked_token: RevokedRefreshToken) -> Self {
+        Self {
+            id: dmn_revoked_token.id,
+            user_id: dmn_revoked_token.user_id,
+            family_id: dmn_revoked_token.family_id,
+            expires_at: dmn_revoked_token.expires_at,
+            revoked_at: dmn_revoked_token.revoked_at,
+        }
+    }
+}
@@ -1,13 +1,28 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 use uuid::Uuid;
 
-use crate::{db::note as db, tx::ctx::PgTransactionContextExt};
-use common::{error::AppError, params::PaginationParams, tx::ctx::TransactionContext};
+use crate::{
+    db::{note as db, tag as tag_db, take_next_cursor},
+    models::tag::{NoteTagPg, TagPg},
+    tx::ctx::PgTransactionContextExt,
+};
+use common::{
+    error::AppError,
+    params::{PageCursor, Pagination, PaginationParams},
+    tx::ctx::TransactionContext,
+};
 use dmn::{
-    entities::note::{CreateNoteData, Note, NoteData, UpdateNoteData},
-    repos::note::{GetNotesResponse, NoteRepository},
+    entities::{
+        note::{CreateNoteData, Note, NoteData, NoteFlags, PatchNoteData, UpdateNoteData},
+        tag::{Tag, TagData},
+    },
+    repos::note::{
+        GetNotesFilter, GetNotesResponse, NoteChanges, NoteRepository, NoteSort,
+        SearchNotesResponse, SyncContinuation, SyncFrom, SyncPosition, SyncToken,
+    },
 };
 
 pub struct PgNoteRepository {
@@ -28,47 +43,173 @@ impl NoteRepository for PgNoteRepository {
         note_id: Uuid,
         user_id: Option<Uuid>,
     ) -> Result<Note, AppError> {
-        let db_note = match ctx {
+        let (db_note, db_note_tags) = match ctx {
             Some(ctx) => {
                 let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
                     AppError::internal("Invalid transaction context for Postgres repository")
                 })?;
-                db::get_note(&mut **pg_tx, note_id, user_id).await?
+                let db_note = db::get_note(&mut **pg_tx, note_id, user_id).await?;
+                let db_note_tags = tag_db::get_note_tags(&mut **pg_tx, &[db_note.id]).await?;
+                (db_note, db_note_tags)
+            }
+            None => {
+                let db_note = db::get_note(&*self.db_pool, note_id, user_id).await?;
+                let db_note_tags = tag_db::get_note_tags(&*self.db_pool, &[db_note.id]).await?;
+                (db_note, db_note_tags)
             }
-            None => db::get_note(&*self.db_pool, note_id, user_id).await?,
         };
-        let note_data: NoteData = db_note.into();
+        let mut note_data: NoteData = db_note.into();
+        note_data.tags = group_note_tags(db_note_tags)?
+            .remove(&note_data.id)
+            .unwrap_or_default();
         let note = note_data.try_into()?;
         Ok(note)
     }
 
+    async fn lock_note(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+    ) -> Result<(), AppError> {
+        match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::lock_note(&mut **pg_tx, note_id).await
+            }
+            None => db::lock_note(&*self.db_pool, note_id).await,
+        }
+    }
+
     async fn get_notes(
         &self,
         ctx: Option<&mut dyn TransactionContext>,
+        pagination: &Pagination,
+        filter: &GetNotesFilter,
+        sort: &NoteSort,
+        user_id: Option<Uuid>,
+    ) -> Result<GetNotesResponse, AppError> {
+        let (mut db_notes, db_note_tags, count) = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                let db_notes =
+                    db::get_notes(&mut **pg_tx, pagination, filter, sort, user_id).await?;
+                let note_ids: Vec<Uuid> = db_notes.iter().map(|db_note| db_note.id).collect();
+                let db_note_tags = tag_db::get_note_tags(&mut **pg_tx, &note_ids).await?;
+                let count = db::get_note_count(&mut **pg_tx, filter, user_id).await?;
+                (db_notes, db_note_tags, count)
+            }
+            None => {
+                let db_notes =
+                    db::get_notes(&*self.db_pool, pagination, filter, sort, user_id).await?;
+                let note_ids: Vec<Uuid> = db_notes.iter().map(|db_note| db_note.id).collect();
+                let db_note_tags = tag_db::get_note_tags(&*self.db_pool, &note_ids).await?;
+                let count = db::get_note_count(&*self.db_pool, filter, user_id).await?;
+                (db_notes, db_note_tags, count)
+            }
+        };
+        let next_cursor = take_next_cursor(&mut db_notes, pagination, |db_note| PageCursor {
+            created_at: db_note.created_at,
+            id: db_note.id,
+        });
+        let mut tags = group_note_tags(db_note_tags)?;
+        let notes = db_notes
+            .into_iter()
+            .map(|db_note| {
+                let mut note_data: NoteData = db_note.into();
+                note_data.tags = tags.remove(&note_data.id).unwrap_or_default();
+                note_data.try_into()
+            })
+            .collect::<Result<_, AppError>>()?;
+        let dmn_res = GetNotesResponse {
+            notes,
+            count,
+            next_cursor,
+        };
+        Ok(dmn_res)
+    }
+
+    async fn search_notes(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        query: &str,
         pagination: &PaginationParams,
         user_id: Option<Uuid>,
+    ) -> Result<SearchNotesResponse, AppError> {
+        let (db_results, db_note_tags, count) = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                let db_results = db::search_notes(&mut **pg_tx, query, pagination, user_id).await?;
+                let note_ids: Vec<Uuid> = db_results.iter().map(|db_res| db_res.id).collect();
+                let db_note_tags = tag_db::get_note_tags(&mut **pg_tx, &note_ids).await?;
+                let count = db::get_search_note_count(&mut **pg_tx, query, user_id).await?;
+                (db_results, db_note_tags, count)
+            }
+            None => {
+                let db_results =
+                    db::search_notes(&*self.db_pool, query, pagination, user_id).await?;
+                let note_ids: Vec<Uuid> = db_results.iter().map(|db_res| db_res.id).collect();
+                let db_note_tags = tag_db::get_note_tags(&*self.db_pool, &note_ids).await?;
+                let count = db::get_search_note_count(&*self.db_pool, query, user_id).await?;
+                (db_results, db_note_tags, count)
+            }
+        };
+        let mut tags = group_note_tags(db_note_tags)?;
+        let results = db_results
+            .into_iter()
+            .map(|db_result| {
+                let note_tags = tags.remove(&db_result.id).unwrap_or_default();
+                db_result.into_dmn(note_tags)
+            })
+            .collect::<Result<_, AppError>>()?;
+        let dmn_res = SearchNotesResponse { results, count };
+        Ok(dmn_res)
+    }
+
+    async fn get_shared_notes(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        pagination: &PaginationParams,
+        user_id: Uuid,
     ) -> Result<GetNotesResponse, AppError> {
-        let (db_notes, count) = match ctx {
+        let (db_notes, db_note_tags, count) = match ctx {
             Some(ctx) => {
                 let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
                     AppError::internal("Invalid transaction context for Postgres repository")
                 })?;
-                let db_notes = db::get_notes(&mut **pg_tx, pagination, user_id).await?;
-                let count = db::get_note_count(&mut **pg_tx, user_id).await?;
-                (db_notes, count)
+                let db_notes = db::get_shared_notes(&mut **pg_tx, pagination, user_id).await?;
+                let note_ids: Vec<Uuid> = db_notes.iter().map(|db_note| db_note.id).collect();
+                let db_note_tags = tag_db::get_note_tags(&mut **pg_tx, &note_ids).await?;
+                let count = db::get_shared_note_count(&mut **pg_tx, user_id).await?;
+                (db_notes, db_note_tags, count)
             }
             None => {
-                let db_notes = db::get_notes(&*self.db_pool, pagination, user_id).await?;
-                let count = db::get_note_count(&*self.db_pool, user_id).await?;
-                (db_notes, count)
+                let db_notes = db::get_shared_notes(&*self.db_pool, pagination, user_id).await?;
+                let note_ids: Vec<Uuid> = db_notes.iter().map(|db_note| db_note.id).collect();
+                let db_note_tags = tag_db::get_note_tags(&*self.db_pool, &note_ids).await?;
+                let count = db::get_shared_note_count(&*self.db_pool, user_id).await?;
+                (db_notes, db_note_tags, count)
             }
         };
+        let mut tags = group_note_tags(db_note_tags)?;
         let notes = db_notes
             .into_iter()
-            .map(|db_note| db_note.into())
-            .map(|note_data: NoteData| note_data.try_into())
+            .map(|db_note| {
+                let mut note_data: NoteData = db_note.into();
+                note_data.tags = tags.remove(&note_data.id).unwrap_or_default();
+                note_data.try_into()
+            })
             .collect::<Result<_, AppError>>()?;
-        let dmn_res = GetNotesResponse { notes, count };
+        let dmn_res = GetNotesResponse {
+            notes,
+            count,
+            next_cursor: None,
+        };
         Ok(dmn_res)
     }
 
@@ -86,6 +227,7 @@ impl NoteRepository for PgNoteRepository {
             }
             None => db::create_note(&*self.db_pool, note_data.into()).await?,
         };
+        // Newly created notes carry no tags.
         let note_data: NoteData = db_note.into();
         let note = note_data.try_into()?;
         Ok(note)
@@ -96,18 +238,149 @@ impl NoteRepository for PgNoteRepository {
         ctx: Option<&mut dyn TransactionContext>,
         note_id: Uuid,
         note_data: UpdateNoteData,
+        expected_version: Option<u32>,
         user_id: Option<Uuid>,
     ) -> Result<Note, AppError> {
-        let db_note = match ctx {
+        let (db_note, db_note_tags) = match ctx {
             Some(ctx) => {
                 let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
                     AppError::internal("Invalid transaction context for Postgres repository")
                 })?;
-                db::update_note(&mut **pg_tx, note_id, note_data.into(), user_id).await?
+                let db_note = db::update_note(
+                    &mut **pg_tx,
+                    note_id,
+                    note_data.into(),
+                    expected_version,
+                    user_id,
+                )
+                .await?;
+                let db_note_tags = tag_db::get_note_tags(&mut **pg_tx, &[db_note.id]).await?;
+                (db_note, db_note_tags)
+            }
+            None => {
+                let db_note = db::update_note(
+                    &*self.db_pool,
+                    note_id,
+                    note_data.into(),
+                    expected_version,
+                    user_id,
+                )
+                .await?;
+                let db_note_tags = tag_db::get_note_tags(&*self.db_pool, &[db_note.id]).await?;
+                (db_note, db_note_tags)
             }
-            None => db::update_note(&*self.db_pool, note_id, note_data.into(), user_id).await?,
         };
-        let note_data: NoteData = db_note.into();
+        let mut note_data: NoteData = db_note.into();
+        note_data.tags = group_note_tags(db_note_tags)?
+            .remove(&note_data.id)
+            .unwrap_or_default();
+        let note = note_data.try_into()?;
+        Ok(note)
+    }
+
+    async fn patch_note(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        note_data: PatchNoteData,
+        expected_version: Option<u32>,
+        user_id: Option<Uuid>,
+    ) -> Result<Note, AppError> {
+        let (db_note, db_note_tags) = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                let db_note = db::patch_note(
+                    &mut **pg_tx,
+                    note_id,
+                    note_data.into(),
+                    expected_version,
+                    user_id,
+                )
+                .await?;
+                let db_note_tags = tag_db::get_note_tags(&mut **pg_tx, &[db_note.id]).await?;
+                (db_note, db_note_tags)
+            }
+            None => {
+                let db_note = db::patch_note(
+                    &*self.db_pool,
+                    note_id,
+                    note_data.into(),
+                    expected_version,
+                    user_id,
+                )
+                .await?;
+                let db_note_tags = tag_db::get_note_tags(&*self.db_pool, &[db_note.id]).await?;
+                (db_note, db_note_tags)
+            }
+        };
+        let mut note_data: NoteData = db_note.into();
+        note_data.tags = group_note_tags(db_note_tags)?
+            .remove(&note_data.id)
+            .unwrap_or_default();
+        let note = note_data.try_into()?;
+        Ok(note)
+    }
+
+    async fn move_note(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        notebook_id: Option<Uuid>,
+        user_id: Option<Uuid>,
+    ) -> Result<Note, AppError> {
+        let (db_note, db_note_tags) = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                let db_note = db::move_note(&mut **pg_tx, note_id, notebook_id, user_id).await?;
+                let db_note_tags = tag_db::get_note_tags(&mut **pg_tx, &[db_note.id]).await?;
+                (db_note, db_note_tags)
+            }
+            None => {
+                let db_note = db::move_note(&*self.db_pool, note_id, notebook_id, user_id).await?;
+                let db_note_tags = tag_db::get_note_tags(&*self.db_pool, &[db_note.id]).await?;
+                (db_note, db_note_tags)
+            }
+        };
+        let mut note_data: NoteData = db_note.into();
+        note_data.tags = group_note_tags(db_note_tags)?
+            .remove(&note_data.id)
+            .unwrap_or_default();
+        let note = note_data.try_into()?;
+        Ok(note)
+    }
+
+    async fn update_note_flags(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        flags: NoteFlags,
+        user_id: Option<Uuid>,
+    ) -> Result<Note, AppError> {
+        let (db_note, db_note_tags) = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                let db_note =
+                    db::update_note_flags(&mut **pg_tx, note_id, flags.into(), user_id).await?;
+                let db_note_tags = tag_db::get_note_tags(&mut **pg_tx, &[db_note.id]).await?;
+                (db_note, db_note_tags)
+            }
+            None => {
+                let db_note =
+                    db::update_note_flags(&*self.db_pool, note_id, flags.into(), user_id).await?;
+                let db_note_tags = tag_db::get_note_tags(&*self.db_pool, &[db_note.id]).await?;
+                (db_note, db_note_tags)
+            }
+        };
+        let mut note_data: NoteData = db_note.into();
+        note_data.tags = group_note_tags(db_note_tags)?
+            .remove(&note_data.id)
+            .unwrap_or_default();
         let note = note_data.try_into()?;
         Ok(note)
     }
@@ -116,6 +389,7 @@ impl NoteRepository for PgNoteRepository {
         &self,
         ctx: Option<&mut dyn TransactionContext>,
         note_id: Uuid,
+        expected_version: Option<u32>,
         user_id: Option<Uuid>,
     ) -> Result<(), AppError> {
         match ctx {
@@ -123,20 +397,253 @@ impl NoteRepository for PgNoteRepository {
                 let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
                     AppError::internal("Invalid transaction context for Postgres repository")
                 })?;
-                db::delete_note(&mut **pg_tx, note_id, user_id).await?
+                db::delete_note(&mut **pg_tx, note_id, expected_version, user_id).await?
             }
-            None => db::delete_note(&*self.db_pool, note_id, user_id).await?,
+            None => db::delete_note(&*self.db_pool, note_id, expected_version, user_id).await?,
         };
         Ok(())
     }
+
+    async fn get_trashed_notes(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        pagination: &PaginationParams,
+        user_id: Option<Uuid>,
+    ) -> Result<GetNotesResponse, AppError> {
+        let (db_notes, db_note_tags, count) = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                let db_notes = db::get_trashed_notes(&mut **pg_tx, pagination, user_id).await?;
+                let note_ids: Vec<Uuid> = db_notes.iter().map(|db_note| db_note.id).collect();
+                let db_note_tags = tag_db::get_note_tags(&mut **pg_tx, &note_ids).await?;
+                let count = db::get_trashed_note_count(&mut **pg_tx, user_id).await?;
+                (db_notes, db_note_tags, count)
+            }
+            None => {
+                let db_notes = db::get_trashed_notes(&*self.db_pool, pagination, user_id).await?;
+                let note_ids: Vec<Uuid> = db_notes.iter().map(|db_note| db_note.id).collect();
+                let db_note_tags = tag_db::get_note_tags(&*self.db_pool, &note_ids).await?;
+                let count = db::get_trashed_note_count(&*self.db_pool, user_id).await?;
+                (db_notes, db_note_tags, count)
+            }
+        };
+        let mut tags = group_note_tags(db_note_tags)?;
+        let notes = db_notes
+            .into_iter()
+            .map(|db_note| {
+                let mut note_data: NoteData = db_note.into();
+                note_data.tags = tags.remove(&note_data.id).unwrap_or_default();
+                note_data.try_into()
+            })
+            .collect::<Result<_, AppError>>()?;
+        let dmn_res = GetNotesResponse {
+            notes,
+            count,
+            next_cursor: None,
+        };
+        Ok(dmn_res)
+    }
+
+    async fn restore_note(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<Note, AppError> {
+        let (db_note, db_note_tags) = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                let db_note = db::restore_note(&mut **pg_tx, note_id, user_id).await?;
+                let db_note_tags = tag_db::get_note_tags(&mut **pg_tx, &[db_note.id]).await?;
+                (db_note, db_note_tags)
+            }
+            None => {
+                let db_note = db::restore_note(&*self.db_pool, note_id, user_id).await?;
+                let db_note_tags = tag_db::get_note_tags(&*self.db_pool, &[db_note.id]).await?;
+                (db_note, db_note_tags)
+            }
+        };
+        let mut note_data: NoteData = db_note.into();
+        note_data.tags = group_note_tags(db_note_tags)?
+            .remove(&note_data.id)
+            .unwrap_or_default();
+        let note = note_data.try_into()?;
+        Ok(note)
+    }
+
+    async fn purge_note(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<(), AppError> {
+        match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::purge_note(&mut **pg_tx, note_id, user_id).await?
+            }
+            None => db::purge_note(&*self.db_pool, note_id, user_id).await?,
+        };
+        Ok(())
+    }
+
+    async fn purge_trashed_notes(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        trashed_before: DateTime<Utc>,
+        user_id: Option<Uuid>,
+    ) -> Result<u32, AppError> {
+        let count = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::purge_trashed_notes(&mut **pg_tx, trashed_before, user_id).await?
+            }
+            None => db::purge_trashed_notes(&*self.db_pool, trashed_before, user_id).await?,
+        };
+        Ok(count)
+    }
+
+    async fn get_note_changes(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        from: SyncFrom,
+        limit: u32,
+        user_id: Uuid,
+    ) -> Result<NoteChanges, AppError> {
+        let (since, next_token, after) = match from {
+            SyncFrom::Start(since) => (since, None, None),
+            SyncFrom::Continuation(continuation) => (
+                continuation.since,
+                Some(continuation.next_token),
+                Some(continuation.after),
+            ),
+        };
+        let since = since.map(|SyncToken(token)| token as i64);
+        let after = after.map(|position| (position.change_xid as i64, position.note_id));
+        // One more change than requested tells whether another page follows
+        let fetch_limit = limit as i64 + 1;
+        // Token taken first, changes committed while reading are then reported again by the next sync
+        let (next_token, db_notes, db_note_tags, db_tombstones) = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                let next_token = match next_token {
+                    Some(SyncToken(token)) => token as i64,
+                    None => db::get_sync_token(&mut **pg_tx).await?,
+                };
+                let db_notes =
+                    db::get_changed_notes(&mut **pg_tx, since, after, fetch_limit, user_id).await?;
+                let note_ids: Vec<Uuid> = db_notes.iter().map(|db_note| db_note.id).collect();
+                let db_note_tags = tag_db::get_note_tags(&mut **pg_tx, &note_ids).await?;
+                let db_tombstones = match since {
+                    Some(since) => {
+                        db::get_note_tombstones(&mut **pg_tx, since, after, fetch_limit, user_id)
+                            .await?
+                    }
+                    None => Vec::new(),
+                };
+                (next_token, db_notes, db_note_tags, db_tombstones)
+            }
+            None => {
+                let next_token = match next_token {
+                    Some(SyncToken(token)) => token as i64,
+                    None => db::get_sync_token(&*self.db_pool).await?,
+                };
+                let db_notes =
+                    db::get_changed_notes(&*self.db_pool, since, after, fetch_limit, user_id)
+                        .await?;
+                let note_ids: Vec<Uuid> = db_notes.iter().map(|db_note| db_note.id).collect();
+                let db_note_tags = tag_db::get_note_tags(&*self.db_pool, &note_ids).await?;
+                let db_tombstones = match since {
+                    Some(since) => {
+                        db::get_note_tombstones(&*self.db_pool, since, after, fetch_limit, user_id)
+                            .await?
+                    }
+                    None => Vec::new(),
+                };
+                (next_token, db_notes, db_note_tags, db_tombstones)
+            }
+        };
+        let since = since.map(|since| SyncToken(since as u64));
+        let next_token = SyncToken(next_token as u64);
+
+        // Notes and tombstones share one ordering, the page ends at the `limit`th change of both
+        let mut positions: Vec<SyncPosition> = db_notes
+            .iter()
+            .map(|db_note| db_note.position())
+            .chain(
+                db_tombstones
+                    .iter()
+                    .map(|db_tombstone| db_tombstone.position()),
+            )
+            .collect();
+        positions.sort_unstable();
+        let last_position = if positions.len() > limit as usize {
+            limit.checked_sub(1).map(|index| positions[index as usize])
+        } else {
+            None
+        };
+        let in_page = |position: SyncPosition| last_position.is_none_or(|last| position <= last);
+
+        let mut tags = group_note_tags(db_note_tags)?;
+        let notes = db_notes
+            .into_iter()
+            .filter(|db_note| in_page(db_note.position()))
+            .map(|db_note| {
+                let mut note_data: NoteData = db_note.into();
+                note_data.tags = tags.remove(&note_data.id).unwrap_or_default();
+                note_data.try_into()
+            })
+            .collect::<Result<_, AppError>>()?;
+        let tombstones = db_tombstones
+            .into_iter()
+            .filter(|db_tombstone| in_page(db_tombstone.position()))
+            .map(|db_tombstone| db_tombstone.into())
+            .collect();
+        let continuation = last_position.map(|after| SyncContinuation {
+            since,
+            next_token,
+            after,
+        });
+        let dmn_res = NoteChanges {
+            notes,
+            tombstones,
+            next_token,
+            continuation,
+        };
+        Ok(dmn_res)
+    }
+}
+
+/// Groups note tag rows by their note id.
+fn group_note_tags(db_note_tags: Vec<NoteTagPg>) -> Result<HashMap<Uuid, Vec<Tag>>, AppError> {
+    let mut tags: HashMap<Uuid, Vec<Tag>> = HashMap::new();
+    for db_note_tag in db_note_tags {
+        let note_id = db_note_tag.note_id;
+        let tag_data: TagData = TagPg::from(db_note_tag).into();
+        tags.entry(note_id).or_default().push(tag_data.try_into()?);
+    }
+    Ok(tags)
 }
 
 impl From<CreateNoteData> for db::CreateNoteDataPg {
     fn from(dmn_note_data: CreateNoteData) -> Self {
         Self {
             user_id: dmn_note_data.user_id,
+            notebook_id: dmn_note_data.notebook_id,
             title: dmn_note_data.title,
             content: dmn_note_data.content,
+            created_at: dmn_note_data.created_at,
+            updated_at: dmn_note_data.updated_at,
         }
     }
 }
@@ -149,3 +656,22 @@ impl From<UpdateNoteData> for db::UpdateNoteDataPg {
         }
     }
 }
+
+impl From<PatchNoteData> for db::PatchNoteDataPg {
+    fn from(dmn_note_data: PatchNoteData) -> Self {
+        Self {
+            title: dmn_note_data.title,
+            content: dmn_note_data.content,
+        }
+    }
+}
+
+impl From<NoteFlags> for db::UpdateNoteFlagsDataPg {
+    fn from(dmn_flags: NoteFlags) -> Self {
+        Self {
+            pinned: dmn_flags.pinned,
+            archived: dmn_flags.archived,
+            favorite: dmn_flags.favorite,
+        }
+    }
+}
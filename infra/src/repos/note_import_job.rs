@@ -0,0 +1,137 @@
+use async_trait::async_trait;
+use chrono::{TimeDelta, Utc};
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{db::note_import_job as db, tx::ctx::PgTransactionContextExt};
+use common::{error::AppError, tx::ctx::TransactionContext};
+use dmn::{
+    entities::note_import_job::{
+        CreateNoteImportJobData, NoteImportJob, NoteImportJobData, UpdateNoteImportJobData,
+    },
+    repos::note_import_job::NoteImportJobRepository,
+};
+
+pub struct PgNoteImportJobRepository {
+    db_pool: Arc<PgPool>,
+}
+
+impl PgNoteImportJobRepository {
+    pub fn new(db_pool: Arc<PgPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl NoteImportJobRepository for PgNoteImportJobRepository {
+    async fn get_note_import_job(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        job_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<NoteImportJob, AppError> {
+        let db_job = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::get_note_import_job(&mut **pg_tx, job_id, user_id).await?
+            }
+            None => db::get_note_import_job(&*self.db_pool, job_id, user_id).await?,
+        };
+        let job_data: NoteImportJobData = db_job.try_into()?;
+        let job = job_data.try_into()?;
+        Ok(job)
+    }
+
+    async fn create_note_import_job(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        job_data: CreateNoteImportJobData,
+    ) -> Result<NoteImportJob, AppError> {
+        let db_job = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::create_note_import_job(&mut **pg_tx, job_data.into()).await?
+            }
+            None => db::create_note_import_job(&*self.db_pool, job_data.into()).await?,
+        };
+        let job_data: NoteImportJobData = db_job.try_into()?;
+        let job = job_data.try_into()?;
+        Ok(job)
+    }
+
+    async fn claim_note_import_job(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        lease: TimeDelta,
+    ) -> Result<Option<NoteImportJob>, AppError> {
+        let lease_until = Utc::now() + lease;
+        let db_job = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::claim_note_import_job(&mut **pg_tx, lease_until).await?
+            }
+            None => db::claim_note_import_job(&*self.db_pool, lease_until).await?,
+        };
+        let Some(db_job) = db_job else {
+            return Ok(None);
+        };
+        let job_data: NoteImportJobData = db_job.try_into()?;
+        let job = job_data.try_into()?;
+        Ok(Some(job))
+    }
+
+    async fn update_note_import_job(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        job_id: Uuid,
+        job_data: UpdateNoteImportJobData,
+    ) -> Result<NoteImportJob, AppError> {
+        let db_job = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::update_note_import_job(&mut **pg_tx, job_id, job_data.into()).await?
+            }
+            None => db::update_note_import_job(&*self.db_pool, job_id, job_data.into()).await?,
+        };
+        let job_data: NoteImportJobData = db_job.try_into()?;
+        let job = job_data.try_into()?;
+        Ok(job)
+    }
+}
+
+impl From<CreateNoteImportJobData> for db::CreateNoteImportJobDataPg {
+    fn from(dmn_job_data: CreateNoteImportJobData) -> Self {
+        Self {
+            id: dmn_job_data.id,
+            user_id: dmn_job_data.user_id,
+            format: dmn_job_data.format.as_str().into(),
+        }
+    }
+}
+
+impl From<UpdateNoteImportJobData> for db::UpdateNoteImportJobDataPg {
+    fn from(dmn_job_data: UpdateNoteImportJobData) -> Self {
+        Self {
+            status: dmn_job_data.status.as_str().into(),
+            total_count: dmn_job_data.total_count.map(|count| count as i32),
+            processed_count: dmn_job_data.processed_count as i32,
+            imported_count: dmn_job_data.imported_count as i32,
+            failures: dmn_job_data
+                .failures
+                .into_iter()
+                .map(|failure| failure.into())
+                .collect(),
+            error: dmn_job_data.error,
+            finished_at: dmn_job_data.finished_at,
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{db::note_public_link as db, tx::ctx::PgTransactionContextExt};
+use common::{error::AppError, tx::ctx::TransactionContext};
+use dmn::{
+    entities::note_public_link::{CreateNotePublicLinkData, NotePublicLink, NotePublicLinkData},
+    repos::note_public_link::NotePublicLinkRepository,
+};
+
+pub struct PgNotePublicLinkRepository {
+    db_pool: Arc<PgPool>,
+}
+
+impl PgNotePublicLinkRepository {
+    pub fn new(db_pool: Arc<PgPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl NotePublicLinkRepository for PgNotePublicLinkRepository {
+    async fn get_note_public_link(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+    ) -> Result<NotePublicLink, AppError> {
+        let db_link = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::get_note_public_link(&mut **pg_tx, note_id).await?
+            }
+            None => db::get_note_public_link(&*self.db_pool, note_id).await?,
+        };
+        Ok(NotePublicLinkData::from(db_link).into())
+    }
+
+    async fn get_note_public_link_by_token(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        token: &str,
+    ) -> Result<NotePublicLink, AppError> {
+        let db_link = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::get_note_public_link_by_token(&mut **pg_tx, token).await?
+            }
+            None => db::get_note_public_link_by_token(&*self.db_pool, token).await?,
+        };
+        Ok(NotePublicLinkData::from(db_link).into())
+    }
+
+    async fn create_note_public_link(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        link_data: CreateNotePublicLinkData,
+    ) -> Result<NotePublicLink, AppError> {
+        let db_link = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::create_note_public_link(&mut **pg_tx, link_data.into()).await?
+            }
+            None => db::create_note_public_link(&*self.db_pool, link_data.into()).await?,
+        };
+        Ok(NotePublicLinkData::from(db_link).into())
+    }
+
+    async fn delete_note_public_link(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+    ) -> Result<(), AppError> {
+        match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::delete_note_public_link(&mut **pg_tx, note_id).await?
+            }
+            None => db::delete_note_public_link(&*self.db_pool, note_id).await?,
+        };
+        Ok(())
+    }
+}
+
+impl From<CreateNotePublicLinkData> for db::CreateNotePublicLinkDataPg {
+    fn from(dmn_link_data: CreateNotePublicLinkData) -> Self {
+        Self {
+            note_id: dmn_link_data.note_id,
+            expires_at: dmn_link_data.expires_at,
+        }
+    }
+}
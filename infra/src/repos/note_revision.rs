@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{db::note_revision as db, tx::ctx::PgTransactionContextExt};
+use common::{error::AppError, params::PaginationParams, tx::ctx::TransactionContext};
+use dmn::{
+    entities::note_revision::{CreateNoteRevisionData, NoteRevision, NoteRevisionData},
+    repos::note_revision::{GetNoteRevisionsResponse, NoteRevisionRepository},
+};
+
+pub struct PgNoteRevisionRepository {
+    db_pool: Arc<PgPool>,
+}
+
+impl PgNoteRevisionRepository {
+    pub fn new(db_pool: Arc<PgPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl NoteRevisionRepository for PgNoteRevisionRepository {
+    async fn get_note_revision(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        revision: u32,
+    ) -> Result<NoteRevision, AppError> {
+        let db_revision = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::get_note_revision(&mut **pg_tx, note_id, revision).await?
+            }
+            None => db::get_note_revision(&*self.db_pool, note_id, revision).await?,
+        };
+        let revision_data: NoteRevisionData = db_revision.into();
+        Ok(revision_data.into())
+    }
+
+    async fn get_note_revisions(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        pagination: &PaginationParams,
+    ) -> Result<GetNoteRevisionsResponse, AppError> {
+        let (db_revisions, count) = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                let db_revisions =
+                    db::get_note_revisions(&mut **pg_tx, note_id, pagination).await?;
+                let count = db::get_note_revision_count(&mut **pg_tx, note_id).await?;
+                (db_revisions, count)
+            }
+            None => {
+                let db_revisions =
+                    db::get_note_revisions(&*self.db_pool, note_id, pagination).await?;
+                let count = db::get_note_revision_count(&*self.db_pool, note_id).await?;
+                (db_revisions, count)
+            }
+        };
+        let revisions = db_revisions
+            .into_iter()
+            .map(|db_revision| NoteRevisionData::from(db_revision).into())
+            .collect();
+        let dmn_res = GetNoteRevisionsResponse { revisions, count };
+        Ok(dmn_res)
+    }
+
+    async fn create_note_revision(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        revision_data: CreateNoteRevisionData,
+    ) -> Result<NoteRevision, AppError> {
+        let db_revision = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::create_note_revision(&mut **pg_tx, revision_data.into()).await?
+            }
+            None => db::create_note_revision(&*self.db_pool, revision_data.into()).await?,
+        };
+        let revision_data: NoteRevisionData = db_revision.into();
+        Ok(revision_data.into())
+    }
+}
+
+impl From<CreateNoteRevisionData> for db::CreateNoteRevisionDataPg {
+    fn from(dmn_revision_data: CreateNoteRevisionData) -> Self {
+        Self {
+            note_id: dmn_revision_data.note_id,
+            title: dmn_revision_data.title,
+            content: dmn_revision_data.content,
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{db::note_share as db, tx::ctx::PgTransactionContextExt};
+use common::{error::AppError, tx::ctx::TransactionContext};
+use dmn::{
+    entities::note_share::{
+        CreateNoteShareData, NoteAccess, NoteShare, NoteShareData, NoteShareRole,
+    },
+    repos::note_share::NoteShareRepository,
+};
+
+pub struct PgNoteShareRepository {
+    db_pool: Arc<PgPool>,
+}
+
+impl PgNoteShareRepository {
+    pub fn new(db_pool: Arc<PgPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl NoteShareRepository for PgNoteShareRepository {
+    async fn get_note_access(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<NoteAccess, AppError> {
+        let db_access = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::get_note_access(&mut **pg_tx, note_id, user_id).await?
+            }
+            None => db::get_note_access(&*self.db_pool, note_id, user_id).await?,
+        };
+        db_access.try_into()
+    }
+
+    async fn get_note_shares(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+    ) -> Result<Vec<NoteShare>, AppError> {
+        let db_shares = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::get_note_shares(&mut **pg_tx, note_id).await?
+            }
+            None => db::get_note_shares(&*self.db_pool, note_id).await?,
+        };
+        db_shares
+            .into_iter()
+            .map(|db_share| NoteShareData::try_from(db_share).map(NoteShare::from))
+            .collect()
+    }
+
+    async fn create_note_share(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        share_data: CreateNoteShareData,
+    ) -> Result<NoteShare, AppError> {
+        let db_share = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::create_note_share(&mut **pg_tx, share_data.into()).await?
+            }
+            None => db::create_note_share(&*self.db_pool, share_data.into()).await?,
+        };
+        let share_data: NoteShareData = db_share.try_into()?;
+        Ok(share_data.into())
+    }
+
+    async fn update_note_share(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        user_id: Uuid,
+        role: NoteShareRole,
+    ) -> Result<NoteShare, AppError> {
+        let db_share = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::update_note_share(&mut **pg_tx, note_id, user_id, role.as_str()).await?
+            }
+            None => db::update_note_share(&*self.db_pool, note_id, user_id, role.as_str()).await?,
+        };
+        let share_data: NoteShareData = db_share.try_into()?;
+        Ok(share_data.into())
+    }
+
+    async fn delete_note_share(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), AppError> {
+        match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::delete_note_share(&mut **pg_tx, note_id, user_id).await?
+            }
+            None => db::delete_note_share(&*self.db_pool, note_id, user_id).await?,
+        };
+        Ok(())
+    }
+}
+
+impl From<CreateNoteShareData> for db::CreateNoteShareDataPg {
+    fn from(dmn_share_data: CreateNoteShareData) -> Self {
+        Self {
+            note_id: dmn_share_data.note_id,
+            user_id: dmn_share_data.user_id,
+            role: dmn_share_data.role.as_str().to_string(),
+        }
+    }
+}
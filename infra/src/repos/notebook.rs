@@ -0,0 +1,180 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{db::notebook as db, tx::ctx::PgTransactionContextExt};
+use common::{error::AppError, tx::ctx::TransactionContext};
+use dmn::{
+    entities::notebook::{CreateNotebookData, Notebook, NotebookData, UpdateNotebookData},
+    repos::notebook::NotebookRepository,
+};
+
+pub struct PgNotebookRepository {
+    db_pool: Arc<PgPool>,
+}
+
+impl PgNotebookRepository {
+    pub fn new(db_pool: Arc<PgPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl NotebookRepository for PgNotebookRepository {
+    async fn get_notebook(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        notebook_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<Notebook, AppError> {
+        let db_notebook = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::get_notebook(&mut **pg_tx, notebook_id, user_id).await?
+            }
+            None => db::get_notebook(&*self.db_pool, notebook_id, user_id).await?,
+        };
+        let notebook_data: NotebookData = db_notebook.into();
+        let notebook = notebook_data.try_into()?;
+        Ok(notebook)
+    }
+
+    async fn get_notebooks(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        user_id: Option<Uuid>,
+    ) -> Result<Vec<Notebook>, AppError> {
+        let db_notebooks = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::get_notebooks(&mut **pg_tx, user_id).await?
+            }
+            None => db::get_notebooks(&*self.db_pool, user_id).await?,
+        };
+        let notebooks = db_notebooks
+            .into_iter()
+            .map(|db_notebook| db_notebook.into())
+            .map(|notebook_data: NotebookData| notebook_data.try_into())
+            .collect::<Result<_, AppError>>()?;
+        Ok(notebooks)
+    }
+
+    async fn get_notebook_path(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        notebook_id: Uuid,
+    ) -> Result<Vec<Uuid>, AppError> {
+        let path = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::get_notebook_path(&mut **pg_tx, notebook_id).await?
+            }
+            None => db::get_notebook_path(&*self.db_pool, notebook_id).await?,
+        };
+        Ok(path)
+    }
+
+    async fn lock_notebooks(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        notebook_ids: &[Uuid],
+    ) -> Result<(), AppError> {
+        match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::lock_notebooks(&mut **pg_tx, notebook_ids).await
+            }
+            None => db::lock_notebooks(&*self.db_pool, notebook_ids).await,
+        }
+    }
+
+    async fn create_notebook(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        notebook_data: CreateNotebookData,
+    ) -> Result<Notebook, AppError> {
+        let db_notebook = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::create_notebook(&mut **pg_tx, notebook_data.into()).await?
+            }
+            None => db::create_notebook(&*self.db_pool, notebook_data.into()).await?,
+        };
+        let notebook_data: NotebookData = db_notebook.into();
+        let notebook = notebook_data.try_into()?;
+        Ok(notebook)
+    }
+
+    async fn update_notebook(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        notebook_id: Uuid,
+        notebook_data: UpdateNotebookData,
+        user_id: Option<Uuid>,
+    ) -> Result<Notebook, AppError> {
+        let db_notebook = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::update_notebook(&mut **pg_tx, notebook_id, notebook_data.into(), user_id)
+                    .await?
+            }
+            None => {
+                db::update_notebook(&*self.db_pool, notebook_id, notebook_data.into(), user_id)
+                    .await?
+            }
+        };
+        let notebook_data: NotebookData = db_notebook.into();
+        let notebook = notebook_data.try_into()?;
+        Ok(notebook)
+    }
+
+    async fn delete_notebook(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        notebook_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<(), AppError> {
+        match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::delete_notebook(&mut **pg_tx, notebook_id, user_id).await?
+            }
+            None => db::delete_notebook(&*self.db_pool, notebook_id, user_id).await?,
+        };
+        Ok(())
+    }
+}
+
+impl From<CreateNotebookData> for db::CreateNotebookDataPg {
+    fn from(dmn_notebook_data: CreateNotebookData) -> Self {
+        Self {
+            user_id: dmn_notebook_data.user_id,
+            parent_id: dmn_notebook_data.parent_id,
+            name: dmn_notebook_data.name,
+        }
+    }
+}
+
+impl From<UpdateNotebookData> for db::UpdateNotebookDataPg {
+    fn from(dmn_notebook_data: UpdateNotebookData) -> Self {
+        Self {
+            parent_id: dmn_notebook_data.parent_id,
+            name: dmn_notebook_data.name,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use std::sync::Arc;
+
+use crate::{db::outbox as db, tx::ctx::PgTransactionContextExt};
+use common::{error::AppError, tx::ctx::TransactionContext};
+use dmn::{events::DomainEvent, repos::outbox::OutboxRepository};
+
+pub struct PgOutboxRepository {
+    db_pool: Arc<PgPool>,
+}
+
+impl PgOutboxRepository {
+    pub fn new(db_pool: Arc<PgPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for PgOutboxRepository {
+    async fn append_events(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        events: &[DomainEvent],
+    ) -> Result<(), AppError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+        match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::append_outbox_events(&mut **pg_tx, events).await
+            }
+            None => db::append_outbox_events(&*self.db_pool, events).await,
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{db::tag as db, tx::ctx::PgTransactionContextExt};
+use common::{error::AppError, tx::ctx::TransactionContext};
+use dmn::{
+    entities::tag::{CreateTagData, Tag, TagData, UpdateTagData},
+    repos::tag::TagRepository,
+};
+
+pub struct PgTagRepository {
+    db_pool: Arc<PgPool>,
+}
+
+impl PgTagRepository {
+    pub fn new(db_pool: Arc<PgPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl TagRepository for PgTagRepository {
+    async fn get_tag(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        tag_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<Tag, AppError> {
+        let db_tag = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::get_tag(&mut **pg_tx, tag_id, user_id).await?
+            }
+            None => db::get_tag(&*self.db_pool, tag_id, user_id).await?,
+        };
+        let tag_data: TagData = db_tag.into();
+        let tag = tag_data.try_into()?;
+        Ok(tag)
+    }
+
+    async fn get_tags(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        user_id: Option<Uuid>,
+    ) -> Result<Vec<Tag>, AppError> {
+        let db_tags = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::get_tags(&mut **pg_tx, user_id).await?
+            }
+            None => db::get_tags(&*self.db_pool, user_id).await?,
+        };
+        let tags = db_tags
+            .into_iter()
+            .map(|db_tag| db_tag.into())
+            .map(|tag_data: TagData| tag_data.try_into())
+            .collect::<Result<_, AppError>>()?;
+        Ok(tags)
+    }
+
+    async fn create_tag(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        tag_data: CreateTagData,
+    ) -> Result<Tag, AppError> {
+        let db_tag = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::create_tag(&mut **pg_tx, tag_data.into()).await?
+            }
+            None => db::create_tag(&*self.db_pool, tag_data.into()).await?,
+        };
+        let tag_data: TagData = db_tag.into();
+        let tag = tag_data.try_into()?;
+        Ok(tag)
+    }
+
+    async fn update_tag(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        tag_id: Uuid,
+        tag_data: UpdateTagData,
+        user_id: Option<Uuid>,
+    ) -> Result<Tag, AppError> {
+        let db_tag = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::update_tag(&mut **pg_tx, tag_id, tag_data.into(), user_id).await?
+            }
+            None => db::update_tag(&*self.db_pool, tag_id, tag_data.into(), user_id).await?,
+        };
+        let tag_data: TagData = db_tag.into();
+        let tag = tag_data.try_into()?;
+        Ok(tag)
+    }
+
+    async fn delete_tag(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        tag_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<(), AppError> {
+        match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::delete_tag(&mut **pg_tx, tag_id, user_id).await?
+            }
+            None => db::delete_tag(&*self.db_pool, tag_id, user_id).await?,
+        };
+        Ok(())
+    }
+
+    async fn attach_note_tag(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        tag_id: Uuid,
+    ) -> Result<(), AppError> {
+        match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::attach_note_tag(&mut **pg_tx, note_id, tag_id).await?
+            }
+            None => db::attach_note_tag(&*self.db_pool, note_id, tag_id).await?,
+        };
+        Ok(())
+    }
+
+    async fn detach_note_tag(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        note_id: Uuid,
+        tag_id: Uuid,
+    ) -> Result<(), AppError> {
+        match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::detach_note_tag(&mut **pg_tx, note_id, tag_id).await?
+            }
+            None => db::detach_note_tag(&*self.db_pool, note_id, tag_id).await?,
+        };
+        Ok(())
+    }
+}
+
+impl From<CreateTagData> for db::CreateTagDataPg {
+    fn from(dmn_tag_data: CreateTagData) -> Self {
+        Self {
+            user_id: dmn_tag_data.user_id,
+            name: dmn_tag_data.name,
+        }
+    }
+}
+
+impl From<UpdateTagData> for db::UpdateTagDataPg {
+    fn from(dmn_tag_data: UpdateTagData) -> Self {
+        Self {
+            name: dmn_tag_data.name,
+        }
+    }
+}
@@ -2,8 +2,15 @@ use async_trait::async_trait;
 use sqlx::PgPool;
 use std::sync::Arc;
 
-use crate::{db::user as db, tx::ctx::PgTransactionContextExt};
-use common::{error::AppError, params::PaginationParams, tx::ctx::TransactionContext};
+use crate::{
+    db::{take_next_cursor, user as db},
+    tx::ctx::PgTransactionContextExt,
+};
+use common::{
+    error::AppError,
+    params::{PageCursor, Pagination},
+    tx::ctx::TransactionContext,
+};
 use dmn::{
     entities::user::{CreateUserData, UniqueUserIdentifier, UpdateUserData, User, UserData},
     repos::user::{GetUsersResponse, UserRepository},
@@ -43,9 +50,9 @@ impl UserRepository for PgUserRepository {
     async fn get_users(
         &self,
         ctx: Option<&mut dyn TransactionContext>,
-        pagination: &PaginationParams,
+        pagination: &Pagination,
     ) -> Result<GetUsersResponse, AppError> {
-        let (db_users, count) = match ctx {
+        let (mut db_users, count) = match ctx {
             Some(ctx) => {
                 let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
                     AppError::internal("Invalid transaction context for Postgres repository")
@@ -60,6 +67,10 @@ impl UserRepository for PgUserRepository {
                 (db_users, count)
             }
         };
+        let next_cursor = take_next_cursor(&mut db_users, pagination, |db_user| PageCursor {
+            created_at: db_user.created_at,
+            id: db_user.id,
+        });
         let users = db_users
             .into_iter()
             .map(|db_user| {
@@ -67,7 +78,11 @@ impl UserRepository for PgUserRepository {
                 user_data.try_into()
             })
             .collect::<Result<_, AppError>>()?;
-        let dmn_res = GetUsersResponse { users, count };
+        let dmn_res = GetUsersResponse {
+            users,
+            count,
+            next_cursor,
+        };
         Ok(dmn_res)
     }
 
@@ -0,0 +1,155 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{db::webhook as db, tx::ctx::PgTransactionContextExt};
+use common::{error::AppError, tx::ctx::TransactionContext};
+use dmn::{
+    entities::webhook::{CreateWebhookData, UpdateWebhookData, Webhook, WebhookData},
+    repos::webhook::WebhookRepository,
+};
+
+pub struct PgWebhookRepository {
+    db_pool: Arc<PgPool>,
+}
+
+impl PgWebhookRepository {
+    pub fn new(db_pool: Arc<PgPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for PgWebhookRepository {
+    async fn get_webhook(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        webhook_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<Webhook, AppError> {
+        let db_webhook = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::get_webhook(&mut **pg_tx, webhook_id, user_id).await?
+            }
+            None => db::get_webhook(&*self.db_pool, webhook_id, user_id).await?,
+        };
+        let webhook_data: WebhookData = db_webhook.into();
+        let webhook = webhook_data.try_into()?;
+        Ok(webhook)
+    }
+
+    async fn get_webhooks(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        user_id: Option<Uuid>,
+    ) -> Result<Vec<Webhook>, AppError> {
+        let db_webhooks = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::get_webhooks(&mut **pg_tx, user_id).await?
+            }
+            None => db::get_webhooks(&*self.db_pool, user_id).await?,
+        };
+        let webhooks = db_webhooks
+            .into_iter()
+            .map(|db_webhook| db_webhook.into())
+            .map(|webhook_data: WebhookData| webhook_data.try_into())
+            .collect::<Result<_, AppError>>()?;
+        Ok(webhooks)
+    }
+
+    async fn create_webhook(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        webhook_data: CreateWebhookData,
+    ) -> Result<Webhook, AppError> {
+        let db_webhook = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::create_webhook(&mut **pg_tx, webhook_data.into()).await?
+            }
+            None => db::create_webhook(&*self.db_pool, webhook_data.into()).await?,
+        };
+        let webhook_data: WebhookData = db_webhook.into();
+        let webhook = webhook_data.try_into()?;
+        Ok(webhook)
+    }
+
+    async fn update_webhook(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        webhook_id: Uuid,
+        webhook_data: UpdateWebhookData,
+        user_id: Option<Uuid>,
+    ) -> Result<Webhook, AppError> {
+        let db_webhook = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::update_webhook(&mut **pg_tx, webhook_id, webhook_data.into(), user_id).await?
+            }
+            None => {
+                db::update_webhook(&*self.db_pool, webhook_id, webhook_data.into(), user_id).await?
+            }
+        };
+        let webhook_data: WebhookData = db_webhook.into();
+        let webhook = webhook_data.try_into()?;
+        Ok(webhook)
+    }
+
+    async fn delete_webhook(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        webhook_id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<(), AppError> {
+        match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::delete_webhook(&mut **pg_tx, webhook_id, user_id).await?
+            }
+            None => db::delete_webhook(&*self.db_pool, webhook_id, user_id).await?,
+        };
+        Ok(())
+    }
+}
+
+impl From<CreateWebhookData> for db::CreateWebhookDataPg {
+    fn from(dmn_webhook_data: CreateWebhookData) -> Self {
+        Self {
+            user_id: dmn_webhook_data.user_id,
+            url: dmn_webhook_data.url,
+            secret: dmn_webhook_data.secret,
+            event_types: dmn_webhook_data
+                .event_types
+                .iter()
+                .map(|event_type| event_type.as_str().into())
+                .collect(),
+        }
+    }
+}
+
+impl From<UpdateWebhookData> for db::UpdateWebhookDataPg {
+    fn from(dmn_webhook_data: UpdateWebhookData) -> Self {
+        Self {
+            url: dmn_webhook_data.url,
+            secret: dmn_webhook_data.secret,
+            event_types: dmn_webhook_data
+                .event_types
+                .iter()
+                .map(|event_type| event_type.as_str().into())
+                .collect(),
+        }
+    }
+}
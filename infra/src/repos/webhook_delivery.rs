@@ -0,0 +1,155 @@
+use async_trait::async_trait;
+use chrono::{TimeDelta, Utc};
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{db::webhook_delivery as db, tx::ctx::PgTransactionContextExt};
+use common::{error::AppError, params::PaginationParams, tx::ctx::TransactionContext};
+use dmn::{
+    entities::webhook_delivery::{
+        CreateWebhookDeliveryData, UpdateWebhookDeliveryData, WebhookDelivery, WebhookDeliveryData,
+    },
+    repos::webhook_delivery::{GetWebhookDeliveriesResponse, WebhookDeliveryRepository},
+};
+
+pub struct PgWebhookDeliveryRepository {
+    db_pool: Arc<PgPool>,
+}
+
+impl PgWebhookDeliveryRepository {
+    pub fn new(db_pool: Arc<PgPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for PgWebhookDeliveryRepository {
+    async fn get_webhook_deliveries(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        webhook_id: Uuid,
+        pagination: &PaginationParams,
+    ) -> Result<GetWebhookDeliveriesResponse, AppError> {
+        let (db_deliveries, count) = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                let db_deliveries =
+                    db::get_webhook_deliveries(&mut **pg_tx, webhook_id, pagination).await?;
+                let count = db::get_webhook_delivery_count(&mut **pg_tx, webhook_id).await?;
+                (db_deliveries, count)
+            }
+            None => {
+                let db_deliveries =
+                    db::get_webhook_deliveries(&*self.db_pool, webhook_id, pagination).await?;
+                let count = db::get_webhook_delivery_count(&*self.db_pool, webhook_id).await?;
+                (db_deliveries, count)
+            }
+        };
+        let deliveries = db_deliveries
+            .into_iter()
+            .map(|db_delivery| db_delivery.into())
+            .map(|delivery_data: WebhookDeliveryData| delivery_data.try_into())
+            .collect::<Result<_, AppError>>()?;
+        let dmn_res = GetWebhookDeliveriesResponse { deliveries, count };
+        Ok(dmn_res)
+    }
+
+    async fn create_webhook_delivery(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        delivery_data: CreateWebhookDeliveryData,
+    ) -> Result<Option<WebhookDelivery>, AppError> {
+        let db_delivery = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::create_webhook_delivery(&mut **pg_tx, delivery_data.into()).await?
+            }
+            None => db::create_webhook_delivery(&*self.db_pool, delivery_data.into()).await?,
+        };
+        let Some(db_delivery) = db_delivery else {
+            return Ok(None);
+        };
+        let delivery_data: WebhookDeliveryData = db_delivery.into();
+        let delivery = delivery_data.try_into()?;
+        Ok(Some(delivery))
+    }
+
+    async fn claim_due_webhook_deliveries(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        limit: u32,
+        lease: TimeDelta,
+    ) -> Result<Vec<WebhookDelivery>, AppError> {
+        let lease_until = Utc::now() + lease;
+        let db_deliveries = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::claim_due_webhook_deliveries(&mut **pg_tx, limit, lease_until).await?
+            }
+            None => db::claim_due_webhook_deliveries(&*self.db_pool, limit, lease_until).await?,
+        };
+        let deliveries = db_deliveries
+            .into_iter()
+            .map(|db_delivery| db_delivery.into())
+            .map(|delivery_data: WebhookDeliveryData| delivery_data.try_into())
+            .collect::<Result<_, AppError>>()?;
+        Ok(deliveries)
+    }
+
+    async fn update_webhook_delivery(
+        &self,
+        ctx: Option<&mut dyn TransactionContext>,
+        delivery_id: Uuid,
+        delivery_data: UpdateWebhookDeliveryData,
+    ) -> Result<WebhookDelivery, AppError> {
+        let db_delivery = match ctx {
+            Some(ctx) => {
+                let pg_tx = ctx.as_postgres_tx().ok_or_else(|| {
+                    AppError::internal("Invalid transaction context for Postgres repository")
+                })?;
+                db::update_webhook_delivery(&mut **pg_tx, delivery_id, delivery_data.into()).await?
+            }
+            None => {
+                db::update_webhook_delivery(&*self.db_pool, delivery_id, delivery_data.into())
+                    .await?
+            }
+        };
+        let delivery_data: WebhookDeliveryData = db_delivery.into();
+        let delivery = delivery_data.try_into()?;
+        Ok(delivery)
+    }
+}
+
+impl From<CreateWebhookDeliveryData> for db::CreateWebhookDeliveryDataPg {
+    fn from(dmn_delivery_data: CreateWebhookDeliveryData) -> Self {
+        Self {
+            webhook_id: dmn_delivery_data.webhook_id,
+            event_id: dmn_delivery_data.event_id,
+            event_type: dmn_delivery_data.event_type.as_str().into(),
+            note_id: dmn_delivery_data.note_id,
+            next_attempt_at: dmn_delivery_data.next_attempt_at,
+        }
+    }
+}
+
+impl From<UpdateWebhookDeliveryData> for db::UpdateWebhookDeliveryDataPg {
+    fn from(dmn_delivery_data: UpdateWebhookDeliveryData) -> Self {
+        Self {
+            status: dmn_delivery_data.status.as_str().into(),
+            attempts: dmn_delivery_data.attempts as i32,
+            response_status: dmn_delivery_data
+                .response_status
+                .map(|status| status as i32),
+            last_error: dmn_delivery_data.last_error,
+            next_attempt_at: dmn_delivery_data.next_attempt_at,
+            delivered_at: dmn_delivery_data.delivered_at,
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+use tokio::fs;
+
+use app::storage::blob_store::BlobStore;
+use common::error::AppError;
+
+/// [`BlobStore`] keeping each blob as a file within a root directory.
+pub struct LocalBlobStore {
+    root_dir: PathBuf,
+}
+
+impl LocalBlobStore {
+    pub fn new(root_dir: impl AsRef<Path>) -> Self {
+        Self {
+            root_dir: root_dir.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Resolves the blob's file path, refusing keys that could escape the root directory.
+    fn blob_path(&self, key: &str) -> Result<PathBuf, AppError> {
+        let is_safe_key = !key.is_empty()
+            && key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !is_safe_key {
+            return Err(AppError::internal(format!("Invalid blob key ({key})!")));
+        }
+        Ok(self.root_dir.join(key))
+    }
+}
+
+#[async_trait]
+impl BlobStore for LocalBlobStore {
+    async fn put_blob(&self, key: &str, data: Vec<u8>) -> Result<(), AppError> {
+        #[allow(non_snake_case)]
+        let INTERNAL_ERR_STR = format!("Failed to store blob ({key})!");
+
+        let path = self.blob_path(key)?;
+        // Written aside then renamed, so that readers never see a partial blob
+        let tmp_path = path.with_extension("tmp");
+        fs::create_dir_all(&self.root_dir)
+            .await
+            .map_err(|err| AppError::internal_with_private(&INTERNAL_ERR_STR, err.to_string()))?;
+        fs::write(&tmp_path, data)
+            .await
+            .map_err(|err| AppError::internal_with_private(&INTERNAL_ERR_STR, err.to_string()))?;
+        fs::rename(&tmp_path, &path)
+            .await
+            .map_err(|err| AppError::internal_with_private(&INTERNAL_ERR_STR, err.to_string()))
+    }
+
+    async fn get_blob(&self, key: &str) -> Result<Vec<u8>, AppError> {
+        #[allow(non_snake_case)]
+        let INTERNAL_ERR_STR = format!("Failed to retrieve blob ({key})!");
+        #[allow(non_snake_case)]
+        let NOT_FOUND_ERR_STR = format!("Blob ({key}) doesn't exist!");
+
+        let path = self.blob_path(key)?;
+        fs::read(&path).await.map_err(|err| match err.kind() {
+            ErrorKind::NotFound => AppError::not_found(NOT_FOUND_ERR_STR),
+            _ => AppError::internal_with_private(INTERNAL_ERR_STR, err.to_string()),
+        })
+    }
+
+    async fn delete_blob(&self, key: &str) -> Result<(), AppError> {
+        #[allow(non_snake_case)]
+        let INTERNAL_ERR_STR = format!("Failed to delete blob ({key})!");
+
+        let path = self.blob_path(key)?;
+        match fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(AppError::internal_with_private(
+                INTERNAL_ERR_STR,
+                err.to_string(),
+            )),
+        }
+    }
+}
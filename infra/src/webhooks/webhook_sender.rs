@@ -0,0 +1,242 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use reqwest::{
+    dns::{Addrs, Name, Resolve, Resolving},
+    header::CONTENT_TYPE,
+    redirect::Policy,
+    Client, Url,
+};
+use serde::Serialize;
+use sha2::Sha256;
+use std::{
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
+use uuid::Uuid;
+
+use app::webhooks::webhook_sender::WebhookSender;
+use common::error::AppError;
+use dmn::entities::{
+    webhook::Webhook,
+    webhook_delivery::{WebhookAttemptOutcome, WebhookDelivery},
+};
+
+const USER_AGENT: &str = "NoteDDD-Webhooks/1.0";
+const ID_HEADER: &str = "X-Webhook-Id";
+const EVENT_HEADER: &str = "X-Webhook-Event";
+const TIMESTAMP_HEADER: &str = "X-Webhook-Timestamp";
+const SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+
+/// Posts deliveries as JSON, signed with HMAC-SHA256 over `{timestamp}.{body}` using the
+/// webhook's secret.<br />
+/// Redirects aren't followed, they count as failed attempts.<br />
+/// Unless local targets are allowed, hosts have to resolve to public addresses only.
+/// Addresses are checked again on every connection, so that DNS rebinding can't get past the check.
+pub struct HttpWebhookSender {
+    client: Client,
+    allow_local_targets: bool,
+}
+
+impl HttpWebhookSender {
+    pub fn new(timeout: Duration, allow_local_targets: bool) -> Result<Self, AppError> {
+        let mut builder = Client::builder()
+            .user_agent(USER_AGENT)
+            .timeout(timeout)
+            .redirect(Policy::none());
+        if !allow_local_targets {
+            builder = builder.dns_resolver(Arc::new(PublicAddressResolver));
+        }
+        let client = builder.build().map_err(|err| {
+            AppError::internal_with_private("Failed to build webhook client!", err.to_string())
+        })?;
+        Ok(Self {
+            client,
+            allow_local_targets,
+        })
+    }
+}
+
+#[async_trait]
+impl WebhookSender for HttpWebhookSender {
+    async fn check_url(&self, url: &str) -> Result<(), AppError> {
+        if self.allow_local_targets {
+            return Ok(());
+        }
+        let result = match url_host_address(url) {
+            Ok(Some(address)) => ensure_public_address(address),
+            Ok(None) => {
+                let host = Url::parse(url)
+                    .ok()
+                    .and_then(|url| url.host_str().map(str::to_string))
+                    .unwrap_or_default();
+                resolve_public_addresses(&host).await.map(|_| ())
+            }
+            Err(err) => Err(err),
+        };
+        result.map_err(|err| {
+            AppError::bad_request_with_private(
+                "Webhook URL has to resolve to a public address!",
+                err,
+            )
+        })
+    }
+
+    async fn send(&self, webhook: &Webhook, delivery: &WebhookDelivery) -> WebhookAttemptOutcome {
+        // Hosts given as addresses don't go through the resolver
+        if !self.allow_local_targets {
+            if let Err(err) = url_host_address(webhook.url())
+                .and_then(|address| address.map_or(Ok(()), ensure_public_address))
+            {
+                return WebhookAttemptOutcome::Error(err);
+            }
+        }
+        let payload = WebhookPayload {
+            id: delivery.event_id(),
+            event_type: delivery.event_type().as_str(),
+            created_at: delivery.created_at(),
+            data: WebhookPayloadData {
+                user_id: webhook.user_id(),
+                note_id: delivery.note_id(),
+            },
+        };
+        let body = match serde_json::to_string(&payload) {
+            Ok(body) => body,
+            Err(err) => return WebhookAttemptOutcome::Error(err.to_string()),
+        };
+        let timestamp = Utc::now().timestamp().to_string();
+        let signature = sign_payload(webhook.secret(), &timestamp, &body);
+
+        let res = self
+            .client
+            .post(webhook.url())
+            .header(CONTENT_TYPE, "application/json")
+            .header(ID_HEADER, delivery.event_id().to_string())
+            .header(EVENT_HEADER, payload.event_type)
+            .header(TIMESTAMP_HEADER, timestamp)
+            .header(SIGNATURE_HEADER, format!("sha256={signature}"))
+            .body(body)
+            .send()
+            .await;
+        match res {
+            Ok(res) => WebhookAttemptOutcome::Response(res.status().as_u16()),
+            Err(err) => WebhookAttemptOutcome::Error(err.to_string()),
+        }
+    }
+}
+
+/// Resolver refusing hosts with any non-public address.
+struct PublicAddressResolver;
+
+impl Resolve for PublicAddressResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addresses = resolve_public_addresses(name.as_str()).await?;
+            let addrs: Addrs = Box::new(
+                addresses
+                    .into_iter()
+                    .map(|address| SocketAddr::new(address, 0)),
+            );
+            Ok(addrs)
+        })
+    }
+}
+
+/// Parses the URL, returning its host if given as an IP address.
+fn url_host_address(url: &str) -> Result<Option<IpAddr>, String> {
+    let url = Url::parse(url).map_err(|err| err.to_string())?;
+    let host = url.host_str().ok_or("URL has no host")?;
+    Ok(host
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse::<IpAddr>()
+        .ok())
+}
+
+async fn resolve_public_addresses(host: &str) -> Result<Vec<IpAddr>, String> {
+    let addresses: Vec<IpAddr> = tokio::net::lookup_host((host, 0))
+        .await
+        .map_err(|err| format!("Failed to resolve {host}: {err}"))?
+        .map(|address| address.ip())
+        .collect();
+    if addresses.is_empty() {
+        return Err(format!("{host} resolves to no address"));
+    }
+    for address in &addresses {
+        ensure_public_address(*address).map_err(|err| format!("{host} {err}"))?;
+    }
+    Ok(addresses)
+}
+
+fn ensure_public_address(address: IpAddr) -> Result<(), String> {
+    match is_public_address(address) {
+        true => Ok(()),
+        false => Err(format!("resolves to non-public address {address}")),
+    }
+}
+
+/// Loopback, private, link-local (cloud metadata endpoints included), shared, unspecified,
+/// broadcast, multicast and documentation addresses aren't public.
+fn is_public_address(address: IpAddr) -> bool {
+    match address {
+        IpAddr::V4(address) => {
+            let [a, b, ..] = address.octets();
+            !(address.is_unspecified()
+                || address.is_loopback()
+                || address.is_private()
+                || address.is_link_local()
+                || address.is_broadcast()
+                || address.is_multicast()
+                || address.is_documentation()
+                || a == 0
+                || (a == 100 && (b & 0xc0) == 64))
+        }
+        IpAddr::V6(address) => {
+            if let Some(mapped) = address.to_ipv4_mapped() {
+                return is_public_address(mapped.into());
+            }
+            let segments = address.segments();
+            // NAT64 addresses embed an IPv4 one
+            if segments[..6] == [0x64, 0xff9b, 0, 0, 0, 0] {
+                let embedded = (u32::from(segments[6]) << 16) | u32::from(segments[7]);
+                return is_public_address(Ipv4Addr::from(embedded).into());
+            }
+            !(address.is_unspecified()
+                || address.is_loopback()
+                || address.is_multicast()
+                || (segments[0] & 0xfe00) == 0xfc00
+                || (segments[0] & 0xffc0) == 0xfe80
+                || (segments[0] == 0x2001 && segments[1] == 0xdb8))
+        }
+    }
+}
+
+/// Hex encoded HMAC-SHA256 of `{timestamp}.{body}`.
+fn sign_payload(secret: &str, timestamp: &str, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
+    mac.update(timestamp.as_bytes());
+    mac.update(b".");
+    mac.update(body.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+#[derive(Serialize)]
+struct WebhookPayload {
+    id: Uuid,
+    #[serde(rename = "type")]
+    event_type: &'static str,
+    created_at: DateTime<Utc>,
+    data: WebhookPayloadData,
+}
+
+#[derive(Serialize)]
+struct WebhookPayloadData {
+    user_id: Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note_id: Option<Uuid>,
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,245 @@
+use super::*;
+
+fn parse_address(address: &str) -> IpAddr {
+    address.parse().unwrap()
+}
+
+#[test]
+fn test_is_public_address_accepts_public_addresses() {
+    for address in ["93.184.216.34", "8.8.8.8", "2606:4700::1111"] {
+        assert!(
+            is_public_address(parse_address(address)),
+            "Expected {address} to be public"
+        );
+    }
+}
+
+#[test]
+fn test_is_public_address_rejects_local_ipv4_addresses() {
+    for address in [
+        "0.0.0.0",
+        "127.0.0.1",
+        "10.1.2.3",
+        "172.16.0.1",
+        "192.168.1.1",
+        "169.254.169.254",
+        "100.64.0.1",
+        "100.100.100.200",
+        "255.255.255.255",
+        "224.0.0.1",
+        "192.0.2.1",
+    ] {
+        assert!(
+            !is_public_address(parse_address(address)),
+            "Expected {address} to be rejected"
+        );
+    }
+}
+
+#[test]
+fn test_is_public_address_rejects_local_ipv6_addresses() {
+    for address in [
+        "::",
+        "::1",
+        "fc00::1",
+        "fd00:ec2::254",
+        "fe80::1",
+        "ff02::1",
+        "2001:db8::1",
+        "::ffff:127.0.0.1",
+        "::ffff:169.254.169.254",
+        "64:ff9b::a9fe:a9fe",
+    ] {
+        assert!(
+            !is_public_address(parse_address(address)),
+            "Expected {address} to be rejected"
+        );
+    }
+}
+
+#[test]
+fn test_url_host_address_parses_address_hosts() {
+    assert_eq!(
+        url_host_address("http://127.0.0.1:8080/hooks").unwrap(),
+        Some(parse_address("127.0.0.1"))
+    );
+    assert_eq!(
+        url_host_address("http://[::1]/hooks").unwrap(),
+        Some(parse_address("::1"))
+    );
+    assert_eq!(url_host_address("https://example.com/hooks").unwrap(), None);
+}
+
+#[test]
+fn test_url_host_address_normalizes_address_hosts() {
+    // Alternative notations are parsed into dotted addresses, leaving no way around the checks
+    assert_eq!(
+        url_host_address("http://2130706433/").unwrap(),
+        Some(parse_address("127.0.0.1"))
+    );
+    assert_eq!(
+        url_host_address("http://0x7f.1/").unwrap(),
+        Some(parse_address("127.0.0.1"))
+    );
+}
+
+const TEST_SECRET: &str = "0123456789abcdef";
+
+fn create_webhook(url: &str) -> Webhook {
+    let now = Utc::now();
+    Webhook::try_from(dmn::entities::webhook::WebhookData {
+        id: Uuid::new_v4(),
+        user_id: Uuid::new_v4(),
+        url: url.to_string(),
+        secret: TEST_SECRET.to_string(),
+        event_types: vec!["note.created".to_string()],
+        created_at: now,
+        updated_at: now,
+    })
+    .unwrap()
+}
+
+fn create_delivery(webhook: &Webhook) -> WebhookDelivery {
+    let now = Utc::now();
+    WebhookDelivery::try_from(dmn::entities::webhook_delivery::WebhookDeliveryData {
+        id: Uuid::new_v4(),
+        webhook_id: webhook.id(),
+        event_id: Uuid::new_v4(),
+        event_type: "note.created".to_string(),
+        note_id: Some(Uuid::new_v4()),
+        status: "pending".to_string(),
+        attempts: 0,
+        response_status: None,
+        last_error: None,
+        next_attempt_at: Some(now),
+        created_at: now,
+        delivered_at: None,
+    })
+    .unwrap()
+}
+
+/// Accepts a single request, answering it with `204 No Content`, and returns it once read.
+fn receive_request(
+    listener: tokio::net::TcpListener,
+) -> tokio::task::JoinHandle<(Vec<(String, String)>, String)> {
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut headers = Vec::new();
+        let mut line = String::new();
+        reader.read_line(&mut line).await.unwrap();
+        loop {
+            line.clear();
+            reader.read_line(&mut line).await.unwrap();
+            let Some((name, value)) = line.trim_end().split_once(':') else {
+                break;
+            };
+            headers.push((name.to_lowercase(), value.trim().to_string()));
+        }
+        let content_length = headers
+            .iter()
+            .find(|(name, _)| name == "content-length")
+            .map(|(_, value)| value.parse::<usize>().unwrap())
+            .unwrap();
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).await.unwrap();
+        reader
+            .into_inner()
+            .write_all(b"HTTP/1.1 204 No Content\r\nContent-Length: 0\r\n\r\n")
+            .await
+            .unwrap();
+        (headers, String::from_utf8(body).unwrap())
+    })
+}
+
+fn find_header<'a>(headers: &'a [(String, String)], name: &str) -> &'a str {
+    headers
+        .iter()
+        .find(|(header, _)| header == name)
+        .map(|(_, value)| value.as_str())
+        .unwrap_or_else(|| panic!("Expected {name} header"))
+}
+
+#[test]
+fn test_sign_payload_matches_known_signature() {
+    assert_eq!(
+        sign_payload(TEST_SECRET, "1700000000", r#"{"id":1}"#),
+        "4bcaced68dfea90a68df035b89cb7fb26692d899d32a1ccb1b0616cf48e4d1ed"
+    );
+}
+
+#[tokio::test]
+async fn test_send_posts_signed_payload() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let request = receive_request(listener);
+    let webhook = create_webhook(&format!("http://{address}/hooks"));
+    let delivery = create_delivery(&webhook);
+
+    let sender = HttpWebhookSender::new(Duration::from_secs(5), true).unwrap();
+    let outcome = sender.send(&webhook, &delivery).await;
+    assert_eq!(outcome, WebhookAttemptOutcome::Response(204));
+
+    let (headers, body) = request.await.unwrap();
+    let event_id = delivery.event_id().to_string();
+    assert_eq!(find_header(&headers, "x-webhook-id"), event_id);
+    assert_eq!(find_header(&headers, "x-webhook-event"), "note.created");
+    assert_eq!(find_header(&headers, "content-type"), "application/json");
+
+    let timestamp = find_header(&headers, "x-webhook-timestamp");
+    let mut mac = Hmac::<Sha256>::new_from_slice(TEST_SECRET.as_bytes()).unwrap();
+    mac.update(format!("{timestamp}.{body}").as_bytes());
+    let signature = format!("sha256={}", hex::encode(mac.finalize().into_bytes()));
+    assert_eq!(find_header(&headers, "x-webhook-signature"), signature);
+
+    let payload: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(payload["id"], event_id.as_str());
+    assert_eq!(payload["type"], "note.created");
+    assert_eq!(payload["data"]["user_id"], webhook.user_id().to_string());
+    assert_eq!(
+        payload["data"]["note_id"],
+        delivery.note_id().unwrap().to_string()
+    );
+}
+
+#[tokio::test]
+async fn test_send_refuses_local_targets_unless_allowed() {
+    let sender = HttpWebhookSender::new(Duration::from_secs(5), false).unwrap();
+    for url in ["http://127.0.0.1:9/hooks", "http://localhost:9/hooks"] {
+        let webhook = create_webhook(url);
+        let outcome = sender.send(&webhook, &create_delivery(&webhook)).await;
+        assert!(
+            matches!(outcome, WebhookAttemptOutcome::Error(_)),
+            "Expected {url} to be refused"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_check_url_rejects_local_targets_unless_allowed() {
+    let sender = HttpWebhookSender::new(Duration::from_secs(5), false).unwrap();
+    for url in [
+        "http://127.0.0.1/hooks",
+        "http://[::1]/hooks",
+        "http://169.254.169.254/latest/meta-data",
+        "http://localhost:8080/hooks",
+    ] {
+        let result = sender.check_url(url).await;
+        if let Err(AppError::BadRequest(base_error)) = result {
+            assert_eq!(
+                base_error.public_info,
+                "Webhook URL has to resolve to a public address!"
+            );
+        } else {
+            panic!("Expected BadRequest error for local URL ({url})");
+        }
+    }
+
+    let sender = HttpWebhookSender::new(Duration::from_secs(5), true).unwrap();
+    assert!(sender
+        .check_url("http://localhost:8080/hooks")
+        .await
+        .is_ok());
+}
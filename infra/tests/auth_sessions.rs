@@ -0,0 +1,171 @@
+mod support;
+
+use std::sync::Arc;
+
+use app::{
+    auth::authenticator::Authenticator,
+    types::auth_token::{
+        AuthClientInfo, AuthTokenPair, AuthTokenSession, UniqueAccessTokenIdentifier,
+    },
+    usecases::auth::{GetAuthSessionsUseCase, RevokeAuthSessionInput, RevokeAuthSessionUseCase},
+};
+use common::error::AppError;
+use infra::{auth::authenticator::JwtAuthenticator, repos::auth_token::PgAuthTokenRepository};
+use support::{create_test_authenticator, create_test_user, get_test_pool};
+
+fn client_info(ip_address: &str) -> AuthClientInfo {
+    AuthClientInfo {
+        ip_address: Some(ip_address.into()),
+        user_agent: Some("test-agent".into()),
+    }
+}
+
+async fn refresh(
+    authenticator: &JwtAuthenticator,
+    token_pair: &AuthTokenPair,
+    ip_address: &str,
+) -> AuthTokenPair {
+    let session = AuthTokenSession::Rotate {
+        revoke_token_pair_id: UniqueAccessTokenIdentifier::Id(token_pair.access_token.id),
+        client_info: client_info(ip_address),
+    };
+    authenticator
+        .generate_auth_tokens(token_pair.access_token.user_id, session)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_sessions_listed_by_last_refresh() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let authenticator = create_test_authenticator(&db);
+    let use_case = GetAuthSessionsUseCase::new(Arc::new(PgAuthTokenRepository::new(db.clone())));
+    let auth_ctx = create_test_user(&db).await;
+    let user_id = auth_ctx.user.id();
+    let first = authenticator
+        .generate_auth_tokens(user_id, AuthTokenSession::Start(client_info("10.0.0.1")))
+        .await
+        .unwrap();
+    let second = authenticator
+        .generate_auth_tokens(user_id, AuthTokenSession::Start(client_info("10.0.0.2")))
+        .await
+        .unwrap();
+    // Sessions of other users are left out
+    let other_user = create_test_user(&db).await;
+    authenticator
+        .generate_auth_tokens(
+            other_user.user.id(),
+            AuthTokenSession::Start(AuthClientInfo::default()),
+        )
+        .await
+        .unwrap();
+
+    let sessions = use_case.execute(auth_ctx.clone()).await.unwrap();
+    let session_ids: Vec<_> = sessions.iter().map(|session| session.id).collect();
+    assert_eq!(
+        session_ids,
+        vec![
+            second.access_token.session_id,
+            first.access_token.session_id
+        ]
+    );
+
+    // Refreshing keeps the session, bumping it and updating its client details
+    let refreshed = refresh(&authenticator, &first, "10.0.0.3").await;
+    assert_eq!(
+        refreshed.access_token.session_id,
+        first.access_token.session_id
+    );
+
+    let sessions = use_case.execute(auth_ctx).await.unwrap();
+    let session_ids: Vec<_> = sessions.iter().map(|session| session.id).collect();
+    assert_eq!(
+        session_ids,
+        vec![
+            first.access_token.session_id,
+            second.access_token.session_id
+        ]
+    );
+    assert_eq!(sessions[0].ip_address.as_deref(), Some("10.0.0.3"));
+    assert!(sessions[0].last_refreshed_at > sessions[0].created_at);
+    assert_eq!(sessions[1].ip_address.as_deref(), Some("10.0.0.2"));
+}
+
+#[tokio::test]
+async fn test_revoked_session_tokens_rejected() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let authenticator = create_test_authenticator(&db);
+    let token_repo = Arc::new(PgAuthTokenRepository::new(db.clone()));
+    let auth_ctx = create_test_user(&db).await;
+    let user_id = auth_ctx.user.id();
+    let revoked = authenticator
+        .generate_auth_tokens(user_id, AuthTokenSession::Start(AuthClientInfo::default()))
+        .await
+        .unwrap();
+    let kept = authenticator
+        .generate_auth_tokens(user_id, AuthTokenSession::Start(AuthClientInfo::default()))
+        .await
+        .unwrap();
+
+    let input = RevokeAuthSessionInput {
+        session_id: revoked.access_token.session_id,
+    };
+    RevokeAuthSessionUseCase::new(token_repo.clone())
+        .execute(auth_ctx.clone(), input)
+        .await
+        .unwrap();
+
+    assert!(authenticator
+        .authenticate_access_token(revoked.access_token.jwt.clone())
+        .await
+        .is_err());
+    assert!(authenticator
+        .authenticate_refresh_token(revoked.refresh_token.jwt.clone())
+        .await
+        .is_err());
+    assert!(authenticator
+        .authenticate_access_token(kept.access_token.jwt.clone())
+        .await
+        .is_ok());
+    let sessions = GetAuthSessionsUseCase::new(token_repo)
+        .execute(auth_ctx)
+        .await
+        .unwrap();
+    let session_ids: Vec<_> = sessions.iter().map(|session| session.id).collect();
+    assert_eq!(session_ids, vec![kept.access_token.session_id]);
+}
+
+#[tokio::test]
+async fn test_other_users_sessions_not_revoked() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let authenticator = create_test_authenticator(&db);
+    let token_repo = Arc::new(PgAuthTokenRepository::new(db.clone()));
+    let auth_ctx = create_test_user(&db).await;
+    let owner = create_test_user(&db).await;
+    let token_pair = authenticator
+        .generate_auth_tokens(
+            owner.user.id(),
+            AuthTokenSession::Start(AuthClientInfo::default()),
+        )
+        .await
+        .unwrap();
+
+    let input = RevokeAuthSessionInput {
+        session_id: token_pair.access_token.session_id,
+    };
+    let result = RevokeAuthSessionUseCase::new(token_repo)
+        .execute(auth_ctx, input)
+        .await;
+
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+    assert!(authenticator
+        .authenticate_access_token(token_pair.access_token.jwt)
+        .await
+        .is_ok());
+}
@@ -0,0 +1,146 @@
+mod support;
+
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use app::{
+    auth::authenticator::Authenticator,
+    repos::auth_token::AuthTokenRepository,
+    types::auth_token::{AuthClientInfo, AuthTokenPair, AuthTokenSession, RevokedRefreshToken},
+    usecases::auth::{AuthRefreshInput, AuthRefreshUseCase, PurgeRevokedRefreshTokensUseCase},
+};
+use common::error::AppError;
+use infra::{auth::authenticator::JwtAuthenticator, repos::auth_token::PgAuthTokenRepository};
+use support::{create_test_authenticator, create_test_user, get_test_pool};
+
+async fn login(authenticator: &JwtAuthenticator, user_id: Uuid) -> AuthTokenPair {
+    authenticator
+        .generate_auth_tokens(user_id, AuthTokenSession::Start(AuthClientInfo::default()))
+        .await
+        .unwrap()
+}
+
+async fn refresh(
+    authenticator: Arc<JwtAuthenticator>,
+    token_pair: &AuthTokenPair,
+) -> Result<AuthTokenPair, AppError> {
+    let auth_ref_ctx = authenticator
+        .authenticate_refresh_token(token_pair.refresh_token.jwt.clone())
+        .await?;
+    let input = AuthRefreshInput {
+        client_info: AuthClientInfo::default(),
+    };
+    AuthRefreshUseCase::new(authenticator)
+        .execute(auth_ref_ctx, input)
+        .await
+}
+
+async fn is_authenticated(authenticator: &JwtAuthenticator, token_pair: &AuthTokenPair) -> bool {
+    authenticator
+        .authenticate_access_token(token_pair.access_token.jwt.clone())
+        .await
+        .is_ok()
+}
+
+#[tokio::test]
+async fn test_rotated_refresh_token_reuse_revokes_family() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let authenticator = Arc::new(create_test_authenticator(&db));
+    let auth_ctx = create_test_user(&db).await;
+    let user_id = auth_ctx.user.id();
+    let stolen = login(&authenticator, user_id).await;
+    let other_session = login(&authenticator, user_id).await;
+    let rotated = refresh(authenticator.clone(), &stolen).await.unwrap();
+    let rotated = refresh(authenticator.clone(), &rotated).await.unwrap();
+    assert_eq!(
+        rotated.refresh_token.family_id,
+        stolen.refresh_token.family_id
+    );
+    assert!(!is_authenticated(&authenticator, &stolen).await);
+    assert!(is_authenticated(&authenticator, &rotated).await);
+
+    // Replaying the first refresh token, two rotations back
+    let result = refresh(authenticator.clone(), &stolen).await;
+
+    assert!(matches!(result, Err(AppError::Unauthorized(_))));
+    // The latest token pair of the family is revoked as well
+    assert!(!is_authenticated(&authenticator, &rotated).await);
+    assert!(refresh(authenticator.clone(), &rotated).await.is_err());
+    // Unlike the user's other sessions
+    assert!(is_authenticated(&authenticator, &other_session).await);
+    assert!(refresh(authenticator.clone(), &other_session).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_logged_out_refresh_token_rejected_without_revocation() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let authenticator = Arc::new(create_test_authenticator(&db));
+    let token_repo = PgAuthTokenRepository::new(db.clone());
+    let auth_ctx = create_test_user(&db).await;
+    let user_id = auth_ctx.user.id();
+    let logged_out = login(&authenticator, user_id).await;
+    let other_session = login(&authenticator, user_id).await;
+    token_repo
+        .delete_session(None, logged_out.access_token.session_id, Some(user_id))
+        .await
+        .unwrap();
+
+    let result = refresh(authenticator.clone(), &logged_out).await;
+
+    // Deleted tokens aren't remembered, so there's no family to revoke
+    assert!(matches!(result, Err(AppError::Unauthorized(_))));
+    assert!(token_repo
+        .get_revoked_refresh_token(None, logged_out.refresh_token.id)
+        .await
+        .is_err());
+    assert!(is_authenticated(&authenticator, &other_session).await);
+}
+
+#[tokio::test]
+async fn test_expired_revoked_refresh_tokens_purged() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let token_repo = Arc::new(PgAuthTokenRepository::new(db.clone()));
+    let auth_ctx = create_test_user(&db).await;
+    let mut token_ids = Vec::new();
+    for expires_at in [
+        Utc::now() - Duration::minutes(1),
+        Utc::now() + Duration::days(1),
+    ] {
+        let revoked_token = RevokedRefreshToken {
+            id: Uuid::new_v4(),
+            user_id: auth_ctx.user.id(),
+            family_id: Uuid::new_v4(),
+            expires_at,
+            revoked_at: Utc::now() - Duration::days(1),
+        };
+        let revoked_token = token_repo
+            .create_revoked_refresh_token(None, revoked_token)
+            .await
+            .unwrap();
+        token_ids.push(revoked_token.id);
+    }
+
+    let purged_count = PurgeRevokedRefreshTokensUseCase::new(token_repo.clone())
+        .execute()
+        .await
+        .unwrap();
+
+    assert!(purged_count >= 1);
+    assert!(matches!(
+        token_repo
+            .get_revoked_refresh_token(None, token_ids[0])
+            .await,
+        Err(AppError::NotFound(_))
+    ));
+    assert!(token_repo
+        .get_revoked_refresh_token(None, token_ids[1])
+        .await
+        .is_ok());
+}
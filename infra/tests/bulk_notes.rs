@@ -0,0 +1,328 @@
+mod support;
+
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use app::{
+    auth::context::AuthAccessContext,
+    usecases::note::{
+        BulkDeleteNotesInput, BulkDeleteNotesUseCase, BulkMoveNotesInput, BulkMoveNotesUseCase,
+        BulkNoteOperation, BulkNoteOperationResult, BulkNoteOperationsInput,
+        BulkNoteOperationsUseCase, BulkTagNotesInput, BulkTagNotesUseCase, CreateNoteInput,
+        DeleteNoteInput, UpdateNoteInput,
+    },
+};
+use common::error::AppError;
+use dmn::{
+    entities::{
+        note::{CreateNoteData, Note},
+        note_share::{CreateNoteShareData, NoteShareRole},
+        notebook::CreateNotebookData,
+        tag::CreateTagData,
+    },
+    repos::{
+        note::NoteRepository, note_share::NoteShareRepository, notebook::NotebookRepository,
+        tag::TagRepository,
+    },
+};
+use infra::{
+    repos::{
+        note::PgNoteRepository, note_revision::PgNoteRevisionRepository,
+        note_share::PgNoteShareRepository, notebook::PgNotebookRepository,
+        outbox::PgOutboxRepository, tag::PgTagRepository,
+    },
+    tx::PgUnitOfWork,
+};
+use support::{create_test_user, get_test_pool};
+
+async fn create_test_note(db: &Arc<PgPool>, auth_ctx: &AuthAccessContext, title: &str) -> Note {
+    let note_data = CreateNoteData {
+        user_id: auth_ctx.user.id(),
+        notebook_id: None,
+        title: title.into(),
+        content: "Content".into(),
+        created_at: None,
+        updated_at: None,
+    };
+    PgNoteRepository::new(db.clone())
+        .create_note(None, note_data)
+        .await
+        .unwrap()
+}
+
+async fn share_note(
+    db: &Arc<PgPool>,
+    note: &Note,
+    auth_ctx: &AuthAccessContext,
+    role: NoteShareRole,
+) {
+    let share_data = CreateNoteShareData {
+        note_id: note.id(),
+        user_id: auth_ctx.user.id(),
+        role,
+    };
+    PgNoteShareRepository::new(db.clone())
+        .create_note_share(None, share_data)
+        .await
+        .unwrap();
+}
+
+/// Fetches the note as it is now, `None` once trashed.
+async fn get_note(db: &Arc<PgPool>, note: &Note) -> Option<Note> {
+    PgNoteRepository::new(db.clone())
+        .get_note(None, note.id(), None)
+        .await
+        .ok()
+}
+
+fn bulk_delete_use_case(db: &Arc<PgPool>) -> BulkDeleteNotesUseCase {
+    BulkDeleteNotesUseCase::new(
+        Arc::new(PgUnitOfWork::new(db.clone())),
+        Arc::new(PgNoteRepository::new(db.clone())),
+        Arc::new(PgNoteShareRepository::new(db.clone())),
+        Arc::new(PgOutboxRepository::new(db.clone())),
+    )
+}
+
+fn bulk_operations_use_case(db: &Arc<PgPool>) -> BulkNoteOperationsUseCase {
+    BulkNoteOperationsUseCase::new(
+        Arc::new(PgUnitOfWork::new(db.clone())),
+        Arc::new(PgNoteRepository::new(db.clone())),
+        Arc::new(PgNoteShareRepository::new(db.clone())),
+        Arc::new(PgNoteRevisionRepository::new(db.clone())),
+        Arc::new(PgNotebookRepository::new(db.clone())),
+        Arc::new(PgOutboxRepository::new(db.clone())),
+    )
+}
+
+fn update_operation(note_id: Uuid, title: &str) -> BulkNoteOperation {
+    BulkNoteOperation::Update(UpdateNoteInput {
+        note_id,
+        title: title.into(),
+        content: "Updated".into(),
+        expected_versions: Vec::new(),
+    })
+}
+
+#[tokio::test]
+async fn test_bulk_delete_rolled_back_on_missing_note() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let auth_ctx = create_test_user(&db).await;
+    let note = create_test_note(&db, &auth_ctx, "Kept").await;
+    let input = BulkDeleteNotesInput {
+        note_ids: vec![note.id(), Uuid::new_v4()],
+    };
+
+    let result = bulk_delete_use_case(&db).execute(auth_ctx, input).await;
+
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+    assert!(get_note(&db, &note).await.is_some());
+}
+
+#[tokio::test]
+async fn test_bulk_delete_rejects_shared_notes() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let auth_ctx = create_test_user(&db).await;
+    let owner = create_test_user(&db).await;
+    let note = create_test_note(&db, &auth_ctx, "Own").await;
+    // Write access still doesn't allow trashing the note
+    let shared_note = create_test_note(&db, &owner, "Shared").await;
+    share_note(&db, &shared_note, &auth_ctx, NoteShareRole::Write).await;
+    let input = BulkDeleteNotesInput {
+        note_ids: vec![note.id(), shared_note.id()],
+    };
+
+    let result = bulk_delete_use_case(&db).execute(auth_ctx, input).await;
+
+    assert!(matches!(result, Err(AppError::Forbidden(_))));
+    assert!(get_note(&db, &note).await.is_some());
+    assert!(get_note(&db, &shared_note).await.is_some());
+}
+
+#[tokio::test]
+async fn test_bulk_delete_deduplicates_note_ids() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let auth_ctx = create_test_user(&db).await;
+    let first = create_test_note(&db, &auth_ctx, "First").await;
+    let second = create_test_note(&db, &auth_ctx, "Second").await;
+    let input = BulkDeleteNotesInput {
+        note_ids: vec![first.id(), second.id(), first.id()],
+    };
+
+    let note_ids = bulk_delete_use_case(&db)
+        .execute(auth_ctx, input)
+        .await
+        .unwrap();
+
+    assert_eq!(note_ids, vec![first.id(), second.id()]);
+    assert!(get_note(&db, &first).await.is_none());
+    assert!(get_note(&db, &second).await.is_none());
+}
+
+#[tokio::test]
+async fn test_bulk_move_rolled_back_on_other_users_note() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let auth_ctx = create_test_user(&db).await;
+    let other_user = create_test_user(&db).await;
+    let notebook_data = CreateNotebookData {
+        user_id: auth_ctx.user.id(),
+        parent_id: None,
+        name: "Target".into(),
+    };
+    let notebook = PgNotebookRepository::new(db.clone())
+        .create_notebook(None, notebook_data)
+        .await
+        .unwrap();
+    let note = create_test_note(&db, &auth_ctx, "Own").await;
+    let other_note = create_test_note(&db, &other_user, "Other").await;
+    let use_case = BulkMoveNotesUseCase::new(
+        Arc::new(PgUnitOfWork::new(db.clone())),
+        Arc::new(PgNoteRepository::new(db.clone())),
+        Arc::new(PgNoteShareRepository::new(db.clone())),
+        Arc::new(PgNotebookRepository::new(db.clone())),
+        Arc::new(PgOutboxRepository::new(db.clone())),
+    );
+    let input = BulkMoveNotesInput {
+        note_ids: vec![note.id(), other_note.id()],
+        notebook_id: Some(notebook.id()),
+    };
+
+    let result = use_case.execute(auth_ctx, input).await;
+
+    // Notes of others are reported as nonexistent
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+    assert_eq!(get_note(&db, &note).await.unwrap().notebook_id(), None);
+    assert_eq!(
+        get_note(&db, &other_note).await.unwrap().notebook_id(),
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_bulk_tag_rolled_back_on_read_only_note() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let auth_ctx = create_test_user(&db).await;
+    let owner = create_test_user(&db).await;
+    let tag_data = CreateTagData {
+        user_id: auth_ctx.user.id(),
+        name: "Work".into(),
+    };
+    let tag_repo = Arc::new(PgTagRepository::new(db.clone()));
+    let tag = tag_repo.create_tag(None, tag_data).await.unwrap();
+    let note = create_test_note(&db, &auth_ctx, "Own").await;
+    let shared_note = create_test_note(&db, &owner, "Shared").await;
+    share_note(&db, &shared_note, &auth_ctx, NoteShareRole::Read).await;
+    let use_case = BulkTagNotesUseCase::new(
+        Arc::new(PgUnitOfWork::new(db.clone())),
+        Arc::new(PgNoteRepository::new(db.clone())),
+        Arc::new(PgNoteShareRepository::new(db.clone())),
+        tag_repo,
+    );
+    let input = BulkTagNotesInput {
+        note_ids: vec![note.id(), shared_note.id()],
+        tag_id: tag.id(),
+    };
+
+    let result = use_case.execute(auth_ctx, input).await;
+
+    assert!(matches!(result, Err(AppError::Forbidden(_))));
+    assert!(get_note(&db, &note).await.unwrap().tags().is_empty());
+    assert!(get_note(&db, &shared_note).await.unwrap().tags().is_empty());
+}
+
+#[tokio::test]
+async fn test_bulk_operations_rolled_back_on_failing_operation() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let auth_ctx = create_test_user(&db).await;
+    let note = create_test_note(&db, &auth_ctx, "Original").await;
+    let input = BulkNoteOperationsInput {
+        operations: vec![
+            BulkNoteOperation::Create(CreateNoteInput {
+                notebook_id: None,
+                title: "Created".into(),
+                content: "Content".into(),
+            }),
+            update_operation(note.id(), "Renamed"),
+            BulkNoteOperation::Delete(DeleteNoteInput {
+                note_id: Uuid::new_v4(),
+                expected_versions: Vec::new(),
+            }),
+        ],
+    };
+
+    let result = bulk_operations_use_case(&db)
+        .execute(auth_ctx.clone(), input)
+        .await;
+
+    let Err(AppError::NotFound(err)) = result else {
+        panic!("Expected the missing note to fail the operations");
+    };
+    assert!(err.public_info.starts_with("Operation #2: "));
+    let (note_count,): (i64,) = sqlx::query_as("SELECT count(*) FROM notes WHERE user_id = $1")
+        .bind(auth_ctx.user.id())
+        .fetch_one(&*db)
+        .await
+        .unwrap();
+    assert_eq!(note_count, 1);
+    let note = get_note(&db, &note).await.unwrap();
+    assert_eq!(note.title(), "Original");
+    assert_eq!(note.version(), 1);
+}
+
+#[tokio::test]
+async fn test_bulk_operations_check_access_per_note() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let auth_ctx = create_test_user(&db).await;
+    let owner = create_test_user(&db).await;
+    let writable_note = create_test_note(&db, &owner, "Writable").await;
+    share_note(&db, &writable_note, &auth_ctx, NoteShareRole::Write).await;
+    let read_only_note = create_test_note(&db, &owner, "Read-only").await;
+    share_note(&db, &read_only_note, &auth_ctx, NoteShareRole::Read).await;
+    let use_case = bulk_operations_use_case(&db);
+
+    // Collaborators with write access can update the note
+    let input = BulkNoteOperationsInput {
+        operations: vec![update_operation(writable_note.id(), "Renamed")],
+    };
+    let results = use_case.execute(auth_ctx.clone(), input).await.unwrap();
+    assert!(matches!(
+        &results[..],
+        [BulkNoteOperationResult::Updated(note)] if note.title() == "Renamed"
+    ));
+
+    // Yet neither update read-only notes nor trash notes they don't own
+    let operations = vec![
+        update_operation(read_only_note.id(), "Renamed"),
+        BulkNoteOperation::Delete(DeleteNoteInput {
+            note_id: writable_note.id(),
+            expected_versions: Vec::new(),
+        }),
+    ];
+    for operation in operations {
+        let input = BulkNoteOperationsInput {
+            operations: vec![operation],
+        };
+        let result = use_case.execute(auth_ctx.clone(), input).await;
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+    }
+    assert_eq!(
+        get_note(&db, &read_only_note).await.unwrap().title(),
+        "Read-only"
+    );
+    assert!(get_note(&db, &writable_note).await.is_some());
+}
@@ -0,0 +1,160 @@
+mod support;
+
+use futures_util::TryStreamExt;
+use std::sync::Arc;
+
+use app::{
+    archive::note_archive::{ArchivedNote, NoteArchiveCodec, NoteArchiveFormat},
+    usecases::note::{ExportNotesInput, ExportNotesUseCase, ImportNotesInput, ImportNotesUseCase},
+};
+use dmn::{
+    entities::{note::CreateNoteData, tag::CreateTagData},
+    repos::{note::NoteRepository, tag::TagRepository},
+};
+use infra::{
+    archive::note_archive::ZipNoteArchiveCodec,
+    repos::{
+        note::PgNoteRepository, notebook::PgNotebookRepository, outbox::PgOutboxRepository,
+        tag::PgTagRepository,
+    },
+    tx::PgUnitOfWork,
+};
+use support::{create_test_user, get_test_pool};
+
+#[tokio::test]
+async fn test_exported_notes_import_back() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let note_repo = Arc::new(PgNoteRepository::new(db.clone()));
+    let tag_repo = Arc::new(PgTagRepository::new(db.clone()));
+    let note_archive_codec = Arc::new(ZipNoteArchiveCodec);
+    let exporter = create_test_user(&db).await;
+    let importer = create_test_user(&db).await;
+
+    for title in ["Tagged", "Untagged"] {
+        let note_data = CreateNoteData {
+            user_id: exporter.user.id(),
+            notebook_id: None,
+            title: title.into(),
+            content: format!("Content of {title}"),
+            created_at: None,
+            updated_at: None,
+        };
+        let note = note_repo.create_note(None, note_data).await.unwrap();
+        if title == "Tagged" {
+            let tag_data = CreateTagData {
+                user_id: exporter.user.id(),
+                name: "Work".into(),
+            };
+            let tag = tag_repo.create_tag(None, tag_data).await.unwrap();
+            tag_repo
+                .attach_note_tag(None, note.id(), tag.id())
+                .await
+                .unwrap();
+        }
+    }
+
+    let export_use_case = ExportNotesUseCase::new(note_repo.clone(), note_archive_codec.clone());
+    let input = ExportNotesInput {
+        format: NoteArchiveFormat::Markdown,
+    };
+    let chunks: Vec<Vec<u8>> = export_use_case
+        .execute(exporter, input)
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+
+    let import_use_case = ImportNotesUseCase::new(
+        Arc::new(PgUnitOfWork::new(db.clone())),
+        note_repo.clone(),
+        Arc::new(PgNotebookRepository::new(db.clone())),
+        Arc::new(PgOutboxRepository::new(db.clone())),
+        tag_repo.clone(),
+        note_archive_codec,
+    );
+    let input = ImportNotesInput {
+        format: NoteArchiveFormat::Markdown,
+        data: chunks.concat(),
+    };
+    let output = import_use_case
+        .execute(importer.clone(), input)
+        .await
+        .unwrap();
+
+    assert!(output.failed.is_empty());
+    let mut notes: Vec<_> = output.imported.into_iter().map(|file| file.note).collect();
+    notes.sort_by(|a, b| a.title().cmp(b.title()));
+    let titles: Vec<_> = notes.iter().map(|note| note.title()).collect();
+    assert_eq!(titles, vec!["Tagged", "Untagged"]);
+    assert!(notes
+        .iter()
+        .all(|note| note.user_id() == importer.user.id()));
+    assert_eq!(notes[0].content(), "Content of Tagged");
+    let tags: Vec<_> = notes[0].tags().iter().map(|tag| tag.name()).collect();
+    assert_eq!(tags, vec!["Work"]);
+    assert!(notes[1].tags().is_empty());
+}
+
+#[tokio::test]
+async fn test_import_reports_invalid_files() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let note_repo = Arc::new(PgNoteRepository::new(db.clone()));
+    let note_archive_codec = Arc::new(ZipNoteArchiveCodec);
+    let auth_ctx = create_test_user(&db).await;
+    let notes = futures_util::stream::iter([
+        Ok(ArchivedNote {
+            id: None,
+            title: "Valid".into(),
+            content: "Body".into(),
+            tags: Vec::new(),
+            created_at: None,
+            updated_at: None,
+        }),
+        // Titles can't be blank
+        Ok(ArchivedNote {
+            id: None,
+            title: " ".into(),
+            content: "Body".into(),
+            tags: Vec::new(),
+            created_at: None,
+            updated_at: None,
+        }),
+    ]);
+    let data: Vec<Vec<u8>> = note_archive_codec
+        .write_archive(NoteArchiveFormat::Markdown, Box::pin(notes))
+        .try_collect()
+        .await
+        .unwrap();
+
+    let import_use_case = ImportNotesUseCase::new(
+        Arc::new(PgUnitOfWork::new(db.clone())),
+        note_repo,
+        Arc::new(PgNotebookRepository::new(db.clone())),
+        Arc::new(PgOutboxRepository::new(db.clone())),
+        Arc::new(PgTagRepository::new(db.clone())),
+        note_archive_codec,
+    );
+    let input = ImportNotesInput {
+        format: NoteArchiveFormat::Markdown,
+        data: data.concat(),
+    };
+    let output = import_use_case.execute(auth_ctx, input).await.unwrap();
+
+    let imported: Vec<_> = output
+        .imported
+        .iter()
+        .map(|file| file.file_name.as_str())
+        .collect();
+    assert_eq!(imported, vec!["Valid.md"]);
+    let failed: Vec<_> = output
+        .failed
+        .iter()
+        .map(|file| file.file_name.as_str())
+        .collect();
+    assert_eq!(failed, vec!["Untitled.md"]);
+}
@@ -0,0 +1,203 @@
+mod support;
+
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use app::auth::context::AuthAccessContext;
+use dmn::{
+    entities::{
+        note::{CreateNoteData, Note},
+        tag::CreateTagData,
+    },
+    repos::{
+        note::{NoteChanges, NoteRepository, SyncFrom, SyncToken},
+        tag::TagRepository,
+    },
+};
+use infra::repos::{note::PgNoteRepository, tag::PgTagRepository};
+use support::{create_test_user, get_test_pool};
+
+async fn create_test_notes(
+    note_repo: &PgNoteRepository,
+    auth_ctx: &AuthAccessContext,
+    count: usize,
+) -> Vec<Note> {
+    let mut notes = Vec::with_capacity(count);
+    for index in 0..count {
+        let note_data = CreateNoteData {
+            user_id: auth_ctx.user.id(),
+            notebook_id: None,
+            title: format!("Note {index}"),
+            content: "Content".into(),
+            created_at: None,
+            updated_at: None,
+        };
+        notes.push(note_repo.create_note(None, note_data).await.unwrap());
+    }
+    notes
+}
+
+/// Reads every page of the changes, checking that each one reports the same `next_token`.
+async fn get_all_note_changes(
+    note_repo: &PgNoteRepository,
+    auth_ctx: &AuthAccessContext,
+    since: Option<SyncToken>,
+    limit: u32,
+) -> Vec<NoteChanges> {
+    let mut pages: Vec<NoteChanges> = Vec::new();
+    let mut from = SyncFrom::Start(since);
+    loop {
+        let page = note_repo
+            .get_note_changes(None, from, limit, auth_ctx.user.id())
+            .await
+            .unwrap();
+        assert!(page.notes.len() + page.tombstones.len() <= limit as usize);
+        if let Some(first_page) = pages.first() {
+            assert_eq!(page.next_token, first_page.next_token);
+        }
+        let continuation = page.continuation;
+        pages.push(page);
+        match continuation {
+            Some(continuation) => from = SyncFrom::Continuation(continuation),
+            None => return pages,
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_full_sync_pages() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let note_repo = PgNoteRepository::new(db.clone());
+    let auth_ctx = create_test_user(&db).await;
+    let notes = create_test_notes(&note_repo, &auth_ctx, 5).await;
+
+    let pages = get_all_note_changes(&note_repo, &auth_ctx, None, 2).await;
+
+    let page_sizes: Vec<_> = pages.iter().map(|page| page.notes.len()).collect();
+    assert_eq!(page_sizes, vec![2, 2, 1]);
+    assert!(pages.iter().all(|page| page.tombstones.is_empty()));
+    let synced_ids: Vec<Uuid> = pages
+        .iter()
+        .flat_map(|page| page.notes.iter().map(|note| note.id()))
+        .collect();
+    let note_ids: HashSet<Uuid> = notes.iter().map(|note| note.id()).collect();
+    assert_eq!(synced_ids.len(), note_ids.len());
+    assert_eq!(synced_ids.into_iter().collect::<HashSet<_>>(), note_ids);
+}
+
+#[tokio::test]
+async fn test_last_page_has_no_continuation() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let note_repo = PgNoteRepository::new(db.clone());
+    let auth_ctx = create_test_user(&db).await;
+    create_test_notes(&note_repo, &auth_ctx, 2).await;
+
+    let pages = get_all_note_changes(&note_repo, &auth_ctx, None, 2).await;
+
+    assert_eq!(pages.len(), 1);
+    assert_eq!(pages[0].notes.len(), 2);
+}
+
+#[tokio::test]
+async fn test_tombstones_reported_since_token() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let note_repo = PgNoteRepository::new(db.clone());
+    let auth_ctx = create_test_user(&db).await;
+    let user_id = Some(auth_ctx.user.id());
+    let notes = create_test_notes(&note_repo, &auth_ctx, 3).await;
+    let since = get_all_note_changes(&note_repo, &auth_ctx, None, 10).await[0].next_token;
+
+    note_repo
+        .delete_note(None, notes[0].id(), None, user_id)
+        .await
+        .unwrap();
+    note_repo
+        .delete_note(None, notes[1].id(), None, user_id)
+        .await
+        .unwrap();
+    note_repo
+        .purge_note(None, notes[1].id(), user_id)
+        .await
+        .unwrap();
+
+    // Tombstones are paged along with the notes
+    let pages = get_all_note_changes(&note_repo, &auth_ctx, Some(since), 1).await;
+
+    let tombstone_ids: HashSet<Uuid> = pages
+        .iter()
+        .flat_map(|page| page.tombstones.iter().map(|tombstone| tombstone.note_id))
+        .collect();
+    assert_eq!(tombstone_ids, HashSet::from([notes[0].id(), notes[1].id()]));
+    let synced_ids: HashSet<Uuid> = pages
+        .iter()
+        .flat_map(|page| page.notes.iter().map(|note| note.id()))
+        .collect();
+    assert!(!synced_ids.contains(&notes[0].id()));
+    assert!(!synced_ids.contains(&notes[1].id()));
+}
+
+#[tokio::test]
+async fn test_full_sync_has_no_tombstones() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let note_repo = PgNoteRepository::new(db.clone());
+    let auth_ctx = create_test_user(&db).await;
+    let notes = create_test_notes(&note_repo, &auth_ctx, 2).await;
+    note_repo
+        .delete_note(None, notes[0].id(), None, Some(auth_ctx.user.id()))
+        .await
+        .unwrap();
+
+    let pages = get_all_note_changes(&note_repo, &auth_ctx, None, 10).await;
+
+    assert_eq!(pages.len(), 1);
+    assert!(pages[0].tombstones.is_empty());
+    let synced_ids: Vec<Uuid> = pages[0].notes.iter().map(|note| note.id()).collect();
+    assert_eq!(synced_ids, vec![notes[1].id()]);
+}
+
+#[tokio::test]
+async fn test_tag_changes_bump_note_version() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let note_repo = PgNoteRepository::new(db.clone());
+    let tag_repo = PgTagRepository::new(db.clone());
+    let auth_ctx = create_test_user(&db).await;
+    let user_id = auth_ctx.user.id();
+    let note = create_test_notes(&note_repo, &auth_ctx, 1).await.remove(0);
+    let tag_data = CreateTagData {
+        user_id,
+        name: "Tag".into(),
+    };
+    let tag = tag_repo.create_tag(None, tag_data).await.unwrap();
+
+    tag_repo
+        .attach_note_tag(None, note.id(), tag.id())
+        .await
+        .unwrap();
+    let tagged_note = note_repo
+        .get_note(None, note.id(), Some(user_id))
+        .await
+        .unwrap();
+    assert_eq!(tagged_note.version(), note.version() + 1);
+    assert!(tagged_note.updated_at() > note.updated_at());
+
+    tag_repo
+        .delete_tag(None, tag.id(), Some(user_id))
+        .await
+        .unwrap();
+    let untagged_note = note_repo
+        .get_note(None, note.id(), Some(user_id))
+        .await
+        .unwrap();
+    assert!(untagged_note.version() > tagged_note.version());
+    assert!(untagged_note.tags().is_empty());
+}
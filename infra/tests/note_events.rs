@@ -0,0 +1,108 @@
+mod support;
+
+use futures_util::StreamExt;
+use std::time::Duration;
+use uuid::Uuid;
+
+use app::events::note_events::{
+    NoteEvent, NoteEventKind, NoteEventPublisher, NoteEventStream, NoteEventSubscriber,
+};
+use infra::events::note_events::PgNoteEventBus;
+use support::get_test_pool;
+
+/// Every bus receives the events of all tests, so flooding them would close the others' streams.
+static FLOOD_LOCK: tokio::sync::RwLock<()> = tokio::sync::RwLock::const_new(());
+
+const RECV_TIMEOUT: Duration = Duration::from_secs(5);
+
+fn note_event(kind: NoteEventKind, user_id: Uuid) -> NoteEvent {
+    NoteEvent {
+        kind,
+        note_id: Uuid::new_v4(),
+        user_id,
+    }
+}
+
+async fn next_event(events: &mut NoteEventStream) -> Option<NoteEvent> {
+    tokio::time::timeout(RECV_TIMEOUT, events.next())
+        .await
+        .expect("Timed out waiting for a note event")
+}
+
+#[tokio::test]
+async fn test_published_events_reach_other_instances() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let _lock = FLOOD_LOCK.read().await;
+    let publisher = PgNoteEventBus::start(db.clone()).await.unwrap();
+    let subscriber = PgNoteEventBus::start(db.clone()).await.unwrap();
+    let user_id = Uuid::new_v4();
+    let mut events = subscriber.subscribe(user_id).await.unwrap();
+
+    let sent = vec![
+        note_event(NoteEventKind::Created, user_id),
+        note_event(NoteEventKind::Updated, user_id),
+        note_event(NoteEventKind::Deleted, user_id),
+    ];
+    for event in &sent {
+        publisher.publish(*event).await.unwrap();
+    }
+
+    let mut received = Vec::new();
+    for _ in 0..sent.len() {
+        received.push(next_event(&mut events).await.unwrap());
+    }
+    assert_eq!(received, sent);
+}
+
+#[tokio::test]
+async fn test_subscribers_only_receive_their_events() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let _lock = FLOOD_LOCK.read().await;
+    let bus = PgNoteEventBus::start(db.clone()).await.unwrap();
+    let user_id = Uuid::new_v4();
+    let other_user_id = Uuid::new_v4();
+    let mut events = bus.subscribe(user_id).await.unwrap();
+    let mut other_events = bus.subscribe(other_user_id).await.unwrap();
+
+    let other_event = note_event(NoteEventKind::Created, other_user_id);
+    let event = note_event(NoteEventKind::Updated, user_id);
+    bus.publish(other_event).await.unwrap();
+    bus.publish(event).await.unwrap();
+
+    assert_eq!(next_event(&mut events).await, Some(event));
+    assert_eq!(next_event(&mut other_events).await, Some(other_event));
+}
+
+#[tokio::test]
+async fn test_lagging_stream_closed() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let _lock = FLOOD_LOCK.write().await;
+    let bus = PgNoteEventBus::start(db.clone()).await.unwrap();
+    let user_id = Uuid::new_v4();
+    let sentinel_user_id = Uuid::new_v4();
+    let mut events = bus.subscribe(user_id).await.unwrap();
+    let mut sentinel_events = bus.subscribe(sentinel_user_id).await.unwrap();
+
+    // More events than buffered, without consuming any
+    for _ in 0..1100 {
+        bus.publish(note_event(NoteEventKind::Updated, user_id))
+            .await
+            .unwrap();
+    }
+    let sentinel = note_event(NoteEventKind::Updated, sentinel_user_id);
+    bus.publish(sentinel).await.unwrap();
+
+    // Once the sentinel is through, or its stream fell behind as well, the other stream has lagged
+    while let Some(event) = next_event(&mut sentinel_events).await {
+        if event == sentinel {
+            break;
+        }
+    }
+    assert_eq!(next_event(&mut events).await, None);
+}
@@ -0,0 +1,125 @@
+mod support;
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+use app::usecases::note_import_job::{
+    CreateNoteImportJobInput, CreateNoteImportJobUseCase, ProcessNoteImportJobUseCase,
+};
+use common::{error::AppError, tx::ctx::TransactionContext};
+use dmn::{
+    entities::note_import_job::{NoteImportFormat, NoteImportJobStatus},
+    events::DomainEvent,
+    repos::{note_import_job::NoteImportJobRepository, outbox::OutboxRepository},
+};
+use infra::{
+    archive::note_import::DefaultNoteImportReader,
+    repos::{
+        note::PgNoteRepository, note_import_job::PgNoteImportJobRepository,
+        notebook::PgNotebookRepository, tag::PgTagRepository,
+    },
+    storage::blob_store::LocalBlobStore,
+    tx::PgUnitOfWork,
+};
+use support::{create_test_user, get_test_pool};
+
+/// Fails the import once all of its notes are created, recording the job progress reported then.
+struct FailingOutboxRepository {
+    db: Arc<PgPool>,
+    job_id: Mutex<Option<Uuid>>,
+    reported_counts: Mutex<Option<(i32, i32)>>,
+}
+
+#[async_trait]
+impl OutboxRepository for FailingOutboxRepository {
+    async fn append_events(
+        &self,
+        _ctx: Option<&mut dyn TransactionContext>,
+        _events: &[DomainEvent],
+    ) -> Result<(), AppError> {
+        let job_id = self.job_id.lock().unwrap().unwrap();
+        let counts = sqlx::query_as(
+            "SELECT processed_count, imported_count FROM note_import_jobs WHERE id = $1",
+        )
+        .bind(job_id)
+        .fetch_one(&*self.db)
+        .await
+        .unwrap();
+        *self.reported_counts.lock().unwrap() = Some(counts);
+        Err(AppError::internal("Test outbox failure!"))
+    }
+}
+
+#[tokio::test]
+async fn test_failed_import_reports_no_imported_note() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let auth_ctx = create_test_user(&db).await;
+    let unit_of_work = Arc::new(PgUnitOfWork::new(db.clone()));
+    let note_repo = Arc::new(PgNoteRepository::new(db.clone()));
+    let note_import_job_repo = Arc::new(PgNoteImportJobRepository::new(db.clone()));
+    let blob_store = Arc::new(LocalBlobStore::new(
+        std::env::temp_dir().join(format!("noteddd-test-{}", Uuid::new_v4())),
+    ));
+    let outbox_repo = Arc::new(FailingOutboxRepository {
+        db: db.clone(),
+        job_id: Mutex::new(None),
+        reported_counts: Mutex::new(None),
+    });
+    // Enough notes for their progress to be reported before the import fails
+    let notes: Vec<_> = (0..60)
+        .map(|index| serde_json::json!({ "title": format!("Note {index}"), "content": "Body" }))
+        .collect();
+    let input = CreateNoteImportJobInput {
+        format: NoteImportFormat::Json,
+        data: serde_json::to_vec(&notes).unwrap(),
+    };
+    let job = CreateNoteImportJobUseCase::new(
+        unit_of_work.clone(),
+        note_import_job_repo.clone(),
+        blob_store.clone(),
+    )
+    .execute(auth_ctx.clone(), input)
+    .await
+    .unwrap();
+    *outbox_repo.job_id.lock().unwrap() = Some(job.id());
+
+    let use_case = ProcessNoteImportJobUseCase::new(
+        unit_of_work,
+        note_repo.clone(),
+        Arc::new(PgNotebookRepository::new(db.clone())),
+        note_import_job_repo.clone(),
+        outbox_repo.clone(),
+        Arc::new(PgTagRepository::new(db.clone())),
+        blob_store,
+        Arc::new(DefaultNoteImportReader),
+    );
+    // Jobs left over by other tests may be claimed first
+    let job = loop {
+        assert!(use_case.execute().await.unwrap(), "Job wasn't processed");
+        let job = note_import_job_repo
+            .get_note_import_job(None, job.id(), None)
+            .await
+            .unwrap();
+        if job.status() != NoteImportJobStatus::Pending {
+            break job;
+        }
+    };
+
+    // Created notes were reported as processed only, as they weren't committed yet
+    assert_eq!(*outbox_repo.reported_counts.lock().unwrap(), Some((50, 0)));
+    assert_eq!(job.status(), NoteImportJobStatus::Failed);
+    assert!(job.failures().is_empty());
+    assert_eq!(job.processed_count(), 0);
+    assert_eq!(job.imported_count(), 0);
+    assert!(job.error().is_some());
+    let (note_count,): (i64,) = sqlx::query_as("SELECT count(*) FROM notes WHERE user_id = $1")
+        .bind(auth_ctx.user.id())
+        .fetch_one(&*db)
+        .await
+        .unwrap();
+    assert_eq!(note_count, 0);
+}
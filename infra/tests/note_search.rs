@@ -0,0 +1,148 @@
+mod support;
+
+use sqlx::PgPool;
+use std::sync::Arc;
+
+use app::{
+    auth::context::AuthAccessContext,
+    usecases::note::{SearchNotesInput, SearchNotesUseCase},
+};
+use common::{error::AppError, params::PaginationParams};
+use dmn::{
+    entities::note::{CreateNoteData, Note},
+    repos::note::{NoteRepository, SearchNotesResponse},
+};
+use infra::repos::note::PgNoteRepository;
+use support::{create_test_user, get_test_pool};
+
+async fn create_test_note(
+    db: &Arc<PgPool>,
+    auth_ctx: &AuthAccessContext,
+    title: &str,
+    content: &str,
+) -> Note {
+    let note_data = CreateNoteData {
+        user_id: auth_ctx.user.id(),
+        notebook_id: None,
+        title: title.into(),
+        content: content.into(),
+        created_at: None,
+        updated_at: None,
+    };
+    PgNoteRepository::new(db.clone())
+        .create_note(None, note_data)
+        .await
+        .unwrap()
+}
+
+async fn search(
+    db: &Arc<PgPool>,
+    auth_ctx: &AuthAccessContext,
+    query: &str,
+) -> Result<SearchNotesResponse, AppError> {
+    let input = SearchNotesInput {
+        query: query.into(),
+        pagination: PaginationParams { skip: 0, limit: 10 },
+    };
+    SearchNotesUseCase::new(Arc::new(PgNoteRepository::new(db.clone())))
+        .execute(auth_ctx.clone(), input)
+        .await
+}
+
+#[tokio::test]
+async fn test_search_rejects_blank_queries() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let auth_ctx = create_test_user(&db).await;
+
+    for query in ["", " ", "\t\n"] {
+        let result = search(&db, &auth_ctx, query).await;
+        assert!(
+            matches!(result, Err(AppError::BadRequest(_))),
+            "Accepted query: {query:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_search_ranks_title_matches_first() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let auth_ctx = create_test_user(&db).await;
+    let in_content = create_test_note(&db, &auth_ctx, "Groceries", "Buy some pineapples").await;
+    let in_title = create_test_note(&db, &auth_ctx, "Pineapple recipes", "Slice it").await;
+    create_test_note(&db, &auth_ctx, "Unrelated", "Nothing to see").await;
+
+    // Stemming matches the plural as well
+    let response = search(&db, &auth_ctx, "pineapple").await.unwrap();
+
+    let note_ids: Vec<_> = response
+        .results
+        .iter()
+        .map(|result| result.note.id())
+        .collect();
+    assert_eq!(note_ids, vec![in_title.id(), in_content.id()]);
+    assert_eq!(response.count, 2);
+    assert!(response.results[0].rank > response.results[1].rank);
+}
+
+#[tokio::test]
+async fn test_search_scoped_to_users_live_notes() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let auth_ctx = create_test_user(&db).await;
+    let other_user = create_test_user(&db).await;
+    let note = create_test_note(&db, &auth_ctx, "Kumquat", "Kept").await;
+    let trashed_note = create_test_note(&db, &auth_ctx, "Kumquat", "Trashed").await;
+    PgNoteRepository::new(db.clone())
+        .delete_note(None, trashed_note.id(), None, Some(auth_ctx.user.id()))
+        .await
+        .unwrap();
+    create_test_note(&db, &other_user, "Kumquat", "Someone else's").await;
+
+    let response = search(&db, &auth_ctx, "kumquat").await.unwrap();
+
+    let note_ids: Vec<_> = response
+        .results
+        .iter()
+        .map(|result| result.note.id())
+        .collect();
+    assert_eq!(note_ids, vec![note.id()]);
+    assert_eq!(response.count, 1);
+}
+
+#[tokio::test]
+async fn test_search_snippets_escape_note_markup() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let auth_ctx = create_test_user(&db).await;
+    create_test_note(
+        &db,
+        &auth_ctx,
+        "<b>Mango</b> & co",
+        r#"<script>alert("mango")</script> isn't <mark>safe</mark>"#,
+    )
+    .await;
+
+    let response = search(&db, &auth_ctx, "mango").await.unwrap();
+
+    assert_eq!(response.results.len(), 1);
+    let result = &response.results[0];
+    assert_eq!(
+        result.title_snippet,
+        "&lt;b&gt;<mark>Mango</mark>&lt;/b&gt; &amp; co"
+    );
+    // Content fragments are cut at word boundaries, yet the highlights stay the only markup
+    assert!(result
+        .content_snippet
+        .contains("alert(&quot;<mark>mango</mark>&quot;)&lt;/script&gt;"));
+    assert!(result.content_snippet.contains("&lt;mark&gt;safe&lt;/mark"));
+    let unhighlighted = result
+        .content_snippet
+        .replace("<mark>mango</mark>", "mango");
+    assert!(!unhighlighted.contains(['<', '>', '"', '\'']));
+}
@@ -0,0 +1,197 @@
+mod support;
+
+use sqlx::PgPool;
+use std::sync::Arc;
+
+use app::{
+    auth::context::AuthAccessContext,
+    usecases::note::{
+        CreateNoteInput, CreateNoteUseCase, DeleteNoteInput, DeleteNoteUseCase, PatchNoteInput,
+        PatchNoteUseCase, UpdateNoteInput, UpdateNoteUseCase,
+    },
+};
+use common::{error::AppError, params::PaginationParams};
+use dmn::{
+    entities::note::Note,
+    repos::{note::NoteRepository, note_revision::NoteRevisionRepository},
+};
+use infra::{
+    repos::{
+        note::PgNoteRepository, note_revision::PgNoteRevisionRepository,
+        note_share::PgNoteShareRepository, notebook::PgNotebookRepository,
+        outbox::PgOutboxRepository,
+    },
+    tx::PgUnitOfWork,
+};
+use support::{create_test_user, get_test_pool};
+
+async fn create_test_note(db: &Arc<PgPool>, auth_ctx: &AuthAccessContext) -> Note {
+    let use_case = CreateNoteUseCase::new(
+        Arc::new(PgUnitOfWork::new(db.clone())),
+        Arc::new(PgNoteRepository::new(db.clone())),
+        Arc::new(PgNotebookRepository::new(db.clone())),
+        Arc::new(PgOutboxRepository::new(db.clone())),
+    );
+    let input = CreateNoteInput {
+        notebook_id: None,
+        title: "Title".into(),
+        content: "Original".into(),
+    };
+    use_case.execute(auth_ctx.clone(), input).await.unwrap()
+}
+
+async fn get_revision_contents(db: &Arc<PgPool>, note: &Note) -> Vec<String> {
+    let pagination = PaginationParams { skip: 0, limit: 10 };
+    let mut contents: Vec<_> = PgNoteRevisionRepository::new(db.clone())
+        .get_note_revisions(None, note.id(), &pagination)
+        .await
+        .unwrap()
+        .revisions
+        .iter()
+        .map(|revision| revision.content().to_owned())
+        .collect();
+    contents.sort();
+    contents
+}
+
+#[tokio::test]
+async fn test_concurrent_updates_revise_successive_versions() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let auth_ctx = create_test_user(&db).await;
+    let note = create_test_note(&db, &auth_ctx).await;
+    let use_case = UpdateNoteUseCase::new(
+        Arc::new(PgUnitOfWork::new(db.clone())),
+        Arc::new(PgNoteRepository::new(db.clone())),
+        Arc::new(PgNoteShareRepository::new(db.clone())),
+        Arc::new(PgNoteRevisionRepository::new(db.clone())),
+        Arc::new(PgOutboxRepository::new(db.clone())),
+    );
+    let update = |content: &str| UpdateNoteInput {
+        note_id: note.id(),
+        title: "Title".into(),
+        content: content.into(),
+        expected_versions: Vec::new(),
+    };
+
+    let (first, second) = tokio::join!(
+        use_case.execute(auth_ctx.clone(), update("First")),
+        use_case.execute(auth_ctx.clone(), update("Second")),
+    );
+    let (first, second) = (first.unwrap(), second.unwrap());
+
+    // Whichever update ran last must have revised the other's content, not the original one
+    let (earlier, later) = if first.version() < second.version() {
+        (first, second)
+    } else {
+        (second, first)
+    };
+    assert_eq!(later.version(), earlier.version() + 1);
+    let mut expected = vec!["Original".to_owned(), earlier.content().to_owned()];
+    expected.sort();
+    assert_eq!(get_revision_contents(&db, &note).await, expected);
+}
+
+#[tokio::test]
+async fn test_concurrent_patches_revise_successive_versions() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let auth_ctx = create_test_user(&db).await;
+    let note = create_test_note(&db, &auth_ctx).await;
+    let use_case = PatchNoteUseCase::new(
+        Arc::new(PgUnitOfWork::new(db.clone())),
+        Arc::new(PgNoteRepository::new(db.clone())),
+        Arc::new(PgNoteShareRepository::new(db.clone())),
+        Arc::new(PgNoteRevisionRepository::new(db.clone())),
+        Arc::new(PgOutboxRepository::new(db.clone())),
+    );
+    let patch = |content: &str| PatchNoteInput {
+        note_id: note.id(),
+        title: None,
+        content: Some(content.into()),
+        expected_versions: Vec::new(),
+    };
+
+    let (first, second) = tokio::join!(
+        use_case.execute(auth_ctx.clone(), patch("First")),
+        use_case.execute(auth_ctx.clone(), patch("Second")),
+    );
+    let (first, second) = (first.unwrap(), second.unwrap());
+
+    let (earlier, later) = if first.version() < second.version() {
+        (first, second)
+    } else {
+        (second, first)
+    };
+    assert_eq!(later.version(), earlier.version() + 1);
+    let mut expected = vec!["Original".to_owned(), earlier.content().to_owned()];
+    expected.sort();
+    assert_eq!(get_revision_contents(&db, &note).await, expected);
+}
+
+#[tokio::test]
+async fn test_update_matches_any_expected_version() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let auth_ctx = create_test_user(&db).await;
+    let note = create_test_note(&db, &auth_ctx).await;
+    let use_case = UpdateNoteUseCase::new(
+        Arc::new(PgUnitOfWork::new(db.clone())),
+        Arc::new(PgNoteRepository::new(db.clone())),
+        Arc::new(PgNoteShareRepository::new(db.clone())),
+        Arc::new(PgNoteRevisionRepository::new(db.clone())),
+        Arc::new(PgOutboxRepository::new(db.clone())),
+    );
+    let update = |expected_versions: Vec<u32>| UpdateNoteInput {
+        note_id: note.id(),
+        title: "Title".into(),
+        content: "Updated".into(),
+        expected_versions,
+    };
+
+    let result = use_case.execute(auth_ctx.clone(), update(vec![2, 3])).await;
+    assert!(matches!(result, Err(AppError::PreconditionFailed(_))));
+
+    let updated = use_case
+        .execute(auth_ctx.clone(), update(vec![1, 2]))
+        .await
+        .unwrap();
+    assert_eq!(updated.version(), 2);
+}
+
+#[tokio::test]
+async fn test_delete_matches_any_expected_version() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let auth_ctx = create_test_user(&db).await;
+    let note = create_test_note(&db, &auth_ctx).await;
+    let note_repo = Arc::new(PgNoteRepository::new(db.clone()));
+    let use_case = DeleteNoteUseCase::new(
+        Arc::new(PgUnitOfWork::new(db.clone())),
+        note_repo.clone(),
+        Arc::new(PgNoteShareRepository::new(db.clone())),
+        Arc::new(PgOutboxRepository::new(db.clone())),
+    );
+    let delete = |expected_versions: Vec<u32>| DeleteNoteInput {
+        note_id: note.id(),
+        expected_versions,
+    };
+
+    for expected_versions in [vec![2], vec![2, 3]] {
+        let result = use_case
+            .execute(auth_ctx.clone(), delete(expected_versions))
+            .await;
+        assert!(matches!(result, Err(AppError::PreconditionFailed(_))));
+    }
+    assert!(note_repo.get_note(None, note.id(), None).await.is_ok());
+
+    use_case
+        .execute(auth_ctx.clone(), delete(vec![3, 1]))
+        .await
+        .unwrap();
+    assert!(note_repo.get_note(None, note.id(), None).await.is_err());
+}
@@ -0,0 +1,161 @@
+mod support;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+use app::events::domain_events::DomainEventHandler;
+use common::error::AppError;
+use dmn::{events::DomainEvent, repos::outbox::OutboxRepository};
+use infra::{
+    events::outbox_dispatcher::{PgOutboxDispatcher, OUTBOX_MAX_ATTEMPTS},
+    repos::outbox::PgOutboxRepository,
+};
+use support::{create_test_user, get_test_pool};
+
+/// Dispatchers claim every due event, so tests dispatching concurrently would handle each other's.
+static DISPATCH_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+/// Records the events of a single user, failing them if told to. Other events succeed.
+struct TestHandler {
+    name: &'static str,
+    user_id: Uuid,
+    fail: bool,
+    event_ids: Mutex<Vec<Uuid>>,
+}
+
+impl TestHandler {
+    fn new(name: &'static str, user_id: Uuid, fail: bool) -> Arc<Self> {
+        Arc::new(Self {
+            name,
+            user_id,
+            fail,
+            event_ids: Mutex::new(Vec::new()),
+        })
+    }
+
+    fn event_ids(&self) -> Vec<Uuid> {
+        self.event_ids.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl DomainEventHandler for TestHandler {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    async fn handle(&self, event_id: Uuid, event: &DomainEvent) -> Result<(), AppError> {
+        if event.user_id() != self.user_id {
+            return Ok(());
+        }
+        self.event_ids.lock().unwrap().push(event_id);
+        if self.fail {
+            return Err(AppError::internal("Test handler failure!"));
+        }
+        Ok(())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct OutboxRow {
+    attempts: i32,
+    handled_by: Vec<String>,
+    failed_at: Option<DateTime<Utc>>,
+}
+
+async fn get_outbox_row(db: &PgPool, event_id: Uuid) -> Option<OutboxRow> {
+    sqlx::query_as("SELECT attempts, handled_by, failed_at FROM outbox WHERE event_id = $1")
+        .bind(event_id)
+        .fetch_optional(db)
+        .await
+        .unwrap()
+}
+
+/// Dispatches until no event is due, then makes the rescheduled ones due again, `rounds` times.
+async fn dispatch_rounds(db: &PgPool, dispatcher: &PgOutboxDispatcher, rounds: usize) {
+    for _ in 0..rounds {
+        while dispatcher.dispatch_pending_events().await.unwrap() > 0 {}
+        sqlx::query("UPDATE outbox SET next_attempt_at = now() WHERE failed_at IS NULL")
+            .execute(db)
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_succeeded_handlers_not_rerun() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let _lock = DISPATCH_LOCK.lock().await;
+    let auth_ctx = create_test_user(&db).await;
+    let user_id = auth_ctx.user.id();
+    PgOutboxRepository::new(db.clone())
+        .append_events(None, &[DomainEvent::UserUpdated { user_id }])
+        .await
+        .unwrap();
+    let succeeding = TestHandler::new("succeeding", user_id, false);
+    let failing = TestHandler::new("failing", user_id, true);
+    let dispatcher = PgOutboxDispatcher::new(db.clone(), vec![succeeding.clone(), failing.clone()]);
+
+    dispatch_rounds(&db, &dispatcher, 3).await;
+
+    let event_ids = failing.event_ids();
+    assert_eq!(event_ids.len(), 3);
+    assert!(event_ids.iter().all(|event_id| *event_id == event_ids[0]));
+    assert_eq!(succeeding.event_ids(), vec![event_ids[0]]);
+    let row = get_outbox_row(&db, event_ids[0]).await.unwrap();
+    assert_eq!(row.attempts, 3);
+    assert_eq!(row.handled_by, vec!["succeeding"]);
+    assert!(row.failed_at.is_none());
+}
+
+#[tokio::test]
+async fn test_event_failed_after_max_attempts() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let _lock = DISPATCH_LOCK.lock().await;
+    let auth_ctx = create_test_user(&db).await;
+    let user_id = auth_ctx.user.id();
+    PgOutboxRepository::new(db.clone())
+        .append_events(None, &[DomainEvent::UserUpdated { user_id }])
+        .await
+        .unwrap();
+    let failing = TestHandler::new("failing", user_id, true);
+    let dispatcher = PgOutboxDispatcher::new(db.clone(), vec![failing.clone()]);
+
+    // Rounds past the last attempt leave the failed event alone
+    dispatch_rounds(&db, &dispatcher, OUTBOX_MAX_ATTEMPTS as usize + 2).await;
+
+    let event_ids = failing.event_ids();
+    assert_eq!(event_ids.len(), OUTBOX_MAX_ATTEMPTS as usize);
+    let row = get_outbox_row(&db, event_ids[0]).await.unwrap();
+    assert_eq!(row.attempts, OUTBOX_MAX_ATTEMPTS);
+    assert!(row.failed_at.is_some());
+}
+
+#[tokio::test]
+async fn test_handled_event_removed() {
+    let Some(db) = get_test_pool().await else {
+        return;
+    };
+    let _lock = DISPATCH_LOCK.lock().await;
+    let auth_ctx = create_test_user(&db).await;
+    let user_id = auth_ctx.user.id();
+    PgOutboxRepository::new(db.clone())
+        .append_events(None, &[DomainEvent::UserUpdated { user_id }])
+        .await
+        .unwrap();
+    let succeeding = TestHandler::new("succeeding", user_id, false);
+    let dispatcher = PgOutboxDispatcher::new(db.clone(), vec![succeeding.clone()]);
+
+    dispatch_rounds(&db, &dispatcher, 2).await;
+
+    let event_ids = succeeding.event_ids();
+    assert_eq!(event_ids.len(), 1);
+    assert!(get_outbox_row(&db, event_ids[0]).await.is_none());
+}
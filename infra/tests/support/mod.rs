@@ -0,0 +1,63 @@
+//! Helpers for the tests running against a Postgres database.
+//!
+//! These tests need `DATABASE_URL` pointing to a migrated database and are skipped otherwise.
+
+#![allow(dead_code)]
+
+use chrono::{Duration, Utc};
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use app::{auth::context::AuthAccessContext, types::auth_token::AccessToken};
+use dmn::{entities::user::CreateUserData, repos::user::UserRepository};
+use infra::{
+    auth::{authenticator::JwtAuthenticator, token_adapter::JwtTokenAdapter},
+    get_pg_pool,
+    repos::{auth_token::PgAuthTokenRepository, user::PgUserRepository},
+    tx::PgUnitOfWork,
+};
+
+/// Connects to the test database, `None` when `DATABASE_URL` isn't set.
+pub async fn get_test_pool() -> Option<Arc<PgPool>> {
+    let Ok(db_url) = std::env::var("DATABASE_URL") else {
+        eprintln!("DATABASE_URL isn't set, skipping test");
+        return None;
+    };
+    let pool = get_pg_pool(&db_url)
+        .await
+        .expect("Failed to connect to the test database");
+    Some(Arc::new(pool))
+}
+
+/// Registers a user with a unique email and returns its access context.
+pub async fn create_test_user(db: &Arc<PgPool>) -> AuthAccessContext {
+    let user_repo = PgUserRepository::new(db.clone());
+    let user_data = CreateUserData {
+        email: format!("test-{}@example.com", Uuid::new_v4())
+            .try_into()
+            .unwrap(),
+        password_hash: "not-a-hash".into(),
+    };
+    let user = user_repo.create_user(None, user_data).await.unwrap();
+    let access_token = AccessToken {
+        id: Uuid::new_v4(),
+        user_id: user.id(),
+        session_id: Uuid::new_v4(),
+        jwt: String::new(),
+        expires_at: Utc::now() + Duration::minutes(5),
+    };
+    AuthAccessContext { user, access_token }
+}
+
+/// Issues tokens signed with a test secret, lasting 5 minutes and a day.
+pub fn create_test_authenticator(db: &Arc<PgPool>) -> JwtAuthenticator {
+    JwtAuthenticator::new(
+        300,
+        86_400,
+        Arc::new(JwtTokenAdapter::new("test-secret".into())),
+        Arc::new(PgUnitOfWork::new(db.clone())),
+        Arc::new(PgAuthTokenRepository::new(db.clone())),
+        Arc::new(PgUserRepository::new(db.clone())),
+    )
+}
@@ -1,16 +1,37 @@
 use dotenv::dotenv;
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
-use app::state::AppState;
+use app::{
+    events::{note_events::NoteEventRelay, webhook_events::WebhookEventRelay},
+    state::AppState,
+    usecases::{note::PurgeExpiredNotesInput, webhook::DeliverWebhooksInput},
+};
 use common::error::AppError;
 use infra::{
+    archive::{note_archive::ZipNoteArchiveCodec, note_import::DefaultNoteImportReader},
     auth::{
-        authenticator::JwtAuthenticator, pass_hasher::BcryptPasswordHasher,
-        pass_service::DefaultPasswordService, token_adapter::JwtTokenAdapter,
+        authenticator::JwtAuthenticator,
+        pass_hasher::{Argon2PasswordHasher, BcryptPasswordHasher, MultiAlgorithmPasswordHasher},
+        pass_policy::PasswordPolicy,
+        pass_service::DefaultPasswordService,
+        token_adapter::JwtTokenAdapter,
+    },
+    events::{
+        note_events::PgNoteEventBus,
+        outbox_dispatcher::{PgOutboxDispatcher, OUTBOX_BATCH_SIZE},
     },
     get_pg_pool,
-    repos::{auth_token::PgAuthTokenRepository, note::PgNoteRepository, user::PgUserRepository},
+    repos::{
+        attachment::PgAttachmentRepository, auth_token::PgAuthTokenRepository,
+        note::PgNoteRepository, note_import_job::PgNoteImportJobRepository,
+        note_public_link::PgNotePublicLinkRepository, note_revision::PgNoteRevisionRepository,
+        note_share::PgNoteShareRepository, notebook::PgNotebookRepository,
+        outbox::PgOutboxRepository, tag::PgTagRepository, user::PgUserRepository,
+        webhook::PgWebhookRepository, webhook_delivery::PgWebhookDeliveryRepository,
+    },
+    storage::blob_store::LocalBlobStore,
     tx::PgUnitOfWork,
+    webhooks::webhook_sender::HttpWebhookSender,
 };
 use pres::utils::BuildHttpServerResponse;
 
@@ -20,43 +41,261 @@ pub(crate) fn setup_env() {
 
     // Assert Envs
     let _ = crate::utils::get_api_base_url();
-    let _ = crate::utils::get_auth_jwt_secret();
+    // Tokens are signed by either asymmetric key pairs or a shared secret
+    if crate::utils::get_auth_jwt_keys().is_empty() {
+        let _ = crate::utils::get_auth_jwt_secret();
+    }
+    let _ = crate::utils::get_note_trash_retention_days();
 }
 
-pub(crate) async fn build_app_state() -> Result<AppState, AppError> {
+const WEBHOOK_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub(crate) struct BuildAppStateResponse {
+    pub(crate) app_state: AppState,
+    pub(crate) outbox_dispatcher: PgOutboxDispatcher,
+}
+
+pub(crate) async fn build_app_state() -> Result<BuildAppStateResponse, AppError> {
     // Postgres
     let db_url = crate::utils::get_database_url();
     let pg_pool = get_pg_pool(&db_url).await?;
     let db = Arc::new(pg_pool);
 
+    // Blob Storage
+    let blob_storage_dir = crate::utils::get_blob_storage_dir();
+    let blob_store = Arc::new(LocalBlobStore::new(blob_storage_dir));
+
+    // Note Archives
+    let note_archive_codec = Arc::new(ZipNoteArchiveCodec);
+    let note_import_reader = Arc::new(DefaultNoteImportReader);
+
+    // Note Events
+    let note_event_bus = Arc::new(PgNoteEventBus::start(db.clone()).await?);
+
     // Repositories
     let unit_of_work = Arc::new(PgUnitOfWork::new(db.clone()));
+    let attachment_repo = Arc::new(PgAttachmentRepository::new(db.clone()));
     let note_repo = Arc::new(PgNoteRepository::new(db.clone()));
+    let note_import_job_repo = Arc::new(PgNoteImportJobRepository::new(db.clone()));
+    let note_public_link_repo = Arc::new(PgNotePublicLinkRepository::new(db.clone()));
+    let note_revision_repo = Arc::new(PgNoteRevisionRepository::new(db.clone()));
+    let note_share_repo = Arc::new(PgNoteShareRepository::new(db.clone()));
+    let notebook_repo = Arc::new(PgNotebookRepository::new(db.clone()));
+    let outbox_repo = Arc::new(PgOutboxRepository::new(db.clone()));
+    let tag_repo = Arc::new(PgTagRepository::new(db.clone()));
     let user_repo = Arc::new(PgUserRepository::new(db.clone()));
+    let webhook_repo = Arc::new(PgWebhookRepository::new(db.clone()));
+    let webhook_delivery_repo = Arc::new(PgWebhookDeliveryRepository::new(db.clone()));
     let auth_token_repo = Arc::new(PgAuthTokenRepository::new(db.clone()));
 
+    // Webhooks
+    let webhook_sender = Arc::new(HttpWebhookSender::new(
+        WEBHOOK_REQUEST_TIMEOUT,
+        crate::utils::get_webhook_allow_local_targets(),
+    )?);
+
+    // Domain Events
+    let outbox_dispatcher = PgOutboxDispatcher::new(
+        db.clone(),
+        vec![
+            Arc::new(NoteEventRelay::new(note_event_bus.clone())),
+            Arc::new(WebhookEventRelay::new(
+                unit_of_work.clone(),
+                webhook_repo.clone(),
+                webhook_delivery_repo.clone(),
+            )),
+        ],
+    );
+
     // Authentication
-    let auth_jwt_secret = crate::utils::get_auth_jwt_secret();
+    let auth_jwt_keys = crate::utils::get_auth_jwt_keys();
     let auth_access_token_duration_secs = crate::utils::get_auth_access_token_duration_secs();
     let auth_refresh_token_duration_secs = crate::utils::get_auth_access_token_duration_secs();
-    let token_adapter = Arc::new(JwtTokenAdapter::new(auth_jwt_secret));
+    let token_adapter = Arc::new(match auth_jwt_keys.is_empty() {
+        true => JwtTokenAdapter::new(crate::utils::get_auth_jwt_secret()),
+        false => JwtTokenAdapter::with_key_pairs(auth_jwt_keys)?,
+    });
     let authenticator = Arc::new(JwtAuthenticator::new(
         auth_access_token_duration_secs,
         auth_refresh_token_duration_secs,
-        token_adapter,
-        unit_of_work,
-        auth_token_repo,
+        token_adapter.clone(),
+        unit_of_work.clone(),
+        auth_token_repo.clone(),
         user_repo.clone(),
     ));
-    let pass_hasher = Arc::new(BcryptPasswordHasher {});
+    // Argon2id hashes, bcrypt ones being upgraded on login
+    let argon2_pass_hasher = Arc::new(Argon2PasswordHasher::new(
+        crate::utils::get_auth_argon2_memory_kib(),
+        crate::utils::get_auth_argon2_iterations(),
+        crate::utils::get_auth_argon2_parallelism(),
+    )?);
+    let pass_hasher = Arc::new(MultiAlgorithmPasswordHasher::new(
+        argon2_pass_hasher,
+        vec![Arc::new(BcryptPasswordHasher {})],
+    ));
+    let pass_policy = PasswordPolicy::new(
+        crate::utils::get_auth_password_min_length(),
+        crate::utils::get_auth_password_max_length(),
+        crate::utils::get_auth_password_min_char_classes(),
+        crate::utils::get_auth_password_min_strength_score(),
+    )?
+    .with_breached_passwords(crate::utils::get_auth_password_breached_list());
     let pass_service = Arc::new(DefaultPasswordService::new(
         user_repo.clone(),
         pass_hasher.clone(),
+        pass_policy,
     ));
 
     // App State
-    let app_state = AppState::new(authenticator, pass_service, note_repo, user_repo);
-    Ok(app_state)
+    let app_state = AppState::new(
+        authenticator,
+        token_adapter,
+        pass_service,
+        unit_of_work,
+        blob_store,
+        note_archive_codec,
+        note_import_reader,
+        note_event_bus,
+        webhook_sender,
+        attachment_repo,
+        auth_token_repo,
+        note_repo,
+        note_import_job_repo,
+        note_public_link_repo,
+        note_revision_repo,
+        note_share_repo,
+        notebook_repo,
+        outbox_repo,
+        tag_repo,
+        user_repo,
+        webhook_repo,
+        webhook_delivery_repo,
+    );
+    Ok(BuildAppStateResponse {
+        app_state,
+        outbox_dispatcher,
+    })
+}
+
+pub(crate) fn spawn_note_trash_purge_task(app_state: &AppState) {
+    const PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60); // 1 hour
+    let retention_days = crate::utils::get_note_trash_retention_days();
+    let purge_expired_notes_use_case = app_state.purge_expired_notes_use_case.clone();
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(PURGE_INTERVAL);
+        loop {
+            interval.tick().await;
+            let input = PurgeExpiredNotesInput { retention_days };
+            if let Err(err) = purge_expired_notes_use_case.execute(input).await {
+                err.log();
+            }
+        }
+    });
+}
+
+pub(crate) fn spawn_attachment_blob_purge_task(app_state: &AppState) {
+    const PURGE_INTERVAL: Duration = Duration::from_secs(10 * 60); // 10 minutes
+    let purge_orphaned_attachment_blobs_use_case =
+        app_state.purge_orphaned_attachment_blobs_use_case.clone();
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(PURGE_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(err) = purge_orphaned_attachment_blobs_use_case.execute().await {
+                err.log();
+            }
+        }
+    });
+}
+
+pub(crate) fn spawn_revoked_refresh_token_purge_task(app_state: &AppState) {
+    const PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60); // 1 hour
+    let purge_revoked_refresh_tokens_use_case =
+        app_state.purge_revoked_refresh_tokens_use_case.clone();
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(PURGE_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(err) = purge_revoked_refresh_tokens_use_case.execute().await {
+                err.log();
+            }
+        }
+    });
+}
+
+pub(crate) fn spawn_outbox_dispatch_task(outbox_dispatcher: PgOutboxDispatcher) {
+    const DISPATCH_INTERVAL: Duration = Duration::from_secs(1);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(DISPATCH_INTERVAL);
+        loop {
+            interval.tick().await;
+            // Keeps going while full batches come back, as more events are likely due
+            loop {
+                match outbox_dispatcher.dispatch_pending_events().await {
+                    Ok(count) if count == OUTBOX_BATCH_SIZE => continue,
+                    Ok(_) => break,
+                    Err(err) => {
+                        err.log();
+                        break;
+                    }
+                }
+            }
+        }
+    });
+}
+
+pub(crate) fn spawn_webhook_delivery_task(app_state: &AppState) {
+    const DELIVERY_INTERVAL: Duration = Duration::from_secs(5);
+    const DELIVERY_BATCH_SIZE: u32 = 20;
+    let deliver_webhooks_use_case = app_state.deliver_webhooks_use_case.clone();
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(DELIVERY_INTERVAL);
+        loop {
+            interval.tick().await;
+            // Keeps going while full batches come back, as more deliveries are likely due
+            loop {
+                let input = DeliverWebhooksInput {
+                    limit: DELIVERY_BATCH_SIZE,
+                };
+                match deliver_webhooks_use_case.execute(input).await {
+                    Ok(count) if count == DELIVERY_BATCH_SIZE => continue,
+                    Ok(_) => break,
+                    Err(err) => {
+                        err.log();
+                        break;
+                    }
+                }
+            }
+        }
+    });
+}
+
+pub(crate) fn spawn_note_import_task(app_state: &AppState) {
+    const IMPORT_INTERVAL: Duration = Duration::from_secs(5);
+    let process_note_import_job_use_case = app_state.process_note_import_job_use_case.clone();
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(IMPORT_INTERVAL);
+        loop {
+            interval.tick().await;
+            // Keeps going while jobs come back, as more are likely pending
+            loop {
+                match process_note_import_job_use_case.execute().await {
+                    Ok(true) => continue,
+                    Ok(false) => break,
+                    Err(err) => {
+                        err.log();
+                        break;
+                    }
+                }
+            }
+        }
+    });
 }
 
 pub(crate) async fn build_http_server(
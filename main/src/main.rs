@@ -1,13 +1,23 @@
 mod bootstrap;
 mod utils;
 
+use bootstrap::BuildAppStateResponse;
 use pres::utils::BuildHttpServerResponse;
 
 #[tokio::main]
 async fn main() {
     bootstrap::setup_env();
 
-    let app_state = bootstrap::build_app_state().await.unwrap();
+    let BuildAppStateResponse {
+        app_state,
+        outbox_dispatcher,
+    } = bootstrap::build_app_state().await.unwrap();
+    bootstrap::spawn_note_trash_purge_task(&app_state);
+    bootstrap::spawn_attachment_blob_purge_task(&app_state);
+    bootstrap::spawn_revoked_refresh_token_purge_task(&app_state);
+    bootstrap::spawn_outbox_dispatch_task(outbox_dispatcher);
+    bootstrap::spawn_webhook_delivery_task(&app_state);
+    bootstrap::spawn_note_import_task(&app_state);
 
     let BuildHttpServerResponse {
         server,
@@ -1,4 +1,10 @@
-use std::env;
+use chrono::{DateTime, Utc};
+use std::{collections::HashSet, env};
+
+use app::types::auth_token::JsonWebKeyAlgorithm;
+use infra::auth::{
+    jwt_key::JwtKeyPair, pass_hasher::Argon2PasswordHasher, pass_policy::PasswordPolicy,
+};
 
 pub(crate) fn get_database_url() -> String {
     const ERROR_MSG: &str = "DATABASE_URL should be defined!";
@@ -32,6 +38,42 @@ pub(crate) fn get_auth_jwt_secret() -> String {
     }
 }
 
+/// Comma-separated `<kid>;<RS256|ES256|EdDSA>;<private key PEM path>[;<RFC 3339 activation time>]` entries.
+pub(crate) fn get_auth_jwt_keys() -> Vec<JwtKeyPair> {
+    const ERROR_MSG: &str = "Invalid JWT signing keys specified! (AUTH_JWT_KEYS)";
+    let keys = match env::var("AUTH_JWT_KEYS") {
+        Ok(keys) if !keys.trim().is_empty() => keys,
+        _ => return Vec::new(),
+    };
+    keys.split(',')
+        .map(|key| {
+            let fields = key.trim().split(';').map(str::trim).collect::<Vec<_>>();
+            let (kid, algorithm, pem_path, active_from) = match fields[..] {
+                [kid, algorithm, pem_path] => (kid, algorithm, pem_path, None),
+                [kid, algorithm, pem_path, active_from] => {
+                    (kid, algorithm, pem_path, Some(active_from))
+                }
+                _ => panic!("{ERROR_MSG}"),
+            };
+            let algorithm = match algorithm {
+                "RS256" => JsonWebKeyAlgorithm::Rs256,
+                "ES256" => JsonWebKeyAlgorithm::Es256,
+                "EdDSA" => JsonWebKeyAlgorithm::EdDsa,
+                _ => panic!("{ERROR_MSG} Unsupported algorithm ({algorithm})"),
+            };
+            let active_from = active_from.map(|active_from| {
+                DateTime::parse_from_rfc3339(active_from)
+                    .expect(ERROR_MSG)
+                    .with_timezone(&Utc)
+            });
+            let pem = std::fs::read(pem_path)
+                .unwrap_or_else(|err| panic!("{ERROR_MSG} Failed to read {pem_path}: {err}"));
+            JwtKeyPair::from_pem(kid.to_string(), algorithm, &pem, active_from)
+                .unwrap_or_else(|err| panic!("{ERROR_MSG} {err}"))
+        })
+        .collect()
+}
+
 pub(crate) fn get_auth_access_token_duration_secs() -> u32 {
     const DEFAULT_AUTH_ACCESS_TOKEN_DURATION_SECS: u32 = 5 * 60; // 5 minutes
     const ERROR_MSG: &str =
@@ -51,3 +93,100 @@ pub(crate) fn get_auth_refresh_token_duration_secs() -> u32 {
         _ => DEFAULT_AUTH_REFRESH_TOKEN_DURATION_SECS,
     }
 }
+
+pub(crate) fn get_auth_argon2_memory_kib() -> u32 {
+    const ERROR_MSG: &str = "Invalid Argon2 memory cost specified! (AUTH_ARGON2_MEMORY_KIB)";
+    match env::var("AUTH_ARGON2_MEMORY_KIB") {
+        Ok(memory) if !memory.is_empty() => memory.parse::<u32>().expect(ERROR_MSG),
+        _ => Argon2PasswordHasher::DEFAULT_MEMORY_KIB,
+    }
+}
+
+pub(crate) fn get_auth_argon2_iterations() -> u32 {
+    const ERROR_MSG: &str = "Invalid Argon2 iteration count specified! (AUTH_ARGON2_ITERATIONS)";
+    match env::var("AUTH_ARGON2_ITERATIONS") {
+        Ok(iterations) if !iterations.is_empty() => iterations.parse::<u32>().expect(ERROR_MSG),
+        _ => Argon2PasswordHasher::DEFAULT_ITERATIONS,
+    }
+}
+
+pub(crate) fn get_auth_argon2_parallelism() -> u32 {
+    const ERROR_MSG: &str = "Invalid Argon2 parallelism specified! (AUTH_ARGON2_PARALLELISM)";
+    match env::var("AUTH_ARGON2_PARALLELISM") {
+        Ok(parallelism) if !parallelism.is_empty() => parallelism.parse::<u32>().expect(ERROR_MSG),
+        _ => Argon2PasswordHasher::DEFAULT_PARALLELISM,
+    }
+}
+
+pub(crate) fn get_auth_password_min_length() -> usize {
+    const ERROR_MSG: &str = "Invalid minimum password length specified! (AUTH_PASSWORD_MIN_LENGTH)";
+    match env::var("AUTH_PASSWORD_MIN_LENGTH") {
+        Ok(length) if !length.is_empty() => length.parse::<usize>().expect(ERROR_MSG),
+        _ => PasswordPolicy::DEFAULT_MIN_LENGTH,
+    }
+}
+
+pub(crate) fn get_auth_password_max_length() -> usize {
+    const ERROR_MSG: &str = "Invalid maximum password length specified! (AUTH_PASSWORD_MAX_LENGTH)";
+    match env::var("AUTH_PASSWORD_MAX_LENGTH") {
+        Ok(length) if !length.is_empty() => length.parse::<usize>().expect(ERROR_MSG),
+        _ => PasswordPolicy::DEFAULT_MAX_LENGTH,
+    }
+}
+
+pub(crate) fn get_auth_password_min_char_classes() -> usize {
+    const ERROR_MSG: &str =
+        "Invalid password character class count specified! (AUTH_PASSWORD_MIN_CHAR_CLASSES)";
+    match env::var("AUTH_PASSWORD_MIN_CHAR_CLASSES") {
+        Ok(classes) if !classes.is_empty() => classes.parse::<usize>().expect(ERROR_MSG),
+        _ => PasswordPolicy::DEFAULT_MIN_CHAR_CLASSES,
+    }
+}
+
+pub(crate) fn get_auth_password_min_strength_score() -> u8 {
+    const ERROR_MSG: &str =
+        "Invalid password strength score specified! (AUTH_PASSWORD_MIN_STRENGTH_SCORE)";
+    match env::var("AUTH_PASSWORD_MIN_STRENGTH_SCORE") {
+        Ok(score) if !score.is_empty() => score.parse::<u8>().expect(ERROR_MSG),
+        _ => PasswordPolicy::DEFAULT_MIN_STRENGTH_SCORE,
+    }
+}
+
+pub(crate) fn get_auth_password_breached_list() -> HashSet<String> {
+    const ERROR_MSG: &str =
+        "Invalid breached password list specified! (AUTH_PASSWORD_BREACHED_LIST_PATH)";
+    match env::var("AUTH_PASSWORD_BREACHED_LIST_PATH") {
+        Ok(path) if !path.is_empty() => {
+            let list = std::fs::read_to_string(&path)
+                .unwrap_or_else(|err| panic!("{ERROR_MSG} Failed to read {path}: {err}"));
+            PasswordPolicy::parse_breached_passwords(&list)
+        }
+        _ => HashSet::new(),
+    }
+}
+
+pub(crate) fn get_note_trash_retention_days() -> u32 {
+    const DEFAULT_NOTE_TRASH_RETENTION_DAYS: u32 = 30;
+    const ERROR_MSG: &str = "Invalid note trash retention specified! (NOTE_TRASH_RETENTION_DAYS)";
+    match env::var("NOTE_TRASH_RETENTION_DAYS") {
+        Ok(days) if !days.is_empty() => days.parse::<u32>().expect(ERROR_MSG),
+        _ => DEFAULT_NOTE_TRASH_RETENTION_DAYS,
+    }
+}
+
+pub(crate) fn get_blob_storage_dir() -> String {
+    const DEFAULT_BLOB_STORAGE_DIR: &str = "./data/blobs";
+    match env::var("BLOB_STORAGE_DIR") {
+        Ok(dir) if !dir.is_empty() => dir,
+        _ => DEFAULT_BLOB_STORAGE_DIR.to_string(),
+    }
+}
+
+pub(crate) fn get_webhook_allow_local_targets() -> bool {
+    const ERROR_MSG: &str =
+        "Invalid webhook local target setting specified! (WEBHOOK_ALLOW_LOCAL_TARGETS)";
+    match env::var("WEBHOOK_ALLOW_LOCAL_TARGETS") {
+        Ok(allow) if !allow.is_empty() => allow.parse::<bool>().expect(ERROR_MSG),
+        _ => false,
+    }
+}
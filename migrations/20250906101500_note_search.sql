@@ -0,0 +1,12 @@
+-- Full-Text Search
+
+ALTER TABLE notes
+ADD COLUMN search_vector tsvector
+GENERATED ALWAYS AS (
+    setweight(to_tsvector('english', title), 'A') ||
+    setweight(to_tsvector('english', content), 'B')
+) STORED;
+
+CREATE INDEX notes_search_vector_idx
+ON notes
+USING GIN (search_vector);
@@ -0,0 +1,31 @@
+-- Tables
+
+CREATE TABLE tags (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id uuid REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    name text NOT NULL,
+    created_at timestamptz DEFAULT now() NOT NULL,
+    updated_at timestamptz DEFAULT now() NOT NULL
+);
+
+CREATE TABLE note_tags (
+    note_id uuid REFERENCES notes(id) ON DELETE CASCADE NOT NULL,
+    tag_id uuid REFERENCES tags(id) ON DELETE CASCADE NOT NULL,
+    created_at timestamptz DEFAULT now() NOT NULL,
+    PRIMARY KEY (note_id, tag_id)
+);
+
+-- Indexes
+
+CREATE UNIQUE INDEX tags_user_id_name_key
+ON tags (user_id, lower(name));
+
+CREATE INDEX note_tags_tag_id_idx
+ON note_tags (tag_id);
+
+-- UpdatedAt Triggers
+
+CREATE TRIGGER tags_updated_at
+BEFORE UPDATE ON tags
+FOR EACH ROW
+EXECUTE FUNCTION update_updated_at_column();
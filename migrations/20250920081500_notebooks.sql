@@ -0,0 +1,33 @@
+-- Tables
+
+CREATE TABLE notebooks (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id uuid REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    parent_id uuid REFERENCES notebooks(id) ON DELETE CASCADE,
+    name text NOT NULL,
+    created_at timestamptz DEFAULT now() NOT NULL,
+    updated_at timestamptz DEFAULT now() NOT NULL,
+    CONSTRAINT notebooks_parent_id_check CHECK (parent_id <> id)
+);
+
+-- Deleting a notebook moves its notes back to the root level
+ALTER TABLE notes
+ADD COLUMN notebook_id uuid REFERENCES notebooks(id) ON DELETE SET NULL;
+
+-- Indexes
+
+CREATE INDEX notebooks_user_id_idx
+ON notebooks (user_id);
+
+CREATE INDEX notebooks_parent_id_idx
+ON notebooks (parent_id);
+
+CREATE INDEX notes_notebook_id_idx
+ON notes (notebook_id);
+
+-- UpdatedAt Triggers
+
+CREATE TRIGGER notebooks_updated_at
+BEFORE UPDATE ON notebooks
+FOR EACH ROW
+EXECUTE FUNCTION update_updated_at_column();
@@ -0,0 +1,11 @@
+-- Tables
+
+CREATE TABLE note_revisions (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    note_id uuid REFERENCES notes(id) ON DELETE CASCADE NOT NULL,
+    revision integer NOT NULL,
+    title text NOT NULL,
+    content text NOT NULL,
+    created_at timestamptz DEFAULT now() NOT NULL,
+    CONSTRAINT note_revisions_note_id_revision_key UNIQUE (note_id, revision)
+);
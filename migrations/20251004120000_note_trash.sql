@@ -0,0 +1,9 @@
+-- Trashed notes keep their data until purged
+ALTER TABLE notes
+ADD COLUMN deleted_at timestamptz;
+
+-- Indexes
+
+CREATE INDEX notes_deleted_at_idx
+ON notes (deleted_at)
+WHERE deleted_at IS NOT NULL;
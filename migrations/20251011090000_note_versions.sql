@@ -0,0 +1,20 @@
+-- Utility Function
+
+CREATE OR REPLACE FUNCTION increment_version_column()
+RETURNS TRIGGER AS $$
+BEGIN
+   NEW.version = OLD.version + 1;
+RETURN NEW;
+END;
+$$ LANGUAGE 'plpgsql';
+
+-- Optimistic concurrency control, bumped on every note update
+ALTER TABLE notes
+ADD COLUMN version integer DEFAULT 1 NOT NULL;
+
+-- Version Triggers
+
+CREATE TRIGGER notes_version
+BEFORE UPDATE ON notes
+FOR EACH ROW
+EXECUTE FUNCTION increment_version_column();
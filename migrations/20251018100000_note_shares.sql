@@ -0,0 +1,22 @@
+-- Tables
+
+CREATE TABLE note_shares (
+    note_id uuid REFERENCES notes(id) ON DELETE CASCADE NOT NULL,
+    user_id uuid REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    role text NOT NULL CHECK (role IN ('read', 'write')),
+    created_at timestamptz DEFAULT now() NOT NULL,
+    updated_at timestamptz DEFAULT now() NOT NULL,
+    PRIMARY KEY (note_id, user_id)
+);
+
+-- Indexes
+
+CREATE INDEX note_shares_user_id_idx
+ON note_shares (user_id);
+
+-- UpdatedAt Triggers
+
+CREATE TRIGGER note_shares_updated_at
+BEFORE UPDATE ON note_shares
+FOR EACH ROW
+EXECUTE FUNCTION update_updated_at_column();
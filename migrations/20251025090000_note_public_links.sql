@@ -0,0 +1,9 @@
+-- Tables
+
+-- Tokens are 64 hex chars drawn from two v4 uuids (244 random bits from a CSPRNG)
+CREATE TABLE note_public_links (
+    note_id uuid PRIMARY KEY REFERENCES notes(id) ON DELETE CASCADE,
+    token text UNIQUE NOT NULL DEFAULT replace(gen_random_uuid()::text || gen_random_uuid()::text, '-', ''),
+    expires_at timestamptz,
+    created_at timestamptz DEFAULT now() NOT NULL
+);
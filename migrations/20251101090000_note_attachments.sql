@@ -0,0 +1,39 @@
+-- Tables
+
+-- File contents live in the blob store, keyed by attachment id
+CREATE TABLE attachments (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    note_id uuid REFERENCES notes(id) ON DELETE CASCADE NOT NULL,
+    file_name text NOT NULL,
+    mime_type text NOT NULL,
+    size bigint NOT NULL CHECK (size >= 0),
+    sha256 text NOT NULL,
+    created_at timestamptz DEFAULT now() NOT NULL
+);
+
+-- Blobs of deleted attachments, waiting to be removed from the blob store
+CREATE TABLE orphaned_attachment_blobs (
+    attachment_id uuid PRIMARY KEY,
+    orphaned_at timestamptz DEFAULT now() NOT NULL
+);
+
+-- Indexes
+
+CREATE INDEX attachments_note_id_idx
+ON attachments (note_id);
+
+-- Orphaned Blob Triggers
+
+-- Also fires for attachments cascading from purged notes and deleted users
+CREATE OR REPLACE FUNCTION queue_orphaned_attachment_blob()
+RETURNS TRIGGER AS $$
+BEGIN
+   INSERT INTO orphaned_attachment_blobs (attachment_id) VALUES (OLD.id);
+RETURN OLD;
+END;
+$$ LANGUAGE 'plpgsql';
+
+CREATE TRIGGER attachments_orphaned_blob
+AFTER DELETE ON attachments
+FOR EACH ROW
+EXECUTE FUNCTION queue_orphaned_attachment_blob();
@@ -0,0 +1,5 @@
+-- Organizational note state, independent of the note contents
+ALTER TABLE notes
+ADD COLUMN pinned boolean DEFAULT false NOT NULL,
+ADD COLUMN archived boolean DEFAULT false NOT NULL,
+ADD COLUMN favorite boolean DEFAULT false NOT NULL;
@@ -0,0 +1,58 @@
+-- Utility Function
+
+CREATE OR REPLACE FUNCTION update_change_xid_column()
+RETURNS TRIGGER AS $$
+BEGIN
+   NEW.change_xid = pg_current_xact_id();
+RETURN NEW;
+END;
+$$ LANGUAGE 'plpgsql';
+
+-- Last transaction writing the note, sync tokens being snapshot bounds of these ids
+ALTER TABLE notes
+ADD COLUMN change_xid xid8 DEFAULT pg_current_xact_id() NOT NULL;
+
+-- Tables
+
+-- Purged notes, reported as deletions to syncing clients
+CREATE TABLE note_tombstones (
+    note_id uuid PRIMARY KEY,
+    user_id uuid REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    change_xid xid8 DEFAULT pg_current_xact_id() NOT NULL,
+    deleted_at timestamptz DEFAULT now() NOT NULL
+);
+
+-- Indexes
+
+CREATE INDEX notes_user_id_change_xid_idx
+ON notes (user_id, change_xid);
+
+CREATE INDEX note_tombstones_user_id_change_xid_idx
+ON note_tombstones (user_id, change_xid);
+
+-- ChangeXid Triggers
+
+CREATE TRIGGER notes_change_xid
+BEFORE UPDATE ON notes
+FOR EACH ROW
+EXECUTE FUNCTION update_change_xid_column();
+
+-- Tombstone Triggers
+
+-- Skipped for notes cascading from deleted users
+CREATE OR REPLACE FUNCTION create_note_tombstone()
+RETURNS TRIGGER AS $$
+BEGIN
+   INSERT INTO note_tombstones (note_id, user_id)
+   SELECT OLD.id, OLD.user_id
+   WHERE EXISTS (SELECT 1 FROM users WHERE id = OLD.user_id)
+   ON CONFLICT (note_id) DO UPDATE
+   SET change_xid = EXCLUDED.change_xid, deleted_at = EXCLUDED.deleted_at;
+RETURN OLD;
+END;
+$$ LANGUAGE 'plpgsql';
+
+CREATE TRIGGER notes_tombstone
+AFTER DELETE ON notes
+FOR EACH ROW
+EXECUTE FUNCTION create_note_tombstone();
@@ -0,0 +1,17 @@
+-- Tables
+
+-- Domain events recorded along with the state changes they describe, awaiting delivery
+CREATE TABLE outbox (
+    id bigserial PRIMARY KEY,
+    event_type text NOT NULL,
+    payload jsonb NOT NULL,
+    occurred_at timestamptz DEFAULT now() NOT NULL,
+    attempts integer DEFAULT 0 NOT NULL,
+    next_attempt_at timestamptz DEFAULT now() NOT NULL,
+    last_error text
+);
+
+-- Indexes
+
+CREATE INDEX outbox_next_attempt_at_idx
+ON outbox (next_attempt_at);
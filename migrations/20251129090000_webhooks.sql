@@ -0,0 +1,46 @@
+-- Tables
+
+-- Secrets are kept in clear text as they are needed to sign the payloads
+CREATE TABLE webhooks (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id uuid REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    url text NOT NULL,
+    secret text NOT NULL,
+    event_types text[] NOT NULL,
+    created_at timestamptz DEFAULT now() NOT NULL,
+    updated_at timestamptz DEFAULT now() NOT NULL
+);
+
+-- Notes aren't referenced, deliveries outlive the notes they are about
+CREATE TABLE webhook_deliveries (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    webhook_id uuid REFERENCES webhooks(id) ON DELETE CASCADE NOT NULL,
+    event_type text NOT NULL,
+    note_id uuid,
+    status text DEFAULT 'pending' NOT NULL CHECK (status IN ('pending', 'succeeded', 'failed')),
+    attempts integer DEFAULT 0 NOT NULL,
+    response_status integer,
+    last_error text,
+    next_attempt_at timestamptz DEFAULT now(),
+    created_at timestamptz DEFAULT now() NOT NULL,
+    delivered_at timestamptz
+);
+
+-- Indexes
+
+CREATE INDEX webhooks_user_id_idx
+ON webhooks (user_id);
+
+CREATE INDEX webhook_deliveries_webhook_id_created_at_idx
+ON webhook_deliveries (webhook_id, created_at DESC);
+
+CREATE INDEX webhook_deliveries_next_attempt_at_idx
+ON webhook_deliveries (next_attempt_at)
+WHERE status = 'pending';
+
+-- UpdatedAt Triggers
+
+CREATE TRIGGER webhooks_updated_at
+BEFORE UPDATE ON webhooks
+FOR EACH ROW
+EXECUTE FUNCTION update_updated_at_column();
@@ -0,0 +1,35 @@
+-- Tables
+
+-- The uploaded file is kept in the blob store until the job finishes
+CREATE TABLE note_import_jobs (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id uuid REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    format text NOT NULL CHECK (format IN ('markdown', 'enex', 'json')),
+    status text DEFAULT 'pending' NOT NULL CHECK (status IN ('pending', 'running', 'completed', 'failed')),
+    total_count integer,
+    processed_count integer DEFAULT 0 NOT NULL,
+    imported_count integer DEFAULT 0 NOT NULL,
+    failures jsonb DEFAULT '[]' NOT NULL,
+    error text,
+    attempts integer DEFAULT 0 NOT NULL,
+    lease_expires_at timestamptz,
+    created_at timestamptz DEFAULT now() NOT NULL,
+    updated_at timestamptz DEFAULT now() NOT NULL,
+    finished_at timestamptz
+);
+
+-- Indexes
+
+CREATE INDEX note_import_jobs_user_id_created_at_idx
+ON note_import_jobs (user_id, created_at DESC);
+
+CREATE INDEX note_import_jobs_created_at_idx
+ON note_import_jobs (created_at)
+WHERE status IN ('pending', 'running');
+
+-- UpdatedAt Triggers
+
+CREATE TRIGGER note_import_jobs_updated_at
+BEFORE UPDATE ON note_import_jobs
+FOR EACH ROW
+EXECUTE FUNCTION update_updated_at_column();
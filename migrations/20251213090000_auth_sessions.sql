@@ -0,0 +1,34 @@
+-- Tables
+
+-- Token pairs issued from a single login, the pair being rotated on refresh
+CREATE TABLE auth_sessions (
+    id uuid PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id uuid REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    ip_address text,
+    user_agent text,
+    created_at timestamptz DEFAULT now() NOT NULL,
+    last_used_at timestamptz DEFAULT now() NOT NULL
+);
+
+-- Revoking a session revokes its token pair
+ALTER TABLE access_tokens
+ADD COLUMN session_id uuid REFERENCES auth_sessions(id) ON DELETE CASCADE;
+
+-- Existing token pairs each get a session of their own
+INSERT INTO auth_sessions (id, user_id)
+SELECT id, user_id
+FROM access_tokens;
+
+UPDATE access_tokens
+SET session_id = id;
+
+ALTER TABLE access_tokens
+ALTER COLUMN session_id SET NOT NULL;
+
+-- Indexes
+
+CREATE INDEX auth_sessions_user_id_idx
+ON auth_sessions (user_id);
+
+CREATE INDEX access_tokens_session_id_idx
+ON access_tokens (session_id);
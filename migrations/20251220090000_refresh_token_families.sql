@@ -0,0 +1,28 @@
+-- Tables
+
+-- Refresh tokens descending from the same login share a family, kept across rotations
+ALTER TABLE refresh_tokens
+ADD COLUMN family_id uuid;
+
+UPDATE refresh_tokens
+SET family_id = id;
+
+ALTER TABLE refresh_tokens
+ALTER COLUMN family_id SET NOT NULL;
+
+-- Refresh tokens replaced on rotation, remembered until expiry to detect their reuse
+CREATE TABLE revoked_refresh_tokens (
+    id uuid PRIMARY KEY,
+    user_id uuid REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    family_id uuid NOT NULL,
+    expires_at timestamptz NOT NULL,
+    revoked_at timestamptz DEFAULT now() NOT NULL
+);
+
+-- Indexes
+
+CREATE INDEX refresh_tokens_family_id_idx
+ON refresh_tokens (family_id);
+
+CREATE INDEX revoked_refresh_tokens_user_id_expires_at_idx
+ON revoked_refresh_tokens (user_id, expires_at);
@@ -0,0 +1,13 @@
+-- Utility Function
+
+-- Escapes HTML special characters, so search snippets only ever hold the highlight tags as markup
+CREATE OR REPLACE FUNCTION html_escape(input text)
+RETURNS text AS $$
+    SELECT replace(replace(replace(replace(replace(
+        input,
+        '&', '&amp;'),
+        '<', '&lt;'),
+        '>', '&gt;'),
+        '"', '&quot;'),
+        '''', '&#39;');
+$$ LANGUAGE sql IMMUTABLE STRICT;
@@ -0,0 +1,21 @@
+-- Tables
+
+-- Identifies events across redeliveries, so that handlers can recognize the ones they already handled
+ALTER TABLE outbox
+ADD COLUMN event_id uuid DEFAULT gen_random_uuid() NOT NULL;
+
+-- Event a delivery notifies of, shared by the deliveries of the event to other webhooks
+ALTER TABLE webhook_deliveries
+ADD COLUMN event_id uuid;
+
+UPDATE webhook_deliveries
+SET event_id = id;
+
+ALTER TABLE webhook_deliveries
+ALTER COLUMN event_id SET NOT NULL;
+
+-- Constraints
+
+-- Events are delivered once per webhook, however many times they are dispatched
+ALTER TABLE webhook_deliveries
+ADD CONSTRAINT webhook_deliveries_webhook_id_event_id_key UNIQUE (webhook_id, event_id);
@@ -0,0 +1,77 @@
+-- Utility Function
+
+-- Reports the notes as changed to syncing clients, leaving their version and update time as is
+CREATE OR REPLACE FUNCTION touch_notes_change_xid(note_ids uuid[])
+RETURNS void AS $$
+BEGIN
+   PERFORM set_config('noteddd.touching_change_xid', 'on', true);
+   UPDATE notes
+   SET change_xid = pg_current_xact_id()
+   WHERE id = ANY(note_ids);
+   PERFORM set_config('noteddd.touching_change_xid', '', true);
+END;
+$$ LANGUAGE 'plpgsql';
+
+-- Version and UpdatedAt Triggers
+
+DROP TRIGGER notes_version ON notes;
+
+CREATE TRIGGER notes_version
+BEFORE UPDATE ON notes
+FOR EACH ROW
+WHEN (current_setting('noteddd.touching_change_xid', true) IS DISTINCT FROM 'on')
+EXECUTE FUNCTION increment_version_column();
+
+DROP TRIGGER notes_updated_at ON notes;
+
+CREATE TRIGGER notes_updated_at
+BEFORE UPDATE ON notes
+FOR EACH ROW
+WHEN (current_setting('noteddd.touching_change_xid', true) IS DISTINCT FROM 'on')
+EXECUTE FUNCTION update_updated_at_column();
+
+-- ChangeXid Triggers
+
+-- Tags are part of the synced notes
+CREATE OR REPLACE FUNCTION touch_note_tags_note_change_xid()
+RETURNS TRIGGER AS $$
+BEGIN
+   IF TG_OP = 'DELETE' THEN
+      PERFORM touch_notes_change_xid(ARRAY[OLD.note_id]);
+      RETURN OLD;
+   END IF;
+   PERFORM touch_notes_change_xid(ARRAY[NEW.note_id]);
+RETURN NEW;
+END;
+$$ LANGUAGE 'plpgsql';
+
+CREATE TRIGGER note_tags_note_change_xid
+AFTER INSERT OR DELETE ON note_tags
+FOR EACH ROW
+EXECUTE FUNCTION touch_note_tags_note_change_xid();
+
+CREATE OR REPLACE FUNCTION touch_tag_notes_change_xid()
+RETURNS TRIGGER AS $$
+BEGIN
+   PERFORM touch_notes_change_xid(
+      ARRAY(SELECT note_id FROM note_tags WHERE tag_id = OLD.id)
+   );
+   IF TG_OP = 'DELETE' THEN
+      RETURN OLD;
+   END IF;
+RETURN NEW;
+END;
+$$ LANGUAGE 'plpgsql';
+
+-- Renamed tags change the notes they are attached to
+CREATE TRIGGER tags_notes_change_xid
+AFTER UPDATE ON tags
+FOR EACH ROW
+WHEN (OLD.name IS DISTINCT FROM NEW.name)
+EXECUTE FUNCTION touch_tag_notes_change_xid();
+
+-- Runs before the attachments cascade away, while the tagged notes can still be found
+CREATE TRIGGER tags_deleted_notes_change_xid
+BEFORE DELETE ON tags
+FOR EACH ROW
+EXECUTE FUNCTION touch_tag_notes_change_xid();
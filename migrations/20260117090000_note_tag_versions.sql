@@ -0,0 +1,56 @@
+-- Utility Function
+
+-- Tags are part of the note representation, so changing them bumps the version and update time
+CREATE OR REPLACE FUNCTION touch_notes(note_ids uuid[])
+RETURNS void AS $$
+BEGIN
+   UPDATE notes
+   SET change_xid = pg_current_xact_id()
+   WHERE id = ANY(note_ids);
+END;
+$$ LANGUAGE 'plpgsql';
+
+-- Version and UpdatedAt Triggers
+
+DROP TRIGGER notes_version ON notes;
+
+CREATE TRIGGER notes_version
+BEFORE UPDATE ON notes
+FOR EACH ROW
+EXECUTE FUNCTION increment_version_column();
+
+DROP TRIGGER notes_updated_at ON notes;
+
+CREATE TRIGGER notes_updated_at
+BEFORE UPDATE ON notes
+FOR EACH ROW
+EXECUTE FUNCTION update_updated_at_column();
+
+-- ChangeXid Triggers
+
+CREATE OR REPLACE FUNCTION touch_note_tags_note_change_xid()
+RETURNS TRIGGER AS $$
+BEGIN
+   IF TG_OP = 'DELETE' THEN
+      PERFORM touch_notes(ARRAY[OLD.note_id]);
+      RETURN OLD;
+   END IF;
+   PERFORM touch_notes(ARRAY[NEW.note_id]);
+RETURN NEW;
+END;
+$$ LANGUAGE 'plpgsql';
+
+CREATE OR REPLACE FUNCTION touch_tag_notes_change_xid()
+RETURNS TRIGGER AS $$
+BEGIN
+   PERFORM touch_notes(
+      ARRAY(SELECT note_id FROM note_tags WHERE tag_id = OLD.id)
+   );
+   IF TG_OP = 'DELETE' THEN
+      RETURN OLD;
+   END IF;
+RETURN NEW;
+END;
+$$ LANGUAGE 'plpgsql';
+
+DROP FUNCTION touch_notes_change_xid(uuid[]);
@@ -0,0 +1,17 @@
+-- Tables
+
+-- Handlers already done with the event, skipped when it is redelivered
+ALTER TABLE outbox
+ADD COLUMN handled_by text[] DEFAULT '{}' NOT NULL;
+
+-- Set once the event ran out of delivery attempts, keeping it around for inspection
+ALTER TABLE outbox
+ADD COLUMN failed_at timestamptz;
+
+-- Indexes
+
+DROP INDEX outbox_next_attempt_at_idx;
+
+CREATE INDEX outbox_next_attempt_at_idx
+ON outbox (next_attempt_at)
+WHERE failed_at IS NULL;
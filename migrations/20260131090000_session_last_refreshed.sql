@@ -0,0 +1,5 @@
+-- Tables
+
+-- Sessions are only updated when their token pair gets rotated
+ALTER TABLE auth_sessions
+RENAME COLUMN last_used_at TO last_refreshed_at;
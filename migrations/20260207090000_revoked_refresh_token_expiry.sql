@@ -0,0 +1,10 @@
+-- Indexes
+
+-- Expired revoked refresh tokens are purged regardless of their user
+DROP INDEX revoked_refresh_tokens_user_id_expires_at_idx;
+
+CREATE INDEX revoked_refresh_tokens_user_id_idx
+ON revoked_refresh_tokens (user_id);
+
+CREATE INDEX revoked_refresh_tokens_expires_at_idx
+ON revoked_refresh_tokens (expires_at);
@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use dmn::entities::attachment::Attachment;
+
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(title = "Attachment")]
+pub(crate) struct AttachmentDto {
+    pub(crate) id: Uuid,
+    pub(crate) note_id: Uuid,
+    pub(crate) file_name: String,
+    pub(crate) mime_type: String,
+    /// Size of the file in bytes.
+    pub(crate) size: u64,
+    /// Hex encoded SHA-256 digest of the file.
+    pub(crate) sha256: String,
+    pub(crate) created_at: DateTime<Utc>,
+}
+
+impl From<Attachment> for AttachmentDto {
+    fn from(attachment: Attachment) -> Self {
+        Self {
+            id: attachment.id(),
+            note_id: attachment.note_id(),
+            file_name: attachment.file_name().into(),
+            mime_type: attachment.mime_type().into(),
+            size: attachment.size(),
+            sha256: attachment.sha256().into(),
+            created_at: attachment.created_at(),
+        }
+    }
+}
@@ -1,7 +1,11 @@
+use chrono::{DateTime, Utc};
 use serde::Serialize;
 use utoipa::ToSchema;
+use uuid::Uuid;
 
-use app::types::auth_token::AuthTokenPair;
+use app::types::auth_token::{
+    AuthSession, AuthTokenPair, JsonWebKey, JsonWebKeyAlgorithm, JsonWebKeyParams,
+};
 
 #[derive(Debug, Serialize, ToSchema)]
 #[schema(title = "AuthTokenPair")]
@@ -18,3 +22,106 @@ impl From<AuthTokenPair> for AuthTokenPairDto {
         }
     }
 }
+
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(title = "AuthSession")]
+pub(crate) struct AuthSessionDto {
+    pub(crate) id: Uuid,
+    pub(crate) ip_address: Option<String>,
+    pub(crate) user_agent: Option<String>,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) last_refreshed_at: DateTime<Utc>,
+    /// Whether this is the session of the requesting access token.
+    pub(crate) current: bool,
+}
+
+impl AuthSessionDto {
+    pub(crate) fn new(session: AuthSession, current_session_id: Uuid) -> Self {
+        Self {
+            id: session.id,
+            ip_address: session.ip_address,
+            user_agent: session.user_agent,
+            created_at: session.created_at,
+            last_refreshed_at: session.last_refreshed_at,
+            current: session.id == current_session_id,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(title = "JsonWebKeySet")]
+pub(crate) struct JsonWebKeySetDto {
+    pub(crate) keys: Vec<JsonWebKeyDto>,
+}
+
+impl From<Vec<JsonWebKey>> for JsonWebKeySetDto {
+    fn from(keys: Vec<JsonWebKey>) -> Self {
+        Self {
+            keys: keys.into_iter().map(|key| key.into()).collect(),
+        }
+    }
+}
+
+/// Public signing key, as per RFC 7517.
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(title = "JsonWebKey")]
+pub(crate) struct JsonWebKeyDto {
+    pub(crate) kty: String,
+    pub(crate) kid: String,
+    #[serde(rename = "use")]
+    pub(crate) key_use: String,
+    pub(crate) alg: String,
+    /// RSA modulus
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) n: Option<String>,
+    /// RSA public exponent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) e: Option<String>,
+    /// Curve of EC and OKP keys
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) crv: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) x: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) y: Option<String>,
+}
+
+impl From<JsonWebKey> for JsonWebKeyDto {
+    fn from(key: JsonWebKey) -> Self {
+        let alg = match key.algorithm {
+            JsonWebKeyAlgorithm::Rs256 => "RS256",
+            JsonWebKeyAlgorithm::Es256 => "ES256",
+            JsonWebKeyAlgorithm::EdDsa => "EdDSA",
+        };
+        let mut dto = Self {
+            kty: String::new(),
+            kid: key.kid,
+            key_use: "sig".to_string(),
+            alg: alg.to_string(),
+            n: None,
+            e: None,
+            crv: None,
+            x: None,
+            y: None,
+        };
+        match key.params {
+            JsonWebKeyParams::Rsa { modulus, exponent } => {
+                dto.kty = "RSA".to_string();
+                dto.n = Some(modulus);
+                dto.e = Some(exponent);
+            }
+            JsonWebKeyParams::EllipticCurve { curve, x, y } => {
+                dto.kty = "EC".to_string();
+                dto.crv = Some(curve);
+                dto.x = Some(x);
+                dto.y = Some(y);
+            }
+            JsonWebKeyParams::OctetKeyPair { curve, x } => {
+                dto.kty = "OKP".to_string();
+                dto.crv = Some(curve);
+                dto.x = Some(x);
+            }
+        }
+        dto
+    }
+}
@@ -1,7 +1,25 @@
+mod attachment;
 mod auth_token;
 mod note;
+mod note_event;
+mod note_import_job;
+mod note_public_link;
+mod note_revision;
+mod note_share;
+mod notebook;
+mod tag;
 mod user;
+mod webhook;
 
+pub(crate) use attachment::*;
 pub(crate) use auth_token::*;
 pub(crate) use note::*;
+pub(crate) use note_event::*;
+pub(crate) use note_import_job::*;
+pub(crate) use note_public_link::*;
+pub(crate) use note_revision::*;
+pub(crate) use note_share::*;
+pub(crate) use notebook::*;
+pub(crate) use tag::*;
 pub(crate) use user::*;
+pub(crate) use webhook::*;
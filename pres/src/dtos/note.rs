@@ -3,26 +3,80 @@ use serde::Serialize;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-use dmn::entities::note::Note;
+use super::TagDto;
+use dmn::{
+    entities::note::Note,
+    repos::note::{NoteSearchResult, NoteTombstone},
+};
 
 #[derive(Debug, Serialize, ToSchema)]
 #[schema(title = "Note")]
 pub(crate) struct NoteDto {
     pub(crate) id: Uuid,
+    pub(crate) notebook_id: Option<Uuid>,
     pub(crate) title: String,
     pub(crate) content: String,
+    pub(crate) tags: Vec<TagDto>,
+    pub(crate) version: u32,
+    pub(crate) pinned: bool,
+    pub(crate) archived: bool,
+    pub(crate) favorite: bool,
     pub(crate) created_at: DateTime<Utc>,
     pub(crate) updated_at: DateTime<Utc>,
+    pub(crate) deleted_at: Option<DateTime<Utc>>,
 }
 
 impl From<Note> for NoteDto {
     fn from(note: Note) -> Self {
         Self {
             id: note.id(),
+            notebook_id: note.notebook_id(),
             title: note.title().into(),
             content: note.content().into(),
+            tags: note.tags().iter().map(|tag| tag.into()).collect(),
+            version: note.version(),
+            pinned: note.is_pinned(),
+            archived: note.is_archived(),
+            favorite: note.is_favorite(),
             created_at: note.created_at(),
             updated_at: note.updated_at(),
+            deleted_at: note.deleted_at(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(title = "NoteSearchResult")]
+pub(crate) struct NoteSearchResultDto {
+    pub(crate) note: NoteDto,
+    pub(crate) rank: f32,
+    pub(crate) title_snippet: String,
+    pub(crate) content_snippet: String,
+}
+
+impl From<NoteSearchResult> for NoteSearchResultDto {
+    fn from(result: NoteSearchResult) -> Self {
+        Self {
+            note: result.note.into(),
+            rank: result.rank,
+            title_snippet: result.title_snippet,
+            content_snippet: result.content_snippet,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(title = "NoteTombstone")]
+pub(crate) struct NoteTombstoneDto {
+    pub(crate) note_id: Uuid,
+    pub(crate) deleted_at: DateTime<Utc>,
+}
+
+impl From<NoteTombstone> for NoteTombstoneDto {
+    fn from(tombstone: NoteTombstone) -> Self {
+        Self {
+            note_id: tombstone.note_id,
+            deleted_at: tombstone.deleted_at,
         }
     }
 }
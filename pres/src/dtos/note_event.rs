@@ -0,0 +1,51 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use app::events::note_events::{NoteEvent, NoteEventKind};
+
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(title = "NoteEvent")]
+pub(crate) struct NoteEventDto {
+    pub(crate) kind: NoteEventKindDto,
+    pub(crate) note_id: Uuid,
+}
+
+impl From<NoteEvent> for NoteEventDto {
+    fn from(event: NoteEvent) -> Self {
+        Self {
+            kind: event.kind.into(),
+            note_id: event.note_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[schema(title = "NoteEventKind")]
+pub(crate) enum NoteEventKindDto {
+    Created,
+    Updated,
+    Deleted,
+}
+
+impl NoteEventKindDto {
+    /// Name of the Server-Sent Event carrying the event.
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            NoteEventKindDto::Created => "created",
+            NoteEventKindDto::Updated => "updated",
+            NoteEventKindDto::Deleted => "deleted",
+        }
+    }
+}
+
+impl From<NoteEventKind> for NoteEventKindDto {
+    fn from(kind: NoteEventKind) -> Self {
+        match kind {
+            NoteEventKind::Created => NoteEventKindDto::Created,
+            NoteEventKind::Updated => NoteEventKindDto::Updated,
+            NoteEventKind::Deleted => NoteEventKindDto::Deleted,
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use dmn::entities::note_import_job::{
+    NoteImportFailure, NoteImportFormat, NoteImportJob, NoteImportJobStatus,
+};
+
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(title = "NoteImportJob")]
+pub(crate) struct NoteImportJobDto {
+    pub(crate) id: Uuid,
+    pub(crate) format: NoteImportFormatDto,
+    pub(crate) status: NoteImportJobStatusDto,
+    /// Number of notes found in the file, `null` until it has been read.
+    pub(crate) total_count: Option<u32>,
+    /// Number of notes handled so far, failed ones included.
+    pub(crate) processed_count: u32,
+    /// Number of notes imported, only set once they're all kept.
+    pub(crate) imported_count: u32,
+    /// Notes that couldn't be imported, the others being imported regardless.
+    pub(crate) failures: Vec<NoteImportFailureDto>,
+    /// Reason the whole job failed, no note being imported then.
+    pub(crate) error: Option<String>,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) updated_at: DateTime<Utc>,
+    pub(crate) finished_at: Option<DateTime<Utc>>,
+}
+
+impl From<NoteImportJob> for NoteImportJobDto {
+    fn from(job: NoteImportJob) -> Self {
+        Self {
+            id: job.id(),
+            format: job.format().into(),
+            status: job.status().into(),
+            total_count: job.total_count(),
+            processed_count: job.processed_count(),
+            imported_count: job.imported_count(),
+            failures: job
+                .failures()
+                .iter()
+                .map(|failure| failure.clone().into())
+                .collect(),
+            error: job.error().map(|err| err.to_string()),
+            created_at: job.created_at(),
+            updated_at: job.updated_at(),
+            finished_at: job.finished_at(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[schema(title = "NoteImportFormat")]
+pub(crate) enum NoteImportFormatDto {
+    Markdown,
+    Enex,
+    Json,
+}
+
+impl From<NoteImportFormat> for NoteImportFormatDto {
+    fn from(format: NoteImportFormat) -> Self {
+        match format {
+            NoteImportFormat::Markdown => NoteImportFormatDto::Markdown,
+            NoteImportFormat::Enex => NoteImportFormatDto::Enex,
+            NoteImportFormat::Json => NoteImportFormatDto::Json,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[schema(title = "NoteImportJobStatus")]
+pub(crate) enum NoteImportJobStatusDto {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
+
+impl From<NoteImportJobStatus> for NoteImportJobStatusDto {
+    fn from(status: NoteImportJobStatus) -> Self {
+        match status {
+            NoteImportJobStatus::Pending => NoteImportJobStatusDto::Pending,
+            NoteImportJobStatus::Running => NoteImportJobStatusDto::Running,
+            NoteImportJobStatus::Completed => NoteImportJobStatusDto::Completed,
+            NoteImportJobStatus::Failed => NoteImportJobStatusDto::Failed,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(title = "NoteImportFailure")]
+pub(crate) struct NoteImportFailureDto {
+    /// Where the note was found, i.e. its file name within a Markdown archive, or its position
+    /// within an Evernote export or JSON dump (e.g. `Note #2`).
+    pub(crate) item: String,
+    pub(crate) error: String,
+}
+
+impl From<NoteImportFailure> for NoteImportFailureDto {
+    fn from(failure: NoteImportFailure) -> Self {
+        Self {
+            item: failure.item,
+            error: failure.error,
+        }
+    }
+}
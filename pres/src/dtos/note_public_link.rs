@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use dmn::entities::{note::Note, note_public_link::NotePublicLink};
+
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(title = "NotePublicLink")]
+pub(crate) struct NotePublicLinkDto {
+    pub(crate) note_id: Uuid,
+    pub(crate) token: String,
+    pub(crate) expires_at: Option<DateTime<Utc>>,
+    pub(crate) created_at: DateTime<Utc>,
+}
+
+impl From<NotePublicLink> for NotePublicLinkDto {
+    fn from(link: NotePublicLink) -> Self {
+        Self {
+            note_id: link.note_id(),
+            token: link.token().into(),
+            expires_at: link.expires_at(),
+            created_at: link.created_at(),
+        }
+    }
+}
+
+/// Anonymous view of a note, leaving out anything tied to its owner.
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(title = "PublicNote")]
+pub(crate) struct PublicNoteDto {
+    pub(crate) title: String,
+    pub(crate) content: String,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) updated_at: DateTime<Utc>,
+}
+
+impl From<Note> for PublicNoteDto {
+    fn from(note: Note) -> Self {
+        Self {
+            title: note.title().into(),
+            content: note.content().into(),
+            created_at: note.created_at(),
+            updated_at: note.updated_at(),
+        }
+    }
+}
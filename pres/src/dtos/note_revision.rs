@@ -0,0 +1,83 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use dmn::{
+    entities::note_revision::{NoteRevision, NoteRevisionDiff},
+    value_objects::text_diff::{DiffLine, DiffLineKind, TextDiff},
+};
+
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(title = "NoteRevision")]
+pub(crate) struct NoteRevisionDto {
+    pub(crate) id: Uuid,
+    pub(crate) note_id: Uuid,
+    pub(crate) revision: u32,
+    pub(crate) title: String,
+    pub(crate) content: String,
+    pub(crate) created_at: DateTime<Utc>,
+}
+
+impl From<NoteRevision> for NoteRevisionDto {
+    fn from(revision: NoteRevision) -> Self {
+        Self {
+            id: revision.id(),
+            note_id: revision.note_id(),
+            revision: revision.revision(),
+            title: revision.title().into(),
+            content: revision.content().into(),
+            created_at: revision.created_at(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(title = "NoteRevisionDiff")]
+pub(crate) struct NoteRevisionDiffDto {
+    pub(crate) title: Vec<DiffLineDto>,
+    pub(crate) content: Vec<DiffLineDto>,
+}
+
+impl From<NoteRevisionDiff> for NoteRevisionDiffDto {
+    fn from(diff: NoteRevisionDiff) -> Self {
+        Self {
+            title: into_diff_line_dtos(diff.title),
+            content: into_diff_line_dtos(diff.content),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(title = "DiffLine")]
+pub(crate) struct DiffLineDto {
+    pub(crate) kind: DiffLineKindDto,
+    pub(crate) content: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[schema(title = "DiffLineKind")]
+pub(crate) enum DiffLineKindDto {
+    Unchanged,
+    Added,
+    Removed,
+}
+
+impl From<DiffLine> for DiffLineDto {
+    fn from(line: DiffLine) -> Self {
+        let kind = match line.kind {
+            DiffLineKind::Unchanged => DiffLineKindDto::Unchanged,
+            DiffLineKind::Added => DiffLineKindDto::Added,
+            DiffLineKind::Removed => DiffLineKindDto::Removed,
+        };
+        Self {
+            kind,
+            content: line.content,
+        }
+    }
+}
+
+fn into_diff_line_dtos(diff: TextDiff) -> Vec<DiffLineDto> {
+    diff.into_iter().map(|line| line.into()).collect()
+}
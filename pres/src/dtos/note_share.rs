@@ -0,0 +1,56 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use dmn::entities::note_share::{NoteShare, NoteShareRole};
+
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(title = "NoteShare")]
+pub(crate) struct NoteShareDto {
+    pub(crate) note_id: Uuid,
+    pub(crate) user_id: Uuid,
+    pub(crate) email: String,
+    pub(crate) role: NoteShareRoleDto,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) updated_at: DateTime<Utc>,
+}
+
+impl From<NoteShare> for NoteShareDto {
+    fn from(share: NoteShare) -> Self {
+        Self {
+            note_id: share.note_id(),
+            user_id: share.user_id(),
+            email: share.user_email().to_string(),
+            role: share.role().into(),
+            created_at: share.created_at(),
+            updated_at: share.updated_at(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[schema(title = "NoteShareRole")]
+pub(crate) enum NoteShareRoleDto {
+    Read,
+    Write,
+}
+
+impl From<NoteShareRole> for NoteShareRoleDto {
+    fn from(role: NoteShareRole) -> Self {
+        match role {
+            NoteShareRole::Read => NoteShareRoleDto::Read,
+            NoteShareRole::Write => NoteShareRoleDto::Write,
+        }
+    }
+}
+
+impl From<NoteShareRoleDto> for NoteShareRole {
+    fn from(role: NoteShareRoleDto) -> Self {
+        match role {
+            NoteShareRoleDto::Read => NoteShareRole::Read,
+            NoteShareRoleDto::Write => NoteShareRole::Write,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use dmn::entities::notebook::Notebook;
+
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(title = "Notebook")]
+pub(crate) struct NotebookDto {
+    pub(crate) id: Uuid,
+    pub(crate) parent_id: Option<Uuid>,
+    pub(crate) name: String,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) updated_at: DateTime<Utc>,
+}
+
+impl From<Notebook> for NotebookDto {
+    fn from(notebook: Notebook) -> Self {
+        Self {
+            id: notebook.id(),
+            parent_id: notebook.parent_id(),
+            name: notebook.name().into(),
+            created_at: notebook.created_at(),
+            updated_at: notebook.updated_at(),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use dmn::entities::tag::Tag;
+
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(title = "Tag")]
+pub(crate) struct TagDto {
+    pub(crate) id: Uuid,
+    pub(crate) name: String,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) updated_at: DateTime<Utc>,
+}
+
+impl From<Tag> for TagDto {
+    fn from(tag: Tag) -> Self {
+        Self {
+            id: tag.id(),
+            name: tag.name().into(),
+            created_at: tag.created_at(),
+            updated_at: tag.updated_at(),
+        }
+    }
+}
+
+impl From<&Tag> for TagDto {
+    fn from(tag: &Tag) -> Self {
+        tag.clone().into()
+    }
+}
@@ -0,0 +1,138 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use dmn::entities::{
+    webhook::{Webhook, WebhookEventType},
+    webhook_delivery::{WebhookDelivery, WebhookDeliveryStatus},
+};
+
+/// The signing secret is write-only and never returned.
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(title = "Webhook")]
+pub(crate) struct WebhookDto {
+    pub(crate) id: Uuid,
+    pub(crate) url: String,
+    pub(crate) event_types: Vec<WebhookEventTypeDto>,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) updated_at: DateTime<Utc>,
+}
+
+impl From<Webhook> for WebhookDto {
+    fn from(webhook: Webhook) -> Self {
+        Self {
+            id: webhook.id(),
+            url: webhook.url().to_string(),
+            event_types: webhook
+                .event_types()
+                .iter()
+                .map(|event_type| (*event_type).into())
+                .collect(),
+            created_at: webhook.created_at(),
+            updated_at: webhook.updated_at(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[schema(title = "WebhookEventType")]
+pub(crate) enum WebhookEventTypeDto {
+    #[serde(rename = "note.created")]
+    NoteCreated,
+    #[serde(rename = "note.updated")]
+    NoteUpdated,
+    #[serde(rename = "note.deleted")]
+    NoteDeleted,
+    #[serde(rename = "user.updated")]
+    UserUpdated,
+    #[serde(rename = "user.password_changed")]
+    PasswordChanged,
+    /// Only sent through the test endpoint, it cannot be subscribed to.
+    #[serde(rename = "webhook.test")]
+    Test,
+}
+
+impl From<WebhookEventType> for WebhookEventTypeDto {
+    fn from(event_type: WebhookEventType) -> Self {
+        match event_type {
+            WebhookEventType::NoteCreated => WebhookEventTypeDto::NoteCreated,
+            WebhookEventType::NoteUpdated => WebhookEventTypeDto::NoteUpdated,
+            WebhookEventType::NoteDeleted => WebhookEventTypeDto::NoteDeleted,
+            WebhookEventType::UserUpdated => WebhookEventTypeDto::UserUpdated,
+            WebhookEventType::PasswordChanged => WebhookEventTypeDto::PasswordChanged,
+            WebhookEventType::Test => WebhookEventTypeDto::Test,
+        }
+    }
+}
+
+impl From<WebhookEventTypeDto> for WebhookEventType {
+    fn from(event_type: WebhookEventTypeDto) -> Self {
+        match event_type {
+            WebhookEventTypeDto::NoteCreated => WebhookEventType::NoteCreated,
+            WebhookEventTypeDto::NoteUpdated => WebhookEventType::NoteUpdated,
+            WebhookEventTypeDto::NoteDeleted => WebhookEventType::NoteDeleted,
+            WebhookEventTypeDto::UserUpdated => WebhookEventType::UserUpdated,
+            WebhookEventTypeDto::PasswordChanged => WebhookEventType::PasswordChanged,
+            WebhookEventTypeDto::Test => WebhookEventType::Test,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(title = "WebhookDelivery")]
+pub(crate) struct WebhookDeliveryDto {
+    pub(crate) id: Uuid,
+    pub(crate) webhook_id: Uuid,
+    /// Sent as the `X-Webhook-Id` header and payload `id`, identical across retries.
+    pub(crate) event_id: Uuid,
+    pub(crate) event_type: WebhookEventTypeDto,
+    pub(crate) note_id: Option<Uuid>,
+    pub(crate) status: WebhookDeliveryStatusDto,
+    pub(crate) attempts: u32,
+    /// HTTP status of the last attempt, `null` if no response was received.
+    pub(crate) response_status: Option<u16>,
+    pub(crate) last_error: Option<String>,
+    /// When the next retry is scheduled, `null` once the delivery is settled.
+    pub(crate) next_attempt_at: Option<DateTime<Utc>>,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) delivered_at: Option<DateTime<Utc>>,
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryDto {
+    fn from(delivery: WebhookDelivery) -> Self {
+        Self {
+            id: delivery.id(),
+            webhook_id: delivery.webhook_id(),
+            event_id: delivery.event_id(),
+            event_type: delivery.event_type().into(),
+            note_id: delivery.note_id(),
+            status: delivery.status().into(),
+            attempts: delivery.attempts(),
+            response_status: delivery.response_status(),
+            last_error: delivery.last_error().map(|err| err.to_string()),
+            next_attempt_at: delivery.next_attempt_at(),
+            created_at: delivery.created_at(),
+            delivered_at: delivery.delivered_at(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[schema(title = "WebhookDeliveryStatus")]
+pub(crate) enum WebhookDeliveryStatusDto {
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+impl From<WebhookDeliveryStatus> for WebhookDeliveryStatusDto {
+    fn from(status: WebhookDeliveryStatus) -> Self {
+        match status {
+            WebhookDeliveryStatus::Pending => WebhookDeliveryStatusDto::Pending,
+            WebhookDeliveryStatus::Succeeded => WebhookDeliveryStatusDto::Succeeded,
+            WebhookDeliveryStatus::Failed => WebhookDeliveryStatusDto::Failed,
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use axum::{extract::State, Json};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::AuthSessionDto,
+    types::error::PresentationError,
+};
+use app::state::AppState;
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct GetAuthSessionsHttpResponseBody {
+    pub(crate) sessions: Vec<AuthSessionDto>,
+}
+
+/// Retrieves the active sessions of a User.
+#[utoipa::path(
+    tag = "Authentication",
+    get,
+    path = "/sessions",
+    description = "Sessions are listed most recently used first.",
+    responses(
+        (status = 200, description = "Success", body = GetAuthSessionsHttpResponseBody),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn get_auth_sessions(
+    State(state): State<AppState>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Json<GetAuthSessionsHttpResponseBody>, PresentationError> {
+    let AppState {
+        get_auth_sessions_use_case,
+        ..
+    } = state;
+
+    let current_session_id = auth_ctx.access_token.session_id;
+    let sessions = get_auth_sessions_use_case.execute(auth_ctx).await?;
+
+    let session_dtos = sessions
+        .into_iter()
+        .map(|session| AuthSessionDto::new(session, current_session_id))
+        .collect();
+    let http_res = GetAuthSessionsHttpResponseBody {
+        sessions: session_dtos,
+    };
+    Ok(Json(http_res))
+}
@@ -2,7 +2,10 @@ use axum::{extract::State, Json};
 use serde::Deserialize;
 use utoipa::ToSchema;
 
-use crate::{dtos::AuthTokenPairDto, types::error::PresentationError};
+use crate::{
+    dtos::AuthTokenPairDto,
+    types::{client::ClientInfoExtractor, error::PresentationError},
+};
 use app::{state::AppState, usecases::auth::AuthLoginInput};
 
 #[derive(Deserialize, ToSchema)]
@@ -24,6 +27,7 @@ pub(crate) struct AuthLoginHttpRequestBody {
 )]
 pub(crate) async fn auth_login(
     State(state): State<AppState>,
+    ClientInfoExtractor(client_info): ClientInfoExtractor,
     Json(payload): Json<AuthLoginHttpRequestBody>,
 ) -> Result<Json<AuthTokenPairDto>, PresentationError> {
     let AppState {
@@ -31,18 +35,13 @@ pub(crate) async fn auth_login(
         ..
     } = state;
 
-    let input = payload.into();
+    let input = AuthLoginInput {
+        email: payload.email,
+        password: payload.password,
+        client_info,
+    };
     let token_pair = auth_login_use_case.execute(input).await?;
 
     let token_pair_dto = token_pair.into();
     Ok(Json(token_pair_dto))
 }
-
-impl From<AuthLoginHttpRequestBody> for AuthLoginInput {
-    fn from(payload: AuthLoginHttpRequestBody) -> Self {
-        Self {
-            email: payload.email,
-            password: payload.password,
-        }
-    }
-}
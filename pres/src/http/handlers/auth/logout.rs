@@ -0,0 +1,33 @@
+use axum::extract::State;
+
+use crate::{auth::extractors::AuthContextAccessExtractor, types::error::PresentationError};
+use app::state::AppState;
+
+/// Revokes the current session of a User.
+#[utoipa::path(
+    tag = "Authentication",
+    post,
+    path = "/logout",
+    description = "Revokes the token pair of the provided <strong>access token</strong>.",
+    responses(
+        (status = 200, description = "Success", body = String),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn auth_logout(
+    State(state): State<AppState>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<String, PresentationError> {
+    let AppState {
+        auth_logout_use_case,
+        ..
+    } = state;
+
+    auth_logout_use_case.execute(auth_ctx).await?;
+
+    Ok("Logged out successfully.".to_string())
+}
@@ -0,0 +1,33 @@
+use axum::extract::State;
+
+use crate::{auth::extractors::AuthContextAccessExtractor, types::error::PresentationError};
+use app::state::AppState;
+
+/// Revokes all sessions of a User.
+#[utoipa::path(
+    tag = "Authentication",
+    post,
+    path = "/logout-all",
+    description = "Revokes every token pair of the user, including the provided <strong>access token</strong>.",
+    responses(
+        (status = 200, description = "Success", body = String),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn auth_logout_all(
+    State(state): State<AppState>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<String, PresentationError> {
+    let AppState {
+        auth_logout_all_use_case,
+        ..
+    } = state;
+
+    auth_logout_all_use_case.execute(auth_ctx).await?;
+
+    Ok("Logged out of all sessions successfully.".to_string())
+}
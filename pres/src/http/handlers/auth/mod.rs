@@ -1,10 +1,18 @@
 mod change_pass;
+mod get_sessions;
 mod login;
+mod logout;
+mod logout_all;
 mod refresh;
+mod revoke_session;
 
 use change_pass::*;
+use get_sessions::*;
 use login::*;
+use logout::*;
+use logout_all::*;
 use refresh::*;
+use revoke_session::*;
 
 use utoipa::OpenApi;
 
@@ -15,8 +23,12 @@ use app::state::AppState;
     paths(
         // Authentication
         auth_login,
+        auth_logout,
+        auth_logout_all,
         auth_refresh,
         change_user_pass,
+        get_auth_sessions,
+        revoke_auth_session,
     ),
     tags(
         (name = "Authentication"),
@@ -30,6 +42,14 @@ pub fn declare_routes(base_path: &str) -> axum::Router<AppState> {
             &format!("{base_path}/login"),
             axum::routing::post(auth_login),
         )
+        .route(
+            &format!("{base_path}/logout"),
+            axum::routing::post(auth_logout),
+        )
+        .route(
+            &format!("{base_path}/logout-all"),
+            axum::routing::post(auth_logout_all),
+        )
         .route(
             &format!("{base_path}/refresh"),
             axum::routing::post(auth_refresh),
@@ -38,4 +58,12 @@ pub fn declare_routes(base_path: &str) -> axum::Router<AppState> {
             &format!("{base_path}/password"),
             axum::routing::put(change_user_pass),
         )
+        .route(
+            &format!("{base_path}/sessions"),
+            axum::routing::get(get_auth_sessions),
+        )
+        .route(
+            &format!("{base_path}/sessions/{{session_id}}"),
+            axum::routing::delete(revoke_auth_session),
+        )
 }
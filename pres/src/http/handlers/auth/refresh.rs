@@ -1,10 +1,11 @@
 use axum::{extract::State, Json};
 
 use crate::{
-    auth::extractors::AuthContextRefreshExtractor, dtos::AuthTokenPairDto,
-    types::error::PresentationError,
+    auth::extractors::AuthContextRefreshExtractor,
+    dtos::AuthTokenPairDto,
+    types::{client::ClientInfoExtractor, error::PresentationError},
 };
-use app::state::AppState;
+use app::{state::AppState, usecases::auth::AuthRefreshInput};
 
 /// Rotates a User's authentication token pair.
 #[utoipa::path(
@@ -24,13 +25,15 @@ use app::state::AppState;
 pub(crate) async fn auth_refresh(
     State(state): State<AppState>,
     AuthContextRefreshExtractor(auth_ref_ctx): AuthContextRefreshExtractor,
+    ClientInfoExtractor(client_info): ClientInfoExtractor,
 ) -> Result<Json<AuthTokenPairDto>, PresentationError> {
     let AppState {
         auth_refresh_use_case,
         ..
     } = state;
 
-    let token_pair = auth_refresh_use_case.execute(auth_ref_ctx).await?;
+    let input = AuthRefreshInput { client_info };
+    let token_pair = auth_refresh_use_case.execute(auth_ref_ctx, input).await?;
 
     let token_pair_dto = token_pair.into();
     Ok(Json(token_pair_dto))
@@ -0,0 +1,42 @@
+use axum::extract::{Path, State};
+use uuid::Uuid;
+
+use crate::{auth::extractors::AuthContextAccessExtractor, types::error::PresentationError};
+use app::{state::AppState, usecases::auth::RevokeAuthSessionInput};
+
+/// Revokes a session of a User.
+#[utoipa::path(
+    tag = "Authentication",
+    delete,
+    path = "/sessions/{session_id}",
+    description = "Revokes the token pair of the session, e.g. for a lost or stolen device.",
+    params(
+        ("session_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = String),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn revoke_auth_session(
+    State(state): State<AppState>,
+    Path(session_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<String, PresentationError> {
+    let AppState {
+        revoke_auth_session_use_case,
+        ..
+    } = state;
+
+    let input = RevokeAuthSessionInput { session_id };
+    revoke_auth_session_use_case
+        .execute(auth_ctx, input)
+        .await?;
+
+    Ok(format!("Session ({}) revoked successfully.", session_id))
+}
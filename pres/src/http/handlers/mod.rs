@@ -1,3 +1,8 @@
 pub(crate) mod auth;
+pub(crate) mod notebooks;
 pub(crate) mod notes;
+pub(crate) mod public;
+pub(crate) mod tags;
 pub(crate) mod users;
+pub(crate) mod webhooks;
+pub(crate) mod well_known;
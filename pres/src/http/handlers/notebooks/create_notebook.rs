@@ -0,0 +1,59 @@
+use axum::{extract::State, Json};
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::NotebookDto,
+    types::error::PresentationError,
+};
+use app::{state::AppState, usecases::notebook::CreateNotebookInput};
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct CreateNotebookHttpRequestBody {
+    /// Parent notebook, `null` creates a root level notebook.
+    pub(crate) parent_id: Option<Uuid>,
+    pub(crate) name: String,
+}
+
+/// Creates a new Notebook.
+#[utoipa::path(
+    tag = "Notebooks",
+    post,
+    path = "/",
+    responses(
+        (status = 200, description = "Success", body = NotebookDto),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn create_notebook(
+    State(state): State<AppState>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+    Json(payload): Json<CreateNotebookHttpRequestBody>,
+) -> Result<Json<NotebookDto>, PresentationError> {
+    let AppState {
+        create_notebook_use_case,
+        ..
+    } = state;
+
+    let input = payload.into();
+    let notebook = create_notebook_use_case.execute(auth_ctx, input).await?;
+
+    let notebook_dto = notebook.into();
+    Ok(Json(notebook_dto))
+}
+
+impl From<CreateNotebookHttpRequestBody> for CreateNotebookInput {
+    fn from(payload: CreateNotebookHttpRequestBody) -> Self {
+        Self {
+            parent_id: payload.parent_id,
+            name: payload.name,
+        }
+    }
+}
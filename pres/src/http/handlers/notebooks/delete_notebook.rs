@@ -0,0 +1,40 @@
+use axum::extract::{Path, State};
+use uuid::Uuid;
+
+use crate::{auth::extractors::AuthContextAccessExtractor, types::error::PresentationError};
+use app::{state::AppState, usecases::notebook::DeleteNotebookInput};
+
+/// Deletes a Notebook along with its sub-notebooks.
+#[utoipa::path(
+    tag = "Notebooks",
+    delete,
+    path = "/{notebook_id}",
+    description = "Notes contained in the deleted notebooks are moved back to the root level.",
+    params(
+        ("notebook_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = String),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn delete_notebook(
+    State(state): State<AppState>,
+    Path(notebook_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<String, PresentationError> {
+    let AppState {
+        delete_notebook_use_case,
+        ..
+    } = state;
+
+    let input = DeleteNotebookInput { notebook_id };
+    delete_notebook_use_case.execute(auth_ctx, input).await?;
+
+    Ok(format!("Notebook ({}) deleted successfully.", notebook_id))
+}
@@ -0,0 +1,46 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::NotebookDto,
+    types::error::PresentationError,
+};
+use app::{state::AppState, usecases::notebook::GetNotebookInput};
+
+/// Retrieves a Notebook.
+#[utoipa::path(
+    tag = "Notebooks",
+    get,
+    path = "/{notebook_id}",
+    params(
+        ("notebook_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = NotebookDto),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn get_notebook(
+    State(state): State<AppState>,
+    Path(notebook_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Json<NotebookDto>, PresentationError> {
+    let AppState {
+        get_notebook_use_case,
+        ..
+    } = state;
+
+    let input = GetNotebookInput { notebook_id };
+    let notebook = get_notebook_use_case.execute(auth_ctx, input).await?;
+
+    let notebook_dto = notebook.into();
+    Ok(Json(notebook_dto))
+}
@@ -0,0 +1,50 @@
+use axum::{extract::State, Json};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::NotebookDto,
+    types::error::PresentationError,
+};
+use app::state::AppState;
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct GetNotebooksHttpResponseBody {
+    pub(crate) notebooks: Vec<NotebookDto>,
+}
+
+/// Retrieves all Notebooks of the current user.
+#[utoipa::path(
+    tag = "Notebooks",
+    get,
+    path = "/",
+    description = "Notebooks are returned as a flat list, the hierarchy is expressed through `parent_id`.",
+    responses(
+        (status = 200, description = "Success", body = GetNotebooksHttpResponseBody),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn get_notebooks(
+    State(state): State<AppState>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Json<GetNotebooksHttpResponseBody>, PresentationError> {
+    let AppState {
+        get_notebooks_use_case,
+        ..
+    } = state;
+
+    let notebooks = get_notebooks_use_case.execute(auth_ctx).await?;
+
+    let notebook_dtos = notebooks
+        .into_iter()
+        .map(|notebook| notebook.into())
+        .collect();
+    let http_res = GetNotebooksHttpResponseBody {
+        notebooks: notebook_dtos,
+    };
+    Ok(Json(http_res))
+}
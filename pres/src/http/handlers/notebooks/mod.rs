@@ -0,0 +1,49 @@
+mod create_notebook;
+mod delete_notebook;
+mod get_notebook;
+mod get_notebooks;
+mod update_notebook;
+
+use create_notebook::*;
+use delete_notebook::*;
+use get_notebook::*;
+use get_notebooks::*;
+use update_notebook::*;
+
+use utoipa::OpenApi;
+
+use app::state::AppState;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        // Notebooks
+        create_notebook,
+        get_notebook,
+        get_notebooks,
+        update_notebook,
+        delete_notebook,
+    ),
+    tags(
+        (name = "Notebooks"),
+    )
+)]
+pub struct NotebooksApiDoc;
+
+pub fn declare_routes(base_path: &str) -> axum::Router<AppState> {
+    axum::Router::new()
+        .route(base_path, axum::routing::post(create_notebook))
+        .route(base_path, axum::routing::get(get_notebooks))
+        .route(
+            &format!("{base_path}/{{notebook_id}}"),
+            axum::routing::get(get_notebook),
+        )
+        .route(
+            &format!("{base_path}/{{notebook_id}}"),
+            axum::routing::put(update_notebook),
+        )
+        .route(
+            &format!("{base_path}/{{notebook_id}}"),
+            axum::routing::delete(delete_notebook),
+        )
+}
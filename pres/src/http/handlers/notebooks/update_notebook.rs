@@ -0,0 +1,65 @@
+use axum::extract::{Json, Path, State};
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::NotebookDto,
+    types::error::PresentationError,
+};
+use app::{state::AppState, usecases::notebook::UpdateNotebookInput};
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct UpdateNotebookHttpRequestBody {
+    /// Parent notebook, `null` moves the notebook to the root level.
+    pub(crate) parent_id: Option<Uuid>,
+    pub(crate) name: String,
+}
+
+/// Renames and/or moves a Notebook.
+#[utoipa::path(
+    tag = "Notebooks",
+    put,
+    path = "/{notebook_id}",
+    params(
+        ("notebook_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = NotebookDto),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn update_notebook(
+    State(state): State<AppState>,
+    Path(notebook_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+    Json(payload): Json<UpdateNotebookHttpRequestBody>,
+) -> Result<Json<NotebookDto>, PresentationError> {
+    let AppState {
+        update_notebook_use_case,
+        ..
+    } = state;
+
+    let input = get_use_case_input(notebook_id, payload);
+    let notebook = update_notebook_use_case.execute(auth_ctx, input).await?;
+
+    let notebook_dto = notebook.into();
+    Ok(Json(notebook_dto))
+}
+
+fn get_use_case_input(
+    notebook_id: Uuid,
+    payload: UpdateNotebookHttpRequestBody,
+) -> UpdateNotebookInput {
+    UpdateNotebookInput {
+        notebook_id,
+        parent_id: payload.parent_id,
+        name: payload.name,
+    }
+}
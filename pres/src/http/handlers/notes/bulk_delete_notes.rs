@@ -0,0 +1,54 @@
+use axum::{extract::State, Json};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{auth::extractors::AuthContextAccessExtractor, types::error::PresentationError};
+use app::{state::AppState, usecases::note::BulkDeleteNotesInput};
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct BulkDeleteNotesHttpRequestBody {
+    pub(crate) note_ids: Vec<Uuid>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct BulkDeleteNotesHttpResponseBody {
+    pub(crate) note_ids: Vec<Uuid>,
+}
+
+/// Moves several Notes to the trash at once.
+#[utoipa::path(
+    tag = "Notes",
+    post,
+    path = "/bulk/delete",
+    description = "Either all notes are trashed or none. At most 500 note ids are allowed per request.",
+    responses(
+        (status = 200, description = "Success", body = BulkDeleteNotesHttpResponseBody),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn bulk_delete_notes(
+    State(state): State<AppState>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+    Json(payload): Json<BulkDeleteNotesHttpRequestBody>,
+) -> Result<Json<BulkDeleteNotesHttpResponseBody>, PresentationError> {
+    let AppState {
+        bulk_delete_notes_use_case,
+        ..
+    } = state;
+
+    let input = BulkDeleteNotesInput {
+        note_ids: payload.note_ids,
+    };
+    let note_ids = bulk_delete_notes_use_case.execute(auth_ctx, input).await?;
+
+    let http_res = BulkDeleteNotesHttpResponseBody { note_ids };
+    Ok(Json(http_res))
+}
@@ -0,0 +1,61 @@
+use axum::{extract::State, Json};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::NoteDto, types::error::PresentationError,
+};
+use app::{state::AppState, usecases::note::BulkMoveNotesInput};
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct BulkMoveNotesHttpRequestBody {
+    pub(crate) note_ids: Vec<Uuid>,
+    /// Target notebook, `null` moves the notes back to the root level.
+    pub(crate) notebook_id: Option<Uuid>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct BulkMoveNotesHttpResponseBody {
+    pub(crate) notes: Vec<NoteDto>,
+}
+
+/// Moves several Notes into a Notebook at once.
+#[utoipa::path(
+    tag = "Notes",
+    post,
+    path = "/bulk/move",
+    description = "Either all notes are moved or none. At most 500 note ids are allowed per request.",
+    responses(
+        (status = 200, description = "Success", body = BulkMoveNotesHttpResponseBody),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn bulk_move_notes(
+    State(state): State<AppState>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+    Json(payload): Json<BulkMoveNotesHttpRequestBody>,
+) -> Result<Json<BulkMoveNotesHttpResponseBody>, PresentationError> {
+    let AppState {
+        bulk_move_notes_use_case,
+        ..
+    } = state;
+
+    let input = BulkMoveNotesInput {
+        note_ids: payload.note_ids,
+        notebook_id: payload.notebook_id,
+    };
+    let notes = bulk_move_notes_use_case.execute(auth_ctx, input).await?;
+
+    let http_res = BulkMoveNotesHttpResponseBody {
+        notes: notes.into_iter().map(|note| note.into()).collect(),
+    };
+    Ok(Json(http_res))
+}
@@ -0,0 +1,155 @@
+use axum::{extract::State, Json};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::NoteDto, types::error::PresentationError,
+};
+use app::{
+    state::AppState,
+    usecases::note::{
+        BulkNoteOperation, BulkNoteOperationResult, BulkNoteOperationsInput, CreateNoteInput,
+        DeleteNoteInput, UpdateNoteInput,
+    },
+};
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct BulkNoteOperationsHttpRequestBody {
+    pub(crate) operations: Vec<BulkNoteOperationHttpRequestBody>,
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub(crate) enum BulkNoteOperationHttpRequestBody {
+    Create {
+        notebook_id: Option<Uuid>,
+        title: String,
+        content: String,
+    },
+    Update {
+        note_id: Uuid,
+        title: String,
+        content: String,
+        /// Rejects the update unless the note is still at this version.
+        expected_version: Option<u32>,
+    },
+    Delete {
+        note_id: Uuid,
+        /// Rejects the deletion unless the note is still at this version.
+        expected_version: Option<u32>,
+    },
+}
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct BulkNoteOperationsHttpResponseBody {
+    /// One result per operation, in request order.
+    pub(crate) results: Vec<BulkNoteOperationHttpResult>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub(crate) enum BulkNoteOperationHttpResult {
+    Created { note: NoteDto },
+    Updated { note: NoteDto },
+    Deleted { note_id: Uuid },
+}
+
+/// Creates, updates and deletes Notes in a single atomic batch.
+#[utoipa::path(
+    tag = "Notes",
+    post,
+    path = "/bulk",
+    description = "Operations run in order and either all of them are applied or none. \
+The error of a failing operation is prefixed with its index, e.g. `Operation #3: ...`. \
+At most 500 operations are allowed per request.",
+    request_body = BulkNoteOperationsHttpRequestBody,
+    responses(
+        (status = 200, description = "Success", body = BulkNoteOperationsHttpResponseBody),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not Found"),
+        (status = 409, description = "Conflict"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn bulk_note_operations(
+    State(state): State<AppState>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+    Json(payload): Json<BulkNoteOperationsHttpRequestBody>,
+) -> Result<Json<BulkNoteOperationsHttpResponseBody>, PresentationError> {
+    let AppState {
+        bulk_note_operations_use_case,
+        ..
+    } = state;
+
+    let input = BulkNoteOperationsInput {
+        operations: payload
+            .operations
+            .into_iter()
+            .map(|operation| operation.into())
+            .collect(),
+    };
+    let results = bulk_note_operations_use_case
+        .execute(auth_ctx, input)
+        .await?;
+
+    let http_res = BulkNoteOperationsHttpResponseBody {
+        results: results.into_iter().map(|result| result.into()).collect(),
+    };
+    Ok(Json(http_res))
+}
+
+impl From<BulkNoteOperationHttpRequestBody> for BulkNoteOperation {
+    fn from(payload: BulkNoteOperationHttpRequestBody) -> Self {
+        match payload {
+            BulkNoteOperationHttpRequestBody::Create {
+                notebook_id,
+                title,
+                content,
+            } => BulkNoteOperation::Create(CreateNoteInput {
+                notebook_id,
+                title,
+                content,
+            }),
+            BulkNoteOperationHttpRequestBody::Update {
+                note_id,
+                title,
+                content,
+                expected_version,
+            } => BulkNoteOperation::Update(UpdateNoteInput {
+                note_id,
+                title,
+                content,
+                expected_versions: expected_version.into_iter().collect(),
+            }),
+            BulkNoteOperationHttpRequestBody::Delete {
+                note_id,
+                expected_version,
+            } => BulkNoteOperation::Delete(DeleteNoteInput {
+                note_id,
+                expected_versions: expected_version.into_iter().collect(),
+            }),
+        }
+    }
+}
+
+impl From<BulkNoteOperationResult> for BulkNoteOperationHttpResult {
+    fn from(result: BulkNoteOperationResult) -> Self {
+        match result {
+            BulkNoteOperationResult::Created(note) => {
+                BulkNoteOperationHttpResult::Created { note: note.into() }
+            }
+            BulkNoteOperationResult::Updated(note) => {
+                BulkNoteOperationHttpResult::Updated { note: note.into() }
+            }
+            BulkNoteOperationResult::Deleted { note_id } => {
+                BulkNoteOperationHttpResult::Deleted { note_id }
+            }
+        }
+    }
+}
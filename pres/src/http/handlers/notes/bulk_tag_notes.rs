@@ -0,0 +1,60 @@
+use axum::{extract::State, Json};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::NoteDto, types::error::PresentationError,
+};
+use app::{state::AppState, usecases::note::BulkTagNotesInput};
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct BulkTagNotesHttpRequestBody {
+    pub(crate) note_ids: Vec<Uuid>,
+    pub(crate) tag_id: Uuid,
+}
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct BulkTagNotesHttpResponseBody {
+    pub(crate) notes: Vec<NoteDto>,
+}
+
+/// Attaches a Tag to several Notes at once.
+#[utoipa::path(
+    tag = "Notes",
+    post,
+    path = "/bulk/tags",
+    description = "Either all notes are tagged or none. At most 500 note ids are allowed per request.",
+    responses(
+        (status = 200, description = "Success", body = BulkTagNotesHttpResponseBody),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn bulk_tag_notes(
+    State(state): State<AppState>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+    Json(payload): Json<BulkTagNotesHttpRequestBody>,
+) -> Result<Json<BulkTagNotesHttpResponseBody>, PresentationError> {
+    let AppState {
+        bulk_tag_notes_use_case,
+        ..
+    } = state;
+
+    let input = BulkTagNotesInput {
+        note_ids: payload.note_ids,
+        tag_id: payload.tag_id,
+    };
+    let notes = bulk_tag_notes_use_case.execute(auth_ctx, input).await?;
+
+    let http_res = BulkTagNotesHttpResponseBody {
+        notes: notes.into_iter().map(|note| note.into()).collect(),
+    };
+    Ok(Json(http_res))
+}
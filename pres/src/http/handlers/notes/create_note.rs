@@ -1,6 +1,7 @@
 use axum::{extract::State, Json};
 use serde::Deserialize;
 use utoipa::ToSchema;
+use uuid::Uuid;
 
 use crate::{
     auth::extractors::AuthContextAccessExtractor, dtos::NoteDto, types::error::PresentationError,
@@ -9,6 +10,7 @@ use app::{state::AppState, usecases::note::CreateNoteInput};
 
 #[derive(Deserialize, ToSchema)]
 pub(crate) struct CreateNoteHttpRequestBody {
+    pub(crate) notebook_id: Option<Uuid>,
     pub(crate) title: String,
     pub(crate) content: String,
 }
@@ -21,6 +23,7 @@ pub(crate) struct CreateNoteHttpRequestBody {
     responses(
         (status = 200, description = "Success", body = NoteDto),
         (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not Found"),
         (status = 500, description = "Failure"),
     ),
     security(
@@ -47,6 +50,7 @@ pub(crate) async fn create_note(
 impl From<CreateNoteHttpRequestBody> for CreateNoteInput {
     fn from(payload: CreateNoteHttpRequestBody) -> Self {
         Self {
+            notebook_id: payload.notebook_id,
             title: payload.title,
             content: payload.content,
         }
@@ -0,0 +1,90 @@
+use axum::{
+    extract::{Multipart, Query, State},
+    Json,
+};
+use utoipa::ToSchema;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor,
+    dtos::NoteImportJobDto,
+    types::{error::PresentationError, params::NoteImportFormatParams},
+};
+use app::{state::AppState, usecases::note_import_job::CreateNoteImportJobInput};
+use common::error::AppError;
+
+/// Multipart form accepted by [`create_note_import_job`], for documentation purposes only.
+#[allow(dead_code)]
+#[derive(ToSchema)]
+pub(crate) struct CreateNoteImportJobHttpRequestBody {
+    #[schema(value_type = String, format = Binary)]
+    pub(crate) file: Vec<u8>,
+}
+
+/// Schedules the import of Notes from a file.
+#[utoipa::path(
+    tag = "Notes",
+    post,
+    path = "/import-jobs",
+    description = "Expects a single `file` field holding either a ZIP archive of Markdown files \
+        (`markdown`), an Evernote `.enex` export (`enex`), or a JSON array of \
+        `{ \"title\", \"content\", \"tags\", \"created_at\", \"updated_at\" }` objects (`json`). \
+        Notes are imported in the background, the returned job reporting progress along with \
+        the notes that couldn't be imported. Each note becomes a new root level note keeping its \
+        original timestamps, Evernote contents being converted to Markdown. Tags are matched by \
+        name and created when missing. Valid notes are imported all at once.",
+    params(
+        NoteImportFormatParams,
+    ),
+    request_body(content = CreateNoteImportJobHttpRequestBody, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Success", body = NoteImportJobDto),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn create_note_import_job(
+    State(state): State<AppState>,
+    Query(format): Query<NoteImportFormatParams>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+    mut multipart: Multipart,
+) -> Result<Json<NoteImportJobDto>, PresentationError> {
+    const MISSING_FILE_ERR_STR: &str = "Missing multipart file field!";
+    const INVALID_FILE_ERR_STR: &str = "Invalid multipart file field!";
+
+    let AppState {
+        create_note_import_job_use_case,
+        ..
+    } = state;
+
+    let field = loop {
+        let field = multipart
+            .next_field()
+            .await
+            .map_err(|err| {
+                AppError::bad_request_with_private(INVALID_FILE_ERR_STR, err.body_text())
+            })?
+            .ok_or_else(|| AppError::bad_request(MISSING_FILE_ERR_STR))?;
+        if field.name() == Some("file") {
+            break field;
+        }
+    };
+    let data = field
+        .bytes()
+        .await
+        .map_err(|err| AppError::bad_request_with_private(INVALID_FILE_ERR_STR, err.body_text()))?;
+
+    let input = CreateNoteImportJobInput {
+        format: format.try_into()?,
+        data: data.to_vec(),
+    };
+    let job = create_note_import_job_use_case
+        .execute(auth_ctx, input)
+        .await?;
+
+    let job_dto = job.into();
+    Ok(Json(job_dto))
+}
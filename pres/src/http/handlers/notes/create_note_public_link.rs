@@ -0,0 +1,63 @@
+use axum::extract::{Json, Path, State};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::NotePublicLinkDto,
+    types::error::PresentationError,
+};
+use app::{state::AppState, usecases::note_public_link::CreateNotePublicLinkInput};
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct CreateNotePublicLinkHttpRequestBody {
+    /// The link never expires when left out.
+    #[serde(default)]
+    pub(crate) expires_at: Option<DateTime<Utc>>,
+}
+
+/// Creates a public read-only link to a Note.
+#[utoipa::path(
+    tag = "Notes",
+    post,
+    path = "/{note_id}/public-link",
+    description = "Anyone holding the token can read the note at `/public/notes/{token}`. \
+        Creating a link replaces the previous one, invalidating its token.",
+    params(
+        ("note_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = NotePublicLinkDto),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn create_note_public_link(
+    State(state): State<AppState>,
+    Path(note_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+    Json(payload): Json<CreateNotePublicLinkHttpRequestBody>,
+) -> Result<Json<NotePublicLinkDto>, PresentationError> {
+    let AppState {
+        create_note_public_link_use_case,
+        ..
+    } = state;
+
+    let input = CreateNotePublicLinkInput {
+        note_id,
+        expires_at: payload.expires_at,
+    };
+    let link = create_note_public_link_use_case
+        .execute(auth_ctx, input)
+        .await?;
+
+    let link_dto = link.into();
+    Ok(Json(link_dto))
+}
@@ -0,0 +1,47 @@
+use axum::extract::{Path, State};
+use uuid::Uuid;
+
+use crate::{auth::extractors::AuthContextAccessExtractor, types::error::PresentationError};
+use app::{state::AppState, usecases::attachment::DeleteAttachmentInput};
+
+/// Deletes a Note's attachment.
+#[utoipa::path(
+    tag = "Notes",
+    delete,
+    path = "/{note_id}/attachments/{attachment_id}",
+    params(
+        ("note_id" = Uuid, Path),
+        ("attachment_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = String),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn delete_attachment(
+    State(state): State<AppState>,
+    Path((note_id, attachment_id)): Path<(Uuid, Uuid)>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<String, PresentationError> {
+    let AppState {
+        delete_attachment_use_case,
+        ..
+    } = state;
+
+    let input = DeleteAttachmentInput {
+        note_id,
+        attachment_id,
+    };
+    delete_attachment_use_case.execute(auth_ctx, input).await?;
+
+    Ok(format!(
+        "Attachment ({}) deleted successfully.",
+        attachment_id
+    ))
+}
@@ -1,21 +1,28 @@
 use axum::extract::{Path, State};
 use uuid::Uuid;
 
-use crate::{auth::extractors::AuthContextAccessExtractor, types::error::PresentationError};
+use crate::{
+    auth::extractors::AuthContextAccessExtractor,
+    types::{error::PresentationError, etag::IfMatchExtractor},
+};
 use app::{state::AppState, usecases::note::DeleteNoteInput};
 
-/// Deletes a Note.
+/// Moves a Note to the trash.
 #[utoipa::path(
     tag = "Notes",
     delete,
     path = "/{note_id}",
+    description = "Trashed notes can be restored until they are purged or outlive the trash retention period.",
     params(
         ("note_id" = Uuid, Path),
+        ("If-Match" = Option<String>, Header, description = "Rejects the deletion unless the note still matches one of these comma-separated ETags"),
     ),
     responses(
         (status = 200, description = "Success", body = String),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
         (status = 404, description = "Not Found"),
+        (status = 412, description = "Precondition Failed"),
         (status = 500, description = "Failure"),
     ),
     security(
@@ -26,14 +33,18 @@ pub(crate) async fn delete_note(
     State(state): State<AppState>,
     Path(note_id): Path<Uuid>,
     AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+    IfMatchExtractor(expected_versions): IfMatchExtractor,
 ) -> Result<String, PresentationError> {
     let AppState {
         delete_note_use_case,
         ..
     } = state;
 
-    let input = DeleteNoteInput { note_id };
+    let input = DeleteNoteInput {
+        note_id,
+        expected_versions,
+    };
     delete_note_use_case.execute(auth_ctx, input).await?;
 
-    Ok(format!("Note ({}) deleted successfully.", note_id))
+    Ok(format!("Note ({}) moved to trash successfully.", note_id))
 }
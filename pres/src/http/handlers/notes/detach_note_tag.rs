@@ -0,0 +1,44 @@
+use axum::extract::{Json, Path, State};
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::NoteDto, types::error::PresentationError,
+};
+use app::{state::AppState, usecases::tag::DetachNoteTagInput};
+
+/// Detaches a Tag from a Note.
+#[utoipa::path(
+    tag = "Notes",
+    delete,
+    path = "/{note_id}/tags/{tag_id}",
+    params(
+        ("note_id" = Uuid, Path),
+        ("tag_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = NoteDto),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn detach_note_tag(
+    State(state): State<AppState>,
+    Path((note_id, tag_id)): Path<(Uuid, Uuid)>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Json<NoteDto>, PresentationError> {
+    let AppState {
+        detach_note_tag_use_case,
+        ..
+    } = state;
+
+    let input = DetachNoteTagInput { note_id, tag_id };
+    let note = detach_note_tag_use_case.execute(auth_ctx, input).await?;
+
+    let note_dto = note.into();
+    Ok(Json(note_dto))
+}
@@ -0,0 +1,90 @@
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderName, HeaderValue},
+};
+use uuid::Uuid;
+
+use crate::{auth::extractors::AuthContextAccessExtractor, types::error::PresentationError};
+use app::{state::AppState, usecases::attachment::DownloadAttachmentInput};
+use common::error::AppError;
+
+/// Downloads a Note's attachment.
+#[utoipa::path(
+    tag = "Notes",
+    get,
+    path = "/{note_id}/attachments/{attachment_id}",
+    params(
+        ("note_id" = Uuid, Path),
+        ("attachment_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", content_type = "application/octet-stream", body = Vec<u8>, headers(
+            ("Content-Type" = String, description = "Media type the file was uploaded with"),
+            ("Content-Disposition" = String, description = "Original file name of the attachment"),
+        )),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn download_attachment(
+    State(state): State<AppState>,
+    Path((note_id, attachment_id)): Path<(Uuid, Uuid)>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<([(HeaderName, HeaderValue); 3], Vec<u8>), PresentationError> {
+    let AppState {
+        download_attachment_use_case,
+        ..
+    } = state;
+
+    let input = DownloadAttachmentInput {
+        note_id,
+        attachment_id,
+    };
+    let (attachment, data) = download_attachment_use_case
+        .execute(auth_ctx, input)
+        .await?;
+
+    let content_type = HeaderValue::from_str(attachment.mime_type()).map_err(|err| {
+        AppError::internal_with_private("Invalid attachment media type!", err.to_string())
+    })?;
+    let content_disposition = HeaderValue::from_str(&content_disposition(attachment.file_name()))
+        .map_err(|err| {
+        AppError::internal_with_private("Invalid attachment file name!", err.to_string())
+    })?;
+    let headers = [
+        (header::CONTENT_TYPE, content_type),
+        (header::CONTENT_DISPOSITION, content_disposition),
+        // Browsers must not second-guess the declared type of user uploaded files
+        (
+            header::X_CONTENT_TYPE_OPTIONS,
+            HeaderValue::from_static("nosniff"),
+        ),
+    ];
+    Ok((headers, data))
+}
+
+/// Builds an `attachment` disposition carrying both an ASCII fallback and the UTF-8 file name
+/// (RFC 6266).
+fn content_disposition(file_name: &str) -> String {
+    let ascii_file_name: String = file_name
+        .chars()
+        .map(|c| match c {
+            ' '..='~' if c != '"' && c != '\\' => c,
+            _ => '_',
+        })
+        .collect();
+    let encoded_file_name: String = file_name
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect();
+    format!("attachment; filename=\"{ascii_file_name}\"; filename*=UTF-8''{encoded_file_name}")
+}
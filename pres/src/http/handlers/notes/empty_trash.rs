@@ -0,0 +1,32 @@
+use axum::extract::State;
+
+use crate::{auth::extractors::AuthContextAccessExtractor, types::error::PresentationError};
+use app::state::AppState;
+
+/// Permanently deletes all trashed Notes.
+#[utoipa::path(
+    tag = "Notes",
+    delete,
+    path = "/trash",
+    responses(
+        (status = 200, description = "Success", body = String),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn empty_trash(
+    State(state): State<AppState>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<String, PresentationError> {
+    let AppState {
+        empty_trash_use_case,
+        ..
+    } = state;
+
+    let count = empty_trash_use_case.execute(auth_ctx).await?;
+
+    Ok(format!("{} trashed note(s) purged successfully.", count))
+}
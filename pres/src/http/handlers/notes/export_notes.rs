@@ -0,0 +1,65 @@
+use axum::{
+    body::Body,
+    extract::{Query, State},
+    http::{header, HeaderName, HeaderValue},
+};
+use futures_util::TryStreamExt;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor,
+    types::{error::PresentationError, params::NoteArchiveFormatParams},
+};
+use app::{state::AppState, usecases::note::ExportNotesInput};
+
+/// Exports all Notes of the current user as an archive.
+#[utoipa::path(
+    tag = "Notes",
+    get,
+    path = "/export",
+    description = "Streams a ZIP archive holding one Markdown file per note, named after its \
+        title. Each file starts with a front matter block carrying the note `id`, `title`, \
+        `tags`, `created_at` and `updated_at`. Archived notes are included, trashed ones aren't.",
+    params(
+        NoteArchiveFormatParams,
+    ),
+    responses(
+        (status = 200, description = "Success", content_type = "application/zip", body = Vec<u8>, headers(
+            ("Content-Disposition" = String, description = "Suggested archive file name"),
+        )),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn export_notes(
+    State(state): State<AppState>,
+    Query(format): Query<NoteArchiveFormatParams>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<([(HeaderName, HeaderValue); 2], Body), PresentationError> {
+    let AppState {
+        export_notes_use_case,
+        ..
+    } = state;
+
+    let input = ExportNotesInput {
+        format: format.try_into()?,
+    };
+    let archive = export_notes_use_case.execute(auth_ctx, input).await?;
+
+    // Headers are sent by then, so failures can only abort the response
+    let body = Body::from_stream(archive.inspect_err(|err| err.log()));
+    let headers = [
+        (
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/zip"),
+        ),
+        (
+            header::CONTENT_DISPOSITION,
+            HeaderValue::from_static("attachment; filename=\"notes.zip\""),
+        ),
+    ];
+    Ok((headers, body))
+}
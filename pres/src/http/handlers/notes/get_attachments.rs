@@ -0,0 +1,46 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::AttachmentDto,
+    types::error::PresentationError,
+};
+use app::{state::AppState, usecases::attachment::GetAttachmentsInput};
+
+/// Retrieves a Note's attachments.
+#[utoipa::path(
+    tag = "Notes",
+    get,
+    path = "/{note_id}/attachments",
+    params(
+        ("note_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = Vec<AttachmentDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn get_attachments(
+    State(state): State<AppState>,
+    Path(note_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Json<Vec<AttachmentDto>>, PresentationError> {
+    let AppState {
+        get_attachments_use_case,
+        ..
+    } = state;
+
+    let input = GetAttachmentsInput { note_id };
+    let attachments = get_attachments_use_case.execute(auth_ctx, input).await?;
+
+    let attachment_dtos = attachments.into_iter().map(|a| a.into()).collect();
+    Ok(Json(attachment_dtos))
+}
@@ -5,7 +5,12 @@ use axum::{
 use uuid::Uuid;
 
 use crate::{
-    auth::extractors::AuthContextAccessExtractor, dtos::NoteDto, types::error::PresentationError,
+    auth::extractors::AuthContextAccessExtractor,
+    dtos::NoteDto,
+    types::{
+        error::PresentationError,
+        etag::{etag_header, ETagHeader},
+    },
 };
 use app::{state::AppState, usecases::note::GetNoteInput};
 
@@ -18,7 +23,9 @@ use app::{state::AppState, usecases::note::GetNoteInput};
         ("note_id" = Uuid, Path),
     ),
     responses(
-        (status = 200, description = "Success", body = NoteDto),
+        (status = 200, description = "Success", body = NoteDto, headers(
+            ("ETag" = String, description = "Current note version, for use with If-Match"),
+        )),
         (status = 401, description = "Unauthorized"),
         (status = 404, description = "Not Found"),
         (status = 500, description = "Failure"),
@@ -31,7 +38,7 @@ pub(crate) async fn get_note(
     State(state): State<AppState>,
     Path(note_id): Path<Uuid>,
     AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
-) -> Result<Json<NoteDto>, PresentationError> {
+) -> Result<(ETagHeader, Json<NoteDto>), PresentationError> {
     let AppState {
         get_note_use_case, ..
     } = state;
@@ -39,6 +46,7 @@ pub(crate) async fn get_note(
     let input = GetNoteInput { note_id };
     let note = get_note_use_case.execute(auth_ctx, input).await?;
 
+    let etag = etag_header(note.version());
     let note_dto = note.into();
-    Ok(Json(note_dto))
+    Ok((etag, Json(note_dto)))
 }
@@ -0,0 +1,115 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor,
+    dtos::{NoteDto, NoteTombstoneDto},
+    types::{
+        cursor::{
+            decode_sync_continuation, decode_sync_token, encode_sync_continuation,
+            encode_sync_token,
+        },
+        error::PresentationError,
+    },
+};
+use app::{state::AppState, usecases::note::GetNoteChangesInput};
+use common::error::AppError;
+use dmn::repos::note::SyncFrom;
+
+const CHANGES_DEFAULT_LIMIT: u32 = 100;
+const CHANGES_MAX_LIMIT: u32 = 1000;
+
+#[derive(Deserialize, IntoParams)]
+pub(crate) struct GetNoteChangesQueryParams {
+    /// `next_token` of the previous sync. Every note is returned without it.
+    pub(crate) since: Option<String>,
+    /// `continuation` of the previous page. Mutually exclusive with `since`.
+    pub(crate) continuation: Option<String>,
+    /// Maximum number of changes per page, 100 by default and 1000 at most.
+    pub(crate) limit: Option<u32>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct GetNoteChangesHttpResponseBody {
+    /// Created, updated and restored notes.
+    pub(crate) notes: Vec<NoteDto>,
+    /// Trashed and permanently deleted notes.
+    pub(crate) tombstones: Vec<NoteTombstoneDto>,
+    /// Token to pass as `since` for the next sync, once every page has been read.
+    pub(crate) next_token: String,
+    /// Whether more changes are left to read with `continuation`.
+    pub(crate) has_more: bool,
+    /// Token to pass as `continuation` for the next page, only set while `has_more` holds.
+    pub(crate) continuation: Option<String>,
+}
+
+/// Retrieves the Notes changed since a previous sync.
+#[utoipa::path(
+    tag = "Notes",
+    get,
+    path = "/changes",
+    description = "Changes may be reported more than once, clients should apply them idempotently. \
+        Changes are returned in pages, the following ones being read with `continuation` until \
+        `has_more` turns false.",
+    params(
+        GetNoteChangesQueryParams,
+    ),
+    responses(
+        (status = 200, description = "Success", body = GetNoteChangesHttpResponseBody),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn get_note_changes(
+    State(state): State<AppState>,
+    Query(query): Query<GetNoteChangesQueryParams>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Json<GetNoteChangesHttpResponseBody>, PresentationError> {
+    let AppState {
+        get_note_changes_use_case,
+        ..
+    } = state;
+
+    let from = match (query.since, query.continuation) {
+        (Some(_), Some(_)) => {
+            return Err(AppError::bad_request(
+                "The since and continuation parameters are mutually exclusive!",
+            )
+            .into())
+        }
+        (since, None) => SyncFrom::Start(since.as_deref().map(decode_sync_token).transpose()?),
+        (None, Some(continuation)) => {
+            SyncFrom::Continuation(decode_sync_continuation(&continuation)?)
+        }
+    };
+    let limit = query.limit.unwrap_or(CHANGES_DEFAULT_LIMIT);
+    if !(1..=CHANGES_MAX_LIMIT).contains(&limit) {
+        return Err(AppError::bad_request(format!(
+            "The limit must be between 1 and {CHANGES_MAX_LIMIT}!"
+        ))
+        .into());
+    }
+    let input = GetNoteChangesInput { from, limit };
+    let dmn_res = get_note_changes_use_case.execute(auth_ctx, input).await?;
+
+    let http_res = GetNoteChangesHttpResponseBody {
+        notes: dmn_res.notes.into_iter().map(|note| note.into()).collect(),
+        tombstones: dmn_res
+            .tombstones
+            .into_iter()
+            .map(|tombstone| tombstone.into())
+            .collect(),
+        next_token: encode_sync_token(&dmn_res.next_token),
+        has_more: dmn_res.continuation.is_some(),
+        continuation: dmn_res.continuation.as_ref().map(encode_sync_continuation),
+    };
+    Ok(Json(http_res))
+}
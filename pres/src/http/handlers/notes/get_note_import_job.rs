@@ -0,0 +1,48 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::NoteImportJobDto,
+    types::error::PresentationError,
+};
+use app::{state::AppState, usecases::note_import_job::GetNoteImportJobInput};
+
+/// Retrieves a Note Import Job, reporting its progress.
+#[utoipa::path(
+    tag = "Notes",
+    get,
+    path = "/import-jobs/{job_id}",
+    params(
+        ("job_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = NoteImportJobDto),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn get_note_import_job(
+    State(state): State<AppState>,
+    Path(job_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Json<NoteImportJobDto>, PresentationError> {
+    let AppState {
+        get_note_import_job_use_case,
+        ..
+    } = state;
+
+    let input = GetNoteImportJobInput { job_id };
+    let job = get_note_import_job_use_case
+        .execute(auth_ctx, input)
+        .await?;
+
+    let job_dto = job.into();
+    Ok(Json(job_dto))
+}
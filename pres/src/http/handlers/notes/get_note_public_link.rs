@@ -0,0 +1,49 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::NotePublicLinkDto,
+    types::error::PresentationError,
+};
+use app::{state::AppState, usecases::note_public_link::GetNotePublicLinkInput};
+
+/// Retrieves a Note's public link.
+#[utoipa::path(
+    tag = "Notes",
+    get,
+    path = "/{note_id}/public-link",
+    params(
+        ("note_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = NotePublicLinkDto),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn get_note_public_link(
+    State(state): State<AppState>,
+    Path(note_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Json<NotePublicLinkDto>, PresentationError> {
+    let AppState {
+        get_note_public_link_use_case,
+        ..
+    } = state;
+
+    let input = GetNotePublicLinkInput { note_id };
+    let link = get_note_public_link_use_case
+        .execute(auth_ctx, input)
+        .await?;
+
+    let link_dto = link.into();
+    Ok(Json(link_dto))
+}
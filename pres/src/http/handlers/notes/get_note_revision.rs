@@ -0,0 +1,60 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor,
+    dtos::{NoteRevisionDiffDto, NoteRevisionDto},
+    types::error::PresentationError,
+};
+use app::{state::AppState, usecases::note::GetNoteRevisionInput};
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct GetNoteRevisionHttpResponseBody {
+    pub(crate) revision: NoteRevisionDto,
+    /// Line-based changes from the revision to the current version of the note.
+    pub(crate) diff: NoteRevisionDiffDto,
+}
+
+/// Retrieves a Note revision along with its diff against the current version.
+#[utoipa::path(
+    tag = "Notes",
+    get,
+    path = "/{note_id}/revisions/{revision}",
+    params(
+        ("note_id" = Uuid, Path),
+        ("revision" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = GetNoteRevisionHttpResponseBody),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn get_note_revision(
+    State(state): State<AppState>,
+    Path((note_id, revision)): Path<(Uuid, u32)>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Json<GetNoteRevisionHttpResponseBody>, PresentationError> {
+    let AppState {
+        get_note_revision_use_case,
+        ..
+    } = state;
+
+    let input = GetNoteRevisionInput { note_id, revision };
+    let output = get_note_revision_use_case.execute(auth_ctx, input).await?;
+
+    let http_res = GetNoteRevisionHttpResponseBody {
+        revision: output.revision.into(),
+        diff: output.diff.into(),
+    };
+    Ok(Json(http_res))
+}
@@ -0,0 +1,68 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor,
+    dtos::NoteRevisionDto,
+    types::{error::PresentationError, params::PaginationParams},
+};
+use app::{state::AppState, usecases::note::GetNoteRevisionsInput};
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct GetNoteRevisionsHttpResponseBody {
+    pub(crate) revisions: Vec<NoteRevisionDto>,
+    pub(crate) count: u32,
+}
+
+/// Retrieves the revision history of a Note, newest first.
+#[utoipa::path(
+    tag = "Notes",
+    get,
+    path = "/{note_id}/revisions",
+    params(
+        ("note_id" = Uuid, Path),
+        PaginationParams,
+    ),
+    responses(
+        (status = 200, description = "Success", body = GetNoteRevisionsHttpResponseBody),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn get_note_revisions(
+    State(state): State<AppState>,
+    Path(note_id): Path<Uuid>,
+    Query(pagination): Query<PaginationParams>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Json<GetNoteRevisionsHttpResponseBody>, PresentationError> {
+    let AppState {
+        get_note_revisions_use_case,
+        ..
+    } = state;
+
+    let input = GetNoteRevisionsInput {
+        note_id,
+        pagination: pagination.into(),
+    };
+    let dmn_res = get_note_revisions_use_case.execute(auth_ctx, input).await?;
+
+    let revision_dtos = dmn_res
+        .revisions
+        .into_iter()
+        .map(|revision| revision.into())
+        .collect();
+    let http_res = GetNoteRevisionsHttpResponseBody {
+        revisions: revision_dtos,
+        count: dmn_res.count,
+    };
+    Ok(Json(http_res))
+}
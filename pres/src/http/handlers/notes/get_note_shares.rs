@@ -0,0 +1,46 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::NoteShareDto,
+    types::error::PresentationError,
+};
+use app::{state::AppState, usecases::note_share::GetNoteSharesInput};
+
+/// Retrieves the collaborators a Note is shared with.
+#[utoipa::path(
+    tag = "Notes",
+    get,
+    path = "/{note_id}/shares",
+    params(
+        ("note_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = Vec<NoteShareDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn get_note_shares(
+    State(state): State<AppState>,
+    Path(note_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Json<Vec<NoteShareDto>>, PresentationError> {
+    let AppState {
+        get_note_shares_use_case,
+        ..
+    } = state;
+
+    let input = GetNoteSharesInput { note_id };
+    let shares = get_note_shares_use_case.execute(auth_ctx, input).await?;
+
+    let share_dtos = shares.into_iter().map(|share| share.into()).collect();
+    Ok(Json(share_dtos))
+}
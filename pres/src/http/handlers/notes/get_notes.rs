@@ -8,15 +8,25 @@ use utoipa::ToSchema;
 use crate::{
     auth::extractors::AuthContextAccessExtractor,
     dtos::NoteDto,
-    types::{error::PresentationError, params::PaginationParams},
+    types::{
+        cursor::encode_page_cursor,
+        error::PresentationError,
+        params::{
+            CursorPaginationParams, NoteFlagFilterParams, NoteRangeFilter, NoteRangeFilterParams,
+            NoteSortParams, NotebookFilterParams, TagFilterParams,
+        },
+    },
 };
 use app::{state::AppState, usecases::note::GetNotesInput};
-use dmn::repos::note::GetNotesResponse;
+use common::{error::AppError, params::Pagination};
+use dmn::repos::note::{GetNotesFilter, GetNotesResponse, NoteSort, NoteSortField};
 
 #[derive(Serialize, ToSchema)]
 pub(crate) struct GetNotesHttpResponseBody {
     pub(crate) notes: Vec<NoteDto>,
     pub(crate) count: u32,
+    /// Cursor of the next page with cursor pagination, `null` on the last page.
+    pub(crate) next_cursor: Option<String>,
 }
 
 /// Retrieves multiple Notes.
@@ -24,11 +34,20 @@ pub(crate) struct GetNotesHttpResponseBody {
     tag = "Notes",
     get,
     path = "/",
+    description = "Pinned notes are listed first, archived notes are left out unless requested.<br />\
+        Pages are addressed by cursor unless `skip` is given. Cursor pages are ordered by creation \
+        date only, ignoring pinning.",
     params(
-        PaginationParams,
+        CursorPaginationParams,
+        TagFilterParams,
+        NotebookFilterParams,
+        NoteFlagFilterParams,
+        NoteRangeFilterParams,
+        NoteSortParams,
     ),
     responses(
         (status = 200, description = "Success", body = GetNotesHttpResponseBody),
+        (status = 400, description = "Bad Request"),
         (status = 401, description = "Unauthorized"),
         (status = 500, description = "Failure"),
     ),
@@ -36,30 +55,65 @@ pub(crate) struct GetNotesHttpResponseBody {
         ("bearerAuth" = [])
     ),
 )]
+#[allow(clippy::too_many_arguments)]
 pub(crate) async fn get_notes(
     State(state): State<AppState>,
-    Query(pagination): Query<PaginationParams>,
+    Query(pagination): Query<CursorPaginationParams>,
+    Query(tag_filter): Query<TagFilterParams>,
+    Query(notebook_filter): Query<NotebookFilterParams>,
+    Query(flag_filter): Query<NoteFlagFilterParams>,
+    Query(range_filter): Query<NoteRangeFilterParams>,
+    Query(sort): Query<NoteSortParams>,
     AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
 ) -> Result<Json<GetNotesHttpResponseBody>, PresentationError> {
     let AppState {
         get_notes_use_case, ..
     } = state;
 
-    let input = get_use_case_input(pagination);
+    let input = get_use_case_input(
+        pagination,
+        tag_filter,
+        notebook_filter,
+        flag_filter,
+        range_filter,
+        sort,
+    )?;
     let dmn_res = get_notes_use_case.execute(auth_ctx, input).await?;
 
-    let note_dtos = dmn_res.notes.into_iter().map(|note| note.into()).collect();
-    let http_res = GetNotesHttpResponseBody {
-        notes: note_dtos,
-        count: dmn_res.count,
-    };
-    Ok(Json(http_res))
+    Ok(Json(dmn_res.into()))
 }
 
-fn get_use_case_input(pagination: PaginationParams) -> GetNotesInput {
-    GetNotesInput {
-        pagination: pagination.into(),
+fn get_use_case_input(
+    pagination: CursorPaginationParams,
+    tag_filter: TagFilterParams,
+    notebook_filter: NotebookFilterParams,
+    flag_filter: NoteFlagFilterParams,
+    range_filter: NoteRangeFilterParams,
+    sort: NoteSortParams,
+) -> Result<GetNotesInput, PresentationError> {
+    let pagination = Pagination::try_from(pagination)?;
+    let sort = NoteSort::try_from(sort)?;
+    if matches!(pagination, Pagination::Cursor(_)) && sort.field != NoteSortField::CreatedAt {
+        return Err(AppError::bad_request(
+            "Cursor pagination only supports sorting by created_at, use skip instead!",
+        )
+        .into());
     }
+    let range_filter = NoteRangeFilter::try_from(range_filter)?;
+    let filter = GetNotesFilter {
+        tags: tag_filter.try_into()?,
+        notebook: notebook_filter.into(),
+        archived: flag_filter.archived.unwrap_or_default().into(),
+        favorite: flag_filter.favorite,
+        created: range_filter.created,
+        updated: range_filter.updated,
+        title_prefix: range_filter.title_prefix,
+    };
+    Ok(GetNotesInput {
+        pagination,
+        filter,
+        sort,
+    })
 }
 
 impl From<GetNotesResponse> for GetNotesHttpResponseBody {
@@ -68,6 +122,7 @@ impl From<GetNotesResponse> for GetNotesHttpResponseBody {
         Self {
             notes,
             count: dmn_res.count,
+            next_cursor: dmn_res.next_cursor.as_ref().map(encode_page_cursor),
         }
     }
 }
@@ -0,0 +1,59 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor,
+    dtos::NoteDto,
+    types::{error::PresentationError, params::PaginationParams},
+};
+use app::{state::AppState, usecases::note_share::GetSharedNotesInput};
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct GetSharedNotesHttpResponseBody {
+    pub(crate) notes: Vec<NoteDto>,
+    pub(crate) count: u32,
+}
+
+/// Retrieves the Notes other users shared with you, most recently shared first.
+#[utoipa::path(
+    tag = "Notes",
+    get,
+    path = "/shared-with-me",
+    params(
+        PaginationParams,
+    ),
+    responses(
+        (status = 200, description = "Success", body = GetSharedNotesHttpResponseBody),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn get_shared_notes(
+    State(state): State<AppState>,
+    Query(pagination): Query<PaginationParams>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Json<GetSharedNotesHttpResponseBody>, PresentationError> {
+    let AppState {
+        get_shared_notes_use_case,
+        ..
+    } = state;
+
+    let input = GetSharedNotesInput {
+        pagination: pagination.into(),
+    };
+    let dmn_res = get_shared_notes_use_case.execute(auth_ctx, input).await?;
+
+    let note_dtos = dmn_res.notes.into_iter().map(|note| note.into()).collect();
+    let http_res = GetSharedNotesHttpResponseBody {
+        notes: note_dtos,
+        count: dmn_res.count,
+    };
+    Ok(Json(http_res))
+}
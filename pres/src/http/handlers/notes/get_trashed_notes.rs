@@ -0,0 +1,59 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor,
+    dtos::NoteDto,
+    types::{error::PresentationError, params::PaginationParams},
+};
+use app::{state::AppState, usecases::note::GetTrashedNotesInput};
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct GetTrashedNotesHttpResponseBody {
+    pub(crate) notes: Vec<NoteDto>,
+    pub(crate) count: u32,
+}
+
+/// Retrieves the trashed Notes, most recently trashed first.
+#[utoipa::path(
+    tag = "Notes",
+    get,
+    path = "/trash",
+    params(
+        PaginationParams,
+    ),
+    responses(
+        (status = 200, description = "Success", body = GetTrashedNotesHttpResponseBody),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn get_trashed_notes(
+    State(state): State<AppState>,
+    Query(pagination): Query<PaginationParams>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Json<GetTrashedNotesHttpResponseBody>, PresentationError> {
+    let AppState {
+        get_trashed_notes_use_case,
+        ..
+    } = state;
+
+    let input = GetTrashedNotesInput {
+        pagination: pagination.into(),
+    };
+    let dmn_res = get_trashed_notes_use_case.execute(auth_ctx, input).await?;
+
+    let note_dtos = dmn_res.notes.into_iter().map(|note| note.into()).collect();
+    let http_res = GetTrashedNotesHttpResponseBody {
+        notes: note_dtos,
+        count: dmn_res.count,
+    };
+    Ok(Json(http_res))
+}
@@ -0,0 +1,134 @@
+use axum::{
+    extract::{Multipart, Query, State},
+    Json,
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor,
+    dtos::NoteDto,
+    types::{error::PresentationError, params::NoteArchiveFormatParams},
+};
+use app::{
+    state::AppState,
+    usecases::note::{FailedNoteFile, ImportNotesInput, ImportedNoteFile},
+};
+use common::error::AppError;
+
+/// Multipart form accepted by [`import_notes`], for documentation purposes only.
+#[allow(dead_code)]
+#[derive(ToSchema)]
+pub(crate) struct ImportNotesHttpRequestBody {
+    #[schema(value_type = String, format = Binary)]
+    pub(crate) file: Vec<u8>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct ImportNotesHttpResponseBody {
+    pub(crate) imported: Vec<ImportedNoteFileHttpResponseBody>,
+    /// Files that couldn't be imported, the others being imported regardless.
+    pub(crate) failed: Vec<FailedNoteFileHttpResponseBody>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct ImportedNoteFileHttpResponseBody {
+    pub(crate) file_name: String,
+    pub(crate) note: NoteDto,
+}
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct FailedNoteFileHttpResponseBody {
+    pub(crate) file_name: String,
+    pub(crate) error: String,
+}
+
+/// Imports Notes from an archive.
+#[utoipa::path(
+    tag = "Notes",
+    post,
+    path = "/import",
+    description = "Expects a single `file` field holding a ZIP archive of Markdown files, as \
+        produced by `/notes/export`. Each file becomes a new root level note, its title and tags \
+        being read from the front matter if any, the file name being used as title otherwise. \
+        Tags are matched by name and created when missing. Valid files are imported all at once, \
+        invalid ones being reported without failing the import.",
+    params(
+        NoteArchiveFormatParams,
+    ),
+    request_body(content = ImportNotesHttpRequestBody, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Success", body = ImportNotesHttpResponseBody),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn import_notes(
+    State(state): State<AppState>,
+    Query(format): Query<NoteArchiveFormatParams>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+    mut multipart: Multipart,
+) -> Result<Json<ImportNotesHttpResponseBody>, PresentationError> {
+    const MISSING_FILE_ERR_STR: &str = "Missing multipart file field!";
+    const INVALID_FILE_ERR_STR: &str = "Invalid multipart file field!";
+
+    let AppState {
+        import_notes_use_case,
+        ..
+    } = state;
+
+    let field = loop {
+        let field = multipart
+            .next_field()
+            .await
+            .map_err(|err| {
+                AppError::bad_request_with_private(INVALID_FILE_ERR_STR, err.body_text())
+            })?
+            .ok_or_else(|| AppError::bad_request(MISSING_FILE_ERR_STR))?;
+        if field.name() == Some("file") {
+            break field;
+        }
+    };
+    let data = field
+        .bytes()
+        .await
+        .map_err(|err| AppError::bad_request_with_private(INVALID_FILE_ERR_STR, err.body_text()))?;
+
+    let input = ImportNotesInput {
+        format: format.try_into()?,
+        data: data.to_vec(),
+    };
+    let dmn_res = import_notes_use_case.execute(auth_ctx, input).await?;
+
+    let http_res = ImportNotesHttpResponseBody {
+        imported: dmn_res
+            .imported
+            .into_iter()
+            .map(|file| file.into())
+            .collect(),
+        failed: dmn_res.failed.into_iter().map(|file| file.into()).collect(),
+    };
+    Ok(Json(http_res))
+}
+
+impl From<ImportedNoteFile> for ImportedNoteFileHttpResponseBody {
+    fn from(file: ImportedNoteFile) -> Self {
+        Self {
+            file_name: file.file_name,
+            note: file.note.into(),
+        }
+    }
+}
+
+impl From<FailedNoteFile> for FailedNoteFileHttpResponseBody {
+    fn from(file: FailedNoteFile) -> Self {
+        Self {
+            file_name: file.file_name,
+            error: file.error.public_info.clone(),
+        }
+    }
+}
@@ -1,18 +1,93 @@
+mod attach_note_tag;
+mod bulk_delete_notes;
+mod bulk_move_notes;
+mod bulk_note_operations;
+mod bulk_tag_notes;
 mod create_note;
+mod create_note_import_job;
+mod create_note_public_link;
+mod delete_attachment;
 mod delete_note;
+mod detach_note_tag;
+mod download_attachment;
+mod empty_trash;
+mod export_notes;
+mod get_attachments;
 mod get_note;
+mod get_note_changes;
+mod get_note_import_job;
+mod get_note_public_link;
+mod get_note_revision;
+mod get_note_revisions;
+mod get_note_shares;
 mod get_notes;
+mod get_shared_notes;
+mod get_trashed_notes;
+mod import_notes;
+mod move_note;
+mod patch_note;
+mod purge_note;
+mod restore_note;
+mod restore_note_revision;
+mod revoke_note_public_link;
+mod revoke_note_share;
+mod search_notes;
+mod set_note_flag;
+mod share_note;
+mod subscribe_note_events;
 mod update_note;
+mod update_note_share;
+mod upload_attachment;
 
+use attach_note_tag::*;
+use bulk_delete_notes::*;
+use bulk_move_notes::*;
+use bulk_note_operations::*;
+use bulk_tag_notes::*;
 use create_note::*;
+use create_note_import_job::*;
+use create_note_public_link::*;
+use delete_attachment::*;
 use delete_note::*;
+use detach_note_tag::*;
+use download_attachment::*;
+use empty_trash::*;
+use export_notes::*;
+use get_attachments::*;
 use get_note::*;
+use get_note_changes::*;
+use get_note_import_job::*;
+use get_note_public_link::*;
+use get_note_revision::*;
+use get_note_revisions::*;
+use get_note_shares::*;
 use get_notes::*;
+use get_shared_notes::*;
+use get_trashed_notes::*;
+use import_notes::*;
+use move_note::*;
+use patch_note::*;
+use purge_note::*;
+use restore_note::*;
+use restore_note_revision::*;
+use revoke_note_public_link::*;
+use revoke_note_share::*;
+use search_notes::*;
+use set_note_flag::*;
+use share_note::*;
+use subscribe_note_events::*;
 use update_note::*;
+use update_note_share::*;
+use upload_attachment::*;
 
+use axum::extract::DefaultBodyLimit;
 use utoipa::OpenApi;
 
-use app::state::AppState;
+use app::{
+    state::AppState,
+    usecases::note::{NoteFlag, NOTE_IMPORT_MAX_SIZE},
+};
+use dmn::entities::attachment::ATTACHMENT_MAX_SIZE;
 
 #[derive(OpenApi)]
 #[openapi(
@@ -21,8 +96,54 @@ use app::state::AppState;
         create_note,
         get_note,
         get_notes,
+        search_notes,
         update_note,
+        patch_note,
         delete_note,
+        move_note,
+        // Bulk Note Operations
+        bulk_note_operations,
+        bulk_delete_notes,
+        bulk_move_notes,
+        bulk_tag_notes,
+        // Note Archives
+        export_notes,
+        import_notes,
+        // Note Import Jobs
+        create_note_import_job,
+        get_note_import_job,
+        // Note Sync
+        get_note_changes,
+        subscribe_note_events,
+        // Note Flags
+        set_note_flag,
+        // Note Trash
+        get_trashed_notes,
+        restore_note,
+        purge_note,
+        empty_trash,
+        // Note Revisions
+        get_note_revisions,
+        get_note_revision,
+        restore_note_revision,
+        // Note Shares
+        get_shared_notes,
+        get_note_shares,
+        share_note,
+        update_note_share,
+        revoke_note_share,
+        // Note Public Links
+        get_note_public_link,
+        create_note_public_link,
+        revoke_note_public_link,
+        // Note Attachments
+        get_attachments,
+        upload_attachment,
+        download_attachment,
+        delete_attachment,
+        // Note Tags
+        attach_note_tag,
+        detach_note_tag,
     ),
     tags(
         (name = "Notes"),
@@ -32,8 +153,74 @@ pub struct NotesApiDoc;
 
 pub fn declare_routes(base_path: &str) -> axum::Router<AppState> {
     axum::Router::new()
-        .route(&format!("{base_path}"), axum::routing::post(create_note))
-        .route(&format!("{base_path}"), axum::routing::get(get_notes))
+        .route(base_path, axum::routing::post(create_note))
+        .route(base_path, axum::routing::get(get_notes))
+        .route(
+            &format!("{base_path}/bulk"),
+            axum::routing::post(bulk_note_operations),
+        )
+        .route(
+            &format!("{base_path}/bulk/delete"),
+            axum::routing::post(bulk_delete_notes),
+        )
+        .route(
+            &format!("{base_path}/bulk/move"),
+            axum::routing::post(bulk_move_notes),
+        )
+        .route(
+            &format!("{base_path}/bulk/tags"),
+            axum::routing::post(bulk_tag_notes),
+        )
+        .route(
+            &format!("{base_path}/changes"),
+            axum::routing::get(get_note_changes),
+        )
+        .route(
+            &format!("{base_path}/events"),
+            axum::routing::get(subscribe_note_events),
+        )
+        .route(
+            &format!("{base_path}/export"),
+            axum::routing::get(export_notes),
+        )
+        .route(
+            &format!("{base_path}/import"),
+            axum::routing::post(import_notes).layer(DefaultBodyLimit::max(
+                // Leaves room for the multipart framing around the file
+                NOTE_IMPORT_MAX_SIZE + 64 * 1024,
+            )),
+        )
+        .route(
+            &format!("{base_path}/import-jobs"),
+            axum::routing::post(create_note_import_job).layer(DefaultBodyLimit::max(
+                // Leaves room for the multipart framing around the file
+                NOTE_IMPORT_MAX_SIZE + 64 * 1024,
+            )),
+        )
+        .route(
+            &format!("{base_path}/import-jobs/{{job_id}}"),
+            axum::routing::get(get_note_import_job),
+        )
+        .route(
+            &format!("{base_path}/search"),
+            axum::routing::get(search_notes),
+        )
+        .route(
+            &format!("{base_path}/shared-with-me"),
+            axum::routing::get(get_shared_notes),
+        )
+        .route(
+            &format!("{base_path}/trash"),
+            axum::routing::get(get_trashed_notes),
+        )
+        .route(
+            &format!("{base_path}/trash"),
+            axum::routing::delete(empty_trash),
+        )
+        .route(
+            &format!("{base_path}/trash/{{note_id}}"),
+            axum::routing::delete(purge_note),
+        )
         .route(
             &format!("{base_path}/{{note_id}}"),
             axum::routing::get(get_note),
@@ -42,8 +229,123 @@ pub fn declare_routes(base_path: &str) -> axum::Router<AppState> {
             &format!("{base_path}/{{note_id}}"),
             axum::routing::put(update_note),
         )
+        .route(
+            &format!("{base_path}/{{note_id}}"),
+            axum::routing::patch(patch_note),
+        )
         .route(
             &format!("{base_path}/{{note_id}}"),
             axum::routing::delete(delete_note),
         )
+        .route(
+            &format!("{base_path}/{{note_id}}/archive"),
+            axum::routing::put(|state, path, auth_ctx| {
+                set_note_flag(state, path, auth_ctx, NoteFlag::Archived, true)
+            }),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/archive"),
+            axum::routing::delete(|state, path, auth_ctx| {
+                set_note_flag(state, path, auth_ctx, NoteFlag::Archived, false)
+            }),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/attachments"),
+            axum::routing::get(get_attachments),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/attachments"),
+            axum::routing::post(upload_attachment).layer(DefaultBodyLimit::max(
+                // Leaves room for the multipart framing around the file
+                ATTACHMENT_MAX_SIZE as usize + 64 * 1024,
+            )),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/attachments/{{attachment_id}}"),
+            axum::routing::get(download_attachment),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/attachments/{{attachment_id}}"),
+            axum::routing::delete(delete_attachment),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/favorite"),
+            axum::routing::put(|state, path, auth_ctx| {
+                set_note_flag(state, path, auth_ctx, NoteFlag::Favorite, true)
+            }),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/favorite"),
+            axum::routing::delete(|state, path, auth_ctx| {
+                set_note_flag(state, path, auth_ctx, NoteFlag::Favorite, false)
+            }),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/notebook"),
+            axum::routing::put(move_note),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/pin"),
+            axum::routing::put(|state, path, auth_ctx| {
+                set_note_flag(state, path, auth_ctx, NoteFlag::Pinned, true)
+            }),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/pin"),
+            axum::routing::delete(|state, path, auth_ctx| {
+                set_note_flag(state, path, auth_ctx, NoteFlag::Pinned, false)
+            }),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/public-link"),
+            axum::routing::get(get_note_public_link),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/public-link"),
+            axum::routing::post(create_note_public_link),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/public-link"),
+            axum::routing::delete(revoke_note_public_link),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/restore"),
+            axum::routing::post(restore_note),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/revisions"),
+            axum::routing::get(get_note_revisions),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/revisions/{{revision}}"),
+            axum::routing::get(get_note_revision),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/revisions/{{revision}}/restore"),
+            axum::routing::post(restore_note_revision),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/shares"),
+            axum::routing::get(get_note_shares),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/shares"),
+            axum::routing::post(share_note),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/shares/{{user_id}}"),
+            axum::routing::put(update_note_share),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/shares/{{user_id}}"),
+            axum::routing::delete(revoke_note_share),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/tags/{{tag_id}}"),
+            axum::routing::put(attach_note_tag),
+        )
+        .route(
+            &format!("{base_path}/{{note_id}}/tags/{{tag_id}}"),
+            axum::routing::delete(detach_note_tag),
+        )
 }
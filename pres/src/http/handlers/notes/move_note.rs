@@ -0,0 +1,54 @@
+use axum::extract::{Json, Path, State};
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::NoteDto, types::error::PresentationError,
+};
+use app::{state::AppState, usecases::note::MoveNoteInput};
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct MoveNoteHttpRequestBody {
+    /// Target notebook, `null` moves the note back to the root level.
+    pub(crate) notebook_id: Option<Uuid>,
+}
+
+/// Moves a Note into a Notebook.
+#[utoipa::path(
+    tag = "Notes",
+    put,
+    path = "/{note_id}/notebook",
+    params(
+        ("note_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = NoteDto),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn move_note(
+    State(state): State<AppState>,
+    Path(note_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+    Json(payload): Json<MoveNoteHttpRequestBody>,
+) -> Result<Json<NoteDto>, PresentationError> {
+    let AppState {
+        move_note_use_case, ..
+    } = state;
+
+    let input = MoveNoteInput {
+        note_id,
+        notebook_id: payload.notebook_id,
+    };
+    let note = move_note_use_case.execute(auth_ctx, input).await?;
+
+    let note_dto = note.into();
+    Ok(Json(note_dto))
+}
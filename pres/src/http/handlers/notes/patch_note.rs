@@ -0,0 +1,96 @@
+use axum::extract::{Json, Path, State};
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor,
+    dtos::NoteDto,
+    types::{
+        error::PresentationError,
+        etag::{etag_header, ETagHeader, IfMatchExtractor},
+        merge_patch::deserialize_patch_field,
+    },
+};
+use app::{state::AppState, usecases::note::PatchNoteInput};
+use common::error::AppError;
+
+/// JSON Merge Patch document, omitted members are left untouched.
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct PatchNoteHttpRequestBody {
+    #[serde(default, deserialize_with = "deserialize_patch_field")]
+    #[schema(value_type = Option<String>)]
+    pub(crate) title: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_patch_field")]
+    #[schema(value_type = Option<String>)]
+    pub(crate) content: Option<Option<String>>,
+}
+
+/// Partially updates a Note, keeping its previous version as a revision.
+#[utoipa::path(
+    tag = "Notes",
+    patch,
+    path = "/{note_id}",
+    description = "Accepts a JSON Merge Patch (RFC 7396), only the supplied fields are updated.",
+    params(
+        ("note_id" = Uuid, Path),
+        ("If-Match" = Option<String>, Header, description = "Rejects the update unless the note still matches one of these comma-separated ETags"),
+    ),
+    request_body(content = PatchNoteHttpRequestBody, content_type = "application/merge-patch+json"),
+    responses(
+        (status = 200, description = "Success", body = NoteDto, headers(
+            ("ETag" = String, description = "Updated note version"),
+        )),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not Found"),
+        (status = 409, description = "Conflict"),
+        (status = 412, description = "Precondition Failed"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn patch_note(
+    State(state): State<AppState>,
+    Path(note_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+    IfMatchExtractor(expected_versions): IfMatchExtractor,
+    Json(payload): Json<PatchNoteHttpRequestBody>,
+) -> Result<(ETagHeader, Json<NoteDto>), PresentationError> {
+    let AppState {
+        patch_note_use_case,
+        ..
+    } = state;
+
+    let input = get_use_case_input(note_id, expected_versions, payload)?;
+    let note = patch_note_use_case.execute(auth_ctx, input).await?;
+
+    let etag = etag_header(note.version());
+    let note_dto = note.into();
+    Ok((etag, Json(note_dto)))
+}
+
+fn get_use_case_input(
+    note_id: Uuid,
+    expected_versions: Vec<u32>,
+    payload: PatchNoteHttpRequestBody,
+) -> Result<PatchNoteInput, AppError> {
+    // Notes can't exist without a title or content, so they can't be removed
+    let title = match payload.title {
+        Some(None) => return Err(AppError::bad_request("Note title cannot be removed!")),
+        title => title.flatten(),
+    };
+    let content = match payload.content {
+        Some(None) => return Err(AppError::bad_request("Note content cannot be removed!")),
+        content => content.flatten(),
+    };
+    Ok(PatchNoteInput {
+        note_id,
+        title,
+        content,
+        expected_versions,
+    })
+}
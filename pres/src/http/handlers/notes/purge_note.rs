@@ -0,0 +1,39 @@
+use axum::extract::{Path, State};
+use uuid::Uuid;
+
+use crate::{auth::extractors::AuthContextAccessExtractor, types::error::PresentationError};
+use app::{state::AppState, usecases::note::PurgeNoteInput};
+
+/// Permanently deletes a trashed Note.
+#[utoipa::path(
+    tag = "Notes",
+    delete,
+    path = "/trash/{note_id}",
+    params(
+        ("note_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = String),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn purge_note(
+    State(state): State<AppState>,
+    Path(note_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<String, PresentationError> {
+    let AppState {
+        purge_note_use_case,
+        ..
+    } = state;
+
+    let input = PurgeNoteInput { note_id };
+    purge_note_use_case.execute(auth_ctx, input).await?;
+
+    Ok(format!("Note ({}) purged successfully.", note_id))
+}
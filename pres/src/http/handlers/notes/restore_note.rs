@@ -0,0 +1,42 @@
+use axum::extract::{Json, Path, State};
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::NoteDto, types::error::PresentationError,
+};
+use app::{state::AppState, usecases::note::RestoreNoteInput};
+
+/// Restores a Note from the trash.
+#[utoipa::path(
+    tag = "Notes",
+    post,
+    path = "/{note_id}/restore",
+    params(
+        ("note_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = NoteDto),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn restore_note(
+    State(state): State<AppState>,
+    Path(note_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Json<NoteDto>, PresentationError> {
+    let AppState {
+        restore_note_use_case,
+        ..
+    } = state;
+
+    let input = RestoreNoteInput { note_id };
+    let note = restore_note_use_case.execute(auth_ctx, input).await?;
+
+    let note_dto = note.into();
+    Ok(Json(note_dto))
+}
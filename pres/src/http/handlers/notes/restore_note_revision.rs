@@ -0,0 +1,48 @@
+use axum::extract::{Json, Path, State};
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::NoteDto, types::error::PresentationError,
+};
+use app::{state::AppState, usecases::note::RestoreNoteRevisionInput};
+
+/// Restores a Note to one of its revisions.
+#[utoipa::path(
+    tag = "Notes",
+    post,
+    path = "/{note_id}/revisions/{revision}/restore",
+    description = "The version being replaced is kept as a new revision, so restores can be undone.",
+    params(
+        ("note_id" = Uuid, Path),
+        ("revision" = u32, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = NoteDto),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not Found"),
+        (status = 409, description = "Conflict"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn restore_note_revision(
+    State(state): State<AppState>,
+    Path((note_id, revision)): Path<(Uuid, u32)>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Json<NoteDto>, PresentationError> {
+    let AppState {
+        restore_note_revision_use_case,
+        ..
+    } = state;
+
+    let input = RestoreNoteRevisionInput { note_id, revision };
+    let note = restore_note_revision_use_case
+        .execute(auth_ctx, input)
+        .await?;
+
+    let note_dto = note.into();
+    Ok(Json(note_dto))
+}
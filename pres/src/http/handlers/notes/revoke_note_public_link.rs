@@ -0,0 +1,45 @@
+use axum::extract::{Path, State};
+use uuid::Uuid;
+
+use crate::{auth::extractors::AuthContextAccessExtractor, types::error::PresentationError};
+use app::{state::AppState, usecases::note_public_link::RevokeNotePublicLinkInput};
+
+/// Revokes a Note's public link.
+#[utoipa::path(
+    tag = "Notes",
+    delete,
+    path = "/{note_id}/public-link",
+    params(
+        ("note_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = String),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn revoke_note_public_link(
+    State(state): State<AppState>,
+    Path(note_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<String, PresentationError> {
+    let AppState {
+        revoke_note_public_link_use_case,
+        ..
+    } = state;
+
+    let input = RevokeNotePublicLinkInput { note_id };
+    revoke_note_public_link_use_case
+        .execute(auth_ctx, input)
+        .await?;
+
+    Ok(format!(
+        "Note ({}) public link revoked successfully.",
+        note_id
+    ))
+}
@@ -0,0 +1,45 @@
+use axum::extract::{Path, State};
+use uuid::Uuid;
+
+use crate::{auth::extractors::AuthContextAccessExtractor, types::error::PresentationError};
+use app::{state::AppState, usecases::note_share::RevokeNoteShareInput};
+
+/// Revokes a collaborator's access to a Note.
+#[utoipa::path(
+    tag = "Notes",
+    delete,
+    path = "/{note_id}/shares/{user_id}",
+    description = "Owners can revoke any collaborator, collaborators can only revoke their own access.",
+    params(
+        ("note_id" = Uuid, Path),
+        ("user_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = String),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn revoke_note_share(
+    State(state): State<AppState>,
+    Path((note_id, user_id)): Path<(Uuid, Uuid)>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<String, PresentationError> {
+    let AppState {
+        revoke_note_share_use_case,
+        ..
+    } = state;
+
+    let input = RevokeNoteShareInput { note_id, user_id };
+    revoke_note_share_use_case.execute(auth_ctx, input).await?;
+
+    Ok(format!(
+        "Note ({}) share with user ({}) revoked successfully.",
+        note_id, user_id
+    ))
+}
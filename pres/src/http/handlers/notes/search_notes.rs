@@ -0,0 +1,81 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor,
+    dtos::NoteSearchResultDto,
+    types::{error::PresentationError, params::PaginationParams},
+};
+use app::{state::AppState, usecases::note::SearchNotesInput};
+
+#[derive(Deserialize, IntoParams)]
+pub(crate) struct SearchNotesQueryParams {
+    /// Search terms. Supports quoted phrases, `or` and `-` exclusions.
+    pub(crate) q: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct SearchNotesHttpResponseBody {
+    pub(crate) results: Vec<NoteSearchResultDto>,
+    pub(crate) count: u32,
+}
+
+/// Searches Notes by relevance across title and content.
+#[utoipa::path(
+    tag = "Notes",
+    get,
+    path = "/search",
+    description = "Snippets are HTML-escaped, with matching terms wrapped in <em>&lt;mark&gt;</em> tags.",
+    params(
+        SearchNotesQueryParams,
+        PaginationParams,
+    ),
+    responses(
+        (status = 200, description = "Success", body = SearchNotesHttpResponseBody),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn search_notes(
+    State(state): State<AppState>,
+    Query(search): Query<SearchNotesQueryParams>,
+    Query(pagination): Query<PaginationParams>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Json<SearchNotesHttpResponseBody>, PresentationError> {
+    let AppState {
+        search_notes_use_case,
+        ..
+    } = state;
+
+    let input = get_use_case_input(search, pagination);
+    let dmn_res = search_notes_use_case.execute(auth_ctx, input).await?;
+
+    let result_dtos = dmn_res
+        .results
+        .into_iter()
+        .map(|result| result.into())
+        .collect();
+    let http_res = SearchNotesHttpResponseBody {
+        results: result_dtos,
+        count: dmn_res.count,
+    };
+    Ok(Json(http_res))
+}
+
+fn get_use_case_input(
+    search: SearchNotesQueryParams,
+    pagination: PaginationParams,
+) -> SearchNotesInput {
+    SearchNotesInput {
+        query: search.q,
+        pagination: pagination.into(),
+    }
+}
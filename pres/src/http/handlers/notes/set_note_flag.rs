@@ -0,0 +1,57 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::NoteDto, types::error::PresentationError,
+};
+use app::{
+    state::AppState,
+    usecases::note::{NoteFlag, SetNoteFlagInput},
+};
+
+/// Sets (`PUT`) or clears (`DELETE`) a Note flag.<br />
+/// Pinned Notes are listed before any other, while archived ones are hidden from listings unless requested.
+#[utoipa::path(
+    tag = "Notes",
+    method(put, delete),
+    path = "/{note_id}/{flag}",
+    params(
+        ("note_id" = Uuid, Path),
+        ("flag" = String, Path, description = "One of `pin`, `archive`, `favorite`"),
+    ),
+    responses(
+        (status = 200, description = "Success", body = NoteDto),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn set_note_flag(
+    State(state): State<AppState>,
+    Path(note_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+    flag: NoteFlag,
+    value: bool,
+) -> Result<Json<NoteDto>, PresentationError> {
+    let AppState {
+        set_note_flag_use_case,
+        ..
+    } = state;
+
+    let input = SetNoteFlagInput {
+        note_id,
+        flag,
+        value,
+    };
+    let note = set_note_flag_use_case.execute(auth_ctx, input).await?;
+
+    let note_dto = note.into();
+    Ok(Json(note_dto))
+}
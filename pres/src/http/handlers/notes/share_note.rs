@@ -0,0 +1,61 @@
+use axum::extract::{Json, Path, State};
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor,
+    dtos::{NoteShareDto, NoteShareRoleDto},
+    types::error::PresentationError,
+};
+use app::{state::AppState, usecases::note_share::ShareNoteInput};
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct ShareNoteHttpRequestBody {
+    /// Email of the user to share the note with.
+    pub(crate) email: String,
+    pub(crate) role: NoteShareRoleDto,
+}
+
+/// Shares a Note with another user.
+#[utoipa::path(
+    tag = "Notes",
+    post,
+    path = "/{note_id}/shares",
+    params(
+        ("note_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = NoteShareDto),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not Found"),
+        (status = 409, description = "Conflict"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn share_note(
+    State(state): State<AppState>,
+    Path(note_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+    Json(payload): Json<ShareNoteHttpRequestBody>,
+) -> Result<Json<NoteShareDto>, PresentationError> {
+    let AppState {
+        share_note_use_case,
+        ..
+    } = state;
+
+    let input = ShareNoteInput {
+        note_id,
+        email: payload.email,
+        role: payload.role.into(),
+    };
+    let share = share_note_use_case.execute(auth_ctx, input).await?;
+
+    let share_dto = share.into();
+    Ok(Json(share_dto))
+}
@@ -0,0 +1,48 @@
+use axum::{
+    extract::State,
+    response::sse::{Event, KeepAlive, Sse},
+};
+use futures_util::{Stream, StreamExt};
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::NoteEventDto,
+    types::error::PresentationError,
+};
+use app::state::AppState;
+
+/// Streams change events of the user's Notes.
+#[utoipa::path(
+    tag = "Notes",
+    get,
+    path = "/events",
+    description = "Server-Sent Events named after the change kind (`created`, `updated` or \
+        `deleted`), carrying the note id. The stream ends when the client falls behind, events missed while \
+        disconnected aren't replayed, see `/notes/changes` to catch up.",
+    responses(
+        (status = 200, description = "Success", content_type = "text/event-stream", body = NoteEventDto),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn subscribe_note_events(
+    State(state): State<AppState>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, PresentationError> {
+    let AppState {
+        subscribe_note_events_use_case,
+        ..
+    } = state;
+
+    let events = subscribe_note_events_use_case.execute(auth_ctx).await?;
+
+    let sse_events = events.map(|event| {
+        let event_dto = NoteEventDto::from(event);
+        Event::default()
+            .event(event_dto.kind.as_str())
+            .json_data(event_dto)
+    });
+    Ok(Sse::new(sse_events).keep_alive(KeepAlive::default()))
+}
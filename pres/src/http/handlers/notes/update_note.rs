@@ -4,7 +4,12 @@ use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::{
-    auth::extractors::AuthContextAccessExtractor, dtos::NoteDto, types::error::PresentationError,
+    auth::extractors::AuthContextAccessExtractor,
+    dtos::NoteDto,
+    types::{
+        error::PresentationError,
+        etag::{etag_header, ETagHeader, IfMatchExtractor},
+    },
 };
 use app::{state::AppState, usecases::note::UpdateNoteInput};
 
@@ -14,18 +19,24 @@ pub(crate) struct UpdateNoteHttpRequestBody {
     pub(crate) content: String,
 }
 
-/// Updates a Note.
+/// Updates a Note, keeping its previous version as a revision.
 #[utoipa::path(
     tag = "Notes",
     put,
     path = "/{note_id}",
     params(
         ("note_id" = Uuid, Path),
+        ("If-Match" = Option<String>, Header, description = "Rejects the update unless the note still matches one of these comma-separated ETags"),
     ),
     responses(
-        (status = 200, description = "Success", body = NoteDto),
+        (status = 200, description = "Success", body = NoteDto, headers(
+            ("ETag" = String, description = "Updated note version"),
+        )),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
         (status = 404, description = "Not Found"),
+        (status = 409, description = "Conflict"),
+        (status = 412, description = "Precondition Failed"),
         (status = 500, description = "Failure"),
     ),
     security(
@@ -36,24 +47,31 @@ pub(crate) async fn update_note(
     State(state): State<AppState>,
     Path(note_id): Path<Uuid>,
     AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+    IfMatchExtractor(expected_versions): IfMatchExtractor,
     Json(payload): Json<UpdateNoteHttpRequestBody>,
-) -> Result<Json<NoteDto>, PresentationError> {
+) -> Result<(ETagHeader, Json<NoteDto>), PresentationError> {
     let AppState {
         update_note_use_case,
         ..
     } = state;
 
-    let input = get_use_case_input(note_id, payload);
-    let note = update_note_use_case.execute(auth_ctx, input.into()).await?;
+    let input = get_use_case_input(note_id, expected_versions, payload);
+    let note = update_note_use_case.execute(auth_ctx, input).await?;
 
+    let etag = etag_header(note.version());
     let note_dto = note.into();
-    Ok(Json(note_dto))
+    Ok((etag, Json(note_dto)))
 }
 
-fn get_use_case_input(note_id: Uuid, payload: UpdateNoteHttpRequestBody) -> UpdateNoteInput {
+fn get_use_case_input(
+    note_id: Uuid,
+    expected_versions: Vec<u32>,
+    payload: UpdateNoteHttpRequestBody,
+) -> UpdateNoteInput {
     UpdateNoteInput {
         note_id,
         title: payload.title,
         content: payload.content,
+        expected_versions,
     }
 }
@@ -0,0 +1,58 @@
+use axum::extract::{Json, Path, State};
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor,
+    dtos::{NoteShareDto, NoteShareRoleDto},
+    types::error::PresentationError,
+};
+use app::{state::AppState, usecases::note_share::UpdateNoteShareInput};
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct UpdateNoteShareHttpRequestBody {
+    pub(crate) role: NoteShareRoleDto,
+}
+
+/// Changes the role of a Note collaborator.
+#[utoipa::path(
+    tag = "Notes",
+    put,
+    path = "/{note_id}/shares/{user_id}",
+    params(
+        ("note_id" = Uuid, Path),
+        ("user_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = NoteShareDto),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn update_note_share(
+    State(state): State<AppState>,
+    Path((note_id, user_id)): Path<(Uuid, Uuid)>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+    Json(payload): Json<UpdateNoteShareHttpRequestBody>,
+) -> Result<Json<NoteShareDto>, PresentationError> {
+    let AppState {
+        update_note_share_use_case,
+        ..
+    } = state;
+
+    let input = UpdateNoteShareInput {
+        note_id,
+        user_id,
+        role: payload.role.into(),
+    };
+    let share = update_note_share_use_case.execute(auth_ctx, input).await?;
+
+    let share_dto = share.into();
+    Ok(Json(share_dto))
+}
@@ -0,0 +1,88 @@
+use axum::{
+    extract::{Multipart, Path, State},
+    Json,
+};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::AttachmentDto,
+    types::error::PresentationError,
+};
+use app::{state::AppState, usecases::attachment::UploadAttachmentInput};
+use common::error::AppError;
+
+/// Multipart form accepted by [`upload_attachment`], for documentation purposes only.
+#[allow(dead_code)]
+#[derive(ToSchema)]
+pub(crate) struct UploadAttachmentHttpRequestBody {
+    #[schema(value_type = String, format = Binary)]
+    pub(crate) file: Vec<u8>,
+}
+
+/// Uploads a file to a Note.
+#[utoipa::path(
+    tag = "Notes",
+    post,
+    path = "/{note_id}/attachments",
+    description = "Expects a single `file` field, named and typed by its multipart headers.",
+    params(
+        ("note_id" = Uuid, Path),
+    ),
+    request_body(content = UploadAttachmentHttpRequestBody, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Success", body = AttachmentDto),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn upload_attachment(
+    State(state): State<AppState>,
+    Path(note_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+    mut multipart: Multipart,
+) -> Result<Json<AttachmentDto>, PresentationError> {
+    const MISSING_FILE_ERR_STR: &str = "Missing multipart file field!";
+    const INVALID_FILE_ERR_STR: &str = "Invalid multipart file field!";
+
+    let AppState {
+        upload_attachment_use_case,
+        ..
+    } = state;
+
+    let field = loop {
+        let field = multipart
+            .next_field()
+            .await
+            .map_err(|err| {
+                AppError::bad_request_with_private(INVALID_FILE_ERR_STR, err.body_text())
+            })?
+            .ok_or_else(|| AppError::bad_request(MISSING_FILE_ERR_STR))?;
+        if field.name() == Some("file") {
+            break field;
+        }
+    };
+    let file_name = field.file_name().unwrap_or_default().to_string();
+    let mime_type = field.content_type().unwrap_or_default().to_string();
+    let data = field
+        .bytes()
+        .await
+        .map_err(|err| AppError::bad_request_with_private(INVALID_FILE_ERR_STR, err.body_text()))?;
+
+    let input = UploadAttachmentInput {
+        note_id,
+        file_name,
+        mime_type,
+        data: data.to_vec(),
+    };
+    let attachment = upload_attachment_use_case.execute(auth_ctx, input).await?;
+
+    let attachment_dto = attachment.into();
+    Ok(Json(attachment_dto))
+}
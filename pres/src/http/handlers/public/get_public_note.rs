@@ -0,0 +1,120 @@
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderMap, HeaderValue},
+    response::{Html, IntoResponse, Response},
+    Json,
+};
+use pulldown_cmark::{html, Event, Options, Parser};
+
+use crate::{dtos::PublicNoteDto, types::error::PresentationError};
+use app::{state::AppState, usecases::note_public_link::GetPublicNoteInput};
+
+/// Rendered notes only ever need inline styles, anything else is blocked.
+const HTML_CONTENT_SECURITY_POLICY: &str =
+    "default-src 'none'; style-src 'unsafe-inline'; img-src https: data:";
+
+/// Retrieves a Note through its public link.
+#[utoipa::path(
+    tag = "Public",
+    get,
+    path = "/notes/{token}",
+    description = "Responds with the note rendered as an HTML page when `text/html` is preferred \
+        by the `Accept` header, as JSON otherwise.",
+    params(
+        ("token" = String, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", content(
+            (PublicNoteDto = "application/json"),
+            (String = "text/html"),
+        )),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+)]
+pub(crate) async fn get_public_note(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, PresentationError> {
+    let AppState {
+        get_public_note_use_case,
+        ..
+    } = state;
+
+    let input = GetPublicNoteInput { token };
+    let note = get_public_note_use_case.execute(input).await?;
+
+    let note_dto: PublicNoteDto = note.into();
+    if prefers_html(&headers) {
+        let csp = HeaderValue::from_static(HTML_CONTENT_SECURITY_POLICY);
+        let page = Html(render_note_page(&note_dto));
+        Ok(([(header::CONTENT_SECURITY_POLICY, csp)], page).into_response())
+    } else {
+        Ok(Json(note_dto).into_response())
+    }
+}
+
+/// Whether `text/html` comes before `application/json` among the accepted media types.
+fn prefers_html(headers: &HeaderMap) -> bool {
+    let Some(accept) = headers
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+    else {
+        return false;
+    };
+    for media_range in accept.split(',') {
+        let mut parts = media_range.split(';').map(str::trim);
+        let media_type = parts.next().unwrap_or_default();
+        let rejected = parts.any(|param| {
+            param
+                .strip_prefix("q=")
+                .and_then(|q| q.parse::<f32>().ok())
+                .is_some_and(|q| q == 0.0)
+        });
+        if rejected {
+            continue;
+        }
+        match media_type {
+            "text/html" => return true,
+            "application/json" => return false,
+            _ => {}
+        }
+    }
+    false
+}
+
+fn render_note_page(note: &PublicNoteDto) -> String {
+    let mut title = String::new();
+    html::push_html(
+        &mut title,
+        [Event::Text(note.title.as_str().into())].into_iter(),
+    );
+
+    // Raw HTML embedded in the markdown is displayed as text rather than interpreted
+    let parser = Parser::new_ext(&note.content, Options::all()).map(|event| match event {
+        Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+        event => event,
+    });
+    let mut content = String::new();
+    html::push_html(&mut content, parser);
+
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<meta name="robots" content="noindex">
+<title>{title}</title>
+<style>body {{ max-width: 48rem; margin: 2rem auto; padding: 0 1rem; font-family: sans-serif; line-height: 1.5; }}</style>
+</head>
+<body>
+<h1>{title}</h1>
+<article>
+{content}</article>
+</body>
+</html>
+"#
+    )
+}
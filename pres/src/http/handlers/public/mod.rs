@@ -0,0 +1,27 @@
+mod get_public_note;
+
+use get_public_note::*;
+
+use utoipa::OpenApi;
+
+use app::state::AppState;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        // Public Notes
+        get_public_note,
+    ),
+    tags(
+        (name = "Public"),
+    )
+)]
+pub struct PublicApiDoc;
+
+/// Routes served without authentication.
+pub fn declare_routes(base_path: &str) -> axum::Router<AppState> {
+    axum::Router::new().route(
+        &format!("{base_path}/notes/{{token}}"),
+        axum::routing::get(get_public_note),
+    )
+}
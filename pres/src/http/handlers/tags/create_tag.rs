@@ -0,0 +1,52 @@
+use axum::{extract::State, Json};
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::TagDto, types::error::PresentationError,
+};
+use app::{state::AppState, usecases::tag::CreateTagInput};
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct CreateTagHttpRequestBody {
+    pub(crate) name: String,
+}
+
+/// Creates a new Tag.
+#[utoipa::path(
+    tag = "Tags",
+    post,
+    path = "/",
+    responses(
+        (status = 200, description = "Success", body = TagDto),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 409, description = "Conflict"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn create_tag(
+    State(state): State<AppState>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+    Json(payload): Json<CreateTagHttpRequestBody>,
+) -> Result<Json<TagDto>, PresentationError> {
+    let AppState {
+        create_tag_use_case,
+        ..
+    } = state;
+
+    let input = payload.into();
+    let tag = create_tag_use_case.execute(auth_ctx, input).await?;
+
+    let tag_dto = tag.into();
+    Ok(Json(tag_dto))
+}
+
+impl From<CreateTagHttpRequestBody> for CreateTagInput {
+    fn from(payload: CreateTagHttpRequestBody) -> Self {
+        Self { name: payload.name }
+    }
+}
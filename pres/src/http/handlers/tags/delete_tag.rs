@@ -0,0 +1,39 @@
+use axum::extract::{Path, State};
+use uuid::Uuid;
+
+use crate::{auth::extractors::AuthContextAccessExtractor, types::error::PresentationError};
+use app::{state::AppState, usecases::tag::DeleteTagInput};
+
+/// Deletes a Tag and detaches it from all Notes.
+#[utoipa::path(
+    tag = "Tags",
+    delete,
+    path = "/{tag_id}",
+    params(
+        ("tag_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = String),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn delete_tag(
+    State(state): State<AppState>,
+    Path(tag_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<String, PresentationError> {
+    let AppState {
+        delete_tag_use_case,
+        ..
+    } = state;
+
+    let input = DeleteTagInput { tag_id };
+    delete_tag_use_case.execute(auth_ctx, input).await?;
+
+    Ok(format!("Tag ({}) deleted successfully.", tag_id))
+}
@@ -0,0 +1,42 @@
+use axum::{extract::State, Json};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::TagDto, types::error::PresentationError,
+};
+use app::state::AppState;
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct GetTagsHttpResponseBody {
+    pub(crate) tags: Vec<TagDto>,
+}
+
+/// Retrieves all Tags of the current user.
+#[utoipa::path(
+    tag = "Tags",
+    get,
+    path = "/",
+    responses(
+        (status = 200, description = "Success", body = GetTagsHttpResponseBody),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn get_tags(
+    State(state): State<AppState>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Json<GetTagsHttpResponseBody>, PresentationError> {
+    let AppState {
+        get_tags_use_case, ..
+    } = state;
+
+    let tags = get_tags_use_case.execute(auth_ctx).await?;
+
+    let tag_dtos = tags.into_iter().map(|tag| tag.into()).collect();
+    let http_res = GetTagsHttpResponseBody { tags: tag_dtos };
+    Ok(Json(http_res))
+}
@@ -0,0 +1,42 @@
+mod create_tag;
+mod delete_tag;
+mod get_tags;
+mod rename_tag;
+
+use create_tag::*;
+use delete_tag::*;
+use get_tags::*;
+use rename_tag::*;
+
+use utoipa::OpenApi;
+
+use app::state::AppState;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        // Tags
+        create_tag,
+        get_tags,
+        rename_tag,
+        delete_tag,
+    ),
+    tags(
+        (name = "Tags"),
+    )
+)]
+pub struct TagsApiDoc;
+
+pub fn declare_routes(base_path: &str) -> axum::Router<AppState> {
+    axum::Router::new()
+        .route(base_path, axum::routing::post(create_tag))
+        .route(base_path, axum::routing::get(get_tags))
+        .route(
+            &format!("{base_path}/{{tag_id}}"),
+            axum::routing::put(rename_tag),
+        )
+        .route(
+            &format!("{base_path}/{{tag_id}}"),
+            axum::routing::delete(delete_tag),
+        )
+}
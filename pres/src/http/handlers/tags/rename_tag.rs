@@ -0,0 +1,55 @@
+use axum::extract::{Json, Path, State};
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::TagDto, types::error::PresentationError,
+};
+use app::{state::AppState, usecases::tag::RenameTagInput};
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct RenameTagHttpRequestBody {
+    pub(crate) name: String,
+}
+
+/// Renames a Tag.
+#[utoipa::path(
+    tag = "Tags",
+    put,
+    path = "/{tag_id}",
+    params(
+        ("tag_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = TagDto),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not Found"),
+        (status = 409, description = "Conflict"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn rename_tag(
+    State(state): State<AppState>,
+    Path(tag_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+    Json(payload): Json<RenameTagHttpRequestBody>,
+) -> Result<Json<TagDto>, PresentationError> {
+    let AppState {
+        rename_tag_use_case,
+        ..
+    } = state;
+
+    let input = RenameTagInput {
+        tag_id,
+        name: payload.name,
+    };
+    let tag = rename_tag_use_case.execute(auth_ctx, input).await?;
+
+    let tag_dto = tag.into();
+    Ok(Json(tag_dto))
+}
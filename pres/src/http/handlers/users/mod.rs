@@ -29,7 +29,7 @@ pub struct UsersApiDoc;
 
 pub fn declare_routes(base_path: &str) -> axum::Router<AppState> {
     axum::Router::new()
-        .route(&format!("{base_path}"), axum::routing::post(register_user))
+        .route(base_path, axum::routing::post(register_user))
         .route(
             &format!("{base_path}/self"),
             axum::routing::get(get_self_user),
@@ -0,0 +1,72 @@
+use axum::{extract::State, Json};
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor,
+    dtos::{WebhookDto, WebhookEventTypeDto},
+    types::error::PresentationError,
+};
+use app::{state::AppState, usecases::webhook::CreateWebhookInput};
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct CreateWebhookHttpRequestBody {
+    /// `http` or `https` endpoint receiving the events.
+    pub(crate) url: String,
+    /// Shared secret used to sign the payloads, between 16 and 256 characters.
+    pub(crate) secret: String,
+    pub(crate) event_types: Vec<WebhookEventTypeDto>,
+}
+
+/// Registers a new Webhook.
+#[utoipa::path(
+    tag = "Webhooks",
+    post,
+    path = "/",
+    description = "Events are sent as a `POST` with a JSON body of the form \
+`{ \"id\", \"type\", \"created_at\", \"data\": { \"user_id\", \"note_id\" } }`. \
+Each request carries the `X-Webhook-Id`, `X-Webhook-Event` and `X-Webhook-Timestamp` headers, \
+and an `X-Webhook-Signature` header of the form `sha256=<hex>`, the HMAC-SHA256 of \
+`<timestamp>.<body>` keyed with the webhook secret. \
+Any non 2xx response is retried with exponential backoff, deliveries may arrive more than once. \
+Retries of an event keep its `X-Webhook-Id` (the body `id`), so that duplicates can be told apart.",
+    responses(
+        (status = 200, description = "Success", body = WebhookDto),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn create_webhook(
+    State(state): State<AppState>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+    Json(payload): Json<CreateWebhookHttpRequestBody>,
+) -> Result<Json<WebhookDto>, PresentationError> {
+    let AppState {
+        create_webhook_use_case,
+        ..
+    } = state;
+
+    let input = payload.into();
+    let webhook = create_webhook_use_case.execute(auth_ctx, input).await?;
+
+    let webhook_dto = webhook.into();
+    Ok(Json(webhook_dto))
+}
+
+impl From<CreateWebhookHttpRequestBody> for CreateWebhookInput {
+    fn from(payload: CreateWebhookHttpRequestBody) -> Self {
+        Self {
+            url: payload.url,
+            secret: payload.secret,
+            event_types: payload
+                .event_types
+                .into_iter()
+                .map(|event_type| event_type.into())
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use axum::extract::{Path, State};
+use uuid::Uuid;
+
+use crate::{auth::extractors::AuthContextAccessExtractor, types::error::PresentationError};
+use app::{state::AppState, usecases::webhook::DeleteWebhookInput};
+
+/// Deletes a Webhook along with its delivery log.
+#[utoipa::path(
+    tag = "Webhooks",
+    delete,
+    path = "/{webhook_id}",
+    params(
+        ("webhook_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = String),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn delete_webhook(
+    State(state): State<AppState>,
+    Path(webhook_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<String, PresentationError> {
+    let AppState {
+        delete_webhook_use_case,
+        ..
+    } = state;
+
+    let input = DeleteWebhookInput { webhook_id };
+    delete_webhook_use_case.execute(auth_ctx, input).await?;
+
+    Ok(format!("Webhook ({}) deleted successfully.", webhook_id))
+}
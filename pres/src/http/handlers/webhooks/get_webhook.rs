@@ -0,0 +1,45 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::WebhookDto, types::error::PresentationError,
+};
+use app::{state::AppState, usecases::webhook::GetWebhookInput};
+
+/// Retrieves a Webhook.
+#[utoipa::path(
+    tag = "Webhooks",
+    get,
+    path = "/{webhook_id}",
+    params(
+        ("webhook_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = WebhookDto),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn get_webhook(
+    State(state): State<AppState>,
+    Path(webhook_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Json<WebhookDto>, PresentationError> {
+    let AppState {
+        get_webhook_use_case,
+        ..
+    } = state;
+
+    let input = GetWebhookInput { webhook_id };
+    let webhook = get_webhook_use_case.execute(auth_ctx, input).await?;
+
+    let webhook_dto = webhook.into();
+    Ok(Json(webhook_dto))
+}
@@ -0,0 +1,70 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor,
+    dtos::WebhookDeliveryDto,
+    types::{error::PresentationError, params::PaginationParams},
+};
+use app::{state::AppState, usecases::webhook::GetWebhookDeliveriesInput};
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct GetWebhookDeliveriesHttpResponseBody {
+    pub(crate) deliveries: Vec<WebhookDeliveryDto>,
+    pub(crate) count: u32,
+}
+
+/// Retrieves the delivery log of a Webhook, newest first.
+#[utoipa::path(
+    tag = "Webhooks",
+    get,
+    path = "/{webhook_id}/deliveries",
+    params(
+        ("webhook_id" = Uuid, Path),
+        PaginationParams,
+    ),
+    responses(
+        (status = 200, description = "Success", body = GetWebhookDeliveriesHttpResponseBody),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn get_webhook_deliveries(
+    State(state): State<AppState>,
+    Path(webhook_id): Path<Uuid>,
+    Query(pagination): Query<PaginationParams>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Json<GetWebhookDeliveriesHttpResponseBody>, PresentationError> {
+    let AppState {
+        get_webhook_deliveries_use_case,
+        ..
+    } = state;
+
+    let input = GetWebhookDeliveriesInput {
+        webhook_id,
+        pagination: pagination.into(),
+    };
+    let dmn_res = get_webhook_deliveries_use_case
+        .execute(auth_ctx, input)
+        .await?;
+
+    let delivery_dtos = dmn_res
+        .deliveries
+        .into_iter()
+        .map(|delivery| delivery.into())
+        .collect();
+    let http_res = GetWebhookDeliveriesHttpResponseBody {
+        deliveries: delivery_dtos,
+        count: dmn_res.count,
+    };
+    Ok(Json(http_res))
+}
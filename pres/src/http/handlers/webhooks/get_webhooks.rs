@@ -0,0 +1,45 @@
+use axum::{extract::State, Json};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::WebhookDto, types::error::PresentationError,
+};
+use app::state::AppState;
+
+#[derive(Serialize, ToSchema)]
+pub(crate) struct GetWebhooksHttpResponseBody {
+    pub(crate) webhooks: Vec<WebhookDto>,
+}
+
+/// Retrieves all Webhooks of the current user.
+#[utoipa::path(
+    tag = "Webhooks",
+    get,
+    path = "/",
+    responses(
+        (status = 200, description = "Success", body = GetWebhooksHttpResponseBody),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn get_webhooks(
+    State(state): State<AppState>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Json<GetWebhooksHttpResponseBody>, PresentationError> {
+    let AppState {
+        get_webhooks_use_case,
+        ..
+    } = state;
+
+    let webhooks = get_webhooks_use_case.execute(auth_ctx).await?;
+
+    let webhook_dtos = webhooks.into_iter().map(|webhook| webhook.into()).collect();
+    let http_res = GetWebhooksHttpResponseBody {
+        webhooks: webhook_dtos,
+    };
+    Ok(Json(http_res))
+}
@@ -0,0 +1,64 @@
+mod create_webhook;
+mod delete_webhook;
+mod get_webhook;
+mod get_webhook_deliveries;
+mod get_webhooks;
+mod send_webhook_test_event;
+mod update_webhook;
+
+use create_webhook::*;
+use delete_webhook::*;
+use get_webhook::*;
+use get_webhook_deliveries::*;
+use get_webhooks::*;
+use send_webhook_test_event::*;
+use update_webhook::*;
+
+use utoipa::OpenApi;
+
+use app::state::AppState;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        // Webhooks
+        create_webhook,
+        get_webhook,
+        get_webhooks,
+        update_webhook,
+        delete_webhook,
+        // Deliveries
+        get_webhook_deliveries,
+        send_webhook_test_event,
+    ),
+    tags(
+        (name = "Webhooks"),
+    )
+)]
+pub struct WebhooksApiDoc;
+
+pub fn declare_routes(base_path: &str) -> axum::Router<AppState> {
+    axum::Router::new()
+        .route(base_path, axum::routing::post(create_webhook))
+        .route(base_path, axum::routing::get(get_webhooks))
+        .route(
+            &format!("{base_path}/{{webhook_id}}"),
+            axum::routing::get(get_webhook),
+        )
+        .route(
+            &format!("{base_path}/{{webhook_id}}"),
+            axum::routing::put(update_webhook),
+        )
+        .route(
+            &format!("{base_path}/{{webhook_id}}"),
+            axum::routing::delete(delete_webhook),
+        )
+        .route(
+            &format!("{base_path}/{{webhook_id}}/deliveries"),
+            axum::routing::get(get_webhook_deliveries),
+        )
+        .route(
+            &format!("{base_path}/{{webhook_id}}/test"),
+            axum::routing::post(send_webhook_test_event),
+        )
+}
@@ -0,0 +1,50 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor, dtos::WebhookDeliveryDto,
+    types::error::PresentationError,
+};
+use app::{state::AppState, usecases::webhook::SendWebhookTestEventInput};
+
+/// Sends a `webhook.test` event to a Webhook.
+#[utoipa::path(
+    tag = "Webhooks",
+    post,
+    path = "/{webhook_id}/test",
+    description = "The event is sent right away and is not retried, \
+the returned delivery reports the outcome of the attempt.",
+    params(
+        ("webhook_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = WebhookDeliveryDto),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn send_webhook_test_event(
+    State(state): State<AppState>,
+    Path(webhook_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+) -> Result<Json<WebhookDeliveryDto>, PresentationError> {
+    let AppState {
+        send_webhook_test_event_use_case,
+        ..
+    } = state;
+
+    let input = SendWebhookTestEventInput { webhook_id };
+    let delivery = send_webhook_test_event_use_case
+        .execute(auth_ctx, input)
+        .await?;
+
+    let delivery_dto = delivery.into();
+    Ok(Json(delivery_dto))
+}
@@ -0,0 +1,72 @@
+use axum::extract::{Json, Path, State};
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    auth::extractors::AuthContextAccessExtractor,
+    dtos::{WebhookDto, WebhookEventTypeDto},
+    types::error::PresentationError,
+};
+use app::{state::AppState, usecases::webhook::UpdateWebhookInput};
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct UpdateWebhookHttpRequestBody {
+    pub(crate) url: String,
+    /// New signing secret, `null` keeps the current one.
+    pub(crate) secret: Option<String>,
+    pub(crate) event_types: Vec<WebhookEventTypeDto>,
+}
+
+/// Updates a Webhook.
+#[utoipa::path(
+    tag = "Webhooks",
+    put,
+    path = "/{webhook_id}",
+    params(
+        ("webhook_id" = Uuid, Path),
+    ),
+    responses(
+        (status = 200, description = "Success", body = WebhookDto),
+        (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Not Found"),
+        (status = 500, description = "Failure"),
+    ),
+    security(
+        ("bearerAuth" = [])
+    ),
+)]
+pub(crate) async fn update_webhook(
+    State(state): State<AppState>,
+    Path(webhook_id): Path<Uuid>,
+    AuthContextAccessExtractor(auth_ctx): AuthContextAccessExtractor,
+    Json(payload): Json<UpdateWebhookHttpRequestBody>,
+) -> Result<Json<WebhookDto>, PresentationError> {
+    let AppState {
+        update_webhook_use_case,
+        ..
+    } = state;
+
+    let input = get_use_case_input(webhook_id, payload);
+    let webhook = update_webhook_use_case.execute(auth_ctx, input).await?;
+
+    let webhook_dto = webhook.into();
+    Ok(Json(webhook_dto))
+}
+
+fn get_use_case_input(
+    webhook_id: Uuid,
+    payload: UpdateWebhookHttpRequestBody,
+) -> UpdateWebhookInput {
+    UpdateWebhookInput {
+        webhook_id,
+        url: payload.url,
+        secret: payload.secret,
+        event_types: payload
+            .event_types
+            .into_iter()
+            .map(|event_type| event_type.into())
+            .collect(),
+    }
+}
@@ -0,0 +1,29 @@
+use axum::{extract::State, Json};
+
+use crate::{dtos::JsonWebKeySetDto, types::error::PresentationError};
+use app::state::AppState;
+
+/// Retrieves the public keys verifying authentication tokens.
+#[utoipa::path(
+    tag = "Well-Known",
+    get,
+    path = "/jwks.json",
+    description = "Tokens name their signing key in the <em>kid</em> header. Upcoming keys are listed ahead of their activation, and retired keys until tokens signed with them expire. Empty when tokens are signed with a shared secret.",
+    responses(
+        (status = 200, description = "Success", body = JsonWebKeySetDto),
+        (status = 500, description = "Failure"),
+    ),
+)]
+pub(crate) async fn get_jwks(
+    State(state): State<AppState>,
+) -> Result<Json<JsonWebKeySetDto>, PresentationError> {
+    let AppState {
+        get_json_web_keys_use_case,
+        ..
+    } = state;
+
+    let keys = get_json_web_keys_use_case.execute().await?;
+
+    let key_set_dto = keys.into();
+    Ok(Json(key_set_dto))
+}
@@ -0,0 +1,27 @@
+mod get_jwks;
+
+use get_jwks::*;
+
+use utoipa::OpenApi;
+
+use app::state::AppState;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        // JSON Web Keys
+        get_jwks,
+    ),
+    tags(
+        (name = "Well-Known"),
+    )
+)]
+pub struct WellKnownApiDoc;
+
+/// Discovery routes served without authentication (RFC 8615).
+pub fn declare_routes(base_path: &str) -> axum::Router<AppState> {
+    axum::Router::new().route(
+        &format!("{base_path}/jwks.json"),
+        axum::routing::get(get_jwks),
+    )
+}
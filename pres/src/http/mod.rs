@@ -11,7 +11,10 @@ use utoipa::{
 use utoipa_swagger_ui::SwaggerUi;
 
 use app::state::AppState;
-use handlers::{auth::AuthApiDoc, notes::NotesApiDoc, users::UsersApiDoc};
+use handlers::{
+    auth::AuthApiDoc, notebooks::NotebooksApiDoc, notes::NotesApiDoc, public::PublicApiDoc,
+    tags::TagsApiDoc, users::UsersApiDoc, webhooks::WebhooksApiDoc, well_known::WellKnownApiDoc,
+};
 
 #[derive(OpenApi)]
 #[openapi(
@@ -23,12 +26,17 @@ use handlers::{auth::AuthApiDoc, notes::NotesApiDoc, users::UsersApiDoc};
         (path = "/auth", api = AuthApiDoc),
         (path = "/users", api = UsersApiDoc),
         (path = "/notes", api = NotesApiDoc),
+        (path = "/notebooks", api = NotebooksApiDoc),
+        (path = "/tags", api = TagsApiDoc),
+        (path = "/webhooks", api = WebhooksApiDoc),
+        (path = "/public", api = PublicApiDoc),
+        (path = "/.well-known", api = WellKnownApiDoc),
     ),
 )]
 struct ApiDoc;
 
 impl ApiDoc {
-    pub fn new(api_base_url: &str) -> utoipa::openapi::OpenApi {
+    pub fn build(api_base_url: &str) -> utoipa::openapi::OpenApi {
         let mut doc = Self::openapi();
         doc.servers = Some(vec![utoipa::openapi::Server::new(api_base_url)]);
 
@@ -50,7 +58,12 @@ pub(crate) fn build_router(app_state: AppState, api_base_url: &str) -> Normalize
         .merge(setup_swagger_ui(api_base_url))
         .merge(handlers::auth::declare_routes("/auth"))
         .merge(handlers::notes::declare_routes("/notes"))
+        .merge(handlers::notebooks::declare_routes("/notebooks"))
+        .merge(handlers::tags::declare_routes("/tags"))
         .merge(handlers::users::declare_routes("/users"))
+        .merge(handlers::webhooks::declare_routes("/webhooks"))
+        .merge(handlers::public::declare_routes("/public"))
+        .merge(handlers::well_known::declare_routes("/.well-known"))
         .with_state(app_state);
 
     // Fix trailing slash endpoints
@@ -68,5 +81,5 @@ fn setup_swagger_ui(api_base_url: &str) -> SwaggerUi {
 
     SwaggerUi::new(SWAGGER_UI_PATH)
         .config(config)
-        .url(SWAGGER_API_DOC_PATH, ApiDoc::new(api_base_url))
+        .url(SWAGGER_API_DOC_PATH, ApiDoc::build(api_base_url))
 }
@@ -0,0 +1,54 @@
+use axum::{
+    extract::{ConnectInfo, FromRequestParts},
+    http::{header, request::Parts, HeaderMap},
+};
+use std::{convert::Infallible, net::SocketAddr};
+
+use app::types::auth_token::AuthClientInfo;
+
+/// Upper bound on the length of stored client details, as they're client-controlled.
+const CLIENT_INFO_MAX_LEN: usize = 512;
+
+/// Extracts the address and user agent of the requesting client.<br />
+/// Prefers the first `X-Forwarded-For` hop (or `X-Real-IP`) over the peer address, for deployments behind a reverse proxy.<br />
+/// As headers can be spoofed, these details are informational only.
+pub(crate) struct ClientInfoExtractor(pub(crate) AuthClientInfo);
+
+impl<S: Send + Sync> FromRequestParts<S> for ClientInfoExtractor {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let ip_address = forwarded_ip_address(&parts.headers).or_else(|| {
+            parts
+                .extensions
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(addr)| addr.ip().to_string())
+        });
+        let user_agent = header_value(&parts.headers, header::USER_AGENT.as_str());
+        Ok(Self(AuthClientInfo {
+            ip_address,
+            user_agent,
+        }))
+    }
+}
+
+fn forwarded_ip_address(headers: &HeaderMap) -> Option<String> {
+    header_value(headers, "x-forwarded-for")
+        .and_then(|value| {
+            value
+                .split(',')
+                .next()
+                .map(|hop| hop.trim().to_string())
+                .filter(|hop| !hop.is_empty())
+        })
+        .or_else(|| header_value(headers, "x-real-ip"))
+}
+
+fn header_value(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+        .map(|value| value.chars().take(CLIENT_INFO_MAX_LEN).collect())
+}
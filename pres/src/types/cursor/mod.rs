@@ -0,0 +1,95 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::DateTime;
+use uuid::Uuid;
+
+use common::{error::AppError, params::PageCursor};
+use dmn::repos::note::{SyncContinuation, SyncPosition, SyncToken};
+
+/// Serializes a [`PageCursor`] into an opaque URL-safe token.
+pub(crate) fn encode_page_cursor(cursor: &PageCursor) -> String {
+    let mut bytes = [0u8; 24];
+    bytes[..8].copy_from_slice(&cursor.created_at.timestamp_micros().to_be_bytes());
+    bytes[8..].copy_from_slice(cursor.id.as_bytes());
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+pub(crate) fn decode_page_cursor(token: &str) -> Result<PageCursor, AppError> {
+    const BAD_REQUEST_ERR_STR: &str = "Invalid cursor!";
+
+    let bytes = URL_SAFE_NO_PAD
+        .decode(token)
+        .map_err(|err| AppError::bad_request_with_private(BAD_REQUEST_ERR_STR, err.to_string()))?;
+    let (micros, id) = bytes
+        .split_first_chunk::<8>()
+        .ok_or_else(|| AppError::bad_request(BAD_REQUEST_ERR_STR))?;
+    let created_at = DateTime::from_timestamp_micros(i64::from_be_bytes(*micros))
+        .ok_or_else(|| AppError::bad_request(BAD_REQUEST_ERR_STR))?;
+    let id = Uuid::from_slice(id)
+        .map_err(|err| AppError::bad_request_with_private(BAD_REQUEST_ERR_STR, err.to_string()))?;
+    Ok(PageCursor { created_at, id })
+}
+
+/// Serializes a [`SyncToken`] into an opaque URL-safe token.
+pub(crate) fn encode_sync_token(token: &SyncToken) -> String {
+    URL_SAFE_NO_PAD.encode(token.0.to_be_bytes())
+}
+
+pub(crate) fn decode_sync_token(token: &str) -> Result<SyncToken, AppError> {
+    const BAD_REQUEST_ERR_STR: &str = "Invalid sync token!";
+
+    let bytes = URL_SAFE_NO_PAD
+        .decode(token)
+        .map_err(|err| AppError::bad_request_with_private(BAD_REQUEST_ERR_STR, err.to_string()))?;
+    let bytes = <[u8; 8]>::try_from(bytes.as_slice())
+        .map_err(|_| AppError::bad_request(BAD_REQUEST_ERR_STR))?;
+    Ok(SyncToken(u64::from_be_bytes(bytes)))
+}
+
+/// Serializes a [`SyncContinuation`] into an opaque URL-safe token.
+pub(crate) fn encode_sync_continuation(continuation: &SyncContinuation) -> String {
+    let mut bytes = Vec::with_capacity(40);
+    bytes.extend_from_slice(&continuation.next_token.0.to_be_bytes());
+    bytes.extend_from_slice(&continuation.after.change_xid.to_be_bytes());
+    bytes.extend_from_slice(continuation.after.note_id.as_bytes());
+    // Full syncs have no `since` token, leaving it out
+    if let Some(since) = continuation.since {
+        bytes.extend_from_slice(&since.0.to_be_bytes());
+    }
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+pub(crate) fn decode_sync_continuation(token: &str) -> Result<SyncContinuation, AppError> {
+    const BAD_REQUEST_ERR_STR: &str = "Invalid continuation!";
+
+    let bytes = URL_SAFE_NO_PAD
+        .decode(token)
+        .map_err(|err| AppError::bad_request_with_private(BAD_REQUEST_ERR_STR, err.to_string()))?;
+    let (next_token, rest) = bytes
+        .split_first_chunk::<8>()
+        .ok_or_else(|| AppError::bad_request(BAD_REQUEST_ERR_STR))?;
+    let (change_xid, rest) = rest
+        .split_first_chunk::<8>()
+        .ok_or_else(|| AppError::bad_request(BAD_REQUEST_ERR_STR))?;
+    let (note_id, rest) = rest
+        .split_first_chunk::<16>()
+        .ok_or_else(|| AppError::bad_request(BAD_REQUEST_ERR_STR))?;
+    let since = match rest.len() {
+        0 => None,
+        _ => {
+            let since = <[u8; 8]>::try_from(rest)
+                .map_err(|_| AppError::bad_request(BAD_REQUEST_ERR_STR))?;
+            Some(SyncToken(u64::from_be_bytes(since)))
+        }
+    };
+    Ok(SyncContinuation {
+        since,
+        next_token: SyncToken(u64::from_be_bytes(*next_token)),
+        after: SyncPosition {
+            change_xid: u64::from_be_bytes(*change_xid),
+            note_id: Uuid::from_bytes(*note_id),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,168 @@
+use super::*;
+
+#[test]
+fn test_sync_token_round_trip() {
+    for token in [SyncToken(0), SyncToken(748), SyncToken(u64::MAX)] {
+        let encoded = encode_sync_token(&token);
+        assert_eq!(decode_sync_token(&encoded).unwrap(), token);
+    }
+}
+
+#[test]
+fn test_invalid_sync_token_rejected() {
+    let invalid_tokens = vec![
+        "",
+        "not base64!",
+        // Too short and too long
+        "AAAAAAAAAA",
+        "AAAAAAAAAAAAAA",
+    ];
+
+    for token in invalid_tokens {
+        assert!(
+            decode_sync_token(token).is_err(),
+            "Accepted invalid sync token: {token}"
+        );
+    }
+}
+
+#[test]
+fn test_sync_continuation_round_trip() {
+    let after = SyncPosition {
+        change_xid: 1024,
+        note_id: Uuid::new_v4(),
+    };
+    let continuations = [
+        SyncContinuation {
+            since: None,
+            next_token: SyncToken(2048),
+            after,
+        },
+        SyncContinuation {
+            since: Some(SyncToken(512)),
+            next_token: SyncToken(2048),
+            after,
+        },
+    ];
+
+    for continuation in continuations {
+        let encoded = encode_sync_continuation(&continuation);
+        assert_eq!(decode_sync_continuation(&encoded).unwrap(), continuation);
+    }
+}
+
+#[test]
+fn test_invalid_sync_continuation_rejected() {
+    let continuation = SyncContinuation {
+        since: Some(SyncToken(512)),
+        next_token: SyncToken(2048),
+        after: SyncPosition {
+            change_xid: 1024,
+            note_id: Uuid::new_v4(),
+        },
+    };
+    let bytes = URL_SAFE_NO_PAD
+        .decode(encode_sync_continuation(&continuation))
+        .unwrap();
+    let invalid_tokens = vec![
+        String::new(),
+        "not base64!".into(),
+        // Truncated position
+        URL_SAFE_NO_PAD.encode(&bytes[..20]),
+        // Truncated since token
+        URL_SAFE_NO_PAD.encode(&bytes[..36]),
+        // Trailing bytes
+        URL_SAFE_NO_PAD.encode([bytes.as_slice(), &[0]].concat()),
+    ];
+
+    for token in invalid_tokens {
+        assert!(
+            decode_sync_continuation(&token).is_err(),
+            "Accepted invalid continuation: {token}"
+        );
+    }
+}
+
+fn page_cursor() -> PageCursor {
+    PageCursor {
+        created_at: DateTime::from_timestamp_micros(1_735_725_600_123_456).unwrap(),
+        id: Uuid::new_v4(),
+    }
+}
+
+#[test]
+fn test_page_cursor_round_trip() {
+    let cursors = [
+        page_cursor(),
+        // Dates before the epoch have negative timestamps
+        PageCursor {
+            created_at: DateTime::from_timestamp_micros(-1).unwrap(),
+            id: Uuid::nil(),
+        },
+    ];
+
+    for cursor in cursors {
+        let encoded = encode_page_cursor(&cursor);
+        assert_eq!(decode_page_cursor(&encoded).unwrap(), cursor);
+    }
+}
+
+#[test]
+fn test_page_cursor_keeps_microseconds_only() {
+    let created_at = DateTime::from_timestamp(1_735_725_600, 123_456_789).unwrap();
+    let cursor = PageCursor {
+        created_at,
+        id: Uuid::new_v4(),
+    };
+
+    let decoded = decode_page_cursor(&encode_page_cursor(&cursor)).unwrap();
+
+    // Postgres timestamps have a microsecond precision too
+    assert_eq!(
+        decoded.created_at,
+        DateTime::from_timestamp(1_735_725_600, 123_456_000).unwrap()
+    );
+}
+
+#[test]
+fn test_garbage_page_cursor_rejected() {
+    let invalid_cursors = vec![
+        String::new(),
+        "not base64!".into(),
+        "AAAA".into(),
+        // Valid base64 one byte short of a cursor
+        "a".repeat(31),
+    ];
+
+    for cursor in invalid_cursors {
+        assert!(
+            decode_page_cursor(&cursor).is_err(),
+            "Accepted invalid cursor: {cursor}"
+        );
+    }
+}
+
+#[test]
+fn test_tampered_page_cursor_rejected() {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(encode_page_cursor(&page_cursor()))
+        .unwrap();
+    let mut out_of_range = bytes.clone();
+    out_of_range[..8].copy_from_slice(&i64::MAX.to_be_bytes());
+    let invalid_cursors = vec![
+        // Truncated and extended id
+        URL_SAFE_NO_PAD.encode(&bytes[..23]),
+        URL_SAFE_NO_PAD.encode([bytes.as_slice(), &[0]].concat()),
+        // Timestamp beyond the supported dates
+        URL_SAFE_NO_PAD.encode(out_of_range),
+        // Standard alphabet encoding
+        base64::engine::general_purpose::STANDARD.encode([0xfb; 24]),
+    ];
+
+    for cursor in invalid_cursors {
+        assert!(
+            decode_page_cursor(&cursor).is_err(),
+            "Accepted tampered cursor: {cursor}"
+        );
+    }
+}
@@ -20,6 +20,7 @@ impl PresentationError {
             AppError::BadRequest(_) => StatusCode::BAD_REQUEST,
             AppError::NotFound(_) => StatusCode::NOT_FOUND,
             AppError::Conflict(_) => StatusCode::CONFLICT,
+            AppError::PreconditionFailed(_) => StatusCode::PRECONDITION_FAILED,
             AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
             AppError::Forbidden(_) => StatusCode::FORBIDDEN,
         }
@@ -30,12 +31,14 @@ impl axum::response::IntoResponse for PresentationError {
     fn into_response(self) -> axum::response::Response {
         self.0.log();
 
-        let body = axum::Json(json!({
-            "error": {
-                "type": self.0.error_type(),
-                "message": self.0.public_info,
-            }
-        }));
+        let mut error = json!({
+            "type": self.0.error_type(),
+            "message": self.0.public_info,
+        });
+        if !self.0.details.is_empty() {
+            error["details"] = json!(self.0.details);
+        }
+        let body = axum::Json(json!({ "error": error }));
         (self.status_code(), body).into_response()
     }
 }
@@ -0,0 +1,71 @@
+use axum::{
+    extract::FromRequestParts,
+    http::{header, request::Parts, HeaderName, HeaderValue},
+};
+
+use crate::types::error::PresentationError;
+use common::error::AppError;
+
+/// Response header pair exposing a resource version as a strong entity tag.
+pub(crate) type ETagHeader = [(HeaderName, HeaderValue); 1];
+
+pub(crate) fn etag_header(version: u32) -> ETagHeader {
+    let etag = HeaderValue::from_str(&format!("\"{version}\""))
+        .expect("Entity tags built from integers are valid header values");
+    [(header::ETAG, etag)]
+}
+
+/// Extracts the resource versions expected by the `If-Match` request header, any of them matching.<br />
+/// Resolves to no version when the header is missing or set to `*`.<br />
+pub(crate) struct IfMatchExtractor(pub(crate) Vec<u32>);
+
+impl<S: Send + Sync> FromRequestParts<S> for IfMatchExtractor {
+    type Rejection = PresentationError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let versions = parse_if_match(parts.headers.get_all(header::IF_MATCH).iter())?;
+        Ok(Self(versions))
+    }
+}
+
+/// Parses the comma-separated entity tags of the `If-Match` header lines.<br />
+/// Weak entity tags are skipped as they never match, failing the precondition when there's nothing else.
+fn parse_if_match<'a>(
+    header_values: impl Iterator<Item = &'a HeaderValue>,
+) -> Result<Vec<u32>, AppError> {
+    const BAD_REQUEST_ERR_STR: &str = "Invalid If-Match header!";
+    const WEAK_ETAG_ERR_STR: &str = "Weak entity tags never satisfy If-Match!";
+
+    let mut etags = Vec::new();
+    for header_value in header_values {
+        let value = header_value.to_str().map_err(|err| {
+            AppError::bad_request_with_private(BAD_REQUEST_ERR_STR, err.to_string())
+        })?;
+        etags.extend(value.split(',').map(str::trim));
+    }
+
+    match etags[..] {
+        [] => return Ok(Vec::new()),
+        ["*"] => return Ok(Vec::new()),
+        _ => {}
+    }
+    let mut versions = Vec::with_capacity(etags.len());
+    for etag in &etags {
+        if etag.starts_with("W/") {
+            continue;
+        }
+        let version = etag
+            .strip_prefix('"')
+            .and_then(|etag| etag.strip_suffix('"'))
+            .and_then(|etag| etag.parse::<u32>().ok())
+            .ok_or_else(|| AppError::bad_request(BAD_REQUEST_ERR_STR))?;
+        versions.push(version);
+    }
+    if versions.is_empty() {
+        return Err(AppError::precondition_failed(WEAK_ETAG_ERR_STR));
+    }
+    Ok(versions)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,53 @@
+use super::*;
+
+fn if_match(values: &[&str]) -> Result<Vec<u32>, AppError> {
+    let header_values: Vec<_> = values
+        .iter()
+        .map(|value| HeaderValue::from_str(value).unwrap())
+        .collect();
+    parse_if_match(header_values.iter())
+}
+
+#[test]
+fn test_if_match_missing_or_any() {
+    assert_eq!(if_match(&[]).unwrap(), Vec::<u32>::new());
+    assert_eq!(if_match(&["*"]).unwrap(), Vec::<u32>::new());
+    assert_eq!(if_match(&[" * "]).unwrap(), Vec::<u32>::new());
+}
+
+#[test]
+fn test_if_match_single_etag() {
+    assert_eq!(if_match(&["\"3\""]).unwrap(), vec![3]);
+}
+
+#[test]
+fn test_if_match_etag_list() {
+    assert_eq!(if_match(&["\"1\", \"2\",\"3\""]).unwrap(), vec![1, 2, 3]);
+    // Repeated header lines combine into a single list
+    assert_eq!(if_match(&["\"1\"", "\"2\", \"3\""]).unwrap(), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_if_match_skips_weak_etags() {
+    assert_eq!(if_match(&["W/\"1\", \"2\""]).unwrap(), vec![2]);
+
+    let result = if_match(&["W/\"1\", W/\"2\""]);
+
+    assert!(matches!(result, Err(AppError::PreconditionFailed(_))));
+}
+
+#[test]
+fn test_if_match_rejects_invalid_etags() {
+    let invalid_values = vec![
+        "", "3", "\"3", "\"abc\"", "\"-1\"", "\"1\",", "\"1\", 2",
+        // Any version can't be combined with specific ones
+        "*, \"1\"",
+    ];
+
+    for value in invalid_values {
+        assert!(
+            matches!(if_match(&[value]), Err(AppError::BadRequest(_))),
+            "Accepted If-Match: {value}"
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Deserializer};
+
+/// Tells apart omitted JSON Merge Patch (RFC 7396) members from explicit `null` ones.<br />
+/// Use with `#[serde(default, deserialize_with = "...")]` on `Option<Option<T>>` fields:
+/// omitted members stay `None` while `null` ones become `Some(None)`.
+pub(crate) fn deserialize_patch_field<'de, T, D>(
+    deserializer: D,
+) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
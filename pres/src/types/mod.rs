@@ -1,3 +1,7 @@
+pub(crate) mod client;
+pub(crate) mod cursor;
 pub(crate) mod error;
+pub(crate) mod etag;
 pub mod http;
+pub(crate) mod merge_patch;
 pub(crate) mod params;
@@ -0,0 +1,315 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::types::cursor::decode_page_cursor;
+use app::archive::note_archive::NoteArchiveFormat;
+use common::{error::AppError, params as cmn};
+use dmn::{
+    entities::note_import_job::NoteImportFormat,
+    repos::note::{
+        ArchivedFilter, DateRangeFilter, NoteSort, NoteSortField, NotebookFilter, TagFilter,
+    },
+};
+
+const TITLE_PREFIX_MAX_LENGTH: usize = 255;
+
+#[derive(Deserialize, IntoParams)]
+pub struct PaginationParams {
+    pub skip: u32,
+    pub limit: u32,
+}
+
+impl From<PaginationParams> for cmn::PaginationParams {
+    fn from(pagination: PaginationParams) -> Self {
+        Self {
+            skip: pagination.skip,
+            limit: pagination.limit,
+        }
+    }
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct CursorPaginationParams {
+    /// Number of rows to skip with offset pagination, 0 by default. Mutually exclusive with `cursor`.
+    pub skip: Option<u32>,
+    pub limit: u32,
+    /// `next_cursor` of the previous page, or empty for the first one. Switches to cursor
+    /// pagination, offset pagination being used without it.
+    pub cursor: Option<String>,
+}
+
+impl TryFrom<CursorPaginationParams> for cmn::Pagination {
+    type Error = AppError;
+
+    fn try_from(pagination: CursorPaginationParams) -> Result<Self, Self::Error> {
+        match (pagination.skip, pagination.cursor) {
+            (Some(_), Some(_)) => Err(AppError::bad_request(
+                "The skip and cursor parameters are mutually exclusive!",
+            )),
+            (skip, None) => Ok(cmn::PaginationParams {
+                skip: skip.unwrap_or_default(),
+                limit: pagination.limit,
+            }
+            .into()),
+            (None, Some(cursor)) => Ok(cmn::CursorPaginationParams {
+                after: match cursor.as_str() {
+                    "" => None,
+                    cursor => Some(decode_page_cursor(cursor)?),
+                },
+                limit: pagination.limit,
+            }
+            .into()),
+        }
+    }
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct TagFilterParams {
+    /// Comma-separated list of tag ids.
+    pub tags: Option<String>,
+    /// Whether notes must carry any (default) or all of the given tags.
+    #[param(inline)]
+    pub tag_match: Option<TagMatchMode>,
+}
+
+#[derive(Deserialize, ToSchema, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TagMatchMode {
+    #[default]
+    Any,
+    All,
+}
+
+impl TryFrom<TagFilterParams> for Option<TagFilter> {
+    type Error = AppError;
+
+    fn try_from(params: TagFilterParams) -> Result<Self, Self::Error> {
+        let Some(tags) = params.tags else {
+            return Ok(None);
+        };
+        let tag_ids = tags
+            .split(',')
+            .map(str::trim)
+            .filter(|tag_id| !tag_id.is_empty())
+            .map(|tag_id| {
+                Uuid::parse_str(tag_id)
+                    .map_err(|_| AppError::bad_request(format!("Invalid tag id ({tag_id})!")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if tag_ids.is_empty() {
+            return Ok(None);
+        }
+        let tag_filter = match params.tag_match.unwrap_or_default() {
+            TagMatchMode::Any => TagFilter::AnyOf(tag_ids),
+            TagMatchMode::All => TagFilter::AllOf(tag_ids),
+        };
+        Ok(Some(tag_filter))
+    }
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct NotebookFilterParams {
+    pub notebook_id: Option<Uuid>,
+    /// Whether notes of descendant notebooks should be included as well.
+    pub recursive: Option<bool>,
+}
+
+impl From<NotebookFilterParams> for Option<NotebookFilter> {
+    fn from(params: NotebookFilterParams) -> Self {
+        params.notebook_id.map(|notebook_id| NotebookFilter {
+            notebook_id,
+            recursive: params.recursive.unwrap_or_default(),
+        })
+    }
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct NoteFlagFilterParams {
+    /// Whether archived notes are left out (default), included or exclusively matched.
+    #[param(inline)]
+    pub archived: Option<ArchivedMode>,
+    /// Only matches favorite (`true`) or non favorite (`false`) notes.
+    pub favorite: Option<bool>,
+}
+
+#[derive(Deserialize, ToSchema, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ArchivedMode {
+    #[default]
+    Exclude,
+    Include,
+    Only,
+}
+
+impl From<ArchivedMode> for ArchivedFilter {
+    fn from(mode: ArchivedMode) -> Self {
+        match mode {
+            ArchivedMode::Exclude => ArchivedFilter::Exclude,
+            ArchivedMode::Include => ArchivedFilter::Include,
+            ArchivedMode::Only => ArchivedFilter::Only,
+        }
+    }
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct NoteRangeFilterParams {
+    /// Inclusive lower bound of the creation date (RFC 3339).
+    pub created_from: Option<String>,
+    /// Exclusive upper bound of the creation date (RFC 3339).
+    pub created_to: Option<String>,
+    /// Inclusive lower bound of the last update date (RFC 3339).
+    pub updated_from: Option<String>,
+    /// Exclusive upper bound of the last update date (RFC 3339).
+    pub updated_to: Option<String>,
+    /// Only matches notes whose title starts with the given text, ignoring case.
+    pub title_prefix: Option<String>,
+}
+
+/// Validated [`NoteRangeFilterParams`].
+pub struct NoteRangeFilter {
+    pub created: DateRangeFilter,
+    pub updated: DateRangeFilter,
+    pub title_prefix: Option<String>,
+}
+
+impl TryFrom<NoteRangeFilterParams> for NoteRangeFilter {
+    type Error = AppError;
+
+    fn try_from(params: NoteRangeFilterParams) -> Result<Self, Self::Error> {
+        let created = parse_date_range("created", params.created_from, params.created_to)?;
+        let updated = parse_date_range("updated", params.updated_from, params.updated_to)?;
+        let title_prefix = match params.title_prefix {
+            Some(title_prefix) if title_prefix.is_empty() => {
+                return Err(AppError::bad_request("Title prefix must not be empty!"));
+            }
+            Some(title_prefix) if title_prefix.chars().count() > TITLE_PREFIX_MAX_LENGTH => {
+                return Err(AppError::bad_request(format!(
+                    "Title prefix must be at most {TITLE_PREFIX_MAX_LENGTH} characters long!"
+                )));
+            }
+            title_prefix => title_prefix,
+        };
+        Ok(Self {
+            created,
+            updated,
+            title_prefix,
+        })
+    }
+}
+
+fn parse_date_range(
+    name: &str,
+    from: Option<String>,
+    to: Option<String>,
+) -> Result<DateRangeFilter, AppError> {
+    let parse_date = |bound: &str, value: Option<String>| {
+        value
+            .map(|value| {
+                DateTime::parse_from_rfc3339(&value)
+                    .map(|date| date.with_timezone(&Utc))
+                    .map_err(|_| {
+                        AppError::bad_request(format!("Invalid {name}_{bound} date ({value})!"))
+                    })
+            })
+            .transpose()
+    };
+    let range = DateRangeFilter {
+        from: parse_date("from", from)?,
+        to: parse_date("to", to)?,
+    };
+    if let DateRangeFilter {
+        from: Some(from),
+        to: Some(to),
+    } = range
+    {
+        if from >= to {
+            return Err(AppError::bad_request(format!(
+                "{name}_from must be earlier than {name}_to!"
+            )));
+        }
+    }
+    Ok(range)
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct NoteSortParams {
+    /// Field to sort by: `created_at` (default), `updated_at` or `title`.
+    pub sort_by: Option<String>,
+    /// Sort direction: `asc` or `desc`. Defaults to `asc` for `title` and `desc` otherwise.
+    pub sort_order: Option<String>,
+}
+
+impl TryFrom<NoteSortParams> for NoteSort {
+    type Error = AppError;
+
+    fn try_from(params: NoteSortParams) -> Result<Self, Self::Error> {
+        let field = match params.sort_by.as_deref() {
+            None | Some("created_at") => NoteSortField::CreatedAt,
+            Some("updated_at") => NoteSortField::UpdatedAt,
+            Some("title") => NoteSortField::Title,
+            Some(sort_by) => {
+                return Err(AppError::bad_request(format!(
+                    "Invalid sort_by value ({sort_by})!"
+                )));
+            }
+        };
+        let direction = match params.sort_order.as_deref() {
+            None if field == NoteSortField::Title => cmn::SortDirection::Asc,
+            None => cmn::SortDirection::Desc,
+            Some("asc") => cmn::SortDirection::Asc,
+            Some("desc") => cmn::SortDirection::Desc,
+            Some(sort_order) => {
+                return Err(AppError::bad_request(format!(
+                    "Invalid sort_order value ({sort_order})!"
+                )));
+            }
+        };
+        Ok(Self { field, direction })
+    }
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct NoteArchiveFormatParams {
+    /// Archive format: `markdown` (default), a ZIP archive of Markdown files with front matter.
+    pub format: Option<String>,
+}
+
+impl TryFrom<NoteArchiveFormatParams> for NoteArchiveFormat {
+    type Error = AppError;
+
+    fn try_from(params: NoteArchiveFormatParams) -> Result<Self, Self::Error> {
+        match params.format.as_deref() {
+            None | Some("markdown") => Ok(NoteArchiveFormat::Markdown),
+            Some(format) => Err(AppError::bad_request(format!(
+                "Invalid format value ({format})!"
+            ))),
+        }
+    }
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct NoteImportFormatParams {
+    /// Imported file format: `markdown`, a ZIP archive of Markdown files with front matter,
+    /// `enex`, an Evernote export, or `json`, a JSON array of notes.
+    pub format: String,
+}
+
+impl TryFrom<NoteImportFormatParams> for NoteImportFormat {
+    type Error = AppError;
+
+    fn try_from(params: NoteImportFormatParams) -> Result<Self, Self::Error> {
+        match params.format.as_str() {
+            "markdown" => Ok(NoteImportFormat::Markdown),
+            "enex" => Ok(NoteImportFormat::Enex),
+            "json" => Ok(NoteImportFormat::Json),
+            format => Err(AppError::bad_request(format!(
+                "Invalid format value ({format})!"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
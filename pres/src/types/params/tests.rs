@@ -0,0 +1,188 @@
+use super::*;
+
+use crate::types::cursor::encode_page_cursor;
+
+fn pagination(skip: Option<u32>, cursor: Option<&str>) -> Result<cmn::Pagination, AppError> {
+    CursorPaginationParams {
+        skip,
+        limit: 20,
+        cursor: cursor.map(str::to_string),
+    }
+    .try_into()
+}
+
+#[test]
+fn test_pagination_defaults_to_offset() {
+    let pagination = pagination(None, None).unwrap();
+
+    assert!(matches!(
+        pagination,
+        cmn::Pagination::Offset(cmn::PaginationParams { skip: 0, limit: 20 })
+    ));
+}
+
+#[test]
+fn test_pagination_with_skip() {
+    let pagination = pagination(Some(40), None).unwrap();
+
+    assert!(matches!(
+        pagination,
+        cmn::Pagination::Offset(cmn::PaginationParams {
+            skip: 40,
+            limit: 20
+        })
+    ));
+}
+
+#[test]
+fn test_pagination_empty_cursor_starts_cursor_pagination() {
+    let pagination = pagination(None, Some("")).unwrap();
+
+    assert!(matches!(
+        pagination,
+        cmn::Pagination::Cursor(cmn::CursorPaginationParams {
+            after: None,
+            limit: 20
+        })
+    ));
+}
+
+#[test]
+fn test_pagination_with_cursor() {
+    let cursor = cmn::PageCursor {
+        created_at: DateTime::from_timestamp(1_735_725_600, 0).unwrap(),
+        id: Uuid::new_v4(),
+    };
+
+    let pagination = pagination(None, Some(&encode_page_cursor(&cursor))).unwrap();
+
+    let cmn::Pagination::Cursor(pagination) = pagination else {
+        panic!("Expected cursor pagination");
+    };
+    assert_eq!(pagination.after, Some(cursor));
+    assert_eq!(pagination.limit, 20);
+}
+
+#[test]
+fn test_pagination_rejects_skip_with_cursor() {
+    assert!(pagination(Some(0), Some("")).is_err());
+}
+
+#[test]
+fn test_pagination_rejects_invalid_cursor() {
+    assert!(pagination(None, Some("not a cursor")).is_err());
+}
+
+fn date_range(from: Option<&str>, to: Option<&str>) -> Result<DateRangeFilter, AppError> {
+    parse_date_range("created", from.map(str::to_string), to.map(str::to_string))
+}
+
+#[test]
+fn test_date_range_bounds_optional() {
+    let range = date_range(None, None).unwrap();
+    assert!(range.from.is_none() && range.to.is_none());
+
+    let range = date_range(Some("2025-01-01T00:00:00Z"), None).unwrap();
+    assert_eq!(range.from, DateTime::from_timestamp(1_735_689_600, 0));
+    assert!(range.to.is_none());
+}
+
+#[test]
+fn test_date_range_converts_to_utc() {
+    let range = date_range(
+        Some("2025-01-01T02:00:00+02:00"),
+        Some("2025-01-02T00:00:00Z"),
+    )
+    .unwrap();
+
+    assert_eq!(range.from, DateTime::from_timestamp(1_735_689_600, 0));
+    assert_eq!(range.to, DateTime::from_timestamp(1_735_776_000, 0));
+}
+
+#[test]
+fn test_date_range_rejects_inverted_and_empty_ranges() {
+    let inverted = date_range(Some("2025-01-02T00:00:00Z"), Some("2025-01-01T00:00:00Z"));
+    assert!(inverted.is_err());
+
+    // The upper bound is exclusive
+    let empty = date_range(Some("2025-01-01T00:00:00Z"), Some("2025-01-01T00:00:00Z"));
+    assert!(empty.is_err());
+}
+
+#[test]
+fn test_date_range_rejects_bad_timestamps() {
+    let invalid_dates = vec![
+        "",
+        "yesterday",
+        "2025-01-01",
+        // Missing offset
+        "2025-01-01T00:00:00",
+        "2025-13-01T00:00:00Z",
+        "1735689600",
+    ];
+
+    for date in invalid_dates {
+        assert!(
+            date_range(Some(date), None).is_err(),
+            "Accepted date: {date}"
+        );
+        assert!(
+            date_range(None, Some(date)).is_err(),
+            "Accepted date: {date}"
+        );
+    }
+}
+
+fn note_sort(sort_by: Option<&str>, sort_order: Option<&str>) -> Result<NoteSort, AppError> {
+    NoteSortParams {
+        sort_by: sort_by.map(str::to_string),
+        sort_order: sort_order.map(str::to_string),
+    }
+    .try_into()
+}
+
+#[test]
+fn test_note_sort_defaults() {
+    let sort = note_sort(None, None).unwrap();
+    assert!(sort.field == NoteSortField::CreatedAt);
+    assert!(matches!(sort.direction, cmn::SortDirection::Desc));
+
+    let sort = note_sort(Some("updated_at"), None).unwrap();
+    assert!(sort.field == NoteSortField::UpdatedAt);
+    assert!(matches!(sort.direction, cmn::SortDirection::Desc));
+
+    // Titles read alphabetically
+    let sort = note_sort(Some("title"), None).unwrap();
+    assert!(sort.field == NoteSortField::Title);
+    assert!(matches!(sort.direction, cmn::SortDirection::Asc));
+}
+
+#[test]
+fn test_note_sort_with_order() {
+    let sort = note_sort(Some("title"), Some("desc")).unwrap();
+    assert!(sort.field == NoteSortField::Title);
+    assert!(matches!(sort.direction, cmn::SortDirection::Desc));
+
+    let sort = note_sort(None, Some("asc")).unwrap();
+    assert!(sort.field == NoteSortField::CreatedAt);
+    assert!(matches!(sort.direction, cmn::SortDirection::Asc));
+}
+
+#[test]
+fn test_note_sort_rejects_unknown_values() {
+    let invalid_sorts = vec![
+        (Some("content"), None),
+        (Some("createdAt"), None),
+        (Some("Title"), None),
+        (Some(""), None),
+        (None, Some("descending")),
+        (Some("title"), Some("ASC")),
+    ];
+
+    for (sort_by, sort_order) in invalid_sorts {
+        assert!(
+            note_sort(sort_by, sort_order).is_err(),
+            "Accepted sort: {sort_by:?} {sort_order:?}"
+        );
+    }
+}
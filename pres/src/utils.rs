@@ -1,4 +1,10 @@
-use axum::{extract::Request, routing::IntoMakeService, serve::Serve, Router, ServiceExt};
+use axum::{
+    extract::{connect_info::IntoMakeServiceWithConnectInfo, ConnectInfo, Request},
+    middleware::AddExtension,
+    serve::Serve,
+    Router, ServiceExt,
+};
+use std::net::SocketAddr;
 use tokio::net::TcpListener;
 use tower_http::normalize_path::NormalizePath;
 
@@ -6,6 +12,8 @@ use crate::{http::build_router, types::http::ServerAddress};
 use app::state::AppState;
 use common::error::AppError;
 
+type HttpService = NormalizePath<Router>;
+
 fn get_server_address(api_port: u16) -> String {
     format!("0.0.0.0:{}", api_port)
 }
@@ -24,7 +32,11 @@ pub async fn build_http_server(
             err.to_string(),
         )
     })?;
-    let server = axum::serve(listener, ServiceExt::<Request>::into_make_service(router));
+    // Exposes peer addresses to handlers, e.g. for auth session metadata
+    let server = axum::serve(
+        listener,
+        ServiceExt::<Request>::into_make_service_with_connect_info::<SocketAddr>(router),
+    );
 
     Ok(BuildHttpServerResponse {
         server,
@@ -33,6 +45,10 @@ pub async fn build_http_server(
 }
 
 pub struct BuildHttpServerResponse {
-    pub server: Serve<TcpListener, IntoMakeService<NormalizePath<Router>>, NormalizePath<Router>>,
+    pub server: Serve<
+        TcpListener,
+        IntoMakeServiceWithConnectInfo<HttpService, SocketAddr>,
+        AddExtension<HttpService, ConnectInfo<SocketAddr>>,
+    >,
     pub server_addr: ServerAddress,
 }